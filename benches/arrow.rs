@@ -0,0 +1,60 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use arrow_array::{BooleanArray, FixedSizeBinaryArray, UInt32Array};
+use bogon::{is_bogon_v4_array, is_bogon_v6_array, BogonExt};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// The number of rows, like a few row groups of a flow log.
+const ROWS: usize = 1 << 22;
+
+fn arrow_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arrow 4M");
+    group.throughput(Throughput::Elements(ROWS as u64));
+    group.sample_size(20);
+
+    // Every tenth row is null.
+    let v4 = (0..ROWS)
+        .map(|i| (i % 10 != 0).then(rand::random::<u32>))
+        .collect::<UInt32Array>();
+    group.bench_function("ipv4 per element", |b| {
+        b.iter(|| {
+            black_box(&v4)
+                .iter()
+                .map(|ip| ip.map(|bits| Ipv4Addr::from_bits(bits).is_bogon()))
+                .collect::<BooleanArray>()
+        })
+    });
+    group.bench_function("ipv4 kernel", |b| {
+        b.iter(|| is_bogon_v4_array(black_box(&v4)))
+    });
+
+    // Inside of 2000::/3, where most of the tables are.
+    let v6 = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+        (0..ROWS).map(|i| {
+            let bits = rand::random::<u128>() >> 3 | 1 << 125;
+            (i % 10 != 0).then_some(bits.to_be_bytes())
+        }),
+        16,
+    )
+    .unwrap();
+    group.bench_function("ipv6 per element", |b| {
+        b.iter(|| {
+            black_box(&v6)
+                .iter()
+                .map(|ip| {
+                    ip.map(|octets| {
+                        Ipv6Addr::from(<[u8; 16]>::try_from(octets).unwrap()).is_bogon()
+                    })
+                })
+                .collect::<BooleanArray>()
+        })
+    });
+    group.bench_function("ipv6 kernel", |b| {
+        b.iter(|| is_bogon_v6_array(black_box(&v6)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, arrow_benchmark);
+criterion_main!(benches);
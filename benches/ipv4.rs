@@ -1,7 +1,7 @@
 use core::net::{Ipv4Addr, Ipv6Addr};
 
-use bogon::BogonExt;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use bogon::{is_bogon_v4_batch, is_bogon_v6_batch, BogonExt};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 
 fn generate_random_ipv4() -> Vec<Ipv4Addr> {
     (0..1_024)
@@ -33,6 +33,19 @@ fn generate_random_ipv6() -> Vec<Ipv6Addr> {
         .collect()
 }
 
+/// Returns addresses inside of the /12s IANA allocated to the regional internet registries, where
+/// most real traffic comes from.
+fn generate_allocated_ipv6() -> Vec<Ipv6Addr> {
+    const RIR_BLOCKS: [u16; 5] = [0x2400, 0x2600, 0x2800, 0x2a00, 0x2c00];
+    (0..1_024)
+        .map(|_| {
+            let block = RIR_BLOCKS[rand::random::<usize>() % RIR_BLOCKS.len()];
+            let bits = u128::from(block) << 112 | rand::random::<u128>() >> 12;
+            Ipv6Addr::from_bits(bits)
+        })
+        .collect()
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("ipv4 is_bogon", |b| {
         let ips = generate_random_ipv4();
@@ -51,7 +64,76 @@ fn criterion_benchmark(c: &mut Criterion) {
             }
         })
     });
+
+    c.bench_function("ipv6 is_bogon allocated", |b| {
+        let ips = generate_allocated_ipv6();
+        b.iter(|| {
+            for ip in &ips {
+                black_box(ip.is_bogon());
+            }
+        })
+    });
+}
+
+/// The number of addresses of the batch benches, like a chunk of a flow export.
+const BATCH: usize = 1 << 16;
+
+fn batch_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch 64K");
+    group.throughput(Throughput::Elements(BATCH as u64));
+
+    let v4 = (0..BATCH)
+        .map(|_| Ipv4Addr::from_bits(rand::random()))
+        .collect::<Vec<_>>();
+    group.bench_function("ipv4 per address", |b| {
+        b.iter(|| {
+            for ip in &v4 {
+                black_box(ip.is_bogon());
+            }
+        })
+    });
+    group.bench_function("ipv4 batch", |b| {
+        let mut out = vec![0; BATCH / 64];
+        b.iter(|| is_bogon_v4_batch(black_box(&v4), black_box(&mut out)))
+    });
+
+    // Private and other special-purpose /8s, which the first octet doesn't settle.
+    const MIXED_OCTETS: [u8; 5] = [100, 169, 172, 192, 198];
+    let mixed = (0..BATCH)
+        .map(|_| {
+            let octet = MIXED_OCTETS[rand::random::<usize>() % MIXED_OCTETS.len()];
+            Ipv4Addr::from_bits(u32::from(octet) << 24 | rand::random::<u32>() >> 8)
+        })
+        .collect::<Vec<_>>();
+    group.bench_function("ipv4 mixed per address", |b| {
+        b.iter(|| {
+            for ip in &mixed {
+                black_box(ip.is_bogon());
+            }
+        })
+    });
+    group.bench_function("ipv4 mixed batch", |b| {
+        let mut out = vec![0; BATCH / 64];
+        b.iter(|| is_bogon_v4_batch(black_box(&mixed), black_box(&mut out)))
+    });
+
+    let v6 = (0..BATCH / 1_024)
+        .flat_map(|_| generate_allocated_ipv6())
+        .collect::<Vec<_>>();
+    group.bench_function("ipv6 per address", |b| {
+        b.iter(|| {
+            for ip in &v6 {
+                black_box(ip.is_bogon());
+            }
+        })
+    });
+    group.bench_function("ipv6 batch", |b| {
+        let mut out = vec![0; BATCH / 64];
+        b.iter(|| is_bogon_v6_batch(black_box(&v6), black_box(&mut out)))
+    });
+
+    group.finish();
 }
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(benches, criterion_benchmark, batch_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,82 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use bogon::{classify, is_bogon_v4_batch, par_classify, par_is_bogon_v4_batch};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rayon::ThreadPoolBuilder;
+
+/// The number of addresses, like a slice of an offline dump.
+const LEN: usize = 1 << 20;
+
+/// Returns random IPv4 addresses, and IPv6 addresses inside of 2000::/3.
+fn generate_mixed() -> Vec<IpAddr> {
+    (0..LEN)
+        .map(|_| {
+            if rand::random() {
+                IpAddr::V4(Ipv4Addr::from_bits(rand::random()))
+            } else {
+                IpAddr::V6(Ipv6Addr::from_bits(rand::random::<u128>() >> 3 | 1 << 125))
+            }
+        })
+        .collect()
+}
+
+/// The thread counts to measure, up to every core.
+fn thread_counts() -> Vec<usize> {
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    let mut counts = (0..)
+        .map(|shift| 1 << shift)
+        .take_while(|&threads| threads < cores)
+        .collect::<Vec<_>>();
+    counts.push(cores);
+    counts
+}
+
+fn par_benchmark(c: &mut Criterion) {
+    let ips = generate_mixed();
+    let mut group = c.benchmark_group("classify 1M");
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.sample_size(20);
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            black_box(&ips)
+                .iter()
+                .map(|&ip| classify(ip))
+                .collect::<Vec<_>>()
+        })
+    });
+    for threads in thread_counts() {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("par_classify", threads), &ips, |b, ips| {
+            b.iter(|| pool.install(|| par_classify(black_box(ips))))
+        });
+    }
+    group.finish();
+
+    let v4 = (0..LEN)
+        .map(|_| Ipv4Addr::from_bits(rand::random()))
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("ipv4 batch 1M");
+    group.throughput(Throughput::Elements(LEN as u64));
+    group.sample_size(20);
+    group.bench_function("sequential", |b| {
+        let mut out = vec![0; LEN / 64];
+        b.iter(|| is_bogon_v4_batch(black_box(&v4), black_box(&mut out)))
+    });
+    for threads in thread_counts() {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("parallel", threads), &v4, |b, v4| {
+            let mut out = vec![0; LEN / 64];
+            b.iter(|| pool.install(|| par_is_bogon_v4_batch(black_box(v4), black_box(&mut out))))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, par_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use core::net::Ipv4Addr;
+use std::time::Instant;
+
+use bogon::{is_bogon_v4, v4_bogon_slash24_bitmap, BitmapChecker};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// The number of addresses, like a chunk of a flow export.
+const LEN: usize = 1 << 16;
+
+/// Returns random addresses, and addresses inside of the /8s the first octet doesn't settle.
+fn generate() -> [(&'static str, Vec<Ipv4Addr>); 2] {
+    const MIXED_OCTETS: [u8; 5] = [100, 169, 172, 192, 198];
+    let random = (0..LEN)
+        .map(|_| Ipv4Addr::from_bits(rand::random()))
+        .collect();
+    let mixed = (0..LEN)
+        .map(|_| {
+            let octet = MIXED_OCTETS[rand::random::<usize>() % MIXED_OCTETS.len()];
+            Ipv4Addr::from_bits(u32::from(octet) << 24 | rand::random::<u32>() >> 8)
+        })
+        .collect();
+    [("random", random), ("mixed /8s", mixed)]
+}
+
+fn slash24_benchmark(c: &mut Criterion) {
+    // Building the bitmap happens once, so it's timed once.
+    let start = Instant::now();
+    black_box(v4_bogon_slash24_bitmap());
+    println!("building the bitmap took {:?}", start.elapsed());
+
+    let checker = BitmapChecker::new();
+    for (name, ips) in generate() {
+        let mut group = c.benchmark_group(format!("slash24 {name}"));
+        group.throughput(Throughput::Elements(LEN as u64));
+        group.bench_function("is_bogon_v4", |b| {
+            b.iter(|| {
+                for &ip in black_box(&ips) {
+                    black_box(is_bogon_v4(ip));
+                }
+            })
+        });
+        group.bench_function("BitmapChecker", |b| {
+            b.iter(|| {
+                for &ip in black_box(&ips) {
+                    black_box(checker.is_bogon(ip));
+                }
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, slash24_benchmark);
+criterion_main!(benches);
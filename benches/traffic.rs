@@ -0,0 +1,120 @@
+//! `is_bogon_v4` on a realistic mix of traffic, next to uniformly random addresses.
+//!
+//! The mix defaults to [`MIX`], and `BOGON_TRAFFIC_MIX` overrides the weights, such as
+//! `BOGON_TRAFFIC_MIX=routable=90,rfc1918=10 cargo bench --bench traffic`.
+
+use core::net::Ipv4Addr;
+
+use bogon::{classify_v4, is_bogon_v4};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of addresses, like a chunk of a flow export.
+const LEN: usize = 1 << 16;
+
+/// The kinds of traffic and their default weights, in percent.
+const MIX: [(&str, u32); 8] = [
+    ("routable", 60),
+    ("rfc1918", 25),
+    ("loopback", 5),
+    ("cgnat", 4),
+    ("link-local", 3),
+    ("multicast", 2),
+    ("documentation", 1),
+    ("broadcast", 0),
+];
+
+/// Returns the networks of a kind of bogus traffic, picked from evenly.
+fn networks(kind: &str) -> &'static [(u32, u8)] {
+    match kind {
+        "rfc1918" => &[(0x0a00_0000, 8), (0xac10_0000, 12), (0xc0a8_0000, 16)],
+        "loopback" => &[(0x7f00_0000, 8)],
+        "cgnat" => &[(0x6440_0000, 10)],
+        "link-local" => &[(0xa9fe_0000, 16)],
+        "multicast" => &[(0xe000_0000, 4)],
+        "documentation" => &[(0xc000_0200, 24), (0xc633_6400, 24), (0xcb00_7100, 24)],
+        "broadcast" => &[(0xffff_ffff, 32)],
+        _ => panic!("unknown kind of traffic {kind:?}"),
+    }
+}
+
+/// Returns the weights of [`MIX`], with those `BOGON_TRAFFIC_MIX` names instead.
+fn weights() -> Vec<(&'static str, u32)> {
+    let mut mix = MIX.to_vec();
+    if let Ok(weights) = std::env::var("BOGON_TRAFFIC_MIX") {
+        for weight in weights.split(',') {
+            let (kind, weight) = weight.split_once('=').expect("expected kind=weight");
+            let entry = mix
+                .iter_mut()
+                .find(|(name, _)| *name == kind)
+                .unwrap_or_else(|| panic!("unknown kind of traffic {kind:?}"));
+            entry.1 = weight.parse().expect("expected a weight in percent");
+        }
+    }
+    mix
+}
+
+/// Returns addresses of the kinds of traffic in proportion to their weights. Routable addresses
+/// are random addresses outside of the bogons, and bogus addresses are random addresses inside of
+/// the networks of their kind.
+fn generate_realistic(rng: &mut StdRng) -> Vec<Ipv4Addr> {
+    let mix = weights();
+    let total = mix.iter().map(|&(_, weight)| weight).sum::<u32>();
+    assert!(total > 0, "the weights must not all be zero");
+    (0..LEN)
+        .map(|_| {
+            let mut pick = rng.gen_range(0..total);
+            let &(kind, _) = mix
+                .iter()
+                .find(|&&(_, weight)| {
+                    let found = pick < weight;
+                    pick = pick.saturating_sub(weight);
+                    found
+                })
+                .unwrap();
+            if kind == "routable" {
+                loop {
+                    let ip = Ipv4Addr::from_bits(rng.gen());
+                    if !is_bogon_v4(ip) {
+                        return ip;
+                    }
+                }
+            }
+            let networks = networks(kind);
+            let (network, prefix) = networks[rng.gen_range(0..networks.len())];
+            let host = u32::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+            Ipv4Addr::from_bits(network | rng.gen::<u32>() & host)
+        })
+        .collect()
+}
+
+fn traffic_benchmark(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(85);
+    let uniform = (0..LEN)
+        .map(|_| Ipv4Addr::from_bits(rng.gen()))
+        .collect::<Vec<_>>();
+    let realistic = generate_realistic(&mut rng);
+
+    for (name, ips) in [("uniform", uniform), ("realistic", realistic)] {
+        let mut group = c.benchmark_group(format!("traffic {name}"));
+        group.throughput(Throughput::Elements(LEN as u64));
+        group.bench_function("is_bogon_v4", |b| {
+            b.iter(|| {
+                for &ip in black_box(&ips) {
+                    black_box(is_bogon_v4(ip));
+                }
+            })
+        });
+        group.bench_function("classify_v4", |b| {
+            b.iter(|| {
+                for &ip in black_box(&ips) {
+                    black_box(classify_v4(ip));
+                }
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, traffic_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,1291 @@
+// The IPv6 registry is parsed and merged by the same modules the library uses at runtime.
+extern crate alloc;
+#[path = "src/allocations.rs"]
+mod allocations;
+#[path = "src/cidr.rs"]
+mod cidr;
+#[path = "src/compact.rs"]
+mod compact;
+#[cfg(feature = "download")]
+#[path = "src/csv_cache.rs"]
+mod csv_cache;
+#[path = "src/csv_source.rs"]
+mod csv_source;
+#[path = "src/decision_tree.rs"]
+mod decision_tree;
+#[path = "src/hextets.rs"]
+mod hextets;
+#[cfg(feature = "download")]
+#[path = "src/iana_client.rs"]
+mod iana_client;
+#[path = "src/iana_csv.rs"]
+#[allow(dead_code)]
+mod iana_csv;
+#[path = "src/ranges.rs"]
+#[allow(dead_code)]
+mod ranges;
+#[path = "src/registry_files.rs"]
+#[allow(dead_code)]
+mod registry_files;
+#[path = "src/sha256.rs"]
+mod sha256;
+#[path = "src/staleness.rs"]
+mod staleness;
+
+use allocations::AllocationTables;
+#[cfg(feature = "download")]
+use csv_cache::{Cache, Fetched};
+use csv_source::CsvSource;
+#[cfg(feature = "download")]
+use iana_client::Iana;
+use iana_csv::{AddressSpaceBlock, Assignment, CsvError, SpecialPurpose};
+use registry_files::{
+    RegistryFile, BUNDLED_SNAPSHOT_DATE, IPV4_ADDRESS_SPACE, IPV4_SPECIAL, IPV6_ASSIGNMENTS,
+    IPV6_SPECIAL, SNAPSHOT_DATE,
+};
+use staleness::{Date, Staleness};
+use std::io::Write;
+
+use std::{collections::HashMap, env, fmt::Display, path::Path, sync::Once, time::SystemTime};
+
+/// A list of distinct strings, so the generated code can refer to each string by index instead of
+/// repeating it for every row.
+#[derive(Debug, Default)]
+struct StringTable {
+    strings: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl StringTable {
+    /// Returns the index of a string, adding it to the table if it's new.
+    fn index(&mut self, string: &str) -> usize {
+        if let Some(&index) = self.indices.get(string) {
+            return index;
+        }
+        self.strings.push(string.to_string());
+        self.indices
+            .insert(string.to_string(), self.strings.len() - 1);
+        self.strings.len() - 1
+    }
+
+    /// Write the table as a constant array.
+    fn write(&self, file: &mut impl Write, name: &str) -> std::io::Result<()> {
+        writeln!(
+            file,
+            "pub const {}: [&str; {}] = [",
+            name,
+            self.strings.len()
+        )?;
+        for string in &self.strings {
+            writeln!(file, "    {:?},", string)?;
+        }
+        writeln!(file, "];")
+    }
+}
+
+/// The deduplicated strings referenced by both registries.
+#[derive(Debug, Default)]
+struct RegistryStrings {
+    rdap_urls: StringTable,
+    whois_servers: StringTable,
+}
+
+/// Returns the preferred RDAP base URL from the registry's RDAP column.
+///
+/// The column may list several URLs on separate lines, the first one is the HTTPS URL.
+fn rdap_url(rdap: &str) -> Option<&str> {
+    rdap.lines().map(str::trim).find(|url| !url.is_empty())
+}
+
+/// The recorded SHA-256 digest of the bundled IPv6 unicast address assignments, in the format
+/// `sha256sum` writes.
+const BUNDLED_IPV6_ASSIGNMENTS_SHA256: &str =
+    include_str!("ipv6-unicast-address-assignments.csv.sha256");
+
+/// The networks the `allow-*` features remove from the IPv4 bogons.
+const ALLOW_FEATURES: [(&str, u32, u8); 3] = [
+    ("allow-cgnat", 0x6440_0000, 10),
+    ("allow-benchmarking", 0xc612_0000, 15),
+    ("allow-reserved-240-4", 0xf000_0000, 4),
+];
+
+fn main() {
+    // With the `pregenerated` feature the library includes the checked-in copies of the generated
+    // files in `src/generated` instead.
+    if env::var_os("CARGO_FEATURE_PREGENERATED").is_some() {
+        if env::var_os("CARGO_FEATURE_DOWNLOAD").is_some() {
+            println!("cargo:warning=The `pregenerated` feature ignores the `download` feature");
+        }
+        warn_if_stale();
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=snapshot-date");
+        return;
+    }
+
+    // Read every registry up front, so that the dataset digest covers all of them.
+    let ipv6_assignments = get_csv(&IPV6_ASSIGNMENTS);
+    let ipv4_address_space = get_csv(&IPV4_ADDRESS_SPACE);
+    let ipv6_special = get_csv(&IPV6_SPECIAL);
+    let ipv4_special = get_csv(&IPV4_SPECIAL);
+    let digest = dataset_sha256([
+        ipv6_assignments.1,
+        ipv4_address_space.1,
+        ipv6_special.1,
+        ipv4_special.1,
+    ]);
+    let date = dataset_date([
+        &ipv6_assignments.0,
+        &ipv4_address_space.0,
+        &ipv6_special.0,
+        &ipv4_special.0,
+    ]);
+
+    // Parse the CSV file, keeping the ranges allocated to a regional internet registry.
+    let registry = get_ipv6_allocations(&ipv6_assignments);
+
+    // Merge neighboring ranges into larger blocks, and find the unallocated space between them.
+    let tables = allocations::allocation_tables(
+        &registry
+            .iter()
+            .map(|a| (a.network.to_bits(), a.prefix_len))
+            .collect::<Vec<_>>(),
+    );
+
+    // The `codegen-match` feature compiles the 32-bit ranges as a tree of `match` expressions.
+    write_decision_tree_file(&tables).unwrap();
+
+    // The `compact-data` feature compiles the bounds of the 32-bit ranges as deltas in LEB128.
+    write_boundaries_file(&tables).unwrap();
+
+    // URLs and servers are shared between many rows of both registries.
+    let mut strings = RegistryStrings::default();
+
+    // Write the merged ranges and the original registry rows to a file in the build directory.
+    write_file(&tables, &registry, &digest, &date, &mut strings).unwrap();
+
+    // The IPv4 address space registry lists the administrator of every /8.
+    write_ipv4_file(&get_ipv4_blocks(&ipv4_address_space), &mut strings).unwrap();
+
+    write_strings_file(&strings).unwrap();
+
+    // The IPv4 special-purpose registry lists the bogus networks and their exceptions.
+    write_ipv4_bogons_file(&get_ipv4_special_registry(&ipv4_special)).unwrap();
+
+    // The IPv6 special-purpose registry lists the bogus networks inside of 2000::/3, and the
+    // attributes of every special-purpose block.
+    let bogons = write_ipv6_special_file(&get_ipv6_special_registry(&ipv6_special)).unwrap();
+
+    // Most IPv6 addresses are settled by the bucket of their /16.
+    write_hextets_file(&tables, &bogons).unwrap();
+
+    // Tell Cargo to rerun the build script if the CSV files change.
+    println!("cargo:rerun-if-changed=ipv6-unicast-address-assignments.csv");
+    println!("cargo:rerun-if-changed=ipv6-unicast-address-assignments.csv.sha256");
+    println!("cargo:rerun-if-changed=ipv4-address-space.csv");
+    println!("cargo:rerun-if-changed=iana-ipv4-special-registry-1.csv");
+    println!("cargo:rerun-if-changed=iana-ipv6-special-registry-1.csv");
+    println!("cargo:rerun-if-changed=snapshot-date");
+    println!("cargo:rerun-if-changed=src/allocations.rs");
+    println!("cargo:rerun-if-changed=src/compact.rs");
+    println!("cargo:rerun-if-changed=src/csv_cache.rs");
+    println!("cargo:rerun-if-changed=src/csv_source.rs");
+    println!("cargo:rerun-if-changed=src/decision_tree.rs");
+    println!("cargo:rerun-if-changed=src/hextets.rs");
+    println!("cargo:rerun-if-changed=src/iana_client.rs");
+    println!("cargo:rerun-if-changed=src/iana_csv.rs");
+    println!("cargo:rerun-if-changed=src/ranges.rs");
+    println!("cargo:rerun-if-changed=src/registry_files.rs");
+    println!("cargo:rerun-if-changed=src/sha256.rs");
+    println!("cargo:rerun-if-changed=src/staleness.rs");
+}
+
+/// Downloads a registry CSV file, revalidating the copy cached by an earlier build.
+///
+/// Falls back to the cached copy when the IANA website is unreachable, and returns `None` when
+/// there's no cached copy either, so the build uses the bundled copy. With
+/// `BOGON_REQUIRE_FRESH_DATA=1` the build fails instead of falling back.
+#[cfg(feature = "download")]
+fn download_csv(registry: &RegistryFile) -> Option<&'static str> {
+    println!("cargo:rerun-if-env-changed=BOGON_CACHE_DIR");
+    println!("cargo:rerun-if-env-changed=BOGON_REQUIRE_FRESH_DATA");
+
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    let cache = Cache::choose(env::var_os("BOGON_CACHE_DIR"), Path::new(&out_dir));
+    let require_fresh = csv_cache::require_fresh_data(env::var_os("BOGON_REQUIRE_FRESH_DATA"));
+    let mut iana = Iana::new(
+        &format!("bogon-data/{}", env::var("CARGO_PKG_VERSION").unwrap()),
+        &env::var("CARGO_CFG_TARGET_OS").unwrap(),
+        &env::var("CARGO_CFG_TARGET_ARCH").unwrap(),
+    );
+    match csv_cache::fetch_cached(&mut iana, &cache, registry.url) {
+        Fetched::Downloaded { csv, cache_error } => {
+            if let Some(error) = cache_error {
+                println!(
+                    "cargo:warning=Failed to cache the downloaded {}: {error}",
+                    registry.name
+                );
+            }
+            Some(csv.leak())
+        }
+        Fetched::Revalidated(csv) => Some(csv.leak()),
+        Fetched::Stale { error, .. } | Fetched::Unavailable { error } if require_fresh => {
+            eprintln!(
+                "Failed to download {}, and BOGON_REQUIRE_FRESH_DATA is set: {error}",
+                registry.name
+            );
+            std::process::exit(1);
+        }
+        Fetched::Stale { csv, error } => {
+            println!(
+                "cargo:warning=Failed to download {}, using the copy cached at {}: {error}",
+                registry.name,
+                cache.path(registry.url).display()
+            );
+            Some(csv.leak())
+        }
+        Fetched::Unavailable { error } => {
+            println!(
+                "cargo:warning=Failed to download {}, using the bundled copy: {error}",
+                registry.name
+            );
+            None
+        }
+    }
+}
+
+#[cfg(not(feature = "download"))]
+fn download_csv(_registry: &RegistryFile) -> Option<&'static str> {
+    unreachable!("registries are only downloaded with the `download` feature")
+}
+
+/// Returns the contents of a registry CSV file, and where they were read from.
+///
+/// A local file named by the registry's environment variable takes precedence over downloading the
+/// registry with the `download` feature, which takes precedence over the bundled copy. The build
+/// falls back to the bundled copy when the registry can be neither downloaded nor read from the
+/// cache.
+fn get_csv(registry: &RegistryFile) -> (CsvSource, &'static str) {
+    println!("cargo:rerun-if-env-changed={}", registry.env);
+
+    let source = CsvSource::choose(env::var_os(registry.env), cfg!(feature = "download"));
+    let csv = match &source {
+        CsvSource::Override(path) => {
+            println!("cargo:rerun-if-changed={}", path.display());
+            match std::fs::read_to_string(path) {
+                Ok(csv) => &*csv.leak(),
+                Err(error) => fail(&source, registry, error),
+            }
+        }
+        CsvSource::Download => match download_csv(registry) {
+            Some(csv) => csv,
+            None => {
+                warn_if_stale();
+                return (CsvSource::Bundled, registry.bundled);
+            }
+        },
+        CsvSource::Bundled => {
+            warn_if_stale();
+            registry.bundled
+        }
+    };
+    (source, csv)
+}
+
+/// Warns once when the build compiles bundled registries that were downloaded more than
+/// `BOGON_STALE_AFTER_MONTHS` months ago, 12 by default. `BOGON_SUPPRESS_STALE_WARNING=1` silences
+/// the warning.
+fn warn_if_stale() {
+    static CHECKED: Once = Once::new();
+    CHECKED.call_once(|| {
+        println!("cargo:rerun-if-env-changed=BOGON_STALE_AFTER_MONTHS");
+        println!("cargo:rerun-if-env-changed=BOGON_SUPPRESS_STALE_WARNING");
+        println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+        let staleness = Staleness::from_env(
+            env::var_os("BOGON_STALE_AFTER_MONTHS"),
+            env::var_os("BOGON_SUPPRESS_STALE_WARNING"),
+        )
+        .unwrap_or_else(|error| {
+            eprintln!("{error}");
+            std::process::exit(1);
+        });
+        let today = staleness::build_date(env::var_os("SOURCE_DATE_EPOCH"), SystemTime::now());
+        if let Some(warning) = staleness.warning(snapshot_date(), today) {
+            println!("cargo:warning={warning}");
+        }
+    });
+}
+
+/// Returns the date the bundled registries were downloaded, exiting the build if `snapshot-date`
+/// isn't a `YYYY-MM-DD` date.
+fn snapshot_date() -> Date {
+    Date::parse(BUNDLED_SNAPSHOT_DATE.trim()).unwrap_or_else(|| {
+        eprintln!("Failed to read {SNAPSHOT_DATE}: expected a `YYYY-MM-DD` date");
+        eprintln!("To fix this, restore the bundled copy.");
+        std::process::exit(1);
+    })
+}
+
+/// Returns the SHA-256 digest of the registry CSV files, concatenated in a fixed order: the IPv6
+/// unicast address assignments, the IPv4 address space, the IPv6 special-purpose registry and the
+/// IPv4 special-purpose registry.
+fn dataset_sha256(csvs: [&str; 4]) -> String {
+    sha256::sha256(csvs.concat().as_bytes())
+}
+
+/// Returns the date of the registry snapshot: the date in `snapshot-date` when every registry is
+/// the bundled copy, or the build date when any of them was downloaded or read from a local file.
+fn dataset_date(sources: [&CsvSource; 4]) -> String {
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    if sources.iter().all(|source| **source == CsvSource::Bundled) {
+        snapshot_date().to_string()
+    } else {
+        staleness::build_date(env::var_os("SOURCE_DATE_EPOCH"), SystemTime::now()).to_string()
+    }
+}
+
+/// Exits the build, naming the registry CSV file that couldn't be read and how to fix it.
+fn fail(source: &CsvSource, registry: &RegistryFile, error: impl Display) -> ! {
+    eprintln!("Failed to read {}: {error}", source.describe(registry.name));
+    eprintln!("To fix this, {}.", source.remedy(registry.env));
+    std::process::exit(1);
+}
+
+/// Parses a registry CSV file.
+///
+/// Skipped rows and malformed records are printed as warnings, and the build exits if there were
+/// any malformed records.
+fn parse_rows<T>(
+    source: &CsvSource,
+    registry: &RegistryFile,
+    csv: &str,
+    parse: impl FnOnce(&str, &mut Vec<CsvError>) -> Result<T, Vec<CsvError>>,
+) -> T {
+    let mut warnings = Vec::new();
+    let result = parse(csv, &mut warnings);
+    let describe = source.describe(registry.name);
+    for warning in &warnings {
+        println!("cargo:warning={describe}: {warning}");
+    }
+    match result {
+        Ok(rows) => rows,
+        Err(errors) => {
+            for error in &errors {
+                println!("cargo:warning={describe}: {error}");
+            }
+            let error = match &errors[..] {
+                [error] => error.to_string(),
+                errors => format!("{} malformed records, see the warnings", errors.len()),
+            };
+            fail(source, registry, error)
+        }
+    }
+}
+
+/// Returns the rows of the IPv6 unicast address assignments allocated to a regional internet
+/// registry.
+fn get_ipv6_allocations((source, csv): &(CsvSource, &str)) -> Vec<Assignment> {
+    verify_ipv6_allocations(source, csv);
+    parse_rows(source, &IPV6_ASSIGNMENTS, csv, iana_csv::rir_allocations)
+}
+
+/// Exits the build if the IPv6 unicast address assignments don't match the digest pinned by
+/// `BOGON_CSV_SHA256`, or the recorded digest of the bundled copy.
+fn verify_ipv6_allocations(source: &CsvSource, csv: &str) {
+    println!("cargo:rerun-if-env-changed=BOGON_CSV_SHA256");
+
+    let pinned = env::var("BOGON_CSV_SHA256").ok();
+    let (expected, remedy) = match (pinned.as_deref(), source) {
+        (Some(pinned), _) if !pinned.is_empty() => (
+            Some(pinned),
+            "set BOGON_CSV_SHA256 to the digest of the snapshot you vetted, or unset it",
+        ),
+        (_, CsvSource::Bundled) => (
+            Some(BUNDLED_IPV6_ASSIGNMENTS_SHA256),
+            "restore the bundled copy, or record its digest in \
+             ipv6-unicast-address-assignments.csv.sha256",
+        ),
+        _ => (None, ""),
+    };
+    if let Err(error) = sha256::verify(csv, expected) {
+        eprintln!(
+            "Failed to verify {}: {error}",
+            source.describe(IPV6_ASSIGNMENTS.name)
+        );
+        eprintln!("To fix this, {remedy}.");
+        std::process::exit(1);
+    }
+}
+
+fn get_ipv4_blocks((source, csv): &(CsvSource, &str)) -> Vec<Option<AddressSpaceBlock>> {
+    parse_rows(
+        source,
+        &IPV4_ADDRESS_SPACE,
+        csv,
+        iana_csv::parse_address_space,
+    )
+}
+
+fn get_ipv4_special_registry((source, csv): &(CsvSource, &str)) -> Vec<SpecialPurpose> {
+    parse_rows(source, &IPV4_SPECIAL, csv, |csv, _| {
+        iana_csv::parse_special_registry(csv)
+    })
+}
+
+fn get_ipv6_special_registry((source, csv): &(CsvSource, &str)) -> Vec<SpecialPurpose> {
+    parse_rows(source, &IPV6_SPECIAL, csv, |csv, _| {
+        iana_csv::parse_special_registry(csv)
+    })
+}
+
+/// The designations of the regional internet registries, as IANA writes them.
+const RIRS: [&str; 5] = ["AFRINIC", "APNIC", "ARIN", "LACNIC", "RIPE NCC"];
+
+/// Write the IPv6 allocation tables and the registry rows to a file.
+fn write_file(
+    tables: &AllocationTables,
+    registry: &[Assignment],
+    digest: &str,
+    date: &str,
+    strings: &mut RegistryStrings,
+) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv6-unicast-address-allocations.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    // Identify the snapshot of the registries the data was generated from.
+    writeln!(
+        file,
+        "/// The SHA-256 digest of the IANA registries the compiled-in data was generated from,"
+    )?;
+    writeln!(
+        file,
+        "/// concatenated in a fixed order: the IPv6 unicast address assignments, the IPv4 address"
+    )?;
+    writeln!(
+        file,
+        "/// space, the IPv6 special-purpose registry and the IPv4 special-purpose registry, as"
+    )?;
+    writeln!(file, "/// lowercase hexadecimal.")?;
+    writeln!(file, "pub const DATASET_SHA256: &str = {digest:?};")?;
+    writeln!(
+        file,
+        "/// The date the IANA registries the compiled-in data was generated from were downloaded,"
+    )?;
+    writeln!(file, "/// as `YYYY-MM-DD`.")?;
+    writeln!(file, "pub const DATASET_DATE: &str = {date:?};")?;
+    // Sorted and disjoint, so that lookups can binary search them.
+    writeln!(
+        file,
+        "/// The merged allocations covering whole /32s, as sorted and disjoint inclusive ranges of the"
+    )?;
+    writeln!(file, "/// top 32 bits of their addresses.")?;
+    writeln!(
+        file,
+        "pub const V6_ALLOCATION_RANGES: [(u32, u32); {}] = [",
+        tables.narrow.len()
+    )?;
+    for (first, last) in &tables.narrow {
+        writeln!(file, "    ({first:#x}, {last:#x}),")?;
+    }
+    writeln!(file, "];")?;
+
+    // Allocations that don't cover whole /32s don't fit into the table above.
+    writeln!(
+        file,
+        "/// The rest of the merged allocations, with prefixes longer than 32 bits."
+    )?;
+    write_networks(&mut file, "V6_WIDE_ALLOCATIONS", "u128", &tables.wide)?;
+    writeln!(
+        file,
+        "/// The number of merged allocations, in `V6_ALLOCATION_RANGES` and `V6_WIDE_ALLOCATIONS`."
+    )?;
+    writeln!(
+        file,
+        "pub const DATASET_V6_RANGES: usize = {};",
+        tables.narrow.len() + tables.wide.len()
+    )?;
+
+    // The gaps between the allocations, for classifying unallocated addresses.
+    writeln!(
+        file,
+        "/// The networks inside of 2000::/3 that none of the allocations cover."
+    )?;
+    write_networks(&mut file, "V6_UNALLOCATED", "u128", &tables.unallocated)?;
+
+    // The registry rows are kept unmerged so that lookups can report the original prefix.
+    let prefixes = registry
+        .iter()
+        .map(|allocation| (allocation.network.to_bits(), allocation.prefix_len))
+        .collect::<Vec<_>>();
+    writeln!(
+        file,
+        "/// The network of each allocation in the registry, unmerged."
+    )?;
+    write_networks(&mut file, "V6_REGISTRY_PREFIXES", "u128", &prefixes)?;
+
+    writeln!(
+        file,
+        "/// The regional internet registry of each entry in `V6_REGISTRY_PREFIXES`, as IANA names it."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_REGISTRY_RIRS: [&str; {}] = [",
+        registry.len()
+    )?;
+    for allocation in registry {
+        assert!(
+            RIRS.contains(&&*allocation.designation),
+            "Allocation without a registry"
+        );
+        writeln!(file, "    {:?},", allocation.designation)?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The date each entry in `V6_REGISTRY_PREFIXES` was allocated, as `YYYY-MM-DD`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_REGISTRY_DATES: [&str; {}] = [",
+        registry.len()
+    )?;
+    for allocation in registry {
+        writeln!(file, "    {:?},", allocation.date)?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The index into `RDAP_URLS` of each entry in `V6_REGISTRY_PREFIXES`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_REGISTRY_RDAP: [u8; {}] = [",
+        registry.len()
+    )?;
+    for allocation in registry {
+        let url = rdap_url(&allocation.rdap).expect("Allocation without an RDAP URL");
+        writeln!(file, "    {},", strings.rdap_urls.index(url))?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The index into `WHOIS_SERVERS` of each entry in `V6_REGISTRY_PREFIXES`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_REGISTRY_WHOIS: [u8; {}] = [",
+        registry.len()
+    )?;
+    for allocation in registry {
+        assert!(
+            !allocation.whois.is_empty(),
+            "Allocation without a WHOIS server"
+        );
+        writeln!(
+            file,
+            "    {},",
+            strings.whois_servers.index(&allocation.whois)
+        )?;
+    }
+    writeln!(file, "];")?;
+
+    Ok(())
+}
+
+/// Write `is_bogon_v6_generated`, the 32-bit allocation ranges as a tree of `match` expressions, to
+/// a file.
+fn write_decision_tree_file(tables: &AllocationTables) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv6-allocation-tree.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    let intervals = decision_tree::intervals(&tables.narrow);
+    writeln!(
+        file,
+        "/// Returns a boolean indicating whether the IPv6 addresses whose top 32 bits are `top32` are"
+    )?;
+    writeln!(
+        file,
+        "/// outside of every range of `V6_ALLOCATION_RANGES`, as a balanced tree of `match` expressions"
+    )?;
+    writeln!(file, "/// over its {} intervals.", intervals.len())?;
+    writeln!(file, "#[inline]")?;
+    writeln!(
+        file,
+        "pub const fn is_bogon_v6_generated(top32: u32) -> bool {{"
+    )?;
+    write!(file, "{}", decision_tree::decision_tree(&intervals, 1))?;
+    writeln!(file, "}}")
+}
+
+/// Write `V6_ALLOCATION_BOUNDARIES`, the bounds of the 32-bit allocation ranges as deltas in
+/// LEB128, the offsets of its blocks `V6_ALLOCATION_BLOCKS`, and the trailing zeros shifted out of
+/// the bounds `V6_ALLOCATION_BOUNDARY_SHIFT`, to a file.
+fn write_boundaries_file(tables: &AllocationTables) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv6-allocation-boundaries.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    let boundaries = compact::boundaries(&tables.narrow);
+    let shift = compact::shift(&boundaries);
+    let shifted = boundaries
+        .iter()
+        .map(|boundary| boundary >> shift)
+        .collect::<Vec<_>>();
+    let (bytes, blocks) = compact::encode(&shifted);
+    writeln!(
+        file,
+        "/// The number of trailing zeros the bounds of `V6_ALLOCATION_BOUNDARIES` were shifted out of."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_ALLOCATION_BOUNDARY_SHIFT: u32 = {shift};"
+    )?;
+    writeln!(
+        file,
+        "/// The {} bounds of the ranges of `V6_ALLOCATION_RANGES`: the first /32 of each range, and the",
+        boundaries.len()
+    )?;
+    writeln!(
+        file,
+        "/// /32 after its last one, shifted right by `V6_ALLOCATION_BOUNDARY_SHIFT`. In blocks of {}",
+        compact::BLOCK_BOUNDARIES
+    )?;
+    writeln!(
+        file,
+        "/// bounds, each starting with its first bound followed by the difference of each bound to the"
+    )?;
+    writeln!(file, "/// one before it, all as unsigned LEB128.")?;
+    writeln!(
+        file,
+        "pub const V6_ALLOCATION_BOUNDARIES: [u8; {}] = [",
+        bytes.len()
+    )?;
+    for byte in bytes {
+        writeln!(file, "    {byte:#04x},")?;
+    }
+    writeln!(file, "];")?;
+    writeln!(
+        file,
+        "/// The offset of each block of `V6_ALLOCATION_BOUNDARIES`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_ALLOCATION_BLOCKS: [u16; {}] = [",
+        blocks.len()
+    )?;
+    for offset in blocks {
+        writeln!(file, "    {offset},")?;
+    }
+    writeln!(file, "];")
+}
+
+/// Writes a constant array of networks as `(network, prefix_len)` tuples, with the network as an
+/// integer of type `int`.
+fn write_networks<N: std::fmt::LowerHex>(
+    file: &mut impl Write,
+    name: &str,
+    int: &str,
+    networks: &[(N, u8)],
+) -> std::io::Result<()> {
+    writeln!(
+        file,
+        "pub const {name}: [({int}, u8); {}] = [",
+        networks.len()
+    )?;
+    for (network, prefix_len) in networks {
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")
+}
+
+/// Returns the regional internet registry responsible for an IPv4 /8 as IANA names it, if any.
+///
+/// Legacy blocks that are "Administered by" a registry belong to that registry, the remaining
+/// legacy blocks were assigned directly to an organization before the registries existed.
+fn ipv4_block_rir(octet: usize, block: &AddressSpaceBlock) -> Option<&str> {
+    let designation = match &*block.status {
+        "ALLOCATED" => &*block.designation,
+        "LEGACY" => match block.designation.strip_prefix("Administered by ") {
+            Some(designation) => designation,
+            None => return Some("LEGACY"),
+        },
+        _ => return None,
+    };
+    if RIRS.contains(&designation) {
+        return Some(designation);
+    }
+    println!(
+        "cargo:warning={}: unknown registry `{designation}` for {octet:03}/8, leaving it without \
+         a registry",
+        IPV4_ADDRESS_SPACE.name
+    );
+    None
+}
+
+/// Write the administrator of every IPv4 /8 to a file.
+///
+/// Rows skipped because of an unknown status leave their /8 without a registry, RDAP URL, or
+/// WHOIS server.
+fn write_ipv4_file(
+    blocks: &[Option<AddressSpaceBlock>],
+    strings: &mut RegistryStrings,
+) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv4-address-space.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    writeln!(
+        file,
+        "/// The regional internet registry of each IPv4 /8 as IANA names it, or `\"LEGACY\"` for legacy"
+    )?;
+    writeln!(file, "/// space that no registry administers.")?;
+    writeln!(file, "pub const V4_REGISTRY_RIRS: [Option<&str>; 256] = [")?;
+    for (octet, block) in blocks.iter().enumerate() {
+        match block
+            .as_ref()
+            .and_then(|block| ipv4_block_rir(octet, block))
+        {
+            Some(rir) => writeln!(file, "    Some({:?}),", rir)?,
+            None => writeln!(file, "    None,")?,
+        }
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// Whether each IPv4 /8 was assigned before the registries existed, whoever administers it now."
+    )?;
+    writeln!(file, "pub const V4_REGISTRY_LEGACY: [bool; 256] = [")?;
+    for block in blocks {
+        let legacy = block.as_ref().is_some_and(|block| block.status == "LEGACY");
+        writeln!(file, "    {},", legacy)?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(file, "/// The index into `RDAP_URLS` of each IPv4 /8.")?;
+    writeln!(file, "pub const V4_REGISTRY_RDAP: [Option<u8>; 256] = [")?;
+    for block in blocks {
+        match block.as_ref().and_then(|block| rdap_url(&block.rdap)) {
+            Some(url) => writeln!(file, "    Some({}),", strings.rdap_urls.index(url))?,
+            None => writeln!(file, "    None,")?,
+        }
+    }
+    writeln!(file, "];")?;
+
+    writeln!(file, "/// The index into `WHOIS_SERVERS` of each IPv4 /8.")?;
+    writeln!(file, "pub const V4_REGISTRY_WHOIS: [Option<u8>; 256] = [")?;
+    for block in blocks {
+        match block.as_ref().map_or("", |block| &block.whois) {
+            "" => writeln!(file, "    None,")?,
+            whois => writeln!(file, "    Some({}),", strings.whois_servers.index(whois))?,
+        }
+    }
+    writeln!(file, "];")?;
+
+    Ok(())
+}
+
+/// Returns the name of the `BogonKind` variant for a row of the IPv4 special-purpose registry.
+fn ipv4_bogon_kind(name: &str) -> &'static str {
+    match name {
+        "\"This network\"" => "ThisNetwork",
+        "Private-Use" => "PrivateUse",
+        "Shared Address Space" => "SharedAddressSpace",
+        "Loopback" => "Loopback",
+        "Link Local" => "LinkLocal",
+        "IETF Protocol Assignments" => "ProtocolAssignments",
+        "Deprecated (6to4 Relay Anycast)" => "SixToFourRelayAnycast",
+        "Benchmarking" => "Benchmarking",
+        "Reserved" => "ReservedFutureUse",
+        "Limited Broadcast" => "Broadcast",
+        name if name.starts_with("Documentation") => "Documentation",
+        _ => {
+            println!(
+                "cargo:warning=Unknown IPv4 special-purpose block `{name}`, classified as reserved"
+            );
+            "Reserved"
+        }
+    }
+}
+
+/// Returns a boolean indicating whether network `a` is inside of network `b`.
+fn is_inside((a, a_len): (u32, u8), (b, b_len): (u32, u8)) -> bool {
+    let mask = u32::MAX.checked_shl(32 - u32::from(b_len)).unwrap_or(0);
+    a_len >= b_len && a & mask == b & mask
+}
+
+/// Write the bogus IPv4 networks, their classifications, and their exceptions to a file.
+///
+/// Every network of the special-purpose registry that isn't globally reachable is bogus, unless it
+/// is inside of another bogus network. Networks inside of the ones an `allow-*` feature removes are
+/// kept, so that 255.255.255.255/32 stays bogus without 240.0.0.0/4. The globally reachable
+/// networks inside of the bogus ones are their exceptions. Multicast has a registry of its own, so
+/// 224.0.0.0/4 is added by hand.
+fn write_ipv4_bogons_file(rows: &[SpecialPurpose]) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv4-special-registry.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    let networks = |reachable: bool| {
+        rows.iter()
+            .filter(move |row| (row.globally_reachable == Some(true)) == reachable)
+            .flat_map(|row| row.networks.iter().map(move |&network| (network, row)))
+            .map(|((network, prefix_len), row)| match network {
+                std::net::IpAddr::V4(network) => ((network.to_bits(), prefix_len), row),
+                std::net::IpAddr::V6(_) => panic!("IPv6 network in the IPv4 registry: {network}"),
+            })
+            .collect::<Vec<_>>()
+    };
+    let (bogus, reachable) = (networks(false), networks(true));
+
+    let is_allowed = |network: (u32, u8)| {
+        ALLOW_FEATURES
+            .iter()
+            .any(|&(_, allowed, prefix_len)| network == (allowed, prefix_len))
+    };
+    let mut bogons = bogus
+        .iter()
+        .filter(|&&(network, _)| {
+            !bogus.iter().any(|&(other, _)| {
+                other != network && is_inside(network, other) && !is_allowed(other)
+            })
+        })
+        .map(|&(network, row)| (network, ipv4_bogon_kind(&row.name), &*row.name))
+        .collect::<Vec<_>>();
+    bogons.push(((0xe000_0000, 4), "Multicast", "Multicast"));
+    bogons.sort_by_key(|&(network, _, _)| network);
+    bogons.dedup_by_key(|&mut (network, _, _)| network);
+
+    for (feature, network, prefix_len) in ALLOW_FEATURES {
+        assert!(
+            bogons
+                .iter()
+                .any(|&(bogon, _, _)| bogon == (network, prefix_len)),
+            "The network removed by `{feature}` is missing from the registry"
+        );
+    }
+
+    let exceptions = reachable
+        .iter()
+        .filter(|&&(network, _)| {
+            bogons
+                .iter()
+                .any(|&(bogon, _, _)| is_inside(network, bogon))
+        })
+        .collect::<Vec<_>>();
+
+    // Networks an `allow-*` feature removes are only compiled without that feature.
+    let cfg = |file: &mut std::fs::File, network: (u32, u8)| match ALLOW_FEATURES
+        .iter()
+        .find(|&&(_, allowed, prefix_len)| network == (allowed, prefix_len))
+    {
+        Some((feature, _, _)) => writeln!(file, "    #[cfg(not(feature = {feature:?}))]"),
+        None => Ok(()),
+    };
+
+    writeln!(
+        file,
+        "/// The bogus IPv4 networks, without the ones the `allow-*` features remove."
+    )?;
+    writeln!(
+        file,
+        "pub const V4_BOGON_NETWORKS: [(u32, u8); {} - V4_BOGON_ALLOWED] = [",
+        bogons.len()
+    )?;
+    for &((network, prefix_len), _, name) in &bogons {
+        writeln!(file, "    // {name}")?;
+        cfg(&mut file, (network, prefix_len))?;
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+    writeln!(file, "/// The number of entries in `V4_BOGON_NETWORKS`.")?;
+    writeln!(
+        file,
+        "pub const DATASET_V4_RULES: usize = {} - V4_BOGON_ALLOWED;",
+        bogons.len()
+    )?;
+
+    writeln!(
+        file,
+        "/// The `BogonKind` variant of each entry in `V4_BOGON_NETWORKS`."
+    )?;
+    writeln!(
+        file,
+        "pub const V4_BOGON_KINDS: [&str; {} - V4_BOGON_ALLOWED] = [",
+        bogons.len()
+    )?;
+    for &(network, kind, _) in &bogons {
+        cfg(&mut file, network)?;
+        writeln!(file, "    {kind:?},")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The globally reachable networks inside of the bogus IPv4 networks."
+    )?;
+    writeln!(
+        file,
+        "pub const V4_BOGON_EXCEPTIONS: [(u32, u8); {}] = [",
+        exceptions.len()
+    )?;
+    for &((network, prefix_len), row) in &exceptions {
+        writeln!(file, "    // {}", row.name)?;
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+
+    Ok(())
+}
+
+/// Returns the name of the `BogonKind` variant for a block of the IPv6 special-purpose registry, or
+/// `None` if the block isn't bogus.
+///
+/// Everything outside of 2000::/3 is bogus regardless of the registry. Inside of it, the blocks
+/// that aren't globally reachable are bogus, and ORCHIDv2 is bogus even though the registry marks it
+/// globally reachable, ORCHIDs are cryptographic identifiers that are never routed (RFC 7343,
+/// section 2). Unknown bogus blocks are classified as reserved, with a warning.
+fn ipv6_bogon_kind(
+    (network, prefix_len): (u128, u8),
+    block: &SpecialPurpose,
+) -> Option<&'static str> {
+    let is_global_unicast = network >> 125 == 0b001;
+    match &*block.name {
+        "ORCHIDv2" => Some("OrchidV2"),
+        _ if is_global_unicast && block.globally_reachable == Some(true) => None,
+        "IETF Protocol Assignments" => Some("ProtocolAssignments"),
+        "TEREDO" => Some("Teredo"),
+        "Benchmarking" => Some("Benchmarking"),
+        "Documentation" => Some("Documentation"),
+        "Unspecified Address" => Some("Unspecified"),
+        "Loopback Address" => Some("Loopback"),
+        "IPv4-mapped Address" => Some("Ipv4Mapped"),
+        // The well-known prefix is a /96, the local-use prefix a /48 (RFC 8215).
+        "IPv4-IPv6 Translat." if prefix_len == 96 => Some("Nat64WellKnown"),
+        "IPv4-IPv6 Translat." => Some("Nat64LocalUse"),
+        "Discard-Only Address Block" => Some("DiscardOnly"),
+        "Segment Routing (SRv6) SIDs" => Some("SegmentRouting"),
+        "Unique-Local" => Some("UniqueLocal"),
+        "Link-Local Unicast" => Some("LinkLocal"),
+        name if !is_global_unicast || block.globally_reachable == Some(false) => {
+            println!(
+                "cargo:warning=Unknown IPv6 special-purpose block `{name}`, classified as reserved"
+            );
+            Some("Reserved")
+        }
+        _ => None,
+    }
+}
+
+/// Returns a boolean indicating whether IPv6 network `a` is inside of network `b`.
+fn is_inside_v6((a, a_len): (u128, u8), (b, b_len): (u128, u8)) -> bool {
+    let mask = u128::MAX.checked_shl(128 - u32::from(b_len)).unwrap_or(0);
+    a_len >= b_len && a & mask == b & mask
+}
+
+/// The bogus IPv6 networks inside of 2000::/3, and the globally reachable exceptions inside of them.
+struct V6GlobalBogons {
+    networks: Vec<(u128, u8)>,
+    exceptions: Vec<(u128, u8)>,
+}
+
+/// Write the IPv6 special-purpose registry, the bogus IPv6 networks outside and inside of 2000::/3,
+/// their classifications, and the exceptions inside of 2000::/3 to a file, and return the bogus
+/// networks and exceptions inside of 2000::/3.
+///
+/// The bogus networks are the blocks with a classification. The globally reachable blocks inside of
+/// them are their exceptions. Everything outside of 2000::/3 is bogus regardless of the registry:
+/// the deprecated IPv4-compatible addresses (RFC 4291) and multicast, which has a registry of its
+/// own, are added by hand, and the rest of the space that no block covers is reserved. Outside of
+/// 2000::/3 the networks that fit into the top 32 bits are written apart from the more specific
+/// ones.
+fn write_ipv6_special_file(rows: &[SpecialPurpose]) -> std::io::Result<V6GlobalBogons> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv6-special-registry.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    // Every network of every row, from the most to the least specific, so the first match wins.
+    let mut blocks = rows
+        .iter()
+        .flat_map(|row| row.networks.iter().map(move |&network| (network, row)))
+        .map(|((network, prefix_len), row)| match network {
+            std::net::IpAddr::V6(network) => ((network.to_bits(), prefix_len), row),
+            std::net::IpAddr::V4(_) => panic!("IPv4 network in the IPv6 registry: {network}"),
+        })
+        .collect::<Vec<_>>();
+    blocks.sort_by_key(|&((network, prefix_len), _)| (std::cmp::Reverse(prefix_len), network));
+
+    let is_global_unicast = |(network, _): (u128, u8)| network >> 125 == 0b001;
+    let bogons = blocks
+        .iter()
+        .filter(|&&(network, _)| is_global_unicast(network))
+        .filter_map(|&(network, row)| Some((network, ipv6_bogon_kind(network, row)?, &*row.name)))
+        .collect::<Vec<_>>();
+
+    // Outside of 2000::/3, from the most to the least specific, so the first match wins.
+    let mut outside = vec![
+        (
+            (0, 96),
+            "Ipv4Compatible",
+            "IPv4-Compatible IPv6 Address (deprecated)",
+        ),
+        ((0xff00 << 112, 8), "Multicast", "Multicast"),
+    ];
+    outside.extend(
+        blocks
+            .iter()
+            .filter(|&&(network, _)| !is_global_unicast(network))
+            .filter_map(|&(network, row)| {
+                Some((network, ipv6_bogon_kind(network, row)?, &*row.name))
+            }),
+    );
+
+    // The rest of the space outside of 2000::/3 is reserved by IETF, as the fewest networks that
+    // don't overlap the ones above.
+    let special_purpose = ranges::coverage(outside.iter().map(|&(network, _, _)| network), 128);
+    let reserved = ranges::subtract(
+        &[(0, (0x2000 << 112) - 1), (0x4000 << 112, u128::MAX)],
+        &special_purpose,
+    );
+    outside.extend(
+        reserved
+            .into_iter()
+            .flat_map(|(start, end)| ranges::range_to_cidrs(start, end, 128))
+            .map(|network| (network, "Reserved", "Reserved by IETF")),
+    );
+    outside.sort_by_key(|&((network, prefix_len), _, _)| (std::cmp::Reverse(prefix_len), network));
+    let fits_top_32_bits =
+        |(network, prefix_len): (u128, u8)| prefix_len <= 32 && network.trailing_zeros() >= 96;
+    let (wide, special): (Vec<_>, Vec<_>) = outside
+        .into_iter()
+        .partition(|&(network, _, _)| fits_top_32_bits(network));
+    let exceptions = blocks
+        .iter()
+        .filter(|&&(network, row)| {
+            row.globally_reachable == Some(true)
+                && is_global_unicast(network)
+                && !bogons.iter().any(|&(bogon, _, _)| bogon == network)
+                && bogons
+                    .iter()
+                    .any(|&(bogon, _, _)| is_inside_v6(network, bogon))
+        })
+        .collect::<Vec<_>>();
+
+    writeln!(
+        file,
+        "/// The networks of the IPv6 special-purpose registry, from the most to the least specific."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_SPECIAL_REGISTRY_NETWORKS: [(u128, u8); {}] = [",
+        blocks.len()
+    )?;
+    for &((network, prefix_len), _) in &blocks {
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The block of each entry in `V6_SPECIAL_REGISTRY_NETWORKS`: its name, whether it is valid as a"
+    )?;
+    writeln!(
+        file,
+        "/// source, valid as a destination, forwardable, and globally reachable, and whether it is"
+    )?;
+    writeln!(file, "/// deprecated.")?;
+    writeln!(
+        file,
+        "pub const V6_SPECIAL_REGISTRY: [(&str, [Option<bool>; 4], bool); {}] = [",
+        blocks.len()
+    )?;
+    for &(_, row) in &blocks {
+        writeln!(
+            file,
+            "    ({:?}, [{:?}, {:?}, {:?}, {:?}], {}),",
+            row.name,
+            row.source,
+            row.destination,
+            row.forwardable,
+            row.globally_reachable,
+            row.terminated,
+        )?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The bogus IPv6 networks outside of 2000::/3 that fit into the top 32 bits, as the top 32"
+    )?;
+    writeln!(
+        file,
+        "/// bits of their addresses, from the most to the least specific."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_BOGON_NETWORKS: [(u32, u8); {}] = [",
+        wide.len()
+    )?;
+    for &((network, prefix_len), _, name) in &wide {
+        writeln!(file, "    // {name}")?;
+        writeln!(file, "    ({:#x}, {prefix_len}),", network >> 96)?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The `BogonKind` variant of each entry in `V6_BOGON_NETWORKS`."
+    )?;
+    writeln!(file, "pub const V6_BOGON_KINDS: [&str; {}] = [", wide.len())?;
+    for &(_, kind, _) in &wide {
+        writeln!(file, "    {kind:?},")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The bogus IPv6 networks outside of 2000::/3 that are too specific for `V6_BOGON_NETWORKS`,"
+    )?;
+    writeln!(file, "/// from the most to the least specific.")?;
+    writeln!(
+        file,
+        "pub const V6_SPECIAL_BOGON_NETWORKS: [(u128, u8); {}] = [",
+        special.len()
+    )?;
+    for &((network, prefix_len), _, name) in &special {
+        writeln!(file, "    // {name}")?;
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The `BogonKind` variant of each entry in `V6_SPECIAL_BOGON_NETWORKS`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_SPECIAL_BOGON_KINDS: [&str; {}] = [",
+        special.len()
+    )?;
+    for &(_, kind, _) in &special {
+        writeln!(file, "    {kind:?},")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The bogus IPv6 networks inside of 2000::/3, from the most to the least specific."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_GLOBAL_BOGON_NETWORKS: [(u128, u8); {}] = [",
+        bogons.len()
+    )?;
+    for &((network, prefix_len), _, name) in &bogons {
+        writeln!(file, "    // {name}")?;
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The `BogonKind` variant of each entry in `V6_GLOBAL_BOGON_NETWORKS`."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_GLOBAL_BOGON_KINDS: [&str; {}] = [",
+        bogons.len()
+    )?;
+    for &(_, kind, _) in &bogons {
+        writeln!(file, "    {kind:?},")?;
+    }
+    writeln!(file, "];")?;
+
+    writeln!(
+        file,
+        "/// The globally reachable networks inside of the bogus IPv6 networks."
+    )?;
+    writeln!(
+        file,
+        "pub const V6_BOGON_EXCEPTIONS: [(u128, u8); {}] = [",
+        exceptions.len()
+    )?;
+    for &((network, prefix_len), row) in &exceptions {
+        writeln!(file, "    // {}", row.name)?;
+        writeln!(file, "    ({network:#x}, {prefix_len}),")?;
+    }
+    writeln!(file, "];")?;
+
+    Ok(V6GlobalBogons {
+        networks: bogons.iter().map(|&(network, _, _)| network).collect(),
+        exceptions: exceptions.iter().map(|&&(network, _)| network).collect(),
+    })
+}
+
+/// Write the bucket of every /16 of the IPv6 address space to a file.
+fn write_hextets_file(tables: &AllocationTables, bogons: &V6GlobalBogons) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("ipv6-first-hextets.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    // Building the buckets asserts that every bucket is homogeneous.
+    let bogus = hextets::bogus_ranges(&bogons.networks, &bogons.exceptions, &tables.unallocated);
+    let words = hextets::pack(&hextets::first_hextets(&bogus));
+
+    writeln!(
+        file,
+        "/// The bucket of every /16 of the IPv6 address space, {} bits each, starting with the least",
+        hextets::BUCKET_BITS
+    )?;
+    writeln!(
+        file,
+        "/// significant bits of the first word: 0 if none of the /16 is bogus, 1 if all of it is, and"
+    )?;
+    writeln!(file, "/// 2 if only part of it is.")?;
+    writeln!(
+        file,
+        "pub const V6_FIRST_HEXTETS: [u64; {}] = [",
+        words.len()
+    )?;
+    for line in words.chunks(4) {
+        let line = line
+            .iter()
+            .map(|word| format!("{word:#018x},"))
+            .collect::<Vec<_>>();
+        writeln!(file, "    {}", line.join(" "))?;
+    }
+    writeln!(file, "];")
+}
+
+/// Write the deduplicated registry strings to a file.
+fn write_strings_file(strings: &RegistryStrings) -> std::io::Result<()> {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+
+    let path = Path::new(&out_dir).join("registry-strings.rs");
+    let mut file = std::fs::File::create(path).unwrap();
+
+    // The tables are indexed with a u8.
+    assert!(strings.rdap_urls.strings.len() <= 256, "Too many RDAP URLs");
+    assert!(
+        strings.whois_servers.strings.len() <= 256,
+        "Too many WHOIS servers"
+    );
+
+    writeln!(file, "/// The distinct RDAP base URLs of both registries.")?;
+    strings.rdap_urls.write(&mut file, "RDAP_URLS")?;
+    writeln!(file, "/// The distinct WHOIS servers of both registries.")?;
+    strings.whois_servers.write(&mut file, "WHOIS_SERVERS")
+}
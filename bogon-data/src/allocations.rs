@@ -0,0 +1,101 @@
+//! Turning the RIR allocations of the IPv6 unicast address assignments registry into the tables
+//! `is_bogon_v6` searches.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`. The library only
+//! compiles it for its tests.
+
+use alloc::vec::Vec;
+
+use crate::ranges::{coverage, range, range_to_cidrs};
+
+/// The first and last address of 2000::/3, the global unicast space the RIRs allocate from.
+const GLOBAL_UNICAST: (u128, u128) = (0x2000 << 112, (0x4000 << 112) - 1);
+
+/// The tables the build script generates from the RIR allocations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct AllocationTables {
+    /// The merged allocations covering whole /32s, as inclusive ranges of the top 32 bits of their
+    /// addresses.
+    pub(crate) narrow: Vec<(u32, u32)>,
+    /// The rest of the merged allocations, which don't cover whole /32s.
+    pub(crate) wide: Vec<(u128, u8)>,
+    /// The networks inside of 2000::/3 that none of the allocations cover.
+    pub(crate) unallocated: Vec<(u128, u8)>,
+}
+
+/// Merges the RIR allocations into the fewest ranges covering the same addresses, and splits them
+/// into the tables the build script generates. Every table is sorted by address, and the ranges of
+/// the 32-bit table don't overlap, so that `is_bogon_v6` can binary search them.
+///
+/// Duplicate, overlapping, nested, and adjacent allocations are merged. Allocations outside of
+/// 2000::/3 don't leave gaps in the unallocated space.
+///
+/// # Panics
+///
+/// Panics if the allocations in the tables don't cover exactly the addresses of the allocations
+/// they were merged from, or if the ranges of the 32-bit table aren't sorted and disjoint.
+pub(crate) fn allocation_tables(allocations: &[(u128, u8)]) -> AllocationTables {
+    let ranges = coverage(allocations.iter().copied(), 128);
+
+    let mut tables = AllocationTables::default();
+    for &(start, end) in &ranges {
+        // The /32s the range covers completely go into the 32-bit table as a single range, the
+        // ragged ends around them into the wide table.
+        let mut whole = None;
+        for (network, prefix_len) in range_to_cidrs(start, end, 128) {
+            if prefix_len <= 32 {
+                let (first, last) = range(network, prefix_len, 128);
+                let (first, last) = ((first >> 96) as u32, (last >> 96) as u32);
+                whole = Some(whole.map_or((first, last), |(first, _)| (first, last)));
+            } else {
+                tables.wide.push((network, prefix_len));
+            }
+        }
+        tables.narrow.extend(whole);
+    }
+
+    // The space between the allocations is bogus.
+    let (first, last) = GLOBAL_UNICAST;
+    let mut next = first;
+    for &(start, end) in &ranges {
+        if end < first || start > last {
+            continue;
+        }
+        if start > next {
+            tables
+                .unallocated
+                .extend(range_to_cidrs(next, start - 1, 128));
+        }
+        next = next.max(end.saturating_add(1));
+    }
+    if next <= last {
+        tables.unallocated.extend(range_to_cidrs(next, last, 128));
+    }
+
+    assert!(
+        tables.narrow.iter().all(|&(first, last)| first <= last)
+            && tables.narrow.windows(2).all(|pair| pair[0].1 < pair[1].0),
+        "The 32-bit IPv6 allocation ranges aren't sorted and disjoint"
+    );
+    let merged = tables
+        .narrow
+        .iter()
+        .flat_map(|&(first, last)| {
+            range_to_cidrs(
+                u128::from(first) << 96,
+                u128::from(last) << 96 | LOW_BITS,
+                128,
+            )
+        })
+        .chain(tables.wide.iter().copied());
+    assert_eq!(
+        coverage(merged, 128),
+        ranges,
+        "The IPv6 allocation tables don't cover the same addresses as the registry"
+    );
+
+    tables
+}
+
+/// The 96 bits of an IPv6 address below the top 32 bits the 32-bit table stores.
+const LOW_BITS: u128 = u128::MAX >> 32;
@@ -0,0 +1,212 @@
+use core::net::Ipv6Addr;
+
+use crate::allocations::{allocation_tables, AllocationTables};
+
+fn net(network: &str, prefix_len: u8) -> (u128, u8) {
+    (network.parse::<Ipv6Addr>().unwrap().to_bits(), prefix_len)
+}
+
+/// Returns the range of the top 32 bits of the /32s from `first` to `last`, like the 32-bit table
+/// stores it.
+fn narrow(first: &str, last: &str) -> (u32, u32) {
+    let top = |address: &str| (address.parse::<Ipv6Addr>().unwrap().to_bits() >> 96) as u32;
+    (top(first), top(last))
+}
+
+fn unallocated(allocations: &[(u128, u8)]) -> Vec<(u128, u8)> {
+    allocation_tables(allocations).unallocated
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(
+        allocation_tables(&[]),
+        AllocationTables {
+            narrow: Vec::new(),
+            wide: Vec::new(),
+            unallocated: vec![net("2000::", 3)],
+        }
+    );
+}
+
+#[test]
+fn one_allocation() {
+    assert_eq!(
+        allocation_tables(&[net("2001:200::", 23)]),
+        AllocationTables {
+            narrow: vec![narrow("2001:200::", "2001:3ff::")],
+            wide: Vec::new(),
+            unallocated: vec![
+                net("2000::", 16),
+                net("2001::", 23),
+                net("2001:400::", 22),
+                net("2001:800::", 21),
+                net("2001:1000::", 20),
+                net("2001:2000::", 19),
+                net("2001:4000::", 18),
+                net("2001:8000::", 17),
+                net("2002::", 15),
+                net("2004::", 14),
+                net("2008::", 13),
+                net("2010::", 12),
+                net("2020::", 11),
+                net("2040::", 10),
+                net("2080::", 9),
+                net("2100::", 8),
+                net("2200::", 7),
+                net("2400::", 6),
+                net("2800::", 5),
+                net("3000::", 4),
+            ],
+        }
+    );
+}
+
+#[test]
+fn adjacent_allocations_merge() {
+    let tables = allocation_tables(&[net("2001:600::", 23), net("2001:400::", 23)]);
+    assert_eq!(tables.narrow, [narrow("2001:400::", "2001:7ff::")]);
+
+    // Ranges don't need to be networks, so adjacent allocations that aren't the halves of a larger
+    // network merge too.
+    let tables = allocation_tables(&[net("2001:200::", 23), net("2001:400::", 23)]);
+    assert_eq!(tables.narrow, [narrow("2001:200::", "2001:5ff::")]);
+
+    // Allocations with a gap between them stay apart.
+    let tables = allocation_tables(&[net("2001:200::", 23), net("2001:600::", 23)]);
+    assert_eq!(
+        tables.narrow,
+        [
+            narrow("2001:200::", "2001:3ff::"),
+            narrow("2001:600::", "2001:7ff::")
+        ]
+    );
+}
+
+#[test]
+fn overlapping_allocations_merge() {
+    let tables = allocation_tables(&[net("2400::", 12), net("2408::", 13), net("2410::", 12)]);
+    assert_eq!(tables.narrow, [narrow("2400::", "241f:ffff::")]);
+}
+
+#[test]
+fn subsets_are_eliminated() {
+    let tables = allocation_tables(&[
+        net("2a00::", 12),
+        net("2a00:1450::", 32),
+        net("2a00:1450:4000::", 37),
+        net("2a00::", 12),
+    ]);
+    assert_eq!(tables.narrow, [narrow("2a00::", "2a0f:ffff::")]);
+    assert_eq!(tables.wide, []);
+}
+
+#[test]
+fn long_prefixes_go_into_the_wide_table() {
+    let tables = allocation_tables(&[net("2001:db8::", 48), net("2001:db8:1::", 48)]);
+    assert_eq!(tables.narrow, []);
+    assert_eq!(tables.wide, [net("2001:db8::", 47)]);
+
+    // Merging can widen them into the 32-bit table.
+    let halves = (0..2u128).map(|half| (net("2001:db8::", 33).0 | half << 95, 33));
+    let tables = allocation_tables(&halves.collect::<Vec<_>>());
+    assert_eq!(tables.narrow, [narrow("2001:db8::", "2001:db8::")]);
+    assert_eq!(tables.wide, []);
+}
+
+#[test]
+fn ragged_ends_go_into_the_wide_table() {
+    // The /32s a range covers completely stay in the 32-bit table, the rest of the range doesn't.
+    let tables = allocation_tables(&[
+        net("2001:db7:8000::", 33),
+        net("2001:db8::", 31),
+        net("2001:dba::", 48),
+    ]);
+    assert_eq!(tables.narrow, [narrow("2001:db8::", "2001:db9::")]);
+    assert_eq!(
+        tables.wide,
+        [net("2001:db7:8000::", 33), net("2001:dba::", 48)]
+    );
+}
+
+#[test]
+fn ranges_are_sorted_and_disjoint() {
+    let tables = allocation_tables(&[
+        net("2c00::", 12),
+        net("2001:200::", 23),
+        net("2600::", 12),
+        net("2001:400::", 23),
+        net("2610::", 23),
+    ]);
+    assert_eq!(
+        tables.narrow,
+        [
+            narrow("2001:200::", "2001:5ff::"),
+            narrow("2600::", "2610:1ff::"),
+            narrow("2c00::", "2c0f:ffff::"),
+        ]
+    );
+}
+
+#[test]
+fn unallocated_space_between_allocations() {
+    assert_eq!(unallocated(&[]), [net("2000::", 3)]);
+    assert_eq!(unallocated(&[net("2000::", 4)]), [net("3000::", 4)]);
+    assert_eq!(unallocated(&[net("2000::", 4), net("3000::", 4)]), []);
+
+    // Allocations longer than /32 leave gaps that don't fit into 32 bits either.
+    assert_eq!(
+        unallocated(&[net("2000::", 4), net("3000::", 5), net("3800::", 48)]),
+        [
+            net("3800:0:1::", 48),
+            net("3800:0:2::", 47),
+            net("3800:0:4::", 46),
+            net("3800:0:8::", 45),
+            net("3800:0:10::", 44),
+            net("3800:0:20::", 43),
+            net("3800:0:40::", 42),
+            net("3800:0:80::", 41),
+            net("3800:0:100::", 40),
+            net("3800:0:200::", 39),
+            net("3800:0:400::", 38),
+            net("3800:0:800::", 37),
+            net("3800:0:1000::", 36),
+            net("3800:0:2000::", 35),
+            net("3800:0:4000::", 34),
+            net("3800:0:8000::", 33),
+            net("3800:1::", 32),
+            net("3800:2::", 31),
+            net("3800:4::", 30),
+            net("3800:8::", 29),
+            net("3800:10::", 28),
+            net("3800:20::", 27),
+            net("3800:40::", 26),
+            net("3800:80::", 25),
+            net("3800:100::", 24),
+            net("3800:200::", 23),
+            net("3800:400::", 22),
+            net("3800:800::", 21),
+            net("3800:1000::", 20),
+            net("3800:2000::", 19),
+            net("3800:4000::", 18),
+            net("3800:8000::", 17),
+            net("3801::", 16),
+            net("3802::", 15),
+            net("3804::", 14),
+            net("3808::", 13),
+            net("3810::", 12),
+            net("3820::", 11),
+            net("3840::", 10),
+            net("3880::", 9),
+            net("3900::", 8),
+            net("3a00::", 7),
+            net("3c00::", 6),
+        ]
+    );
+
+    // Allocations outside of 2000::/3 are ignored.
+    assert_eq!(
+        unallocated(&[net("::", 3), net("2000::", 4), net("fe80::", 10)]),
+        [net("3000::", 4)]
+    );
+}
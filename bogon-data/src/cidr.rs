@@ -0,0 +1,24 @@
+//! Splitting ranges of addresses into networks, without allocating.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`, so it must not depend
+//! on the rest of the crate. `ranges` builds `range_to_cidrs` on top of it, and `bogon` its
+//! `ensure_private_v4` and `ensure_private_v6` functions.
+
+/// Returns the largest network that starts at `start` and doesn't extend past `end`, in an address
+/// family with `bits` bits. It is the first of the networks exactly covering `start..=end`.
+///
+/// # Panics
+///
+/// Panics if `start` is after `end`.
+pub fn first_network(start: u128, end: u128, bits: u32) -> (u128, u8) {
+    assert!(start <= end, "the range must not end before it starts");
+
+    // The number of host bits of the largest network aligned to `start`, and of the largest
+    // network that fits into the range.
+    let aligned = start.trailing_zeros().min(bits);
+    let fits = match (end - start).checked_add(1) {
+        Some(len) => 127 - len.leading_zeros(),
+        None => 128,
+    };
+    (start, (bits - aligned.min(fits)) as u8)
+}
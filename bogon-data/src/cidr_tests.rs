@@ -0,0 +1,24 @@
+use crate::cidr::first_network;
+
+#[test]
+fn largest_aligned_network_that_fits() {
+    // 10.0.0.0 to 10.0.2.255 starts with 10.0.0.0/23.
+    assert_eq!(
+        first_network(0x0a00_0000, 0x0a00_02ff, 32),
+        (0x0a00_0000, 23)
+    );
+    // 10.0.1.0 is only aligned to a /24.
+    assert_eq!(
+        first_network(0x0a00_0100, 0x0a00_ffff, 32),
+        (0x0a00_0100, 24)
+    );
+    assert_eq!(first_network(7, 7, 32), (7, 32));
+    assert_eq!(first_network(0, u128::from(u32::MAX), 32), (0, 0));
+    assert_eq!(first_network(0, u128::MAX, 128), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "the range must not end before it starts")]
+fn reversed_range() {
+    first_network(2, 1, 32);
+}
@@ -0,0 +1,80 @@
+//! Encoding `V6_ALLOCATION_BOUNDARIES`, the bounds of `V6_ALLOCATION_RANGES` as deltas in LEB128,
+//! that the `compact-data` feature compiles in place of the ranges.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`. The library only
+//! compiles it for its tests.
+
+use alloc::vec::Vec;
+
+/// The number of bounds in each block. Even, so that every block starts with the start of a range.
+pub(crate) const BLOCK_BOUNDARIES: usize = 8;
+
+/// Returns the bounds of the ranges in order: the first value of each range, and the value after its
+/// last one. Adjacent ranges share no bounds, and a range reaching `u32::MAX` has no end.
+///
+/// # Panics
+///
+/// Panics if the ranges aren't sorted and disjoint, like `allocation_tables` returns them.
+pub(crate) fn boundaries(ranges: &[(u32, u32)]) -> Vec<u32> {
+    let mut boundaries = Vec::with_capacity(ranges.len() * 2);
+    let mut next = Some(0u32);
+    for &(first, last) in ranges {
+        let start = next.expect("The ranges must be sorted and disjoint");
+        assert!(
+            start <= first && first <= last,
+            "The ranges must be sorted and disjoint"
+        );
+        if boundaries.last() == Some(&first) {
+            // Adjacent ranges make a single range.
+            boundaries.pop();
+        } else {
+            boundaries.push(first);
+        }
+        next = last.checked_add(1);
+        boundaries.extend(next);
+    }
+    boundaries
+}
+
+/// Returns the number of trailing zeros all of the bounds share, which they are shifted right by
+/// before they're encoded. At most 31, so that the bounds can be shifted back.
+pub(crate) fn shift(boundaries: &[u32]) -> u32 {
+    boundaries
+        .iter()
+        .map(|boundary| boundary.trailing_zeros())
+        .min()
+        .unwrap_or(0)
+        .min(31)
+}
+
+/// Returns the bounds encoded in blocks of [`BLOCK_BOUNDARIES`], and the offset of each block.
+///
+/// Each block starts with its first bound, followed by the difference of each bound to the one
+/// before it, all as unsigned LEB128: seven bits to a byte, the least significant first, with the
+/// most significant bit set on every byte but the last.
+///
+/// # Panics
+///
+/// Panics if the bounds aren't increasing, or if a block starts past 64 KiB.
+pub(crate) fn encode(boundaries: &[u32]) -> (Vec<u8>, Vec<u16>) {
+    let mut bytes = Vec::new();
+    let mut blocks = Vec::new();
+    for block in boundaries.chunks(BLOCK_BOUNDARIES) {
+        blocks.push(u16::try_from(bytes.len()).expect("The blocks must start before 64 KiB"));
+        write_leb128(&mut bytes, block[0]);
+        for pair in block.windows(2) {
+            assert!(pair[0] < pair[1], "The bounds must be increasing");
+            write_leb128(&mut bytes, pair[1] - pair[0]);
+        }
+    }
+    (bytes, blocks)
+}
+
+/// Appends a value as unsigned LEB128.
+fn write_leb128(bytes: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
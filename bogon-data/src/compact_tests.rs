@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use crate::{
+    compact::{boundaries, encode, shift, BLOCK_BOUNDARIES},
+    V6_ALLOCATION_BLOCKS, V6_ALLOCATION_BOUNDARIES, V6_ALLOCATION_BOUNDARY_SHIFT,
+    V6_ALLOCATION_RANGES,
+};
+
+/// Decodes the bounds of each block, the way `bogon` searches them.
+fn decode(bytes: &[u8], blocks: &[u16]) -> Vec<u32> {
+    let mut boundaries = Vec::new();
+    for (index, &offset) in blocks.iter().enumerate() {
+        let end = blocks.get(index + 1).map_or(bytes.len(), |&end| end.into());
+        let mut offset = usize::from(offset);
+        let mut boundary = None;
+        while offset < end {
+            let (mut value, mut shift) = (0, 0);
+            loop {
+                let byte = bytes[offset];
+                offset += 1;
+                value |= u32::from(byte & 0x7f) << shift;
+                shift += 7;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            let next = boundary.map_or(value, |boundary| boundary + value);
+            boundaries.push(next);
+            boundary = Some(next);
+        }
+    }
+    boundaries
+}
+
+#[test]
+fn bounds_of_ranges() {
+    assert_eq!(boundaries(&[]), []);
+    assert_eq!(boundaries(&[(10, 19), (30, 39)]), [10, 20, 30, 40]);
+    assert_eq!(
+        boundaries(&[(0, 0), (u32::MAX, u32::MAX)]),
+        [0, 1, u32::MAX]
+    );
+}
+
+#[test]
+fn adjacent_ranges_share_no_bounds() {
+    assert_eq!(
+        boundaries(&[(10, 19), (20, 29), (40, 49)]),
+        [10, 30, 40, 50]
+    );
+}
+
+#[test]
+#[should_panic(expected = "The ranges must be sorted and disjoint")]
+fn overlapping_ranges() {
+    boundaries(&[(10, 19), (15, 29)]);
+}
+
+#[test]
+fn shared_trailing_zeros() {
+    assert_eq!(shift(&[]), 0);
+    assert_eq!(shift(&[0x200, 0x1000, 0x2_0000]), 9);
+    assert_eq!(shift(&[0x200, 0x1001]), 0);
+    // Zero has 32 trailing zeros, but the bounds can only be shifted by 31 bits.
+    assert_eq!(shift(&[0]), 31);
+}
+
+#[test]
+fn leb128() {
+    assert_eq!(encode(&[0]), (vec![0], vec![0]));
+    assert_eq!(encode(&[0x7f, 0x80]), (vec![0x7f, 0x01], vec![0]));
+    assert_eq!(
+        encode(&[0x80, u32::MAX]),
+        (vec![0x80, 0x01, 0xff, 0xfe, 0xff, 0xff, 0x0f], vec![0])
+    );
+}
+
+#[test]
+fn blocks_start_with_their_first_bound() {
+    let bounds = (1..=BLOCK_BOUNDARIES as u32 * 2 + 1)
+        .map(|n| n * 1000)
+        .collect::<Vec<_>>();
+    let (bytes, blocks) = encode(&bounds);
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(decode(&bytes, &blocks), bounds);
+}
+
+#[test]
+fn generated_bounds_decode_to_the_ranges() {
+    let shifted = decode(&V6_ALLOCATION_BOUNDARIES, &V6_ALLOCATION_BLOCKS);
+    let bounds = shifted
+        .iter()
+        .map(|bound| bound << V6_ALLOCATION_BOUNDARY_SHIFT)
+        .collect::<Vec<_>>();
+    assert_eq!(bounds, boundaries(&V6_ALLOCATION_RANGES));
+    assert_eq!(V6_ALLOCATION_BOUNDARY_SHIFT, shift(&bounds));
+    assert_eq!(
+        (
+            V6_ALLOCATION_BOUNDARIES.to_vec(),
+            V6_ALLOCATION_BLOCKS.to_vec()
+        ),
+        encode(&shifted)
+    );
+}
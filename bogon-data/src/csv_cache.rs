@@ -0,0 +1,221 @@
+//! Caching the registry CSV files the `download` feature downloads, so clean builds only send a
+//! conditional request and still build when the IANA website is unreachable.
+//!
+//! This module is shared with `build.rs` and the `update-data` xtask, which include it with
+//! `#[path]`. The library only compiles it for its tests.
+
+use std::{
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The first line of a cache file, so files written in another format are ignored.
+const MAGIC: &str = "bogon-registry-cache 1";
+
+/// The validators of a downloaded registry, sent back to ask whether the cached copy is current.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Validators {
+    /// The `ETag` header, sent back as `If-None-Match`.
+    pub(crate) etag: Option<String>,
+    /// The `Last-Modified` header, sent back as `If-Modified-Since`.
+    pub(crate) last_modified: Option<String>,
+}
+
+/// The response to a request for a registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Response {
+    /// The registry changed, or there was no cached copy to compare it with.
+    Modified {
+        body: String,
+        validators: Validators,
+    },
+    /// The cached copy is current.
+    NotModified,
+}
+
+/// Downloads registries. The build script downloads them with `reqwest`, the tests use a mock.
+pub(crate) trait Fetcher {
+    /// Requests a registry, conditional on the validators of the cached copy if there is one.
+    fn fetch(&mut self, url: &str, cached: Option<&Validators>) -> Result<Response, String>;
+}
+
+/// A registry CSV file returned by [`fetch_cached`], and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Fetched {
+    /// The registry was downloaded, because it changed or wasn't cached yet.
+    Downloaded {
+        csv: String,
+        /// Why the download couldn't be cached, if it couldn't.
+        cache_error: Option<String>,
+    },
+    /// The cached copy, which the server confirmed is current.
+    Revalidated(String),
+    /// The cached copy, because the registry couldn't be downloaded.
+    Stale { csv: String, error: String },
+    /// Neither a download nor a cached copy, the build falls back to the bundled copy.
+    Unavailable { error: String },
+}
+
+/// A directory of downloaded registries, keyed by URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// Returns the cache directory named by `BOGON_CACHE_DIR`, or `bogon-registry-cache` in the
+    /// parent of `$OUT_DIR/..`, which every build of the crate in a target directory shares. An
+    /// empty variable counts as unset.
+    pub(crate) fn choose(cache_dir: Option<OsString>, out_dir: &Path) -> Self {
+        match cache_dir {
+            Some(dir) if !dir.is_empty() => Cache::new(dir),
+            _ => {
+                let build_dir = out_dir.ancestors().nth(2).unwrap_or(out_dir);
+                Cache::new(build_dir.join("bogon-registry-cache"))
+            }
+        }
+    }
+
+    /// Returns the path of the cached copy of a registry.
+    ///
+    /// The file name is a hash of the URL, followed by the last segment of the URL to tell the
+    /// files apart.
+    pub(crate) fn path(&self, url: &str) -> PathBuf {
+        let name: String = url
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '_' => c,
+                _ => '_',
+            })
+            .collect();
+        self.dir.join(format!("{:016x}-{name}", fnv1a(url)))
+    }
+
+    /// Returns the cached copy of a registry and its validators, or `None` if there's no cached
+    /// copy or it can't be read.
+    pub(crate) fn load(&self, url: &str) -> Option<(String, Validators)> {
+        let contents = fs::read_to_string(self.path(url)).ok()?;
+        let (header, csv) = contents.split_once("\n\n")?;
+
+        let mut lines = header.lines();
+        if lines.next()? != MAGIC {
+            return None;
+        }
+        let mut validators = Validators::default();
+        for line in lines {
+            match line.split_once(": ")? {
+                ("etag", etag) => validators.etag = Some(etag.to_string()),
+                ("last-modified", date) => validators.last_modified = Some(date.to_string()),
+                _ => return None,
+            }
+        }
+        Some((csv.to_string(), validators))
+    }
+
+    /// Caches a downloaded registry.
+    ///
+    /// The file is written next to its final path and renamed into place, so concurrent builds
+    /// never read a half-written file.
+    pub(crate) fn store(&self, url: &str, csv: &str, validators: &Validators) -> io::Result<()> {
+        let mut contents = format!("{MAGIC}\n");
+        // Header values can't contain line breaks, but a misbehaving server shouldn't corrupt
+        // the cache.
+        let headers = [
+            ("etag", &validators.etag),
+            ("last-modified", &validators.last_modified),
+        ];
+        for (name, value) in headers {
+            if let Some(value) = value
+                .as_deref()
+                .filter(|value| !value.contains(['\r', '\n']))
+            {
+                contents.push_str(&format!("{name}: {value}\n"));
+            }
+        }
+        contents.push('\n');
+        contents.push_str(csv);
+
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(url);
+        let mut temp = path.clone().into_os_string();
+        temp.push(format!(".{}.tmp", std::process::id()));
+        fs::write(&temp, contents)?;
+        fs::rename(&temp, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&temp);
+        })
+    }
+}
+
+/// The 64-bit FNV-1a hash, which unlike `DefaultHasher` is the same for every Rust version.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Downloads a registry, revalidating the cached copy if there is one.
+///
+/// A changed registry replaces the cached copy. When the registry can't be downloaded, the cached
+/// copy is returned however old it is.
+pub(crate) fn fetch_cached(fetcher: &mut impl Fetcher, cache: &Cache, url: &str) -> Fetched {
+    let cached = cache.load(url);
+
+    match fetcher.fetch(url, cached.as_ref().map(|(_, validators)| validators)) {
+        Ok(Response::Modified { body, validators }) => {
+            let cache_error = cache
+                .store(url, &body, &validators)
+                .err()
+                .map(|error| error.to_string());
+            Fetched::Downloaded {
+                csv: body,
+                cache_error,
+            }
+        }
+        Ok(Response::NotModified) => match cached {
+            Some((csv, _)) => Fetched::Revalidated(csv),
+            None => Fetched::Unavailable {
+                error: "the server answered an unconditional request with 304 Not Modified"
+                    .to_string(),
+            },
+        },
+        Err(error) => match cached {
+            Some((csv, _)) => Fetched::Stale { csv, error },
+            None => Fetched::Unavailable { error },
+        },
+    }
+}
+
+/// Returns a boolean indicating whether `BOGON_REQUIRE_FRESH_DATA` asks the build to fail rather
+/// than fall back to a cached or bundled copy when a registry can't be downloaded. Only `1`
+/// enables it.
+pub(crate) fn require_fresh_data(value: Option<OsString>) -> bool {
+    value.is_some_and(|value| value == "1")
+}
+
+/// Sends a request, retrying it up to 3 times with 2, 4, and 8 second delays, and returns the
+/// last error if every attempt fails.
+pub(crate) fn with_retries<T>(
+    mut request: impl FnMut() -> Result<T, String>,
+    mut sleep: impl FnMut(Duration),
+) -> Result<T, String> {
+    let mut retries = 0;
+    loop {
+        match request() {
+            Ok(response) => break Ok(response),
+            Err(error) if retries >= 3 => break Err(error),
+            Err(_) => {
+                retries += 1;
+                sleep(Duration::from_secs(2u64.pow(retries)));
+            }
+        }
+    }
+}
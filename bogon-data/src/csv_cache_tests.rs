@@ -0,0 +1,258 @@
+use std::{ffi::OsString, fs, path::Path, time::Duration};
+
+use crate::csv_cache::{
+    fetch_cached, require_fresh_data, with_retries, Cache, Fetched, Fetcher, Response, Validators,
+};
+
+const URL: &str = "https://www.iana.org/assignments/ipv4-address-space/ipv4-address-space.csv";
+
+/// Answers every request with the same response, recording the validators it was sent.
+struct MockFetcher {
+    response: Result<Response, String>,
+    requests: Vec<Option<Validators>>,
+}
+
+impl MockFetcher {
+    fn new(response: Result<Response, String>) -> Self {
+        MockFetcher {
+            response,
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl Fetcher for MockFetcher {
+    fn fetch(&mut self, url: &str, cached: Option<&Validators>) -> Result<Response, String> {
+        assert_eq!(url, URL);
+        self.requests.push(cached.cloned());
+        self.response.clone()
+    }
+}
+
+/// Returns an empty cache in a fresh temporary directory.
+fn cache(name: &str) -> Cache {
+    let dir = std::env::temp_dir().join(format!("bogon-cache-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    Cache::new(dir)
+}
+
+fn validators() -> Validators {
+    Validators {
+        etag: Some("\"5f3a-62c1\"".to_string()),
+        last_modified: Some("Tue, 08 Oct 2024 17:10:03 GMT".to_string()),
+    }
+}
+
+fn modified(body: &str) -> Result<Response, String> {
+    Ok(Response::Modified {
+        body: body.to_string(),
+        validators: validators(),
+    })
+}
+
+#[test]
+fn cache_miss_downloads_and_stores() {
+    let cache = cache("miss");
+    let mut fetcher = MockFetcher::new(modified("Prefix\r\n000/8\r\n"));
+
+    assert_eq!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Downloaded {
+            csv: "Prefix\r\n000/8\r\n".to_string(),
+            cache_error: None,
+        }
+    );
+    // Without a cached copy the request is unconditional.
+    assert_eq!(fetcher.requests, [None]);
+    assert_eq!(
+        cache.load(URL),
+        Some(("Prefix\r\n000/8\r\n".to_string(), validators()))
+    );
+}
+
+#[test]
+fn cache_hit_revalidates() {
+    let cache = cache("hit");
+    cache.store(URL, "Prefix\n000/8\n", &validators()).unwrap();
+    let mut fetcher = MockFetcher::new(Ok(Response::NotModified));
+
+    assert_eq!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Revalidated("Prefix\n000/8\n".to_string())
+    );
+    assert_eq!(fetcher.requests, [Some(validators())]);
+}
+
+#[test]
+fn changed_registry_replaces_cached_copy() {
+    let cache = cache("changed");
+    cache.store(URL, "old", &Validators::default()).unwrap();
+    let mut fetcher = MockFetcher::new(modified("new"));
+
+    assert_eq!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Downloaded {
+            csv: "new".to_string(),
+            cache_error: None,
+        }
+    );
+    assert_eq!(fetcher.requests, [Some(Validators::default())]);
+    assert_eq!(cache.load(URL), Some(("new".to_string(), validators())));
+}
+
+#[test]
+fn stale_cache_is_used_offline() {
+    let cache = cache("offline");
+    cache.store(URL, "Prefix\n000/8\n", &validators()).unwrap();
+    let mut fetcher = MockFetcher::new(Err("dns error".to_string()));
+
+    assert_eq!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Stale {
+            csv: "Prefix\n000/8\n".to_string(),
+            error: "dns error".to_string(),
+        }
+    );
+    // The cached copy is kept for the next build.
+    assert!(cache.load(URL).is_some());
+}
+
+#[test]
+fn offline_without_cache_is_unavailable() {
+    let cache = cache("unavailable");
+    let mut fetcher = MockFetcher::new(Err("dns error".to_string()));
+
+    assert_eq!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Unavailable {
+            error: "dns error".to_string(),
+        }
+    );
+
+    // A 304 is meaningless without a cached copy.
+    let mut fetcher = MockFetcher::new(Ok(Response::NotModified));
+    assert!(matches!(
+        fetch_cached(&mut fetcher, &cache, URL),
+        Fetched::Unavailable { .. }
+    ));
+}
+
+#[test]
+fn store_leaves_no_temporary_files() {
+    let cache = cache("atomic");
+    cache.store(URL, "first", &validators()).unwrap();
+    cache.store(URL, "second", &validators()).unwrap();
+
+    let dir = cache.path(URL).parent().unwrap().to_path_buf();
+    let files: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(files, [cache.path(URL)]);
+}
+
+#[test]
+fn unreadable_cache_files_are_misses() {
+    let cache = cache("corrupt");
+    cache.store(URL, "csv", &validators()).unwrap();
+
+    fs::write(cache.path(URL), "not a cache file").unwrap();
+    assert_eq!(cache.load(URL), None);
+
+    fs::write(cache.path(URL), "bogon-registry-cache 1\nsize: 3\n\ncsv").unwrap();
+    assert_eq!(cache.load(URL), None);
+
+    // Header values with line breaks are dropped rather than corrupting the file.
+    let broken = Validators {
+        etag: Some("\"a\"\n\nb".to_string()),
+        last_modified: None,
+    };
+    cache.store(URL, "csv", &broken).unwrap();
+    assert_eq!(
+        cache.load(URL),
+        Some(("csv".to_string(), Validators::default()))
+    );
+}
+
+#[test]
+fn cache_files_are_keyed_by_url() {
+    let cache = Cache::new("/cache");
+    let v4 = cache.path(URL);
+    let v6 = cache.path(
+        "https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.csv",
+    );
+
+    assert_ne!(v4, v6);
+    assert_eq!(v4.parent(), Some(Path::new("/cache")));
+    let name = v4.file_name().unwrap().to_str().unwrap();
+    assert!(name.ends_with("-ipv4-address-space.csv"), "{name}");
+    assert_eq!(name.len(), 16 + "-ipv4-address-space.csv".len());
+}
+
+#[test]
+fn cache_dir_defaults_to_the_build_directory() {
+    let out_dir = Path::new("/project/target/debug/build/bogon-0123456789abcdef/out");
+    assert_eq!(
+        Cache::choose(None, out_dir),
+        Cache::new("/project/target/debug/build/bogon-registry-cache")
+    );
+    assert_eq!(
+        Cache::choose(Some(OsString::new()), out_dir),
+        Cache::new("/project/target/debug/build/bogon-registry-cache")
+    );
+    assert_eq!(
+        Cache::choose(Some(OsString::from("/var/cache/bogon")), out_dir),
+        Cache::new("/var/cache/bogon")
+    );
+}
+
+#[test]
+fn retries_until_success() {
+    let mut attempts = 0;
+    let mut sleeps = Vec::new();
+    let result = with_retries(
+        || {
+            attempts += 1;
+            if attempts < 3 {
+                Err(format!("attempt {attempts} failed"))
+            } else {
+                Ok("csv")
+            }
+        },
+        |delay| sleeps.push(delay),
+    );
+
+    assert_eq!(result, Ok("csv"));
+    assert_eq!(sleeps, [Duration::from_secs(2), Duration::from_secs(4)]);
+}
+
+#[test]
+fn gives_up_after_three_retries() {
+    let mut attempts = 0;
+    let mut sleeps = Vec::new();
+    let result: Result<(), _> = with_retries(
+        || {
+            attempts += 1;
+            Err(format!("attempt {attempts} failed"))
+        },
+        |delay| sleeps.push(delay),
+    );
+
+    // The error of the last attempt is returned.
+    assert_eq!(result, Err("attempt 4 failed".to_string()));
+    assert_eq!(sleeps, [2, 4, 8].map(Duration::from_secs),);
+}
+
+#[test]
+fn first_success_does_not_sleep() {
+    let result = with_retries(|| Ok(1), |_| panic!("slept after a successful request"));
+    assert_eq!(result, Ok(1));
+}
+
+#[test]
+fn require_fresh_data_opt_in() {
+    assert!(require_fresh_data(Some(OsString::from("1"))));
+    assert!(!require_fresh_data(Some(OsString::from("0"))));
+    assert!(!require_fresh_data(Some(OsString::new())));
+    assert!(!require_fresh_data(None));
+}
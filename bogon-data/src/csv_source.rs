@@ -0,0 +1,66 @@
+//! Choosing where the build script reads each registry CSV file from.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`. The library only
+//! compiles it for its tests.
+
+use std::{ffi::OsString, fmt, path::PathBuf};
+
+/// Where the build script reads a registry CSV file from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CsvSource {
+    /// A local file named by an environment variable, like `BOGON_IPV6_CSV`.
+    Override(PathBuf),
+    /// The IANA website, with the `download` feature.
+    Download,
+    /// The copy bundled with the crate.
+    Bundled,
+}
+
+impl CsvSource {
+    /// Returns where a registry CSV file is read from.
+    ///
+    /// A file named by the environment variable takes precedence over downloading the registry,
+    /// which takes precedence over the bundled copy. An empty variable counts as unset.
+    pub(crate) fn choose(override_path: Option<OsString>, download: bool) -> Self {
+        match override_path {
+            Some(path) if !path.is_empty() => CsvSource::Override(path.into()),
+            _ if download => CsvSource::Download,
+            _ => CsvSource::Bundled,
+        }
+    }
+
+    /// Returns a description of a registry CSV file read from this source, for error messages.
+    pub(crate) fn describe<'a>(&'a self, file_name: &'a str) -> impl fmt::Display + 'a {
+        struct Describe<'a>(&'a CsvSource, &'a str);
+
+        impl fmt::Display for Describe<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.0 {
+                    CsvSource::Override(path) => write!(f, "`{}`", path.display()),
+                    CsvSource::Download => write!(f, "the downloaded {}", self.1),
+                    CsvSource::Bundled => write!(f, "the bundled {}", self.1),
+                }
+            }
+        }
+
+        Describe(self, file_name)
+    }
+
+    /// Returns how to build with a registry CSV file that was malformed when read from this source,
+    /// given the environment variable naming a local copy.
+    pub(crate) fn remedy(&self, env: &str) -> String {
+        match self {
+            CsvSource::Override(_) => {
+                format!("fix the file, or unset {env} to use the bundled copy")
+            }
+            CsvSource::Download => format!(
+                "set {env} to the path of a vetted copy, or disable the `download` feature to use \
+                 the bundled copy"
+            ),
+            CsvSource::Bundled => format!(
+                "set {env} to the path of a corrected copy, and please report the bundled copy as \
+                 a bug"
+            ),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use crate::csv_source::CsvSource;
+
+#[test]
+fn override_takes_precedence() {
+    let path = Some(OsString::from("/srv/iana/ipv6.csv"));
+    let expected = CsvSource::Override(PathBuf::from("/srv/iana/ipv6.csv"));
+    assert_eq!(CsvSource::choose(path.clone(), true), expected);
+    assert_eq!(CsvSource::choose(path, false), expected);
+}
+
+#[test]
+fn download_takes_precedence_over_bundled() {
+    assert_eq!(CsvSource::choose(None, true), CsvSource::Download);
+    assert_eq!(CsvSource::choose(None, false), CsvSource::Bundled);
+}
+
+#[test]
+fn empty_override_is_unset() {
+    assert_eq!(
+        CsvSource::choose(Some(OsString::new()), true),
+        CsvSource::Download
+    );
+    assert_eq!(
+        CsvSource::choose(Some(OsString::new()), false),
+        CsvSource::Bundled
+    );
+}
+
+#[test]
+fn describe() {
+    let name = "ipv6-unicast-address-assignments.csv";
+    assert_eq!(
+        CsvSource::Override("/srv/iana/ipv6.csv".into())
+            .describe(name)
+            .to_string(),
+        "`/srv/iana/ipv6.csv`"
+    );
+    assert_eq!(
+        CsvSource::Download.describe(name).to_string(),
+        "the downloaded ipv6-unicast-address-assignments.csv"
+    );
+    assert_eq!(
+        CsvSource::Bundled.describe(name).to_string(),
+        "the bundled ipv6-unicast-address-assignments.csv"
+    );
+}
+
+#[test]
+fn remedy_names_the_variable() {
+    let remedy = |source: CsvSource| source.remedy("BOGON_IPV4_CSV");
+
+    assert_eq!(
+        remedy(CsvSource::Override(PathBuf::from("/srv/iana/ipv4.csv"))),
+        "fix the file, or unset BOGON_IPV4_CSV to use the bundled copy"
+    );
+    assert!(
+        remedy(CsvSource::Download).starts_with("set BOGON_IPV4_CSV to the path of a vetted copy")
+    );
+    assert!(remedy(CsvSource::Bundled)
+        .starts_with("set BOGON_IPV4_CSV to the path of a corrected copy"));
+}
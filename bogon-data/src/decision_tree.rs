@@ -0,0 +1,69 @@
+//! Generating `is_bogon_v6_generated`, a nested `match` over the top 32 bits of an IPv6 address that
+//! the `codegen-match` feature compiles in place of searching `V6_ALLOCATION_RANGES`.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`. The library only
+//! compiles it for its tests.
+
+use alloc::{format, string::String, vec::Vec};
+
+/// The most intervals a leaf of the tree tests with one `matches!`, past which it splits in two.
+pub(crate) const LEAF_INTERVALS: usize = 4;
+
+/// Returns the intervals of the 32-bit space between the bounds of the ranges, in order and
+/// covering all of it, each with a boolean indicating whether it is outside of every range.
+///
+/// # Panics
+///
+/// Panics if the ranges aren't sorted and disjoint, like `allocation_tables` returns them.
+pub(crate) fn intervals(ranges: &[(u32, u32)]) -> Vec<(u32, u32, bool)> {
+    let mut intervals = Vec::with_capacity(ranges.len() * 2 + 1);
+    let mut next = Some(0u32);
+    for &(first, last) in ranges {
+        let start = next.expect("The ranges must be sorted and disjoint");
+        assert!(
+            start <= first && first <= last,
+            "The ranges must be sorted and disjoint"
+        );
+        if start < first {
+            intervals.push((start, first - 1, true));
+        }
+        match intervals.last_mut() {
+            // Adjacent ranges make a single interval.
+            Some((_, end, false)) if end.checked_add(1) == Some(first) => *end = last,
+            _ => intervals.push((first, last, false)),
+        }
+        next = last.checked_add(1);
+    }
+    if let Some(start) = next {
+        intervals.push((start, u32::MAX, true));
+    }
+    intervals
+}
+
+/// Returns the body of `is_bogon_v6_generated`: a balanced tree of `match` expressions on `top32`
+/// splitting the intervals in half, with a `matches!` of the bogus intervals at each leaf. Every
+/// line is indented by `indent` levels of four spaces.
+pub(crate) fn decision_tree(intervals: &[(u32, u32, bool)], indent: usize) -> String {
+    let pad = "    ".repeat(indent);
+    if intervals.len() <= LEAF_INTERVALS {
+        let bogus = intervals
+            .iter()
+            .filter(|&&(_, _, bogus)| bogus)
+            .map(|&(first, last, _)| format!("{first:#010x}..={last:#010x}"))
+            .collect::<Vec<_>>();
+        return match bogus.len() {
+            0 => format!("{pad}false\n"),
+            n if n == intervals.len() => format!("{pad}true\n"),
+            _ => format!("{pad}matches!(top32, {})\n", bogus.join(" | ")),
+        };
+    }
+
+    let (left, right) = intervals.split_at(intervals.len() / 2);
+    let (first, _, _) = left[0];
+    let (_, last, _) = left[left.len() - 1];
+    format!(
+        "{pad}match top32 {{\n{pad}    {first:#010x}..={last:#010x} => {{\n{}{pad}    }}\n{pad}    _ => {{\n{}{pad}    }}\n{pad}}}\n",
+        decision_tree(left, indent + 2),
+        decision_tree(right, indent + 2),
+    )
+}
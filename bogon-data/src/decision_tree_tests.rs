@@ -0,0 +1,94 @@
+use crate::decision_tree::{decision_tree, intervals, LEAF_INTERVALS};
+
+#[test]
+fn intervals_between_ranges() {
+    assert_eq!(intervals(&[]), vec![(0, u32::MAX, true)]);
+    assert_eq!(
+        intervals(&[(10, 19), (30, 39)]),
+        vec![
+            (0, 9, true),
+            (10, 19, false),
+            (20, 29, true),
+            (30, 39, false),
+            (40, u32::MAX, true),
+        ]
+    );
+}
+
+#[test]
+fn intervals_at_the_ends() {
+    assert_eq!(
+        intervals(&[(0, 9), (u32::MAX - 9, u32::MAX)]),
+        vec![
+            (0, 9, false),
+            (10, u32::MAX - 10, true),
+            (u32::MAX - 9, u32::MAX, false),
+        ]
+    );
+    assert_eq!(intervals(&[(0, u32::MAX)]), vec![(0, u32::MAX, false)]);
+}
+
+#[test]
+fn adjacent_ranges_make_one_interval() {
+    assert_eq!(
+        intervals(&[(10, 19), (20, 29)]),
+        vec![(0, 9, true), (10, 29, false), (30, u32::MAX, true)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "The ranges must be sorted and disjoint")]
+fn unsorted_ranges() {
+    intervals(&[(30, 39), (10, 19)]);
+}
+
+#[test]
+#[should_panic(expected = "The ranges must be sorted and disjoint")]
+fn overlapping_ranges() {
+    intervals(&[(10, 29), (20, 39)]);
+}
+
+#[test]
+fn leaves() {
+    assert_eq!(decision_tree(&[(0, u32::MAX, true)], 0), "true\n");
+    assert_eq!(decision_tree(&[(0, u32::MAX, false)], 1), "    false\n");
+    assert_eq!(
+        decision_tree(&intervals(&[(0x10, 0x1f)]), 0),
+        "matches!(top32, 0x00000000..=0x0000000f | 0x00000020..=0xffffffff)\n"
+    );
+}
+
+#[test]
+fn splits_in_half() {
+    let intervals = intervals(&[(0x10, 0x1f), (0x30, 0x3f), (0x50, 0x5f)]);
+    assert!(intervals.len() > LEAF_INTERVALS);
+    assert_eq!(
+        decision_tree(&intervals, 1),
+        "    match top32 {
+        0x00000000..=0x0000002f => {
+            matches!(top32, 0x00000000..=0x0000000f | 0x00000020..=0x0000002f)
+        }
+        _ => {
+            matches!(top32, 0x00000040..=0x0000004f | 0x00000060..=0xffffffff)
+        }
+    }
+"
+    );
+}
+
+#[test]
+fn balanced() {
+    // 64 full leaves nest 6 matches deep.
+    let intervals = (0..64 * LEAF_INTERVALS as u32)
+        .map(|i| (i, i, i % 2 == 0))
+        .collect::<Vec<_>>();
+    let tree = decision_tree(&intervals, 0);
+    let depth = tree
+        .lines()
+        .map(|line| line.len() - line.trim_start().len())
+        .max()
+        .unwrap()
+        / 8;
+    assert_eq!(tree.matches("matches!").count(), 64);
+    assert_eq!(depth, 6);
+}
@@ -0,0 +1,1037 @@
+/// The regional internet registry of each IPv4 /8 as IANA names it, or `"LEGACY"` for legacy
+/// space that no registry administers.
+pub const V4_REGISTRY_RIRS: [Option<&str>; 256] = [
+    None,
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    None,
+    Some("LEGACY"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("LEGACY"),
+    Some("APNIC"),
+    Some("LEGACY"),
+    Some("LEGACY"),
+    Some("LEGACY"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("LEGACY"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("AFRINIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("AFRINIC"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("AFRINIC"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    None,
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("AFRINIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("LACNIC"),
+    Some("RIPE NCC"),
+    Some("LACNIC"),
+    Some("APNIC"),
+    Some("LACNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("LACNIC"),
+    Some("LACNIC"),
+    Some("RIPE NCC"),
+    Some("LACNIC"),
+    Some("LACNIC"),
+    Some("LACNIC"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("AFRINIC"),
+    Some("AFRINIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("LACNIC"),
+    Some("LACNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("ARIN"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("RIPE NCC"),
+    Some("RIPE NCC"),
+    Some("LEGACY"),
+    Some("LEGACY"),
+    Some("ARIN"),
+    Some("RIPE NCC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    Some("APNIC"),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+];
+/// Whether each IPv4 /8 was assigned before the registries existed, whoever administers it now.
+pub const V4_REGISTRY_LEGACY: [bool; 256] = [
+    false,
+    false,
+    false,
+    true,
+    true,
+    false,
+    true,
+    true,
+    true,
+    true,
+    false,
+    true,
+    true,
+    true,
+    false,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    false,
+    false,
+    true,
+    true,
+    false,
+    true,
+    true,
+    true,
+    false,
+    true,
+    true,
+    true,
+    true,
+    false,
+    false,
+    true,
+    false,
+    true,
+    false,
+    false,
+    true,
+    true,
+    true,
+    false,
+    true,
+    true,
+    false,
+    false,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    true,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    true,
+    false,
+    false,
+    true,
+    true,
+    false,
+    false,
+    false,
+    true,
+    false,
+    true,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    true,
+    true,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+    false,
+];
+/// The index into `RDAP_URLS` of each IPv4 /8.
+pub const V4_REGISTRY_RDAP: [Option<u8>; 256] = [
+    None,
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    None,
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(4),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(4),
+    Some(0),
+    Some(1),
+    Some(4),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    None,
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(4),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(3),
+    Some(2),
+    Some(3),
+    Some(0),
+    Some(3),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(2),
+    Some(3),
+    Some(3),
+    Some(2),
+    Some(3),
+    Some(3),
+    Some(3),
+    Some(1),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(4),
+    Some(4),
+    Some(1),
+    Some(1),
+    Some(3),
+    Some(3),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(0),
+    Some(2),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+];
+/// The index into `WHOIS_SERVERS` of each IPv4 /8.
+pub const V4_REGISTRY_WHOIS: [Option<u8>; 256] = [
+    None,
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    None,
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(4),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(4),
+    Some(0),
+    Some(1),
+    Some(4),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    None,
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(1),
+    Some(0),
+    Some(4),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(2),
+    Some(3),
+    Some(2),
+    Some(3),
+    Some(0),
+    Some(3),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(2),
+    Some(3),
+    Some(3),
+    Some(2),
+    Some(3),
+    Some(3),
+    Some(3),
+    Some(1),
+    Some(2),
+    Some(2),
+    Some(2),
+    Some(4),
+    Some(4),
+    Some(1),
+    Some(1),
+    Some(3),
+    Some(3),
+    Some(0),
+    Some(0),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(0),
+    Some(0),
+    Some(2),
+    Some(2),
+    Some(1),
+    Some(1),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    Some(0),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+];
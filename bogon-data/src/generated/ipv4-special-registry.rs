@@ -0,0 +1,69 @@
+/// The bogus IPv4 networks, without the ones the `allow-*` features remove.
+pub const V4_BOGON_NETWORKS: [(u32, u8); 16 - V4_BOGON_ALLOWED] = [
+    // "This network"
+    (0x0, 8),
+    // Private-Use
+    (0xa000000, 8),
+    // Shared Address Space
+    #[cfg(not(feature = "allow-cgnat"))]
+    (0x64400000, 10),
+    // Loopback
+    (0x7f000000, 8),
+    // Link Local
+    (0xa9fe0000, 16),
+    // Private-Use
+    (0xac100000, 12),
+    // IETF Protocol Assignments
+    (0xc0000000, 24),
+    // Documentation (TEST-NET-1)
+    (0xc0000200, 24),
+    // Deprecated (6to4 Relay Anycast)
+    (0xc0586300, 24),
+    // Private-Use
+    (0xc0a80000, 16),
+    // Benchmarking
+    #[cfg(not(feature = "allow-benchmarking"))]
+    (0xc6120000, 15),
+    // Documentation (TEST-NET-2)
+    (0xc6336400, 24),
+    // Documentation (TEST-NET-3)
+    (0xcb007100, 24),
+    // Multicast
+    (0xe0000000, 4),
+    // Reserved
+    #[cfg(not(feature = "allow-reserved-240-4"))]
+    (0xf0000000, 4),
+    // Limited Broadcast
+    (0xffffffff, 32),
+];
+/// The number of entries in `V4_BOGON_NETWORKS`.
+pub const DATASET_V4_RULES: usize = 16 - V4_BOGON_ALLOWED;
+/// The `BogonKind` variant of each entry in `V4_BOGON_NETWORKS`.
+pub const V4_BOGON_KINDS: [&str; 16 - V4_BOGON_ALLOWED] = [
+    "ThisNetwork",
+    "PrivateUse",
+    #[cfg(not(feature = "allow-cgnat"))]
+    "SharedAddressSpace",
+    "Loopback",
+    "LinkLocal",
+    "PrivateUse",
+    "ProtocolAssignments",
+    "Documentation",
+    "SixToFourRelayAnycast",
+    "PrivateUse",
+    #[cfg(not(feature = "allow-benchmarking"))]
+    "Benchmarking",
+    "Documentation",
+    "Documentation",
+    "Multicast",
+    #[cfg(not(feature = "allow-reserved-240-4"))]
+    "ReservedFutureUse",
+    "Broadcast",
+];
+/// The globally reachable networks inside of the bogus IPv4 networks.
+pub const V4_BOGON_EXCEPTIONS: [(u32, u8); 2] = [
+    // Port Control Protocol Anycast
+    (0xc0000009, 32),
+    // Traversal Using Relays around NAT Anycast
+    (0xc000000a, 32),
+];
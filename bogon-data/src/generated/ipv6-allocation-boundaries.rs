@@ -0,0 +1,61 @@
+/// The number of trailing zeros the bounds of `V6_ALLOCATION_BOUNDARIES` were shifted out of.
+pub const V6_ALLOCATION_BOUNDARY_SHIFT: u32 = 9;
+/// The 24 bounds of the ranges of `V6_ALLOCATION_RANGES`: the first /32 of each range, and the
+/// /32 after its last one, shifted right by `V6_ALLOCATION_BOUNDARY_SHIFT`. In blocks of 8
+/// bounds, each starting with its first bound followed by the difference of each bound to the
+/// one before it, all as unsigned LEB128.
+pub const V6_ALLOCATION_BOUNDARIES: [u8; 47] = [
+    0x81,
+    0x81,
+    0x40,
+    0x07,
+    0x01,
+    0x1e,
+    0x01,
+    0x08,
+    0x10,
+    0x20,
+    0x80,
+    0x83,
+    0x40,
+    0x20,
+    0xe0,
+    0xfc,
+    0x07,
+    0x80,
+    0x10,
+    0x80,
+    0xf0,
+    0x03,
+    0x81,
+    0x10,
+    0xff,
+    0x0f,
+    0x01,
+    0x80,
+    0xb0,
+    0x4c,
+    0x80,
+    0x10,
+    0x80,
+    0xc0,
+    0x03,
+    0x80,
+    0x10,
+    0x80,
+    0xf0,
+    0x03,
+    0x80,
+    0x20,
+    0x80,
+    0xe0,
+    0x03,
+    0x80,
+    0x10,
+];
+/// The offset of each block of `V6_ALLOCATION_BOUNDARIES`.
+pub const V6_ALLOCATION_BLOCKS: [u16; 3] = [
+    0,
+    10,
+    27,
+];
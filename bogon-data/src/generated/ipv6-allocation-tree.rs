@@ -0,0 +1,56 @@
+/// Returns a boolean indicating whether the IPv6 addresses whose top 32 bits are `top32` are
+/// outside of every range of `V6_ALLOCATION_RANGES`, as a balanced tree of `match` expressions
+/// over its 25 intervals.
+#[inline]
+pub const fn is_bogon_v6_generated(top32: u32) -> bool {
+    match top32 {
+        0x00000000..=0x240fffff => {
+            match top32 {
+                0x00000000..=0x20015fff => {
+                    match top32 {
+                        0x00000000..=0x200111ff => {
+                            matches!(top32, 0x00000000..=0x200101ff | 0x20011000..=0x200111ff)
+                        }
+                        _ => {
+                            matches!(top32, 0x20014e00..=0x20014fff)
+                        }
+                    }
+                }
+                _ => {
+                    match top32 {
+                        0x20016000..=0x2002ffff => {
+                            matches!(top32, 0x20016000..=0x20017fff | 0x2001c000..=0x2002ffff)
+                        }
+                        _ => {
+                            matches!(top32, 0x20034000..=0x23ffffff)
+                        }
+                    }
+                }
+            }
+        }
+        _ => {
+            match top32 {
+                0x24100000..=0x263fffff => {
+                    match top32 {
+                        0x24100000..=0x261fffff => {
+                            matches!(top32, 0x24100000..=0x25ffffff | 0x26100200..=0x261fffff)
+                        }
+                        _ => {
+                            matches!(top32, 0x26200200..=0x262fffff)
+                        }
+                    }
+                }
+                _ => {
+                    match top32 {
+                        0x26400000..=0x29ffffff => {
+                            matches!(top32, 0x26400000..=0x27ffffff | 0x28100000..=0x29ffffff)
+                        }
+                        _ => {
+                            matches!(top32, 0x2a200000..=0x2bffffff | 0x2c100000..=0xffffffff)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
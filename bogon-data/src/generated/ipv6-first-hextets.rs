@@ -0,0 +1,517 @@
+/// The bucket of every /16 of the IPv6 address space, 2 bits each, starting with the least
+/// significant bits of the first word: 0 if none of the /16 is bogus, 1 if all of it is, and
+/// 2 if only part of it is.
+pub const V6_FIRST_HEXTETS: [u64; 2048] = [
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555599, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555500000000, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555600000000, 0x0000000055555556, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555500000000, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x0000000000000000, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555500000000, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+    0x5555555555555555, 0x5555555555555555, 0x5555555555555555, 0x5555555555555555,
+];
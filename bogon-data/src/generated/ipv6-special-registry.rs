@@ -0,0 +1,939 @@
+/// The networks of the IPv6 special-purpose registry, from the most to the least specific.
+pub const V6_SPECIAL_REGISTRY_NETWORKS: [(u128, u8); 23] = [
+    (0x0, 128),
+    (0x1, 128),
+    (0x20010001000000000000000000000001, 128),
+    (0x20010001000000000000000000000002, 128),
+    (0xffff00000000, 96),
+    (0x64ff9b000000000000000000000000, 96),
+    (0x1000000000000000000000000000000, 64),
+    (0x64ff9b000100000000000000000000, 48),
+    (0x20010002000000000000000000000000, 48),
+    (0x20010004011200000000000000000000, 48),
+    (0x2620004f800000000000000000000000, 48),
+    (0x20010000000000000000000000000000, 32),
+    (0x20010003000000000000000000000000, 32),
+    (0x20010db8000000000000000000000000, 32),
+    (0x20010010000000000000000000000000, 28),
+    (0x20010020000000000000000000000000, 28),
+    (0x20010030000000000000000000000000, 28),
+    (0x20010000000000000000000000000000, 23),
+    (0x3fff0000000000000000000000000000, 20),
+    (0x20020000000000000000000000000000, 16),
+    (0x5f000000000000000000000000000000, 16),
+    (0xfe800000000000000000000000000000, 10),
+    (0xfc000000000000000000000000000000, 7),
+];
+/// The block of each entry in `V6_SPECIAL_REGISTRY_NETWORKS`: its name, whether it is valid as a
+/// source, valid as a destination, forwardable, and globally reachable, and whether it is
+/// deprecated.
+pub const V6_SPECIAL_REGISTRY: [(&str, [Option<bool>; 4], bool); 23] = [
+    ("Unspecified Address", [Some(true), Some(false), Some(false), Some(false)], false),
+    ("Loopback Address", [Some(false), Some(false), Some(false), Some(false)], false),
+    ("Port Control Protocol Anycast", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("Traversal Using Relays around NAT Anycast", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("IPv4-mapped Address", [Some(false), Some(false), Some(false), Some(false)], false),
+    ("IPv4-IPv6 Translat.", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("Discard-Only Address Block", [Some(true), Some(true), Some(true), Some(false)], false),
+    ("IPv4-IPv6 Translat.", [Some(true), Some(true), Some(true), Some(false)], false),
+    ("Benchmarking", [Some(true), Some(true), Some(true), Some(false)], false),
+    ("AS112-v6", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("Direct Delegation AS112 Service", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("TEREDO", [Some(true), Some(true), Some(true), None], false),
+    ("AMT", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("Documentation", [Some(false), Some(false), Some(false), Some(false)], false),
+    ("Deprecated (previously ORCHID)", [None, None, None, None], true),
+    ("ORCHIDv2", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("Drone Remote ID Protocol Entity Tags (DETs) Prefix", [Some(true), Some(true), Some(true), Some(true)], false),
+    ("IETF Protocol Assignments", [Some(false), Some(false), Some(false), Some(false)], false),
+    ("Documentation", [Some(false), Some(false), Some(false), Some(false)], false),
+    ("6to4", [Some(true), Some(true), Some(true), None], false),
+    ("Segment Routing (SRv6) SIDs", [Some(true), Some(true), Some(true), Some(false)], false),
+    ("Link-Local Unicast", [Some(true), Some(true), Some(false), Some(false)], false),
+    ("Unique-Local", [Some(true), Some(true), Some(true), Some(false)], false),
+];
+/// The bogus IPv6 networks outside of 2000::/3 that fit into the top 32 bits, as the top 32
+/// bits of their addresses, from the most to the least specific.
+pub const V6_BOGON_NETWORKS: [(u32, u8); 98] = [
+    // Reserved by IETF
+    (0x1, 32),
+    // Reserved by IETF
+    (0x64ff9a, 32),
+    // Reserved by IETF
+    (0x1000001, 32),
+    // Reserved by IETF
+    (0x2, 31),
+    // Reserved by IETF
+    (0x64ff98, 31),
+    // Reserved by IETF
+    (0x1000002, 31),
+    // Reserved by IETF
+    (0x4, 30),
+    // Reserved by IETF
+    (0x64ff9c, 30),
+    // Reserved by IETF
+    (0x1000004, 30),
+    // Reserved by IETF
+    (0x8, 29),
+    // Reserved by IETF
+    (0x64ff90, 29),
+    // Reserved by IETF
+    (0x1000008, 29),
+    // Reserved by IETF
+    (0x10, 28),
+    // Reserved by IETF
+    (0x64ff80, 28),
+    // Reserved by IETF
+    (0x1000010, 28),
+    // Reserved by IETF
+    (0x20, 27),
+    // Reserved by IETF
+    (0x64ffa0, 27),
+    // Reserved by IETF
+    (0x1000020, 27),
+    // Reserved by IETF
+    (0x40, 26),
+    // Reserved by IETF
+    (0x64ffc0, 26),
+    // Reserved by IETF
+    (0x1000040, 26),
+    // Reserved by IETF
+    (0x80, 25),
+    // Reserved by IETF
+    (0x64ff00, 25),
+    // Reserved by IETF
+    (0x1000080, 25),
+    // Reserved by IETF
+    (0x100, 24),
+    // Reserved by IETF
+    (0x64fe00, 24),
+    // Reserved by IETF
+    (0x1000100, 24),
+    // Reserved by IETF
+    (0x200, 23),
+    // Reserved by IETF
+    (0x64fc00, 23),
+    // Reserved by IETF
+    (0x1000200, 23),
+    // Reserved by IETF
+    (0x400, 22),
+    // Reserved by IETF
+    (0x64f800, 22),
+    // Reserved by IETF
+    (0x1000400, 22),
+    // Reserved by IETF
+    (0x800, 21),
+    // Reserved by IETF
+    (0x64f000, 21),
+    // Reserved by IETF
+    (0x1000800, 21),
+    // Reserved by IETF
+    (0x1000, 20),
+    // Reserved by IETF
+    (0x64e000, 20),
+    // Reserved by IETF
+    (0x1001000, 20),
+    // Reserved by IETF
+    (0x2000, 19),
+    // Reserved by IETF
+    (0x64c000, 19),
+    // Reserved by IETF
+    (0x1002000, 19),
+    // Reserved by IETF
+    (0x4000, 18),
+    // Reserved by IETF
+    (0x648000, 18),
+    // Reserved by IETF
+    (0x1004000, 18),
+    // Reserved by IETF
+    (0x8000, 17),
+    // Reserved by IETF
+    (0x640000, 17),
+    // Reserved by IETF
+    (0x1008000, 17),
+    // Reserved by IETF
+    (0x10000, 16),
+    // Reserved by IETF
+    (0x650000, 16),
+    // Reserved by IETF
+    (0x1010000, 16),
+    // Segment Routing (SRv6) SIDs
+    (0x5f000000, 16),
+    // Reserved by IETF
+    (0x5f010000, 16),
+    // Reserved by IETF
+    (0x20000, 15),
+    // Reserved by IETF
+    (0x660000, 15),
+    // Reserved by IETF
+    (0x1020000, 15),
+    // Reserved by IETF
+    (0x5f020000, 15),
+    // Reserved by IETF
+    (0x40000, 14),
+    // Reserved by IETF
+    (0x600000, 14),
+    // Reserved by IETF
+    (0x1040000, 14),
+    // Reserved by IETF
+    (0x5f040000, 14),
+    // Reserved by IETF
+    (0x80000, 13),
+    // Reserved by IETF
+    (0x680000, 13),
+    // Reserved by IETF
+    (0x1080000, 13),
+    // Reserved by IETF
+    (0x5f080000, 13),
+    // Reserved by IETF
+    (0x100000, 12),
+    // Reserved by IETF
+    (0x700000, 12),
+    // Reserved by IETF
+    (0x1100000, 12),
+    // Reserved by IETF
+    (0x5f100000, 12),
+    // Reserved by IETF
+    (0x200000, 11),
+    // Reserved by IETF
+    (0x400000, 11),
+    // Reserved by IETF
+    (0x1200000, 11),
+    // Reserved by IETF
+    (0x5f200000, 11),
+    // Reserved by IETF
+    (0x1400000, 10),
+    // Reserved by IETF
+    (0x5f400000, 10),
+    // Link-Local Unicast
+    (0xfe800000, 10),
+    // Reserved by IETF
+    (0xfec00000, 10),
+    // Reserved by IETF
+    (0x800000, 9),
+    // Reserved by IETF
+    (0x1800000, 9),
+    // Reserved by IETF
+    (0x5f800000, 9),
+    // Reserved by IETF
+    (0xfe000000, 9),
+    // Reserved by IETF
+    (0x5e000000, 8),
+    // Multicast
+    (0xff000000, 8),
+    // Reserved by IETF
+    (0x2000000, 7),
+    // Reserved by IETF
+    (0x5c000000, 7),
+    // Unique-Local
+    (0xfc000000, 7),
+    // Reserved by IETF
+    (0x4000000, 6),
+    // Reserved by IETF
+    (0x58000000, 6),
+    // Reserved by IETF
+    (0xf8000000, 6),
+    // Reserved by IETF
+    (0x8000000, 5),
+    // Reserved by IETF
+    (0x50000000, 5),
+    // Reserved by IETF
+    (0xf0000000, 5),
+    // Reserved by IETF
+    (0x10000000, 4),
+    // Reserved by IETF
+    (0x40000000, 4),
+    // Reserved by IETF
+    (0xe0000000, 4),
+    // Reserved by IETF
+    (0x60000000, 3),
+    // Reserved by IETF
+    (0xc0000000, 3),
+    // Reserved by IETF
+    (0x80000000, 2),
+];
+/// The `BogonKind` variant of each entry in `V6_BOGON_NETWORKS`.
+pub const V6_BOGON_KINDS: [&str; 98] = [
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "SegmentRouting",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "LinkLocal",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Multicast",
+    "Reserved",
+    "Reserved",
+    "UniqueLocal",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+];
+/// The bogus IPv6 networks outside of 2000::/3 that are too specific for `V6_BOGON_NETWORKS`,
+/// from the most to the least specific.
+pub const V6_SPECIAL_BOGON_NETWORKS: [(u128, u8); 180] = [
+    // Unspecified Address
+    (0x0, 128),
+    // Loopback Address
+    (0x1, 128),
+    // IPv4-Compatible IPv6 Address (deprecated)
+    (0x0, 96),
+    // Reserved by IETF
+    (0x100000000, 96),
+    // Reserved by IETF
+    (0xfffe00000000, 96),
+    // IPv4-mapped Address
+    (0xffff00000000, 96),
+    // IPv4-IPv6 Translat.
+    (0x64ff9b000000000000000000000000, 96),
+    // Reserved by IETF
+    (0x64ff9b000000000000000100000000, 96),
+    // Reserved by IETF
+    (0x200000000, 95),
+    // Reserved by IETF
+    (0xfffc00000000, 95),
+    // Reserved by IETF
+    (0x64ff9b000000000000000200000000, 95),
+    // Reserved by IETF
+    (0x400000000, 94),
+    // Reserved by IETF
+    (0xfff800000000, 94),
+    // Reserved by IETF
+    (0x64ff9b000000000000000400000000, 94),
+    // Reserved by IETF
+    (0x800000000, 93),
+    // Reserved by IETF
+    (0xfff000000000, 93),
+    // Reserved by IETF
+    (0x64ff9b000000000000000800000000, 93),
+    // Reserved by IETF
+    (0x1000000000, 92),
+    // Reserved by IETF
+    (0xffe000000000, 92),
+    // Reserved by IETF
+    (0x64ff9b000000000000001000000000, 92),
+    // Reserved by IETF
+    (0x2000000000, 91),
+    // Reserved by IETF
+    (0xffc000000000, 91),
+    // Reserved by IETF
+    (0x64ff9b000000000000002000000000, 91),
+    // Reserved by IETF
+    (0x4000000000, 90),
+    // Reserved by IETF
+    (0xff8000000000, 90),
+    // Reserved by IETF
+    (0x64ff9b000000000000004000000000, 90),
+    // Reserved by IETF
+    (0x8000000000, 89),
+    // Reserved by IETF
+    (0xff0000000000, 89),
+    // Reserved by IETF
+    (0x64ff9b000000000000008000000000, 89),
+    // Reserved by IETF
+    (0x10000000000, 88),
+    // Reserved by IETF
+    (0xfe0000000000, 88),
+    // Reserved by IETF
+    (0x64ff9b000000000000010000000000, 88),
+    // Reserved by IETF
+    (0x20000000000, 87),
+    // Reserved by IETF
+    (0xfc0000000000, 87),
+    // Reserved by IETF
+    (0x64ff9b000000000000020000000000, 87),
+    // Reserved by IETF
+    (0x40000000000, 86),
+    // Reserved by IETF
+    (0xf80000000000, 86),
+    // Reserved by IETF
+    (0x64ff9b000000000000040000000000, 86),
+    // Reserved by IETF
+    (0x80000000000, 85),
+    // Reserved by IETF
+    (0xf00000000000, 85),
+    // Reserved by IETF
+    (0x64ff9b000000000000080000000000, 85),
+    // Reserved by IETF
+    (0x100000000000, 84),
+    // Reserved by IETF
+    (0xe00000000000, 84),
+    // Reserved by IETF
+    (0x64ff9b000000000000100000000000, 84),
+    // Reserved by IETF
+    (0x200000000000, 83),
+    // Reserved by IETF
+    (0xc00000000000, 83),
+    // Reserved by IETF
+    (0x64ff9b000000000000200000000000, 83),
+    // Reserved by IETF
+    (0x400000000000, 82),
+    // Reserved by IETF
+    (0x800000000000, 82),
+    // Reserved by IETF
+    (0x64ff9b000000000000400000000000, 82),
+    // Reserved by IETF
+    (0x64ff9b000000000000800000000000, 81),
+    // Reserved by IETF
+    (0x1000000000000, 80),
+    // Reserved by IETF
+    (0x64ff9b000000000001000000000000, 80),
+    // Reserved by IETF
+    (0x2000000000000, 79),
+    // Reserved by IETF
+    (0x64ff9b000000000002000000000000, 79),
+    // Reserved by IETF
+    (0x4000000000000, 78),
+    // Reserved by IETF
+    (0x64ff9b000000000004000000000000, 78),
+    // Reserved by IETF
+    (0x8000000000000, 77),
+    // Reserved by IETF
+    (0x64ff9b000000000008000000000000, 77),
+    // Reserved by IETF
+    (0x10000000000000, 76),
+    // Reserved by IETF
+    (0x64ff9b000000000010000000000000, 76),
+    // Reserved by IETF
+    (0x20000000000000, 75),
+    // Reserved by IETF
+    (0x64ff9b000000000020000000000000, 75),
+    // Reserved by IETF
+    (0x40000000000000, 74),
+    // Reserved by IETF
+    (0x64ff9b000000000040000000000000, 74),
+    // Reserved by IETF
+    (0x80000000000000, 73),
+    // Reserved by IETF
+    (0x64ff9b000000000080000000000000, 73),
+    // Reserved by IETF
+    (0x100000000000000, 72),
+    // Reserved by IETF
+    (0x64ff9b000000000100000000000000, 72),
+    // Reserved by IETF
+    (0x200000000000000, 71),
+    // Reserved by IETF
+    (0x64ff9b000000000200000000000000, 71),
+    // Reserved by IETF
+    (0x400000000000000, 70),
+    // Reserved by IETF
+    (0x64ff9b000000000400000000000000, 70),
+    // Reserved by IETF
+    (0x800000000000000, 69),
+    // Reserved by IETF
+    (0x64ff9b000000000800000000000000, 69),
+    // Reserved by IETF
+    (0x1000000000000000, 68),
+    // Reserved by IETF
+    (0x64ff9b000000001000000000000000, 68),
+    // Reserved by IETF
+    (0x2000000000000000, 67),
+    // Reserved by IETF
+    (0x64ff9b000000002000000000000000, 67),
+    // Reserved by IETF
+    (0x4000000000000000, 66),
+    // Reserved by IETF
+    (0x64ff9b000000004000000000000000, 66),
+    // Reserved by IETF
+    (0x8000000000000000, 65),
+    // Reserved by IETF
+    (0x64ff9b000000008000000000000000, 65),
+    // Reserved by IETF
+    (0x10000000000000000, 64),
+    // Reserved by IETF
+    (0x64ff9b000000010000000000000000, 64),
+    // Discard-Only Address Block
+    (0x1000000000000000000000000000000, 64),
+    // Reserved by IETF
+    (0x1000000000000010000000000000000, 64),
+    // Reserved by IETF
+    (0x20000000000000000, 63),
+    // Reserved by IETF
+    (0x64ff9b000000020000000000000000, 63),
+    // Reserved by IETF
+    (0x1000000000000020000000000000000, 63),
+    // Reserved by IETF
+    (0x40000000000000000, 62),
+    // Reserved by IETF
+    (0x64ff9b000000040000000000000000, 62),
+    // Reserved by IETF
+    (0x1000000000000040000000000000000, 62),
+    // Reserved by IETF
+    (0x80000000000000000, 61),
+    // Reserved by IETF
+    (0x64ff9b000000080000000000000000, 61),
+    // Reserved by IETF
+    (0x1000000000000080000000000000000, 61),
+    // Reserved by IETF
+    (0x100000000000000000, 60),
+    // Reserved by IETF
+    (0x64ff9b000000100000000000000000, 60),
+    // Reserved by IETF
+    (0x1000000000000100000000000000000, 60),
+    // Reserved by IETF
+    (0x200000000000000000, 59),
+    // Reserved by IETF
+    (0x64ff9b000000200000000000000000, 59),
+    // Reserved by IETF
+    (0x1000000000000200000000000000000, 59),
+    // Reserved by IETF
+    (0x400000000000000000, 58),
+    // Reserved by IETF
+    (0x64ff9b000000400000000000000000, 58),
+    // Reserved by IETF
+    (0x1000000000000400000000000000000, 58),
+    // Reserved by IETF
+    (0x800000000000000000, 57),
+    // Reserved by IETF
+    (0x64ff9b000000800000000000000000, 57),
+    // Reserved by IETF
+    (0x1000000000000800000000000000000, 57),
+    // Reserved by IETF
+    (0x1000000000000000000, 56),
+    // Reserved by IETF
+    (0x64ff9b000001000000000000000000, 56),
+    // Reserved by IETF
+    (0x1000000000001000000000000000000, 56),
+    // Reserved by IETF
+    (0x2000000000000000000, 55),
+    // Reserved by IETF
+    (0x64ff9b000002000000000000000000, 55),
+    // Reserved by IETF
+    (0x1000000000002000000000000000000, 55),
+    // Reserved by IETF
+    (0x4000000000000000000, 54),
+    // Reserved by IETF
+    (0x64ff9b000004000000000000000000, 54),
+    // Reserved by IETF
+    (0x1000000000004000000000000000000, 54),
+    // Reserved by IETF
+    (0x8000000000000000000, 53),
+    // Reserved by IETF
+    (0x64ff9b000008000000000000000000, 53),
+    // Reserved by IETF
+    (0x1000000000008000000000000000000, 53),
+    // Reserved by IETF
+    (0x10000000000000000000, 52),
+    // Reserved by IETF
+    (0x64ff9b000010000000000000000000, 52),
+    // Reserved by IETF
+    (0x1000000000010000000000000000000, 52),
+    // Reserved by IETF
+    (0x20000000000000000000, 51),
+    // Reserved by IETF
+    (0x64ff9b000020000000000000000000, 51),
+    // Reserved by IETF
+    (0x1000000000020000000000000000000, 51),
+    // Reserved by IETF
+    (0x40000000000000000000, 50),
+    // Reserved by IETF
+    (0x64ff9b000040000000000000000000, 50),
+    // Reserved by IETF
+    (0x1000000000040000000000000000000, 50),
+    // Reserved by IETF
+    (0x80000000000000000000, 49),
+    // Reserved by IETF
+    (0x64ff9b000080000000000000000000, 49),
+    // Reserved by IETF
+    (0x1000000000080000000000000000000, 49),
+    // Reserved by IETF
+    (0x100000000000000000000, 48),
+    // IPv4-IPv6 Translat.
+    (0x64ff9b000100000000000000000000, 48),
+    // Reserved by IETF
+    (0x1000000000100000000000000000000, 48),
+    // Reserved by IETF
+    (0x200000000000000000000, 47),
+    // Reserved by IETF
+    (0x64ff9b000200000000000000000000, 47),
+    // Reserved by IETF
+    (0x1000000000200000000000000000000, 47),
+    // Reserved by IETF
+    (0x400000000000000000000, 46),
+    // Reserved by IETF
+    (0x64ff9b000400000000000000000000, 46),
+    // Reserved by IETF
+    (0x1000000000400000000000000000000, 46),
+    // Reserved by IETF
+    (0x800000000000000000000, 45),
+    // Reserved by IETF
+    (0x64ff9b000800000000000000000000, 45),
+    // Reserved by IETF
+    (0x1000000000800000000000000000000, 45),
+    // Reserved by IETF
+    (0x1000000000000000000000, 44),
+    // Reserved by IETF
+    (0x64ff9b001000000000000000000000, 44),
+    // Reserved by IETF
+    (0x1000000001000000000000000000000, 44),
+    // Reserved by IETF
+    (0x2000000000000000000000, 43),
+    // Reserved by IETF
+    (0x64ff9b002000000000000000000000, 43),
+    // Reserved by IETF
+    (0x1000000002000000000000000000000, 43),
+    // Reserved by IETF
+    (0x4000000000000000000000, 42),
+    // Reserved by IETF
+    (0x64ff9b004000000000000000000000, 42),
+    // Reserved by IETF
+    (0x1000000004000000000000000000000, 42),
+    // Reserved by IETF
+    (0x8000000000000000000000, 41),
+    // Reserved by IETF
+    (0x64ff9b008000000000000000000000, 41),
+    // Reserved by IETF
+    (0x1000000008000000000000000000000, 41),
+    // Reserved by IETF
+    (0x10000000000000000000000, 40),
+    // Reserved by IETF
+    (0x64ff9b010000000000000000000000, 40),
+    // Reserved by IETF
+    (0x1000000010000000000000000000000, 40),
+    // Reserved by IETF
+    (0x20000000000000000000000, 39),
+    // Reserved by IETF
+    (0x64ff9b020000000000000000000000, 39),
+    // Reserved by IETF
+    (0x1000000020000000000000000000000, 39),
+    // Reserved by IETF
+    (0x40000000000000000000000, 38),
+    // Reserved by IETF
+    (0x64ff9b040000000000000000000000, 38),
+    // Reserved by IETF
+    (0x1000000040000000000000000000000, 38),
+    // Reserved by IETF
+    (0x80000000000000000000000, 37),
+    // Reserved by IETF
+    (0x64ff9b080000000000000000000000, 37),
+    // Reserved by IETF
+    (0x1000000080000000000000000000000, 37),
+    // Reserved by IETF
+    (0x100000000000000000000000, 36),
+    // Reserved by IETF
+    (0x64ff9b100000000000000000000000, 36),
+    // Reserved by IETF
+    (0x1000000100000000000000000000000, 36),
+    // Reserved by IETF
+    (0x200000000000000000000000, 35),
+    // Reserved by IETF
+    (0x64ff9b200000000000000000000000, 35),
+    // Reserved by IETF
+    (0x1000000200000000000000000000000, 35),
+    // Reserved by IETF
+    (0x400000000000000000000000, 34),
+    // Reserved by IETF
+    (0x64ff9b400000000000000000000000, 34),
+    // Reserved by IETF
+    (0x1000000400000000000000000000000, 34),
+    // Reserved by IETF
+    (0x800000000000000000000000, 33),
+    // Reserved by IETF
+    (0x64ff9b800000000000000000000000, 33),
+    // Reserved by IETF
+    (0x1000000800000000000000000000000, 33),
+];
+/// The `BogonKind` variant of each entry in `V6_SPECIAL_BOGON_NETWORKS`.
+pub const V6_SPECIAL_BOGON_KINDS: [&str; 180] = [
+    "Unspecified",
+    "Loopback",
+    "Ipv4Compatible",
+    "Reserved",
+    "Reserved",
+    "Ipv4Mapped",
+    "Nat64WellKnown",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "DiscardOnly",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Nat64LocalUse",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+    "Reserved",
+];
+/// The bogus IPv6 networks inside of 2000::/3, from the most to the least specific.
+pub const V6_GLOBAL_BOGON_NETWORKS: [(u128, u8); 6] = [
+    // Benchmarking
+    (0x20010002000000000000000000000000, 48),
+    // TEREDO
+    (0x20010000000000000000000000000000, 32),
+    // Documentation
+    (0x20010db8000000000000000000000000, 32),
+    // ORCHIDv2
+    (0x20010020000000000000000000000000, 28),
+    // IETF Protocol Assignments
+    (0x20010000000000000000000000000000, 23),
+    // Documentation
+    (0x3fff0000000000000000000000000000, 20),
+];
+/// The `BogonKind` variant of each entry in `V6_GLOBAL_BOGON_NETWORKS`.
+pub const V6_GLOBAL_BOGON_KINDS: [&str; 6] = [
+    "Benchmarking",
+    "Teredo",
+    "Documentation",
+    "OrchidV2",
+    "ProtocolAssignments",
+    "Documentation",
+];
+/// The globally reachable networks inside of the bogus IPv6 networks.
+pub const V6_BOGON_EXCEPTIONS: [(u128, u8); 5] = [
+    // Port Control Protocol Anycast
+    (0x20010001000000000000000000000001, 128),
+    // Traversal Using Relays around NAT Anycast
+    (0x20010001000000000000000000000002, 128),
+    // AS112-v6
+    (0x20010004011200000000000000000000, 48),
+    // AMT
+    (0x20010003000000000000000000000000, 32),
+    // Drone Remote ID Protocol Entity Tags (DETs) Prefix
+    (0x20010030000000000000000000000000, 28),
+];
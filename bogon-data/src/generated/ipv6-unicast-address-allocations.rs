@@ -0,0 +1,275 @@
+/// The SHA-256 digest of the IANA registries the compiled-in data was generated from,
+/// concatenated in a fixed order: the IPv6 unicast address assignments, the IPv4 address
+/// space, the IPv6 special-purpose registry and the IPv4 special-purpose registry, as
+/// lowercase hexadecimal.
+pub const DATASET_SHA256: &str = "bb374c812ad5ca71648a91a9334606464358b655859f206df581fc3f62a9d26d";
+/// The date the IANA registries the compiled-in data was generated from were downloaded,
+/// as `YYYY-MM-DD`.
+pub const DATASET_DATE: &str = "2024-07-23";
+/// The merged allocations covering whole /32s, as sorted and disjoint inclusive ranges of the
+/// top 32 bits of their addresses.
+pub const V6_ALLOCATION_RANGES: [(u32, u32); 12] = [
+    (0x20010200, 0x20010fff),
+    (0x20011200, 0x20014dff),
+    (0x20015000, 0x20015fff),
+    (0x20018000, 0x2001bfff),
+    (0x20030000, 0x20033fff),
+    (0x24000000, 0x240fffff),
+    (0x26000000, 0x261001ff),
+    (0x26200000, 0x262001ff),
+    (0x26300000, 0x263fffff),
+    (0x28000000, 0x280fffff),
+    (0x2a000000, 0x2a1fffff),
+    (0x2c000000, 0x2c0fffff),
+];
+/// The rest of the merged allocations, with prefixes longer than 32 bits.
+pub const V6_WIDE_ALLOCATIONS: [(u128, u8); 0] = [
+];
+/// The number of merged allocations, in `V6_ALLOCATION_RANGES` and `V6_WIDE_ALLOCATIONS`.
+pub const DATASET_V6_RANGES: usize = 12;
+/// The networks inside of 2000::/3 that none of the allocations cover.
+pub const V6_UNALLOCATED: [(u128, u8); 63] = [
+    (0x20000000000000000000000000000000, 16),
+    (0x20010000000000000000000000000000, 23),
+    (0x20011000000000000000000000000000, 23),
+    (0x20014e00000000000000000000000000, 23),
+    (0x20016000000000000000000000000000, 19),
+    (0x2001c000000000000000000000000000, 18),
+    (0x20020000000000000000000000000000, 16),
+    (0x20034000000000000000000000000000, 18),
+    (0x20038000000000000000000000000000, 17),
+    (0x20040000000000000000000000000000, 14),
+    (0x20080000000000000000000000000000, 13),
+    (0x20100000000000000000000000000000, 12),
+    (0x20200000000000000000000000000000, 11),
+    (0x20400000000000000000000000000000, 10),
+    (0x20800000000000000000000000000000, 9),
+    (0x21000000000000000000000000000000, 8),
+    (0x22000000000000000000000000000000, 7),
+    (0x24100000000000000000000000000000, 12),
+    (0x24200000000000000000000000000000, 11),
+    (0x24400000000000000000000000000000, 10),
+    (0x24800000000000000000000000000000, 9),
+    (0x25000000000000000000000000000000, 8),
+    (0x26100200000000000000000000000000, 23),
+    (0x26100400000000000000000000000000, 22),
+    (0x26100800000000000000000000000000, 21),
+    (0x26101000000000000000000000000000, 20),
+    (0x26102000000000000000000000000000, 19),
+    (0x26104000000000000000000000000000, 18),
+    (0x26108000000000000000000000000000, 17),
+    (0x26110000000000000000000000000000, 16),
+    (0x26120000000000000000000000000000, 15),
+    (0x26140000000000000000000000000000, 14),
+    (0x26180000000000000000000000000000, 13),
+    (0x26200200000000000000000000000000, 23),
+    (0x26200400000000000000000000000000, 22),
+    (0x26200800000000000000000000000000, 21),
+    (0x26201000000000000000000000000000, 20),
+    (0x26202000000000000000000000000000, 19),
+    (0x26204000000000000000000000000000, 18),
+    (0x26208000000000000000000000000000, 17),
+    (0x26210000000000000000000000000000, 16),
+    (0x26220000000000000000000000000000, 15),
+    (0x26240000000000000000000000000000, 14),
+    (0x26280000000000000000000000000000, 13),
+    (0x26400000000000000000000000000000, 10),
+    (0x26800000000000000000000000000000, 9),
+    (0x27000000000000000000000000000000, 8),
+    (0x28100000000000000000000000000000, 12),
+    (0x28200000000000000000000000000000, 11),
+    (0x28400000000000000000000000000000, 10),
+    (0x28800000000000000000000000000000, 9),
+    (0x29000000000000000000000000000000, 8),
+    (0x2a200000000000000000000000000000, 11),
+    (0x2a400000000000000000000000000000, 10),
+    (0x2a800000000000000000000000000000, 9),
+    (0x2b000000000000000000000000000000, 8),
+    (0x2c100000000000000000000000000000, 12),
+    (0x2c200000000000000000000000000000, 11),
+    (0x2c400000000000000000000000000000, 10),
+    (0x2c800000000000000000000000000000, 9),
+    (0x2d000000000000000000000000000000, 8),
+    (0x2e000000000000000000000000000000, 7),
+    (0x30000000000000000000000000000000, 4),
+];
+/// The network of each allocation in the registry, unmerged.
+pub const V6_REGISTRY_PREFIXES: [(u128, u8); 33] = [
+    (0x20010200000000000000000000000000, 23),
+    (0x20010400000000000000000000000000, 23),
+    (0x20010600000000000000000000000000, 23),
+    (0x20010800000000000000000000000000, 22),
+    (0x20010c00000000000000000000000000, 23),
+    (0x20010e00000000000000000000000000, 23),
+    (0x20011200000000000000000000000000, 23),
+    (0x20011400000000000000000000000000, 22),
+    (0x20011800000000000000000000000000, 23),
+    (0x20011a00000000000000000000000000, 23),
+    (0x20011c00000000000000000000000000, 22),
+    (0x20012000000000000000000000000000, 19),
+    (0x20014000000000000000000000000000, 23),
+    (0x20014200000000000000000000000000, 23),
+    (0x20014400000000000000000000000000, 23),
+    (0x20014600000000000000000000000000, 23),
+    (0x20014800000000000000000000000000, 23),
+    (0x20014a00000000000000000000000000, 23),
+    (0x20014c00000000000000000000000000, 23),
+    (0x20015000000000000000000000000000, 20),
+    (0x20018000000000000000000000000000, 19),
+    (0x2001a000000000000000000000000000, 20),
+    (0x2001b000000000000000000000000000, 20),
+    (0x20030000000000000000000000000000, 18),
+    (0x24000000000000000000000000000000, 12),
+    (0x26000000000000000000000000000000, 12),
+    (0x26100000000000000000000000000000, 23),
+    (0x26200000000000000000000000000000, 23),
+    (0x26300000000000000000000000000000, 12),
+    (0x28000000000000000000000000000000, 12),
+    (0x2a000000000000000000000000000000, 12),
+    (0x2a100000000000000000000000000000, 12),
+    (0x2c000000000000000000000000000000, 12),
+];
+/// The regional internet registry of each entry in `V6_REGISTRY_PREFIXES`, as IANA names it.
+pub const V6_REGISTRY_RIRS: [&str; 33] = [
+    "APNIC",
+    "ARIN",
+    "RIPE NCC",
+    "RIPE NCC",
+    "APNIC",
+    "APNIC",
+    "LACNIC",
+    "RIPE NCC",
+    "ARIN",
+    "RIPE NCC",
+    "RIPE NCC",
+    "RIPE NCC",
+    "RIPE NCC",
+    "AFRINIC",
+    "APNIC",
+    "RIPE NCC",
+    "ARIN",
+    "RIPE NCC",
+    "RIPE NCC",
+    "RIPE NCC",
+    "APNIC",
+    "APNIC",
+    "APNIC",
+    "RIPE NCC",
+    "APNIC",
+    "ARIN",
+    "ARIN",
+    "ARIN",
+    "ARIN",
+    "LACNIC",
+    "RIPE NCC",
+    "RIPE NCC",
+    "AFRINIC",
+];
+/// The date each entry in `V6_REGISTRY_PREFIXES` was allocated, as `YYYY-MM-DD`.
+pub const V6_REGISTRY_DATES: [&str; 33] = [
+    "1999-07-01",
+    "1999-07-01",
+    "1999-07-01",
+    "2002-11-02",
+    "2002-05-02",
+    "2003-01-01",
+    "2002-11-01",
+    "2003-07-01",
+    "2003-04-01",
+    "2004-01-01",
+    "2004-05-04",
+    "2019-03-12",
+    "2004-06-11",
+    "2004-06-01",
+    "2004-06-11",
+    "2004-08-17",
+    "2004-08-24",
+    "2004-10-15",
+    "2004-12-17",
+    "2004-09-10",
+    "2004-11-30",
+    "2004-11-30",
+    "2006-03-08",
+    "2005-01-12",
+    "2006-10-03",
+    "2006-10-03",
+    "2005-11-17",
+    "2006-09-12",
+    "2019-11-06",
+    "2006-10-03",
+    "2006-10-03",
+    "2019-06-05",
+    "2006-10-03",
+];
+/// The index into `RDAP_URLS` of each entry in `V6_REGISTRY_PREFIXES`.
+pub const V6_REGISTRY_RDAP: [u8; 33] = [
+    0,
+    1,
+    2,
+    2,
+    0,
+    0,
+    3,
+    2,
+    1,
+    2,
+    2,
+    2,
+    2,
+    4,
+    0,
+    2,
+    1,
+    2,
+    2,
+    2,
+    0,
+    0,
+    0,
+    2,
+    0,
+    1,
+    1,
+    1,
+    1,
+    3,
+    2,
+    2,
+    4,
+];
+/// The index into `WHOIS_SERVERS` of each entry in `V6_REGISTRY_PREFIXES`.
+pub const V6_REGISTRY_WHOIS: [u8; 33] = [
+    0,
+    1,
+    2,
+    2,
+    0,
+    0,
+    3,
+    2,
+    1,
+    2,
+    2,
+    2,
+    2,
+    4,
+    0,
+    2,
+    1,
+    2,
+    2,
+    2,
+    0,
+    0,
+    0,
+    2,
+    0,
+    1,
+    1,
+    1,
+    1,
+    3,
+    2,
+    2,
+    4,
+];
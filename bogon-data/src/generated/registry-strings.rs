@@ -0,0 +1,16 @@
+/// The distinct RDAP base URLs of both registries.
+pub const RDAP_URLS: [&str; 5] = [
+    "https://rdap.apnic.net/",
+    "https://rdap.arin.net/registry",
+    "https://rdap.db.ripe.net/",
+    "https://rdap.lacnic.net/rdap/",
+    "https://rdap.afrinic.net/rdap/",
+];
+/// The distinct WHOIS servers of both registries.
+pub const WHOIS_SERVERS: [&str; 5] = [
+    "whois.apnic.net",
+    "whois.arin.net",
+    "whois.ripe.net",
+    "whois.lacnic.net",
+    "whois.afrinic.net",
+];
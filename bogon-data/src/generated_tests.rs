@@ -0,0 +1,103 @@
+/// Returns the generated files and their checked-in copies.
+#[cfg(not(any(feature = "pregenerated", feature = "download")))]
+fn generated_files() -> [(&'static str, &'static str, &'static str); 8] {
+    macro_rules! generated {
+        ($file:literal) => {
+            (
+                $file,
+                include_str!(concat!(env!("OUT_DIR"), "/", $file)),
+                include_str!(concat!("generated/", $file)),
+            )
+        };
+    }
+
+    [
+        generated!("ipv4-address-space.rs"),
+        generated!("ipv4-special-registry.rs"),
+        generated!("ipv6-allocation-boundaries.rs"),
+        generated!("ipv6-allocation-tree.rs"),
+        generated!("ipv6-first-hextets.rs"),
+        generated!("ipv6-special-registry.rs"),
+        generated!("ipv6-unicast-address-allocations.rs"),
+        generated!("registry-strings.rs"),
+    ]
+}
+
+#[test]
+#[cfg(not(any(feature = "pregenerated", feature = "download")))]
+fn checked_in_copies_are_up_to_date() {
+    // Local copies of the registries generate different tables.
+    let overrides = [
+        option_env!("BOGON_IPV6_CSV"),
+        option_env!("BOGON_IPV4_CSV"),
+        option_env!("BOGON_IPV6_SPECIAL_CSV"),
+        option_env!("BOGON_IPV4_SPECIAL_CSV"),
+    ];
+    if overrides.into_iter().flatten().any(|path| !path.is_empty()) {
+        return;
+    }
+
+    for (file, generated, checked_in) in generated_files() {
+        assert!(
+            generated == checked_in,
+            "src/generated/{file} is out of date, run `cargo xtask update-data --no-download`"
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn allocation_tables_match_the_bundled_registry() {
+    use crate::{
+        allocations::allocation_tables,
+        iana_csv::rir_allocations,
+        ranges::{coverage, range_to_cidrs},
+        V6_ALLOCATION_RANGES, V6_UNALLOCATED, V6_WIDE_ALLOCATIONS,
+    };
+
+    let csv = include_str!("../ipv6-unicast-address-assignments.csv");
+    let registry = rir_allocations(csv, &mut Vec::new())
+        .unwrap()
+        .iter()
+        .map(|allocation| (allocation.network.to_bits(), allocation.prefix_len))
+        .collect::<Vec<_>>();
+    let tables = allocation_tables(&registry);
+    assert_eq!(tables.narrow, V6_ALLOCATION_RANGES);
+    assert_eq!(tables.wide, V6_WIDE_ALLOCATIONS);
+    assert_eq!(tables.unallocated, V6_UNALLOCATED);
+
+    // The lookups binary search the ranges, which only works if they are sorted and disjoint.
+    for (first, last) in V6_ALLOCATION_RANGES {
+        assert!(first <= last, "{first:#x}-{last:#x}");
+    }
+    for pair in V6_ALLOCATION_RANGES.windows(2) {
+        assert!(pair[0].1 < pair[1].0, "{pair:x?}");
+    }
+
+    // Together with the wide table, the ranges cover exactly the allocated addresses.
+    let ranges = V6_ALLOCATION_RANGES.iter().flat_map(|&(first, last)| {
+        range_to_cidrs(
+            u128::from(first) << 96,
+            u128::from(last) << 96 | u128::MAX >> 32,
+            128,
+        )
+    });
+    assert_eq!(
+        coverage(ranges.chain(V6_WIDE_ALLOCATIONS), 128),
+        coverage(registry.into_iter(), 128)
+    );
+}
+
+#[test]
+fn dataset_counts_match_the_tables() {
+    use crate::{
+        DATASET_V4_RULES, DATASET_V6_RANGES, V4_BOGON_NETWORKS, V6_ALLOCATION_RANGES,
+        V6_WIDE_ALLOCATIONS,
+    };
+
+    assert_eq!(DATASET_V4_RULES, V4_BOGON_NETWORKS.len());
+    assert_eq!(
+        DATASET_V6_RANGES,
+        V6_ALLOCATION_RANGES.len() + V6_WIDE_ALLOCATIONS.len()
+    );
+}
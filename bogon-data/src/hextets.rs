@@ -0,0 +1,116 @@
+//! Classifying every /16 of the IPv6 address space, so that `is_bogon_v6` can settle most IP
+//! addresses by their first hextet without searching the tables.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`. The library only
+//! compiles it for its tests.
+
+use alloc::{vec, vec::Vec};
+
+use crate::ranges::{coverage, merge, range, subtract};
+
+/// 2000::/3, the global unicast space the RIRs allocate from.
+const GLOBAL_UNICAST: (u128, u8) = (0x2000 << 112, 3);
+
+/// The number of bits each /16 takes up in the packed table.
+pub(crate) const BUCKET_BITS: u32 = 2;
+
+/// What the first hextet of an IPv6 address says about whether the address is bogus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Bucket {
+    /// None of the /16 is bogus.
+    Routable = 0,
+    /// All of the /16 is bogus.
+    Bogon = 1,
+    /// Part of the /16 is bogus, so the address needs the full check.
+    Mixed = 2,
+}
+
+/// Returns the addresses `is_bogon_v6` flags, as sorted and disjoint ranges: everything outside of
+/// 2000::/3, the bogus networks inside of it except for their globally reachable exceptions, and
+/// the unallocated space the bogus networks don't cover.
+pub(crate) fn bogus_ranges(
+    global_bogons: &[(u128, u8)],
+    exceptions: &[(u128, u8)],
+    unallocated: &[(u128, u8)],
+) -> Vec<(u128, u128)> {
+    let global_bogons = coverage(global_bogons.iter().copied(), 128);
+    let exceptions = coverage(exceptions.iter().copied(), 128);
+    let unallocated = coverage(unallocated.iter().copied(), 128);
+
+    let (first, last) = range(GLOBAL_UNICAST.0, GLOBAL_UNICAST.1, 128);
+    let outside = [(0, first - 1), (last + 1, u128::MAX)];
+    let mut bogus = subtract(&global_bogons, &exceptions);
+    bogus.extend(subtract(&unallocated, &global_bogons));
+    bogus.extend(outside);
+    merge(bogus)
+}
+
+/// Returns the bucket of every /16, in order, given the sorted and disjoint ranges of bogus
+/// addresses.
+///
+/// # Panics
+///
+/// Panics if a bucket isn't homogeneous, see [`assert_homogeneous`].
+pub(crate) fn first_hextets(bogus: &[(u128, u128)]) -> Vec<Bucket> {
+    let buckets = (0..=u16::MAX)
+        .map(|hextet| {
+            let (first, last) = range(u128::from(hextet) << 112, 16, 128);
+            // The first bogus range that doesn't end before the /16.
+            let index = bogus.partition_point(|&(_, end)| end < first);
+            match bogus.get(index) {
+                Some(&(start, end)) if start <= first && end >= last => Bucket::Bogon,
+                Some(&(start, _)) if start <= last => Bucket::Mixed,
+                _ => Bucket::Routable,
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_homogeneous(&buckets, bogus);
+    buckets
+}
+
+/// Checks the buckets against the bogus ranges, walking the ranges instead of searching them: a
+/// routable /16 must not overlap any of the ranges, a bogus /16 must be inside of one, and a mixed
+/// /16 must be neither.
+///
+/// # Panics
+///
+/// Panics if a bucket doesn't match the ranges.
+pub(crate) fn assert_homogeneous(buckets: &[Bucket], bogus: &[(u128, u128)]) {
+    assert_eq!(
+        buckets.len(),
+        1 << 16,
+        "There must be a bucket for every /16"
+    );
+
+    let mut touched = vec![false; 1 << 16];
+    let mut covered = vec![false; 1 << 16];
+    for &(start, end) in bogus {
+        for hextet in (start >> 112) as usize..=(end >> 112) as usize {
+            let (first, last) = range((hextet as u128) << 112, 16, 128);
+            touched[hextet] = true;
+            covered[hextet] |= start <= first && end >= last;
+        }
+    }
+    for (hextet, &bucket) in buckets.iter().enumerate() {
+        let expected = match (touched[hextet], covered[hextet]) {
+            (false, _) => Bucket::Routable,
+            (true, true) => Bucket::Bogon,
+            (true, false) => Bucket::Mixed,
+        };
+        assert_eq!(bucket, expected, "The bucket of {hextet:x}::/16 is wrong");
+    }
+}
+
+/// Packs the buckets into 64-bit words, `BUCKET_BITS` each, starting with the least significant
+/// bits of the first word.
+pub(crate) fn pack(buckets: &[Bucket]) -> Vec<u64> {
+    let per_word = (64 / BUCKET_BITS) as usize;
+    buckets
+        .chunks(per_word)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(0, |word, (i, &bucket)| {
+                word | (bucket as u64) << (i as u32 * BUCKET_BITS)
+            })
+        })
+        .collect()
+}
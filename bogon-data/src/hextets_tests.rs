@@ -0,0 +1,102 @@
+use core::net::Ipv6Addr;
+
+use crate::hextets::{assert_homogeneous, bogus_ranges, first_hextets, pack, Bucket};
+
+fn net(network: &str, prefix_len: u8) -> (u128, u8) {
+    (network.parse::<Ipv6Addr>().unwrap().to_bits(), prefix_len)
+}
+
+fn bucket(buckets: &[Bucket], hextet: u16) -> Bucket {
+    buckets[usize::from(hextet)]
+}
+
+#[test]
+fn outside_of_global_unicast_is_bogus() {
+    let buckets = first_hextets(&bogus_ranges(&[], &[], &[]));
+    assert_eq!(bucket(&buckets, 0x0000), Bucket::Bogon);
+    assert_eq!(bucket(&buckets, 0x1fff), Bucket::Bogon);
+    assert_eq!(bucket(&buckets, 0x2000), Bucket::Routable);
+    assert_eq!(bucket(&buckets, 0x3fff), Bucket::Routable);
+    assert_eq!(bucket(&buckets, 0x4000), Bucket::Bogon);
+    assert_eq!(bucket(&buckets, 0xffff), Bucket::Bogon);
+}
+
+#[test]
+fn unallocated_and_bogus_networks() {
+    let bogus = bogus_ranges(
+        &[net("2001:db8::", 32), net("3fff::", 20)],
+        &[],
+        &[net("2c00::", 12), net("2001:4000::", 18)],
+    );
+    let buckets = first_hextets(&bogus);
+    assert_eq!(bucket(&buckets, 0x2001), Bucket::Mixed);
+    assert_eq!(bucket(&buckets, 0x2c00), Bucket::Bogon);
+    assert_eq!(bucket(&buckets, 0x2c0f), Bucket::Bogon);
+    assert_eq!(bucket(&buckets, 0x2c10), Bucket::Routable);
+    assert_eq!(bucket(&buckets, 0x3fff), Bucket::Mixed);
+}
+
+#[test]
+fn exceptions_punch_holes_in_bogus_networks() {
+    let bogus = bogus_ranges(&[net("2002::", 16)], &[net("2002:1::", 32)], &[]);
+    assert_eq!(bucket(&first_hextets(&bogus), 0x2002), Bucket::Mixed);
+
+    // Exceptions are routable even if they are unallocated, like `is_bogon_v6` treats them.
+    let bogus = bogus_ranges(
+        &[net("2002::", 16)],
+        &[net("2002:1::", 32)],
+        &[net("2002::", 16)],
+    );
+    assert_eq!(bucket(&first_hextets(&bogus), 0x2002), Bucket::Mixed);
+
+    // But not outside of the bogus networks.
+    let bogus = bogus_ranges(&[], &[net("2c00:1::", 32)], &[net("2c00::", 16)]);
+    assert_eq!(bucket(&first_hextets(&bogus), 0x2c00), Bucket::Bogon);
+}
+
+#[test]
+#[should_panic(expected = "The bucket of 2001::/16 is wrong")]
+fn heterogeneous_routable_bucket() {
+    let bogus = bogus_ranges(&[net("2001:db8::", 32)], &[], &[]);
+    let mut buckets = first_hextets(&bogus);
+    buckets[0x2001] = Bucket::Routable;
+    assert_homogeneous(&buckets, &bogus);
+}
+
+#[test]
+#[should_panic(expected = "The bucket of 2c00::/16 is wrong")]
+fn heterogeneous_bogus_bucket() {
+    let bogus = bogus_ranges(&[], &[], &[net("2c00::", 17)]);
+    let mut buckets = first_hextets(&bogus);
+    buckets[0x2c00] = Bucket::Bogon;
+    assert_homogeneous(&buckets, &bogus);
+}
+
+#[test]
+fn pack_layout() {
+    let mut buckets = vec![Bucket::Routable; 1 << 16];
+    buckets[0] = Bucket::Bogon;
+    buckets[1] = Bucket::Mixed;
+    buckets[31] = Bucket::Bogon;
+    buckets[32] = Bucket::Mixed;
+    buckets[0xffff] = Bucket::Mixed;
+
+    let words = pack(&buckets);
+    assert_eq!(words.len(), 2048);
+    assert_eq!(words[0], 0b1001 | 1 << 62);
+    assert_eq!(words[1], 0b10);
+    assert_eq!(words[2047], 0b10 << 62);
+    assert!(words[2..2047].iter().all(|&word| word == 0));
+}
+
+#[test]
+fn bundled_buckets_match_the_registries() {
+    use crate::{V6_BOGON_EXCEPTIONS, V6_FIRST_HEXTETS, V6_GLOBAL_BOGON_NETWORKS, V6_UNALLOCATED};
+
+    let bogus = bogus_ranges(
+        &V6_GLOBAL_BOGON_NETWORKS,
+        &V6_BOGON_EXCEPTIONS,
+        &V6_UNALLOCATED,
+    );
+    assert_eq!(pack(&first_hextets(&bogus)), V6_FIRST_HEXTETS);
+}
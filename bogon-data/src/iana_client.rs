@@ -0,0 +1,73 @@
+//! Downloading the registry CSV files from the IANA website.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]` for the `download`
+//! feature, and with the `update-data` xtask. It needs `reqwest`, so the library doesn't compile it.
+
+use crate::csv_cache::{self, Fetcher, Response, Validators};
+
+/// Downloads registry CSV files from the IANA website.
+pub(crate) struct Iana {
+    user_agent: String,
+}
+
+impl Iana {
+    /// Returns a client identifying itself as `program` (for example `bogon-data/0.1.0`) running on
+    /// `os` and `arch`.
+    pub(crate) fn new(program: &str, os: &str, arch: &str) -> Self {
+        // program (platform; arch) Rust/rustc.version
+        let user_agent = format!(
+            "{program} ({os}; {arch}) Rust/{}",
+            rustc_version::version_meta().unwrap().semver
+        );
+        Iana { user_agent }
+    }
+
+    /// Sends a single request, conditional on the validators of the cached copy.
+    fn request(
+        &self,
+        url: &str,
+        cached: Option<&Validators>,
+    ) -> Result<Response, Box<dyn std::error::Error>> {
+        use reqwest::{header, StatusCode};
+
+        // Build the client, it requires a user-agent string.
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(&self.user_agent)
+            .build()?;
+        let mut request = client.get(url);
+        if let Some(etag) = cached.and_then(|cached| cached.etag.as_deref()) {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        if let Some(date) = cached.and_then(|cached| cached.last_modified.as_deref()) {
+            request = request.header(header::IF_MODIFIED_SINCE, date);
+        }
+        let response = request.send()?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(Response::NotModified);
+        }
+
+        // require a successful response
+        let response = response.error_for_status()?;
+        let header = |name: header::HeaderName| {
+            let value = response.headers().get(name)?;
+            value.to_str().ok().map(str::to_string)
+        };
+        let validators = Validators {
+            etag: header(header::ETAG),
+            last_modified: header(header::LAST_MODIFIED),
+        };
+        Ok(Response::Modified {
+            body: response.text()?,
+            validators,
+        })
+    }
+}
+
+impl Fetcher for Iana {
+    fn fetch(&mut self, url: &str, cached: Option<&Validators>) -> Result<Response, String> {
+        csv_cache::with_retries(
+            || self.request(url, cached).map_err(|error| error.to_string()),
+            std::thread::sleep,
+        )
+    }
+}
@@ -0,0 +1,491 @@
+//! Parsing the IANA IPv6 unicast address assignments registry and merging its allocations, and
+//! parsing the IANA IPv4 address space and special-purpose address registries.
+//!
+//! The parsers report every malformed record rather than only the first, so a changed registry
+//! format can be diagnosed from a single build.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]` to generate the
+//! compiled-in tables, so it must not depend on the rest of the crate except for `ranges`, which
+//! the build script includes too. `bogon` parses newer snapshots of the registries at runtime with
+//! it.
+
+use core::{
+    fmt,
+    net::{IpAddr, Ipv6Addr},
+};
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::ranges::aggregate;
+
+/// The error returned when a registry CSV file is malformed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CsvError {
+    line: usize,
+    message: String,
+}
+
+impl CsvError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        CsvError {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the line the malformed record starts on, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Splits CSV text into records of fields, each with the line it starts on.
+///
+/// Fields may be quoted, quoted fields may contain commas, line breaks, and `""` for a quote.
+fn records(text: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut records = Vec::new();
+    // Spreadsheet programs start the files they save with a byte order mark.
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+
+        loop {
+            match chars.next() {
+                None => {
+                    fields.push(field);
+                    break;
+                }
+                Some('\n') => {
+                    line += 1;
+                    fields.push(field);
+                    break;
+                }
+                Some('\r') if chars.peek() == Some(&'\n') => {}
+                Some(',') => fields.push(core::mem::take(&mut field)),
+                Some('"') if field.is_empty() => loop {
+                    match chars.next() {
+                        None => return Err(CsvError::new(start, "unterminated quoted field")),
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                    }
+                },
+                Some(c) => field.push(c),
+            }
+        }
+
+        // Blank lines, like the one a trailing line break leaves, aren't records.
+        if fields.len() > 1 || !fields[0].is_empty() {
+            records.push((start, fields));
+        }
+    }
+
+    Ok(records)
+}
+
+/// The records of a registry, after its header.
+struct Table {
+    header: Vec<String>,
+    records: std::vec::IntoIter<(usize, Vec<String>)>,
+}
+
+impl Table {
+    fn new(text: &str) -> Result<Self, CsvError> {
+        let mut records = records(text)?.into_iter();
+        let (_, header) = records
+            .next()
+            .ok_or_else(|| CsvError::new(1, "missing header"))?;
+        Ok(Table { header, records })
+    }
+
+    /// Returns the index of a column, ignoring footnote references like the one of `Status [1]`.
+    fn column(&self, name: &str) -> Result<usize, CsvError> {
+        self.header
+            .iter()
+            .position(|column| strip_footnotes(column) == name)
+            .ok_or_else(|| CsvError::new(1, ["missing column `", name, "`"].concat()))
+    }
+
+    /// Returns the indices of columns, or an error for every missing one.
+    fn columns<const N: usize>(&self, names: [&str; N]) -> Result<[usize; N], Vec<CsvError>> {
+        let columns = names.map(|name| self.column(name));
+        let errors: Vec<_> = columns
+            .iter()
+            .filter_map(|column| column.clone().err())
+            .collect();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(columns.map(Result::unwrap))
+    }
+
+    /// Returns the next record, checking that it has a field for every column.
+    fn next_record(&mut self) -> Option<Result<(usize, Vec<String>), CsvError>> {
+        let (line, fields) = self.records.next()?;
+        if fields.len() != self.header.len() {
+            return Some(Err(CsvError::new(
+                line,
+                [
+                    "expected ",
+                    &self.header.len().to_string(),
+                    " fields, found ",
+                    &fields.len().to_string(),
+                ]
+                .concat(),
+            )));
+        }
+        Some(Ok((line, fields)))
+    }
+
+    /// Parses every remaining record, returning the errors of all malformed records rather than
+    /// only the first. Records parsed to `None` are skipped.
+    fn parse_records<T>(
+        mut self,
+        mut parse: impl FnMut(&Self, usize, &[String]) -> Result<Option<T>, CsvError>,
+    ) -> Result<Vec<T>, Vec<CsvError>> {
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+        while let Some(record) = self.next_record() {
+            match record.and_then(|(line, fields)| parse(&self, line, &fields)) {
+                Ok(row) => rows.extend(row),
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.is_empty() {
+            Ok(rows)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the error for an invalid value in a column.
+    fn invalid_value(&self, line: usize, column: usize, value: &str) -> CsvError {
+        CsvError::new(
+            line,
+            [
+                "invalid value `",
+                value,
+                "` in column `",
+                &self.header[column],
+                "`",
+            ]
+            .concat(),
+        )
+    }
+
+    /// Returns the status of a record, or `None` with a warning if the status isn't one of the
+    /// known ones.
+    fn status<'a>(
+        &self,
+        line: usize,
+        fields: &'a [String],
+        column: usize,
+        known: &[&str],
+        warnings: &mut Vec<CsvError>,
+    ) -> Option<&'a str> {
+        let status = strip_footnotes(&fields[column]);
+        if known.contains(&status) {
+            return Some(status);
+        }
+        warnings.push(CsvError::new(
+            line,
+            [
+                "unknown value `",
+                status,
+                "` in column `",
+                &self.header[column],
+                "`, skipping the row",
+            ]
+            .concat(),
+        ));
+        None
+    }
+}
+
+/// Removes the footnote references, like ` [1]`, from the end of a field.
+fn strip_footnotes(field: &str) -> &str {
+    let mut field = field.trim();
+    while let Some(rest) = field.strip_suffix(']') {
+        match rest.rfind('[') {
+            Some(start) if rest[start + 1..].bytes().all(|b| b.is_ascii_digit()) => {
+                field = rest[..start].trim_end();
+            }
+            _ => break,
+        }
+    }
+    field
+}
+
+/// Parses a network written as `address/prefix`.
+fn parse_prefix(line: usize, field: &str) -> Result<(IpAddr, u8), CsvError> {
+    let invalid_prefix = || CsvError::new(line, ["invalid prefix `", field, "`"].concat());
+    let (network, prefix_len) = field.split_once('/').ok_or_else(invalid_prefix)?;
+    let network: IpAddr = network.parse().map_err(|_| invalid_prefix())?;
+    let prefix_len: u8 = prefix_len.parse().map_err(|_| invalid_prefix())?;
+    let max_prefix_len = if network.is_ipv4() { 32 } else { 128 };
+    if prefix_len > max_prefix_len {
+        return Err(invalid_prefix());
+    }
+    Ok((network, prefix_len))
+}
+
+/// A row of the IPv6 unicast address assignments registry.
+///
+/// Only `build.rs` reads the date, WHOIS, and RDAP columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub network: Ipv6Addr,
+    pub prefix_len: u8,
+    pub designation: String,
+    pub date: String,
+    pub whois: String,
+    pub rdap: String,
+    pub status: String,
+}
+
+impl Assignment {
+    /// Returns a boolean indicating whether the row is allocated to a regional internet registry.
+    ///
+    /// IP address ranges are only considered reachable if they are both ALLOCATED and assigned to
+    /// one of the 5 regional internet registries (RIRs).
+    pub fn is_rir_allocation(&self) -> bool {
+        const RIRS: [&str; 5] = ["AFRINIC", "APNIC", "ARIN", "LACNIC", "RIPE NCC"];
+        self.status == "ALLOCATED" && RIRS.contains(&&*self.designation)
+    }
+}
+
+/// Parses the rows of the IPv6 unicast address assignments registry.
+///
+/// Rows with a status other than `ALLOCATED` or `RESERVED` are skipped, with a warning.
+pub fn parse_assignments(
+    text: &str,
+    warnings: &mut Vec<CsvError>,
+) -> Result<Vec<Assignment>, Vec<CsvError>> {
+    let table = Table::new(text).map_err(|error| std::vec![error])?;
+    let [prefix, designation, date, whois, rdap, status] =
+        table.columns(["Prefix", "Designation", "Date", "WHOIS", "RDAP", "Status"])?;
+
+    table.parse_records(|table, line, fields| {
+        let invalid_prefix =
+            || CsvError::new(line, ["invalid prefix `", &fields[prefix], "`"].concat());
+        let (network, prefix_len) = match parse_prefix(line, &fields[prefix])? {
+            (IpAddr::V6(network), prefix_len) => (network, prefix_len),
+            (IpAddr::V4(_), _) => return Err(invalid_prefix()),
+        };
+        let Some(status) = table.status(line, fields, status, &["ALLOCATED", "RESERVED"], warnings)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Assignment {
+            network,
+            prefix_len,
+            designation: fields[designation].clone(),
+            date: fields[date].clone(),
+            whois: fields[whois].clone(),
+            rdap: fields[rdap].clone(),
+            status: status.to_string(),
+        }))
+    })
+}
+
+/// A row of one of the IANA special-purpose address registries.
+///
+/// The flags are `None` when the registry leaves them blank, like it does for deprecated rows.
+/// Only `build.rs` reads the special-purpose registries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialPurpose {
+    /// The networks of the row, most rows have a single one.
+    pub networks: Vec<(IpAddr, u8)>,
+    pub name: String,
+    /// Whether the row has a termination date.
+    pub terminated: bool,
+    pub source: Option<bool>,
+    pub destination: Option<bool>,
+    pub forwardable: Option<bool>,
+    pub globally_reachable: Option<bool>,
+}
+
+/// Parses the rows of an IANA special-purpose address registry, IPv4 or IPv6.
+///
+/// Footnote references are removed from every field.
+pub fn parse_special_registry(text: &str) -> Result<Vec<SpecialPurpose>, Vec<CsvError>> {
+    let table = Table::new(text).map_err(|error| std::vec![error])?;
+    let [address_block, name, termination_date, source, destination, forwardable, globally_reachable] =
+        table.columns([
+            "Address Block",
+            "Name",
+            "Termination Date",
+            "Source",
+            "Destination",
+            "Forwardable",
+            "Globally Reachable",
+        ])?;
+    let flags = [source, destination, forwardable, globally_reachable];
+
+    table.parse_records(|table, line, fields| {
+        let networks = strip_footnotes(&fields[address_block])
+            .split(',')
+            .map(|network| parse_prefix(line, strip_footnotes(network)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [source, destination, forwardable, globally_reachable] =
+            flags.map(|column| match strip_footnotes(&fields[column]) {
+                "True" => Ok(Some(true)),
+                "False" => Ok(Some(false)),
+                "" | "N/A" => Ok(None),
+                value => Err(table.invalid_value(line, column, value)),
+            });
+
+        Ok(Some(SpecialPurpose {
+            networks,
+            name: strip_footnotes(&fields[name]).to_string(),
+            terminated: !matches!(strip_footnotes(&fields[termination_date]), "" | "N/A"),
+            source: source?,
+            destination: destination?,
+            forwardable: forwardable?,
+            globally_reachable: globally_reachable?,
+        }))
+    })
+}
+
+/// A row of the IANA IPv4 address space registry, which lists every /8.
+///
+/// Only `build.rs` reads the IPv4 address space registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressSpaceBlock {
+    pub designation: String,
+    pub whois: String,
+    pub rdap: String,
+    /// `ALLOCATED`, `LEGACY`, or `RESERVED`.
+    pub status: String,
+}
+
+/// Parses the rows of the IANA IPv4 address space registry, indexed by the first octet of their /8.
+///
+/// Rows with a status other than `ALLOCATED`, `LEGACY`, or `RESERVED` are `None`, with a warning.
+pub fn parse_address_space(
+    text: &str,
+    warnings: &mut Vec<CsvError>,
+) -> Result<Vec<Option<AddressSpaceBlock>>, Vec<CsvError>> {
+    let table = Table::new(text).map_err(|error| std::vec![error])?;
+    let [prefix, designation, whois, rdap, status] =
+        table.columns(["Prefix", "Designation", "WHOIS", "RDAP", "Status"])?;
+
+    let rows = table.parse_records(|table, line, fields| {
+        let octet = fields[prefix]
+            .strip_suffix("/8")
+            .filter(|octet| octet.len() == 3)
+            .and_then(|octet| octet.parse::<u8>().ok())
+            .ok_or_else(|| {
+                CsvError::new(line, ["invalid prefix `", &fields[prefix], "`"].concat())
+            })?;
+
+        let known = ["ALLOCATED", "LEGACY", "RESERVED"];
+        let block = table
+            .status(line, fields, status, &known, warnings)
+            .map(|status| AddressSpaceBlock {
+                designation: fields[designation].clone(),
+                whois: fields[whois].clone(),
+                rdap: fields[rdap].clone(),
+                status: status.to_string(),
+            });
+        Ok(Some((line, usize::from(octet), block)))
+    })?;
+
+    // The registry lists every /8 in order, so the table can be indexed by the first octet.
+    let mut blocks = Vec::with_capacity(256);
+    let mut errors = Vec::new();
+    let mut last_line = 1;
+    for (line, octet, block) in rows {
+        if octet < blocks.len() {
+            let message = [
+                "duplicate or out of order row for `",
+                &slash_eight(octet),
+                "`",
+            ];
+            errors.push(CsvError::new(line, message.concat()));
+            continue;
+        }
+        while blocks.len() < octet {
+            let message = ["missing row for `", &slash_eight(blocks.len()), "`"];
+            errors.push(CsvError::new(line, message.concat()));
+            blocks.push(None);
+        }
+        blocks.push(block);
+        last_line = line;
+    }
+    while blocks.len() < 256 {
+        let message = ["missing row for `", &slash_eight(blocks.len()), "`"];
+        errors.push(CsvError::new(last_line, message.concat()));
+        blocks.push(None);
+    }
+
+    if errors.is_empty() {
+        Ok(blocks)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns the prefix of a /8 as the IPv4 address space registry writes it, like `010/8`.
+fn slash_eight(octet: usize) -> String {
+    let padding = match octet {
+        0..=9 => "00",
+        10..=99 => "0",
+        _ => "",
+    };
+    [padding, &octet.to_string(), "/8"].concat()
+}
+
+/// Parses the registry and returns the rows allocated to a regional internet registry, sorted by
+/// network.
+pub fn rir_allocations(
+    text: &str,
+    warnings: &mut Vec<CsvError>,
+) -> Result<Vec<Assignment>, Vec<CsvError>> {
+    let mut allocations = parse_assignments(text, warnings)?;
+    allocations.retain(Assignment::is_rir_allocation);
+    allocations.sort_by_key(|allocation| (allocation.network, allocation.prefix_len));
+    Ok(allocations)
+}
+
+/// Returns the mask of a prefix length.
+fn mask(prefix_len: u8) -> u128 {
+    u128::MAX
+        .checked_shl(128 - u32::from(prefix_len))
+        .unwrap_or(0)
+}
+
+/// Combines neighboring allocations into larger blocks to make filtering more efficient.
+///
+/// Returns the fewest networks covering the same addresses, sorted by address.
+pub fn merge_networks(networks: Vec<(u128, u8)>) -> Vec<(u128, u8)> {
+    let ranges = networks
+        .into_iter()
+        .map(|(network, prefix_len)| (network & mask(prefix_len), network | !mask(prefix_len)));
+    aggregate(ranges, 128)
+}
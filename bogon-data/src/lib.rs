@@ -0,0 +1,133 @@
+#![cfg_attr(not(test), no_std)]
+
+//! The tables the [`bogon`] crate compiles in, generated from the IANA registries.
+//!
+//! The tables are plain data, so that refreshing them only takes a patch release of this crate.
+//! Use `bogon` to classify IP addresses, it re-exports `DATASET_SHA256` and `DATASET_DATE`.
+//!
+//! [`bogon`]: https://docs.rs/bogon
+//!
+//! # Cargo Features
+//!
+//! - `download`: Download the latest IPv6 address allocations, IPv4 address space registry, and IPv4 and IPv6 special-purpose address registries from the IANA website during the build process. Requires a network connection.
+//! - `pregenerated`: Compile the checked-in copies of the generated tables in `src/generated` instead of generating them, for builds that can't run the build script. The build script does nothing, so the `download` feature and the environment variables below are ignored.
+//! - `codegen-match`: Compile `is_bogon_v6_generated`, the ranges of `V6_ALLOCATION_RANGES` as a balanced tree of `match` expressions on the top 32 bits of an IPv6 address.
+//! - `compact-data`: Compile `V6_ALLOCATION_BOUNDARIES` and `V6_ALLOCATION_BLOCKS`, the bounds of the ranges of `V6_ALLOCATION_RANGES` as deltas in LEB128, in blocks that can be searched without decoding all of them.
+//! - `allow-cgnat`, `allow-benchmarking`, `allow-reserved-240-4`: Remove `100.64.0.0/10`, `198.18.0.0/15`, or `240.0.0.0/4` from `V4_BOGON_NETWORKS`.
+//! - `alloc`, `std`: The registry parsers and network aggregation `bogon` builds on at runtime.
+//!
+//! # Build Environment
+//!
+//! The build script reads the IANA registries from the copies bundled with the crate, or downloads
+//! them with the `download` feature. A local copy takes precedence over both when an environment
+//! variable names it: `BOGON_IPV6_CSV` for the IPv6 unicast address assignments, `BOGON_IPV4_CSV`
+//! for the IPv4 address space, and `BOGON_IPV6_SPECIAL_CSV` and `BOGON_IPV4_SPECIAL_CSV` for the
+//! special-purpose address registries.
+//!
+//! The `download` feature caches the downloaded registries in
+//! `target/<profile>/build/bogon-registry-cache`, or in the directory named by `BOGON_CACHE_DIR`.
+//! Later builds only download a registry again when it changed, and use the cached copy when the
+//! IANA website is unreachable, or the bundled copy when nothing was cached yet, with a warning.
+//! Set `BOGON_REQUIRE_FRESH_DATA=1` to fail the build instead.
+//!
+//! For reproducible builds, set `BOGON_CSV_SHA256` to the SHA-256 digest of the IPv6 unicast
+//! address assignments snapshot you vetted, and the build fails if the registry it reads differs.
+//! The bundled copy is always checked against its recorded digest. `DATASET_SHA256` and
+//! `DATASET_DATE` report the snapshot a binary embeds: the digest covers all four registries, and
+//! the date is the one in `snapshot-date` for the bundled copies, or the build date otherwise.
+//!
+//! Builds that compile the bundled registries warn when they were downloaded more than 12 months
+//! ago, or more than the number of months in `BOGON_STALE_AFTER_MONTHS`, measured from
+//! `SOURCE_DATE_EPOCH` when it is set. Set `BOGON_SUPPRESS_STALE_WARNING=1` to silence the
+//! warning. Cargo only shows the warnings of build scripts of path dependencies, so check
+//! `DATASET_DATE` as well.
+
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+// Shared with `build.rs`, which uses it to generate the IPv6 allocation tables.
+#[cfg(test)]
+mod allocations;
+#[cfg(test)]
+mod allocations_tests;
+// Shared with `build.rs`, which uses it to split ranges into networks.
+#[doc(hidden)]
+pub mod cidr;
+#[cfg(test)]
+mod cidr_tests;
+// Shared with `build.rs`, which uses it to encode the IPv6 allocation ranges of the `compact-data`
+// feature.
+#[cfg(test)]
+mod compact;
+#[cfg(test)]
+mod compact_tests;
+// Shared with `build.rs`, which uses it to cache the registries the `download` feature downloads.
+#[cfg(test)]
+mod csv_cache;
+#[cfg(test)]
+mod csv_cache_tests;
+// Shared with `build.rs`, which uses it to choose where to read each registry from.
+#[cfg(test)]
+mod csv_source;
+#[cfg(test)]
+mod csv_source_tests;
+// Shared with `build.rs`, which uses it to generate the tree of `match` expressions of the
+// `codegen-match` feature.
+#[cfg(test)]
+mod decision_tree;
+#[cfg(test)]
+mod decision_tree_tests;
+#[cfg(test)]
+mod generated_tests;
+// Shared with `build.rs`, which uses it to classify every /16 of the IPv6 address space.
+#[cfg(test)]
+mod hextets;
+#[cfg(test)]
+mod hextets_tests;
+// Shared with `build.rs`, which uses it to parse the registries.
+#[doc(hidden)]
+#[cfg(any(test, feature = "std"))]
+pub mod iana_csv;
+// Shared with `build.rs`, which uses it to merge the IPv6 allocations.
+#[doc(hidden)]
+#[cfg(any(test, feature = "alloc"))]
+pub mod ranges;
+// Shared with `build.rs`, which uses it to verify the registries against their digests.
+#[cfg(test)]
+mod sha256;
+#[cfg(test)]
+mod sha256_tests;
+// Shared with `build.rs`, which uses it to warn about stale bundled registries.
+#[cfg(test)]
+mod staleness;
+#[cfg(test)]
+mod staleness_tests;
+
+/// Includes a file generated by the build script, or its checked-in copy in `src/generated` with
+/// the `pregenerated` feature.
+macro_rules! include_generated {
+    ($file:literal) => {
+        #[cfg(not(feature = "pregenerated"))]
+        include!(concat!(env!("OUT_DIR"), "/", $file));
+        #[cfg(feature = "pregenerated")]
+        include!(concat!("generated/", $file));
+    };
+}
+
+/// The number of networks the `allow-*` features remove from `V4_BOGON_NETWORKS`.
+pub const V4_BOGON_ALLOWED: usize = cfg!(feature = "allow-cgnat") as usize
+    + cfg!(feature = "allow-benchmarking") as usize
+    + cfg!(feature = "allow-reserved-240-4") as usize;
+
+include_generated!("ipv4-address-space.rs");
+include_generated!("ipv4-special-registry.rs");
+#[cfg(any(test, feature = "compact-data"))]
+include_generated!("ipv6-allocation-boundaries.rs");
+#[cfg(feature = "codegen-match")]
+include_generated!("ipv6-allocation-tree.rs");
+include_generated!("ipv6-first-hextets.rs");
+include_generated!("ipv6-special-registry.rs");
+include_generated!("ipv6-unicast-address-allocations.rs");
+include_generated!("registry-strings.rs");
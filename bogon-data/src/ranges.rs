@@ -0,0 +1,102 @@
+//! Aggregating lists of networks into the fewest networks covering the same addresses.
+//!
+//! This module is shared with `build.rs`, which includes it with `#[path]`, so it must not depend
+//! on the rest of the crate other than `cidr`, which `build.rs` includes as well. `bogon` builds its `aggregate_v4` and `range_to_cidrs_v4` functions,
+//! and their IPv6 twins, on top of it.
+
+use alloc::vec::Vec;
+
+use crate::cidr::first_network;
+
+/// Returns the first and last address of a network of an address family with `bits` bits.
+pub fn range(network: u128, prefix_len: u8, bits: u32) -> (u128, u128) {
+    let host = (u128::MAX >> (128 - bits))
+        .checked_shr(u32::from(prefix_len))
+        .unwrap_or(0);
+    (network & !host, network | host)
+}
+
+/// Sorts ranges and merges the ones that overlap or are adjacent.
+pub fn merge(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u128, u128)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                *last_end = (*last_end).max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Returns the fewest networks exactly covering an inclusive range of an address family with
+/// `bits` bits, in order.
+///
+/// Every network is the largest one that both starts at the first address not covered yet, and
+/// doesn't extend past the end of the range.
+///
+/// # Panics
+///
+/// Panics if `start` is after `end`.
+pub fn range_to_cidrs(mut start: u128, end: u128, bits: u32) -> Vec<(u128, u8)> {
+    let mut networks = Vec::new();
+    loop {
+        let (network, prefix_len) = first_network(start, end, bits);
+        networks.push((network, prefix_len));
+        let last = range(network, prefix_len, bits).1;
+        if last >= end {
+            return networks;
+        }
+        start = last + 1;
+    }
+}
+
+/// Returns the addresses covered by a list of networks of an address family with `bits` bits, as
+/// sorted and disjoint ranges, to compare what two lists of networks cover.
+pub fn coverage(networks: impl Iterator<Item = (u128, u8)>, bits: u32) -> Vec<(u128, u128)> {
+    merge(
+        networks
+            .map(|(network, prefix_len)| range(network, prefix_len, bits))
+            .collect(),
+    )
+}
+
+/// Returns the fewest networks covering the same addresses as a list of ranges of an address
+/// family with `bits` bits, in order.
+pub fn aggregate(ranges: impl Iterator<Item = (u128, u128)>, bits: u32) -> Vec<(u128, u8)> {
+    merge(ranges.collect())
+        .into_iter()
+        .flat_map(|(start, end)| range_to_cidrs(start, end, bits))
+        .collect()
+}
+
+/// Returns the addresses of sorted and disjoint ranges that aren't in the sorted and disjoint
+/// ranges `removed`, as sorted and disjoint ranges.
+pub fn subtract(ranges: &[(u128, u128)], removed: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut remaining = Vec::new();
+    for &(start, end) in ranges {
+        // The first address of the range that is neither removed nor kept yet.
+        let mut next = Some(start);
+        let first = removed.partition_point(|&(_, removed_end)| removed_end < start);
+        for &(removed_start, removed_end) in &removed[first..] {
+            let Some(from) = next else { break };
+            if removed_start > end {
+                break;
+            }
+            if removed_start > from {
+                remaining.push((from, removed_start - 1));
+            }
+            next = removed_end
+                .checked_add(1)
+                .filter(|&after| after <= end)
+                .map(|after| after.max(from));
+        }
+        if let Some(from) = next {
+            remaining.push((from, end));
+        }
+    }
+    remaining
+}
@@ -0,0 +1,50 @@
+//! The IANA registry CSV files bundled with the crate, which the tables are generated from.
+//!
+//! This module is shared with `build.rs` and the `update-data` xtask, which include it with
+//! `#[path]`. The library doesn't compile it.
+
+/// A registry CSV file bundled with the crate.
+pub(crate) struct RegistryFile {
+    /// The name of the bundled copy.
+    pub(crate) name: &'static str,
+    /// The environment variable naming a local copy for the build script to read instead.
+    pub(crate) env: &'static str,
+    /// Where the registry is downloaded from.
+    pub(crate) url: &'static str,
+    pub(crate) bundled: &'static str,
+}
+
+pub(crate) const IPV6_ASSIGNMENTS: RegistryFile = RegistryFile {
+    name: "ipv6-unicast-address-assignments.csv",
+    env: "BOGON_IPV6_CSV",
+    url: "https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.csv",
+    bundled: include_str!("../ipv6-unicast-address-assignments.csv"),
+};
+pub(crate) const IPV4_ADDRESS_SPACE: RegistryFile = RegistryFile {
+    name: "ipv4-address-space.csv",
+    env: "BOGON_IPV4_CSV",
+    url: "https://www.iana.org/assignments/ipv4-address-space/ipv4-address-space.csv",
+    bundled: include_str!("../ipv4-address-space.csv"),
+};
+pub(crate) const IPV6_SPECIAL: RegistryFile = RegistryFile {
+    name: "iana-ipv6-special-registry-1.csv",
+    env: "BOGON_IPV6_SPECIAL_CSV",
+    url: "https://www.iana.org/assignments/iana-ipv6-special-registry/iana-ipv6-special-registry-1.csv",
+    bundled: include_str!("../iana-ipv6-special-registry-1.csv"),
+};
+pub(crate) const IPV4_SPECIAL: RegistryFile = RegistryFile {
+    name: "iana-ipv4-special-registry-1.csv",
+    env: "BOGON_IPV4_SPECIAL_CSV",
+    url: "https://www.iana.org/assignments/iana-ipv4-special-registry/iana-ipv4-special-registry-1.csv",
+    bundled: include_str!("../iana-ipv4-special-registry-1.csv"),
+};
+
+/// The name of the file recording the SHA-256 digest of the bundled IPv6 unicast address
+/// assignments, in the format `sha256sum` writes.
+pub(crate) const IPV6_ASSIGNMENTS_SHA256: &str = "ipv6-unicast-address-assignments.csv.sha256";
+
+/// The name of the file recording the date the bundled registries were downloaded, as
+/// `YYYY-MM-DD`.
+pub(crate) const SNAPSHOT_DATE: &str = "snapshot-date";
+/// The date the bundled registries were downloaded.
+pub(crate) const BUNDLED_SNAPSHOT_DATE: &str = include_str!("../snapshot-date");
@@ -0,0 +1,78 @@
+use crate::sha256::{sha256, verify, ChecksumMismatch};
+
+#[test]
+fn known_digests() {
+    assert_eq!(
+        sha256(b""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        sha256(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    // Two blocks, after padding.
+    assert_eq!(
+        sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(
+        sha256(&[b'a'; 1_000_000]),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+}
+
+#[test]
+fn bundled_digest_is_recorded() {
+    let csv = include_str!("../ipv6-unicast-address-assignments.csv");
+    let recorded = include_str!("../ipv6-unicast-address-assignments.csv.sha256");
+    assert!(verify(csv, Some(recorded)).is_ok());
+}
+
+#[test]
+fn verify_accepts_matching_digests() {
+    let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    assert_eq!(verify("abc", None).as_deref(), Ok(digest));
+    assert_eq!(verify("abc", Some(digest)).as_deref(), Ok(digest));
+    assert_eq!(
+        verify("abc", Some(&digest.to_uppercase())).as_deref(),
+        Ok(digest)
+    );
+    assert_eq!(
+        verify("abc", Some(&format!("{digest}  registry.csv\n"))).as_deref(),
+        Ok(digest)
+    );
+    // An empty digest counts as unset.
+    assert_eq!(verify("abc", Some("")).as_deref(), Ok(digest));
+}
+
+#[test]
+fn verify_reports_both_digests_on_mismatch() {
+    let expected = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    let error = verify("abc", Some(expected)).unwrap_err();
+
+    assert_eq!(
+        error,
+        ChecksumMismatch {
+            expected: expected.to_string(),
+            actual: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "expected SHA-256 e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855, \
+         found ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn dataset_is_the_bundled_snapshot() {
+    let csvs = [
+        include_str!("../ipv6-unicast-address-assignments.csv"),
+        include_str!("../ipv4-address-space.csv"),
+        include_str!("../iana-ipv6-special-registry-1.csv"),
+        include_str!("../iana-ipv4-special-registry-1.csv"),
+    ];
+    assert_eq!(crate::DATASET_SHA256, sha256(csvs.concat().as_bytes()));
+    assert_eq!(crate::DATASET_DATE, include_str!("../snapshot-date").trim());
+}
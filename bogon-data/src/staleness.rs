@@ -0,0 +1,155 @@
+//! Warning about builds that compile bundled registries older than a threshold.
+//!
+//! This module is shared with `build.rs` and the `update-data` xtask, which include it with
+//! `#[path]`. The library only compiles it for its tests.
+
+use std::{
+    ffi::OsString,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The age in months after which the bundled registries are considered stale, unless
+/// `BOGON_STALE_AFTER_MONTHS` sets another threshold.
+pub(crate) const DEFAULT_STALE_AFTER_MONTHS: u32 = 12;
+
+/// A calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Returns a date, or `None` if the month or the day doesn't exist.
+    pub(crate) fn new(year: i64, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Parses a date in the `YYYY-MM-DD` format, ignoring surrounding whitespace.
+    pub(crate) fn parse(date: &str) -> Option<Self> {
+        let mut parts = date.trim().split('-');
+        let mut part = |digits: usize| {
+            parts
+                .next()
+                .filter(|part| part.len() == digits && part.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|part| part.parse().ok())
+        };
+        let (year, month, day) = (part(4)?, part(2)?, part(2)?);
+        if parts.next().is_some() {
+            return None;
+        }
+        Date::new(year, u32::try_from(month).ok()?, u32::try_from(day).ok()?)
+    }
+
+    /// Returns the UTC date of a number of seconds since the Unix epoch.
+    pub(crate) fn from_unix_seconds(seconds: u64) -> Self {
+        // Howard Hinnant's `civil_from_days`, with eras of 400 years starting on March 1st.
+        let days = (seconds / 86_400) as i64 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date { year, month, day }
+    }
+
+    /// Returns the date a number of months later, on the last day of the month if the day doesn't
+    /// exist in that month.
+    pub(crate) fn add_months(self, months: u32) -> Self {
+        let months = i64::from(self.month - 1) + i64::from(months);
+        let year = self.year + months / 12;
+        let month = (months % 12) as u32 + 1;
+        let day = self.day.min(days_in_month(year, month));
+        Date { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns the number of days of a month.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the date of the build, from `SOURCE_DATE_EPOCH` for reproducible builds, or else from
+/// the clock.
+pub(crate) fn build_date(source_date_epoch: Option<OsString>, now: SystemTime) -> Date {
+    let seconds = source_date_epoch
+        .and_then(|epoch| epoch.to_str()?.trim().parse().ok())
+        .unwrap_or_else(|| {
+            now.duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs())
+        });
+    Date::from_unix_seconds(seconds)
+}
+
+/// When the build script warns that the bundled registries are stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Staleness {
+    /// The age in months after which the bundled registries are stale.
+    pub(crate) stale_after_months: u32,
+    /// Whether `BOGON_SUPPRESS_STALE_WARNING=1` silences the warning.
+    pub(crate) suppressed: bool,
+}
+
+impl Staleness {
+    /// Returns the settings of `BOGON_STALE_AFTER_MONTHS` and `BOGON_SUPPRESS_STALE_WARNING`, or an
+    /// error if the threshold isn't a number of months. Only `1` suppresses the warning.
+    pub(crate) fn from_env(
+        stale_after_months: Option<OsString>,
+        suppress: Option<OsString>,
+    ) -> Result<Self, String> {
+        let stale_after_months = match stale_after_months.filter(|months| !months.is_empty()) {
+            Some(months) => months
+                .to_str()
+                .and_then(|months| months.trim().parse().ok())
+                .ok_or_else(|| {
+                    format!(
+                        "BOGON_STALE_AFTER_MONTHS must be a number of months, not `{}`",
+                        months.to_string_lossy()
+                    )
+                })?,
+            None => DEFAULT_STALE_AFTER_MONTHS,
+        };
+        Ok(Staleness {
+            stale_after_months,
+            suppressed: suppress.is_some_and(|suppress| suppress == "1"),
+        })
+    }
+
+    /// Returns the warning for registries downloaded on `snapshot` and compiled on `today`, or
+    /// `None` if they are at most `stale_after_months` old or the warning is suppressed.
+    pub(crate) fn warning(&self, snapshot: Date, today: Date) -> Option<String> {
+        if self.suppressed || today <= snapshot.add_months(self.stale_after_months) {
+            return None;
+        }
+        Some(format!(
+            "The bundled IANA registries were downloaded on {snapshot}, more than {} months ago. \
+             Update bogon-data or enable the `download` feature to refresh them, or set \
+             BOGON_SUPPRESS_STALE_WARNING=1 to silence this warning.",
+            self.stale_after_months
+        ))
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    ffi::OsString,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::staleness::{build_date, Date, Staleness, DEFAULT_STALE_AFTER_MONTHS};
+
+fn date(date: &str) -> Date {
+    Date::parse(date).unwrap()
+}
+
+const DEFAULT: Staleness = Staleness {
+    stale_after_months: DEFAULT_STALE_AFTER_MONTHS,
+    suppressed: false,
+};
+
+#[test]
+fn parse_dates() {
+    assert_eq!(Date::parse("2024-07-23"), Date::new(2024, 7, 23));
+    assert_eq!(Date::parse("2024-07-23\n"), Date::new(2024, 7, 23));
+    assert_eq!(Date::parse("2024-02-29"), Date::new(2024, 2, 29));
+    assert_eq!(date("2024-07-23").to_string(), "2024-07-23");
+}
+
+#[test]
+fn parse_invalid_dates() {
+    for invalid in [
+        "",
+        "2024-07",
+        "2024-7-23",
+        "24-07-23",
+        "2024-07-23-01",
+        "2024-13-01",
+        "2024-00-01",
+        "2024-04-31",
+        "2023-02-29",
+        "+024-07-23",
+        "2024/07/23",
+    ] {
+        assert_eq!(Date::parse(invalid), None, "{invalid}");
+    }
+}
+
+#[test]
+fn dates_from_unix_seconds() {
+    assert_eq!(Date::from_unix_seconds(0), date("1970-01-01"));
+    assert_eq!(Date::from_unix_seconds(86_399), date("1970-01-01"));
+    assert_eq!(Date::from_unix_seconds(86_400), date("1970-01-02"));
+    assert_eq!(Date::from_unix_seconds(951_782_400), date("2000-02-29"));
+    assert_eq!(Date::from_unix_seconds(1_721_692_800), date("2024-07-23"));
+    assert_eq!(Date::from_unix_seconds(4_107_542_400), date("2100-03-01"));
+}
+
+#[test]
+fn add_months() {
+    assert_eq!(date("2024-07-23").add_months(12), date("2025-07-23"));
+    assert_eq!(date("2024-07-23").add_months(6), date("2025-01-23"));
+    assert_eq!(date("2024-12-31").add_months(1), date("2025-01-31"));
+    assert_eq!(date("2024-01-31").add_months(1), date("2024-02-29"));
+    assert_eq!(date("2024-02-29").add_months(12), date("2025-02-28"));
+    assert_eq!(date("2024-07-23").add_months(0), date("2024-07-23"));
+}
+
+#[test]
+fn build_date_prefers_source_date_epoch() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_721_692_800);
+    assert_eq!(build_date(None, now), date("2024-07-23"));
+    assert_eq!(
+        build_date(Some(OsString::from("951782400")), now),
+        date("2000-02-29")
+    );
+    assert_eq!(
+        build_date(Some(OsString::from("yesterday")), now),
+        date("2024-07-23")
+    );
+}
+
+#[test]
+fn exactly_twelve_months_is_fresh() {
+    let snapshot = date("2024-07-23");
+    assert_eq!(DEFAULT.warning(snapshot, date("2024-07-23")), None);
+    assert_eq!(DEFAULT.warning(snapshot, date("2025-07-22")), None);
+    assert_eq!(DEFAULT.warning(snapshot, date("2025-07-23")), None);
+}
+
+#[test]
+fn older_than_twelve_months_is_stale() {
+    let warning = DEFAULT
+        .warning(date("2024-07-23"), date("2025-07-24"))
+        .unwrap();
+    assert!(warning.contains("downloaded on 2024-07-23"), "{warning}");
+    assert!(warning.contains("more than 12 months ago"), "{warning}");
+    assert!(
+        warning.contains("BOGON_SUPPRESS_STALE_WARNING=1"),
+        "{warning}"
+    );
+}
+
+#[test]
+fn suppressed_warning() {
+    let staleness = Staleness::from_env(None, Some(OsString::from("1"))).unwrap();
+    assert!(staleness.suppressed);
+    assert_eq!(
+        staleness.warning(date("2019-11-06"), date("2025-07-24")),
+        None
+    );
+}
+
+#[test]
+fn suppression_opt_in() {
+    let suppressed = |value: Option<&str>| {
+        Staleness::from_env(None, value.map(OsString::from))
+            .unwrap()
+            .suppressed
+    };
+    assert!(suppressed(Some("1")));
+    assert!(!suppressed(Some("0")));
+    assert!(!suppressed(Some("")));
+    assert!(!suppressed(None));
+}
+
+#[test]
+fn configurable_threshold() {
+    let staleness = Staleness::from_env(Some(OsString::from("6")), None).unwrap();
+    assert_eq!(staleness.stale_after_months, 6);
+    let snapshot = date("2024-07-23");
+    assert_eq!(staleness.warning(snapshot, date("2025-01-23")), None);
+    assert!(staleness.warning(snapshot, date("2025-01-24")).is_some());
+
+    let default = Staleness::from_env(Some(OsString::new()), None).unwrap();
+    assert_eq!(default, DEFAULT);
+    assert_eq!(Staleness::from_env(None, None), Ok(DEFAULT));
+}
+
+#[test]
+fn invalid_threshold() {
+    let error = Staleness::from_env(Some(OsString::from("a year")), None).unwrap_err();
+    assert_eq!(
+        error,
+        "BOGON_STALE_AFTER_MONTHS must be a number of months, not `a year`"
+    );
+    assert!(Staleness::from_env(Some(OsString::from("-1")), None).is_err());
+}
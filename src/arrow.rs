@@ -0,0 +1,99 @@
+//! Checking Arrow arrays of IP addresses, for the `arrow` feature.
+//!
+//! The verdicts are computed 64 values at a time, like the batch checks, and the words of the
+//! bitmap become the values of the [`BooleanArray`] without copying.
+
+use std::vec::Vec;
+
+use arrow_array::{Array, BooleanArray, FixedSizeBinaryArray, UInt32Array};
+use arrow_buffer::{BooleanBuffer, Buffer};
+
+use crate::{batch, is_bogon_v4_bits, is_bogon_v6_bits};
+
+/// Returns a boolean array indicating whether each of an array of IPv4 addresses, as integers in
+/// host order like [`Ipv4Addr::to_bits`](core::net::Ipv4Addr::to_bits) returns them, is bogus.
+///
+/// The nulls of the array are kept. Like [`is_bogon_v4_bits`], only the compiled-in tables are
+/// consulted: the overrides of the `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::{BooleanArray, UInt32Array};
+/// use bogon::is_bogon_v4_array;
+///
+/// let ips = UInt32Array::from(vec![Some(0x7f00_0001), None, Some(0x0808_0808)]);
+/// assert_eq!(
+///     is_bogon_v4_array(&ips),
+///     BooleanArray::from(vec![Some(true), None, Some(false)])
+/// );
+/// ```
+pub fn is_bogon_v4_array(arr: &UInt32Array) -> BooleanArray {
+    let values = arr.values();
+    let words = values
+        .chunks(64)
+        .map(|chunk| batch::chunk(chunk.iter().copied(), is_bogon_v4_bits))
+        .collect();
+    boolean_array(words, arr)
+}
+
+/// Returns a boolean array indicating whether each of an array of IPv6 addresses, as 16 bytes in
+/// network byte order (big-endian, as on the wire), is bogus.
+///
+/// The nulls of the array are kept. Like [`is_bogon_v6_bits`], only the compiled-in tables are
+/// consulted: the overrides of the `test-util` and `runtime` features don't apply.
+///
+/// # Panics
+///
+/// Panics if the values of the array aren't 16 bytes long.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use arrow_array::{BooleanArray, FixedSizeBinaryArray};
+/// use bogon::is_bogon_v6_array;
+///
+/// let ips = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+///     [
+///         Some(Ipv6Addr::LOCALHOST.octets()),
+///         None,
+///         Some(Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 1).octets()),
+///     ]
+///     .into_iter(),
+///     16,
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     is_bogon_v6_array(&ips),
+///     BooleanArray::from(vec![Some(true), None, Some(false)])
+/// );
+/// ```
+pub fn is_bogon_v6_array(arr: &FixedSizeBinaryArray) -> BooleanArray {
+    assert_eq!(
+        arr.value_length(),
+        16,
+        "The values of an array of IPv6 addresses must be 16 bytes long"
+    );
+    let words = arr
+        .value_data()
+        .chunks(64 * 16)
+        .map(|chunk| {
+            let ips = chunk
+                .chunks_exact(16)
+                .map(|octets| u128::from_be_bytes(octets.try_into().unwrap()));
+            batch::chunk(ips, is_bogon_v6_bits)
+        })
+        .collect();
+    boolean_array(words, arr)
+}
+
+/// Returns a boolean array of the bits of a bitmap, with the nulls of the array it was computed
+/// from. Arrow bitmaps are little-endian, so the words are swapped on big-endian targets.
+fn boolean_array(mut words: Vec<u64>, arr: &dyn Array) -> BooleanArray {
+    for word in &mut words {
+        *word = word.to_le();
+    }
+    let values = BooleanBuffer::new(Buffer::from_vec(words), 0, arr.len());
+    BooleanArray::new(values, arr.nulls().cloned())
+}
@@ -0,0 +1,107 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+use std::vec::Vec;
+
+use arrow_array::{Array, BooleanArray, FixedSizeBinaryArray, UInt32Array};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{is_bogon_v4, is_bogon_v4_array, is_bogon_v6, is_bogon_v6_array};
+
+/// Returns random addresses, with every seventh one null.
+fn random_v4(rng: &mut StdRng, len: usize) -> Vec<Option<u32>> {
+    (0..len).map(|i| (i % 7 != 3).then(|| rng.gen())).collect()
+}
+
+/// Returns random addresses, half of them inside of 2000::/3, with every seventh one null.
+fn random_v6(rng: &mut StdRng, len: usize) -> Vec<Option<[u8; 16]>> {
+    (0..len)
+        .map(|i| {
+            let bits: u128 = rng.gen();
+            let bits = if rng.gen() {
+                bits >> 3 | 1 << 125
+            } else {
+                bits
+            };
+            (i % 7 != 3).then_some(bits.to_be_bytes())
+        })
+        .collect()
+}
+
+fn v6_array(ips: &[Option<[u8; 16]>]) -> FixedSizeBinaryArray {
+    FixedSizeBinaryArray::try_from_sparse_iter_with_size(ips.iter().copied(), 16).unwrap()
+}
+
+#[test]
+fn v4_matches_the_scalar_checks() {
+    let mut rng = StdRng::seed_from_u64(81);
+    for len in [1, 63, 64, 65, 1000, 4096] {
+        let ips = random_v4(&mut rng, len);
+        let expected = ips
+            .iter()
+            .map(|ip| ip.map(|bits| is_bogon_v4(Ipv4Addr::from_bits(bits))))
+            .collect::<BooleanArray>();
+        assert_eq!(
+            is_bogon_v4_array(&UInt32Array::from(ips)),
+            expected,
+            "{len}"
+        );
+    }
+}
+
+#[test]
+fn v6_matches_the_scalar_checks() {
+    let mut rng = StdRng::seed_from_u64(6);
+    for len in [1, 63, 64, 65, 1000, 4096] {
+        let ips = random_v6(&mut rng, len);
+        let expected = ips
+            .iter()
+            .map(|ip| ip.map(|octets| is_bogon_v6(Ipv6Addr::from(octets))))
+            .collect::<BooleanArray>();
+        assert_eq!(is_bogon_v6_array(&v6_array(&ips)), expected, "{len}");
+    }
+}
+
+#[test]
+fn nulls_are_kept() {
+    let ips = UInt32Array::from(vec![None, Some(0x0a00_0001), None, Some(0x0101_0101)]);
+    let verdicts = is_bogon_v4_array(&ips);
+    assert_eq!(verdicts.null_count(), 2);
+    assert!(verdicts.is_null(0) && verdicts.is_null(2));
+    assert!(verdicts.value(1));
+    assert!(!verdicts.value(3));
+
+    let ips = v6_array(&[None, Some(Ipv6Addr::LOCALHOST.octets()), None]);
+    let verdicts = is_bogon_v6_array(&ips);
+    assert_eq!(verdicts.null_count(), 2);
+    assert!(verdicts.value(1));
+}
+
+#[test]
+fn empty_arrays() {
+    assert!(is_bogon_v4_array(&UInt32Array::from(Vec::<u32>::new())).is_empty());
+    let ips = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+        core::iter::empty::<Option<[u8; 16]>>(),
+        16,
+    )
+    .unwrap();
+    assert!(is_bogon_v6_array(&ips).is_empty());
+}
+
+#[test]
+fn sliced_arrays() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let v4 = UInt32Array::from(random_v4(&mut rng, 300));
+    let v6 = v6_array(&random_v6(&mut rng, 300));
+    for (offset, len) in [(1, 100), (64, 64), (70, 200), (299, 1)] {
+        let verdicts = is_bogon_v4_array(&v4.slice(offset, len));
+        assert_eq!(verdicts, is_bogon_v4_array(&v4).slice(offset, len));
+        let verdicts = is_bogon_v6_array(&v6.slice(offset, len));
+        assert_eq!(verdicts, is_bogon_v6_array(&v6).slice(offset, len));
+    }
+}
+
+#[test]
+#[should_panic(expected = "must be 16 bytes long")]
+fn v6_wrong_width() {
+    let ips = FixedSizeBinaryArray::try_from_iter([[0u8; 4]].into_iter()).unwrap();
+    is_bogon_v6_array(&ips);
+}
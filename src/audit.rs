@@ -0,0 +1,229 @@
+//! Auditing external bogon lists against the compiled-in data.
+
+use core::{fmt, net::IpAddr};
+use std::vec::Vec;
+
+#[cfg(feature = "serde")]
+use crate::config::Network;
+use crate::{
+    ipv4_address_space::V4_REGISTRY_LEGACY, range_to_cidrs_v4, range_to_cidrs_v6, BogonTable,
+};
+
+/// What the compiled-in data says about a network of an external list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AuditStatus {
+    /// Every address of the network is still bogus.
+    StillBogon,
+    /// Every address of the network is routable space that a regional internet registry allocated
+    /// since, so the entry is stale.
+    NowAllocated,
+    /// Every address of the network is legacy IPv4 space, assigned before the regional internet
+    /// registries existed, so the network was never bogus in the first place.
+    NeverWasBogon,
+    /// Some addresses of the network are still bogus, others are routable.
+    PartiallyStale {
+        /// The routable parts of the network, as the fewest networks covering them.
+        routable: Vec<(IpAddr, u8)>,
+    },
+}
+
+impl AuditStatus {
+    /// Returns a boolean indicating whether the network is still bogus as a whole.
+    pub fn is_current(&self) -> bool {
+        matches!(self, AuditStatus::StillBogon)
+    }
+
+    /// Returns the kebab-case name of the status, for example `"still-bogon"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            AuditStatus::StillBogon => "still-bogon",
+            AuditStatus::NowAllocated => "now-allocated",
+            AuditStatus::NeverWasBogon => "never-was-bogon",
+            AuditStatus::PartiallyStale { .. } => "partially-stale",
+        }
+    }
+}
+
+/// A network of an external list and what the compiled-in data says about it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuditEntry {
+    /// The network as listed, host bits and all.
+    pub network: (IpAddr, u8),
+    /// What the compiled-in data says about the network.
+    pub status: AuditStatus,
+}
+
+/// The result of [`audit_external_list`], with an entry for every network of the list in order.
+///
+/// Displaying a report writes one line per network. With the `serde` feature the report
+/// serializes as a list of entries, with networks written as `address/prefix`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct AuditReport {
+    entries: Vec<AuditEntry>,
+}
+
+impl AuditReport {
+    /// Returns the entries of the report, in the order of the list.
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Returns the entries that aren't bogus as a whole anymore.
+    pub fn stale(&self) -> impl Iterator<Item = &AuditEntry> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.status.is_current())
+    }
+
+    /// Returns a boolean indicating whether every network of the list is still bogus.
+    pub fn is_current(&self) -> bool {
+        self.stale().next().is_none()
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (network, prefix_len) = self.network;
+        write!(f, "{network}/{prefix_len}: {}", self.status.name())?;
+        if let AuditStatus::PartiallyStale { routable } = &self.status {
+            f.write_str(", routable")?;
+            for (i, (network, prefix_len)) in routable.iter().enumerate() {
+                let separator = if i == 0 { " " } else { ", " };
+                write!(f, "{separator}{network}/{prefix_len}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the routable parts of a partially stale network.
+#[cfg(feature = "serde")]
+struct Routable<'a>(&'a [(IpAddr, u8)]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Routable<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.0
+                .iter()
+                .map(|&(network, prefix_len)| Network(network, prefix_len)),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AuditEntry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let routable = match &self.status {
+            AuditStatus::PartiallyStale { routable } => &routable[..],
+            _ => &[],
+        };
+        let mut entry = serializer.serialize_struct("AuditEntry", 3)?;
+        entry.serialize_field("network", &Network(self.network.0, self.network.1))?;
+        entry.serialize_field("status", self.status.name())?;
+        entry.serialize_field("routable", &Routable(routable))?;
+        entry.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AuditReport {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.entries)
+    }
+}
+
+/// Returns a boolean indicating whether every /8 overlapping a range is legacy space.
+fn is_legacy_v4(start: u32, end: u32) -> bool {
+    (start >> 24..=end >> 24).all(|octet| V4_REGISTRY_LEGACY[octet as usize])
+}
+
+/// Audits a list of bogus networks, such as an old firewall ACL, against the compiled-in data.
+///
+/// Every network is compared with [`BogonTable::with_defaults`]. Networks that are routable as a
+/// whole are [`NeverWasBogon`](AuditStatus::NeverWasBogon) when every /8 they overlap has the
+/// status `LEGACY` in the IANA IPv4 address space registry, and
+/// [`NowAllocated`](AuditStatus::NowAllocated) otherwise. The registry doesn't record when space
+/// was unallocated, so this is a heuristic: space allocated by a regional internet registry was
+/// reserved by IANA, and bogus, before.
+///
+/// # Panics
+///
+/// Panics if a prefix length is longer than the address.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{audit_external_list, AuditStatus};
+///
+/// let acl = [
+///     ("1.0.0.0".parse().unwrap(), 8),
+///     ("10.0.0.0".parse().unwrap(), 8),
+///     ("198.51.100.0".parse().unwrap(), 23),
+/// ];
+/// let report = audit_external_list(&acl);
+///
+/// assert_eq!(report.entries()[0].status, AuditStatus::NowAllocated);
+/// assert_eq!(report.entries()[1].status, AuditStatus::StillBogon);
+/// assert_eq!(
+///     report.to_string(),
+///     "\
+/// 1.0.0.0/8: now-allocated
+/// 10.0.0.0/8: still-bogon
+/// 198.51.100.0/23: partially-stale, routable 198.51.101.0/24
+/// "
+/// );
+/// ```
+pub fn audit_external_list(list: &[(IpAddr, u8)]) -> AuditReport {
+    let bogons = BogonTable::with_defaults();
+
+    let entries = list
+        .iter()
+        .map(|&network| {
+            let entry: BogonTable = [network].into_iter().collect();
+            let routable = entry.difference(&bogons);
+
+            let status = if routable.is_empty() {
+                AuditStatus::StillBogon
+            } else if routable == entry {
+                let legacy = routable
+                    .v4_ranges()
+                    .all(|(start, end)| is_legacy_v4(start.to_bits(), end.to_bits()))
+                    && routable.v6_ranges().next().is_none();
+                if legacy {
+                    AuditStatus::NeverWasBogon
+                } else {
+                    AuditStatus::NowAllocated
+                }
+            } else {
+                let v4 = routable
+                    .v4_ranges()
+                    .flat_map(|(start, end)| range_to_cidrs_v4(start, end))
+                    .map(|(network, prefix_len)| (IpAddr::V4(network), prefix_len));
+                let v6 = routable
+                    .v6_ranges()
+                    .flat_map(|(start, end)| range_to_cidrs_v6(start, end))
+                    .map(|(network, prefix_len)| (IpAddr::V6(network), prefix_len));
+                AuditStatus::PartiallyStale {
+                    routable: v4.chain(v6).collect(),
+                }
+            };
+
+            AuditEntry { network, status }
+        })
+        .collect();
+
+    AuditReport { entries }
+}
@@ -0,0 +1,139 @@
+use core::net::IpAddr;
+
+use crate::{audit_external_list, AuditReport, AuditStatus};
+
+fn audit(list: &[(&str, u8)]) -> AuditReport {
+    let list = list
+        .iter()
+        .map(|&(network, prefix_len)| (network.parse::<IpAddr>().unwrap(), prefix_len))
+        .collect::<Vec<_>>();
+    audit_external_list(&list)
+}
+
+fn statuses(report: &AuditReport) -> Vec<AuditStatus> {
+    report
+        .entries()
+        .iter()
+        .map(|entry| entry.status.clone())
+        .collect()
+}
+
+fn networks(networks: &[(&str, u8)]) -> Vec<(IpAddr, u8)> {
+    networks
+        .iter()
+        .map(|&(network, prefix_len)| (network.parse().unwrap(), prefix_len))
+        .collect()
+}
+
+#[test]
+fn unbogoned_and_private_networks() {
+    // 1.0.0.0/8 was reserved by IANA until it went to APNIC in 2010.
+    let report = audit(&[("1.0.0.0", 8), ("10.0.0.0", 8), ("192.168.0.0", 16)]);
+    assert_eq!(
+        statuses(&report),
+        [
+            AuditStatus::NowAllocated,
+            AuditStatus::StillBogon,
+            AuditStatus::StillBogon
+        ]
+    );
+    assert!(!report.is_current());
+    assert_eq!(report.stale().count(), 1);
+    assert_eq!(report.entries()[0].network, ("1.0.0.0".parse().unwrap(), 8));
+}
+
+#[test]
+fn legacy_space_never_was_bogus() {
+    // 17.0.0.0/8 was assigned directly, 3.0.0.0/8 is administered by ARIN today.
+    let report = audit(&[("17.0.0.0", 8), ("3.0.0.0", 8), ("3.0.0.0", 7)]);
+    assert_eq!(
+        statuses(&report),
+        [
+            AuditStatus::NeverWasBogon,
+            AuditStatus::NeverWasBogon,
+            // 2.0.0.0/8 is allocated to RIPE NCC.
+            AuditStatus::NowAllocated
+        ]
+    );
+}
+
+#[test]
+fn partially_stale_networks() {
+    let report = audit(&[("198.51.100.0", 23), ("8.0.0.0", 5)]);
+    assert_eq!(
+        statuses(&report),
+        [
+            AuditStatus::PartiallyStale {
+                routable: networks(&[("198.51.101.0", 24)])
+            },
+            AuditStatus::PartiallyStale {
+                routable: networks(&[("8.0.0.0", 7), ("11.0.0.0", 8), ("12.0.0.0", 6)])
+            },
+        ]
+    );
+}
+
+#[test]
+fn ipv6_networks() {
+    let report = audit(&[
+        ("2001:db8::", 32),
+        ("fc00::", 7),
+        ("2a00::", 12),
+        ("2001:db8::", 31),
+    ]);
+    assert_eq!(
+        statuses(&report),
+        [
+            AuditStatus::StillBogon,
+            AuditStatus::StillBogon,
+            AuditStatus::NowAllocated,
+            AuditStatus::PartiallyStale {
+                routable: networks(&[("2001:db9::", 32)])
+            },
+        ]
+    );
+}
+
+#[test]
+fn host_bits_are_kept() {
+    let report = audit(&[("10.1.2.3", 8)]);
+    assert_eq!(
+        report.entries()[0].network,
+        ("10.1.2.3".parse().unwrap(), 8)
+    );
+    assert_eq!(report.entries()[0].status, AuditStatus::StillBogon);
+}
+
+#[test]
+fn empty_list() {
+    let report = audit(&[]);
+    assert!(report.entries().is_empty());
+    assert!(report.is_current());
+    assert_eq!(report.to_string(), "");
+}
+
+#[test]
+fn display() {
+    let report = audit(&[("1.0.0.0", 8), ("17.0.0.0", 8), ("8.0.0.0", 5)]);
+    assert_eq!(
+        report.to_string(),
+        "\
+1.0.0.0/8: now-allocated
+17.0.0.0/8: never-was-bogon
+8.0.0.0/5: partially-stale, routable 8.0.0.0/7, 11.0.0.0/8, 12.0.0.0/6
+"
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+    let report = audit(&[("1.0.0.0", 8), ("198.51.100.0", 23)]);
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        serde_json::json!([
+            {"network": "1.0.0.0/8", "status": "now-allocated", "routable": []},
+            {"network": "198.51.100.0/23", "status": "partially-stale", "routable": ["198.51.101.0/24"]},
+        ])
+    );
+}
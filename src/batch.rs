@@ -0,0 +1,170 @@
+//! Checking slices of IP addresses at once, with the verdicts packed into bitmaps.
+//!
+//! With the `simd` feature, the addresses the first octet or hextet doesn't settle are compared
+//! against the networks and IPv6 allocation ranges eight at a time.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{is_bogon_v4, is_bogon_v6};
+
+/// Checks a slice of IPv4 addresses, setting one bit of `out` per address: bit `i % 64` of
+/// `out[i / 64]`, counting from the least significant bit, is set if `ips[i]` is bogus.
+///
+/// The verdicts are the ones of [`is_bogon_v4`], computed 64 addresses at a time and packed once per
+/// word.
+///
+/// The bits past the end of `ips` in the last word are cleared, and the words after it are left
+/// untouched.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than `ips.len().div_ceil(64)` words.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::is_bogon_v4_batch;
+///
+/// let ips = [
+///     Ipv4Addr::new(8, 8, 8, 8),
+///     Ipv4Addr::new(127, 0, 0, 1),
+///     Ipv4Addr::new(1, 1, 1, 1),
+///     Ipv4Addr::new(10, 0, 0, 1),
+/// ];
+/// let mut out = [0; 1];
+/// is_bogon_v4_batch(&ips, &mut out);
+/// assert_eq!(out, [0b1010]);
+/// ```
+pub fn is_bogon_v4_batch(ips: &[Ipv4Addr], out: &mut [u64]) {
+    check_len(ips.len(), out);
+    for (chunk, word) in ips.chunks(64).zip(out) {
+        *word = chunk_v4(chunk);
+    }
+}
+
+/// Checks a slice of IPv6 addresses, setting one bit of `out` per address: bit `i % 64` of
+/// `out[i / 64]`, counting from the least significant bit, is set if `ips[i]` is bogus.
+///
+/// The verdicts are the ones of [`is_bogon_v6`], computed 64 addresses at a time and packed once per
+/// word.
+///
+/// The bits past the end of `ips` in the last word are cleared, and the words after it are left
+/// untouched.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than `ips.len().div_ceil(64)` words.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::is_bogon_v6_batch;
+///
+/// let ips = [
+///     Ipv6Addr::LOCALHOST,
+///     Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111),
+///     Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+/// ];
+/// let mut out = [0; 1];
+/// is_bogon_v6_batch(&ips, &mut out);
+/// assert_eq!(out, [0b101]);
+/// ```
+pub fn is_bogon_v6_batch(ips: &[Ipv6Addr], out: &mut [u64]) {
+    check_len(ips.len(), out);
+    for (chunk, word) in ips.chunks(64).zip(out) {
+        *word = chunk_v6(chunk);
+    }
+}
+
+/// Returns a boolean for each of a slice of IPv4 addresses, indicating whether it is bogus.
+///
+/// Like [`is_bogon_v4_batch`], with the bitmap unpacked.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::is_bogon_v4_batch_vec;
+///
+/// let ips = [Ipv4Addr::new(8, 8, 8, 8), Ipv4Addr::new(127, 0, 0, 1)];
+/// assert_eq!(is_bogon_v4_batch_vec(&ips), [false, true]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn is_bogon_v4_batch_vec(ips: &[Ipv4Addr]) -> Vec<bool> {
+    let mut out = alloc::vec![0; ips.len().div_ceil(64)];
+    is_bogon_v4_batch(ips, &mut out);
+    unpack(&out, ips.len())
+}
+
+/// Returns a boolean for each of a slice of IPv6 addresses, indicating whether it is bogus.
+///
+/// Like [`is_bogon_v6_batch`], with the bitmap unpacked.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::is_bogon_v6_batch_vec;
+///
+/// let ips = [Ipv6Addr::LOCALHOST, Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 1)];
+/// assert_eq!(is_bogon_v6_batch_vec(&ips), [true, false]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn is_bogon_v6_batch_vec(ips: &[Ipv6Addr]) -> Vec<bool> {
+    let mut out = alloc::vec![0; ips.len().div_ceil(64)];
+    is_bogon_v6_batch(ips, &mut out);
+    unpack(&out, ips.len())
+}
+
+/// Panics if a bitmap is too short for `len` addresses.
+pub(crate) fn check_len(len: usize, out: &[u64]) {
+    assert!(
+        out.len() >= len.div_ceil(64),
+        "The bitmap must have a word for every 64 addresses, {} words is too short for {len} addresses",
+        out.len()
+    );
+}
+
+/// Returns the bits of a bitmap of `len` addresses as booleans.
+#[cfg(feature = "alloc")]
+fn unpack(words: &[u64], len: usize) -> Vec<bool> {
+    (0..len)
+        .map(|i| words[i / 64] >> (i % 64) & 1 == 1)
+        .collect()
+}
+
+/// Returns the verdicts of up to 64 IPv4 addresses, one bit each.
+fn chunk_v4(ips: &[Ipv4Addr]) -> u64 {
+    chunk(ips.iter().copied(), is_bogon_v4)
+}
+
+/// Returns the verdicts of up to 64 IPv6 addresses, one bit each.
+fn chunk_v6(ips: &[Ipv6Addr]) -> u64 {
+    chunk(ips.iter().copied(), is_bogon_v6)
+}
+
+/// Returns the verdicts of up to 64 addresses in any form, one bit each, the first one in the least
+/// significant bit.
+#[inline]
+pub(crate) fn chunk<T>(ips: impl IntoIterator<Item = T>, is_bogon: impl Fn(T) -> bool) -> u64 {
+    let mut lanes = [false; 64];
+    for (lane, ip) in lanes.iter_mut().zip(ips) {
+        *lane = is_bogon(ip);
+    }
+    pack(&lanes)
+}
+
+/// Packs 64 booleans into a word, the first one into the least significant bit. Packing the verdicts
+/// at the end of a chunk is several times faster than shifting each one into the word as it is
+/// computed, which chains every address of the chunk on the previous one.
+#[inline]
+fn pack(lanes: &[bool; 64]) -> u64 {
+    lanes
+        .iter()
+        .enumerate()
+        .fold(0, |word, (i, &lane)| word | u64::from(lane) << i)
+}
@@ -0,0 +1,198 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+use std::vec::Vec;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    is_bogon_v4, is_bogon_v4_batch, is_bogon_v6, is_bogon_v6_batch, V4_BOGON_EXCEPTIONS,
+    V4_BOGON_NETWORKS, V6_BOGON_EXCEPTIONS, V6_GLOBAL_BOGON_NETWORKS,
+};
+
+/// Returns the bits of a bitmap of `len` addresses.
+fn bits(words: &[u64], len: usize) -> Vec<bool> {
+    (0..len)
+        .map(|i| words[i / 64] >> (i % 64) & 1 == 1)
+        .collect()
+}
+
+fn random_v4(rng: &mut StdRng, len: usize) -> Vec<Ipv4Addr> {
+    (0..len).map(|_| Ipv4Addr::from_bits(rng.gen())).collect()
+}
+
+/// Half of the addresses are inside of 2000::/3, where most of the tables are.
+fn random_v6(rng: &mut StdRng, len: usize) -> Vec<Ipv6Addr> {
+    (0..len)
+        .map(|_| {
+            let bits: u128 = rng.gen();
+            let bits = if rng.gen() {
+                bits >> 3 | 1 << 125
+            } else {
+                bits
+            };
+            Ipv6Addr::from_bits(bits)
+        })
+        .collect()
+}
+
+#[test]
+fn bit_order() {
+    let ips = [
+        Ipv4Addr::new(127, 0, 0, 1),
+        Ipv4Addr::new(8, 8, 8, 8),
+        Ipv4Addr::new(8, 8, 4, 4),
+        Ipv4Addr::new(10, 0, 0, 1),
+    ];
+    let mut out = [0];
+    is_bogon_v4_batch(&ips, &mut out);
+    assert_eq!(out, [0b1001]);
+
+    // The 65th address is the least significant bit of the second word.
+    let mut ips = [Ipv4Addr::new(1, 1, 1, 1); 65];
+    ips[63] = Ipv4Addr::new(192, 168, 0, 1);
+    ips[64] = Ipv4Addr::new(0, 0, 0, 0);
+    let mut out = [0; 2];
+    is_bogon_v4_batch(&ips, &mut out);
+    assert_eq!(out, [1 << 63, 1]);
+}
+
+#[test]
+fn partial_words() {
+    let mut rng = StdRng::seed_from_u64(76);
+    for len in [0, 1, 63, 64, 65, 127, 128, 130] {
+        let v4 = random_v4(&mut rng, len);
+        let v6 = random_v6(&mut rng, len);
+        let words = len.div_ceil(64);
+
+        // Stale bits past the end of the input are cleared, and extra words are left alone.
+        let mut out = vec![u64::MAX; words + 1];
+        is_bogon_v4_batch(&v4, &mut out);
+        assert_eq!(
+            bits(&out, len),
+            v4.iter().map(|&ip| is_bogon_v4(ip)).collect::<Vec<_>>()
+        );
+        if len % 64 != 0 {
+            assert_eq!(out[words - 1] >> (len % 64), 0, "{len}");
+        }
+        assert_eq!(out[words], u64::MAX);
+
+        let mut out = vec![u64::MAX; words + 1];
+        is_bogon_v6_batch(&v6, &mut out);
+        assert_eq!(
+            bits(&out, len),
+            v6.iter().map(|&ip| is_bogon_v6(ip)).collect::<Vec<_>>()
+        );
+        if len % 64 != 0 {
+            assert_eq!(out[words - 1] >> (len % 64), 0, "{len}");
+        }
+        assert_eq!(out[words], u64::MAX);
+    }
+}
+
+#[test]
+#[should_panic(expected = "1 words is too short for 65 addresses")]
+fn short_bitmap() {
+    is_bogon_v4_batch(&[Ipv4Addr::LOCALHOST; 65], &mut [0; 1]);
+}
+
+#[test]
+#[should_panic(expected = "0 words is too short for 1 addresses")]
+fn empty_bitmap() {
+    is_bogon_v6_batch(&[Ipv6Addr::LOCALHOST], &mut []);
+}
+
+#[test]
+fn random_v4_matches_is_bogon_v4() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let ips = random_v4(&mut rng, 1 << 16);
+    let mut out = vec![0; ips.len() / 64];
+    is_bogon_v4_batch(&ips, &mut out);
+    for (ip, bogus) in ips.iter().zip(bits(&out, ips.len())) {
+        assert_eq!(bogus, is_bogon_v4(*ip), "{ip}");
+    }
+}
+
+#[test]
+fn random_v6_matches_is_bogon_v6() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let ips = random_v6(&mut rng, 1 << 16);
+    let mut out = vec![0; ips.len() / 64];
+    is_bogon_v6_batch(&ips, &mut out);
+    for (ip, bogus) in ips.iter().zip(bits(&out, ips.len())) {
+        assert_eq!(bogus, is_bogon_v6(*ip), "{ip}");
+    }
+}
+
+#[test]
+fn edges_match_the_free_functions() {
+    // Both sides of the bounds of every bogus network and exception, all in the same chunks.
+    let v4 = V4_BOGON_NETWORKS
+        .iter()
+        .chain(&V4_BOGON_EXCEPTIONS)
+        .flat_map(|network| [network.network(), network.network() | !network.mask()])
+        .flat_map(|edge| [edge.wrapping_sub(1), edge, edge.wrapping_add(1)])
+        .map(Ipv4Addr::from_bits)
+        .collect::<Vec<_>>();
+    let mut out = vec![0; v4.len().div_ceil(64)];
+    is_bogon_v4_batch(&v4, &mut out);
+    for (ip, bogus) in v4.iter().zip(bits(&out, v4.len())) {
+        assert_eq!(bogus, is_bogon_v4(*ip), "{ip}");
+    }
+
+    let v6 = V6_GLOBAL_BOGON_NETWORKS
+        .iter()
+        .chain(&V6_BOGON_EXCEPTIONS)
+        .flat_map(|network| {
+            let host = u128::MAX
+                .checked_shr(u32::from(network.prefix()))
+                .unwrap_or(0);
+            [network.network(), network.network() | host]
+        })
+        .flat_map(|edge| [edge.wrapping_sub(1), edge, edge.wrapping_add(1)])
+        .map(Ipv6Addr::from_bits)
+        .collect::<Vec<_>>();
+    let mut out = vec![0; v6.len().div_ceil(64)];
+    is_bogon_v6_batch(&v6, &mut out);
+    for (ip, bogus) in v6.iter().zip(bits(&out, v6.len())) {
+        assert_eq!(bogus, is_bogon_v6(*ip), "{ip}");
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn unpacked() {
+    use crate::{is_bogon_v4_batch_vec, is_bogon_v6_batch_vec};
+
+    let mut rng = StdRng::seed_from_u64(64);
+    let v4 = random_v4(&mut rng, 100);
+    assert_eq!(
+        is_bogon_v4_batch_vec(&v4),
+        v4.iter().map(|&ip| is_bogon_v4(ip)).collect::<Vec<_>>()
+    );
+    let v6 = random_v6(&mut rng, 100);
+    assert_eq!(
+        is_bogon_v6_batch_vec(&v6),
+        v6.iter().map(|&ip| is_bogon_v6(ip)).collect::<Vec<_>>()
+    );
+    assert!(is_bogon_v4_batch_vec(&[]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "test-util")]
+fn overrides_apply_to_batches() {
+    use core::net::IpAddr;
+
+    use crate::{is_bogon, testing::with_policy};
+
+    let documentation = Ipv4Addr::new(192, 0, 2, 1);
+    let ips = [
+        documentation,
+        Ipv4Addr::new(10, 0, 0, 1),
+        Ipv4Addr::new(8, 8, 8, 8),
+    ];
+    let mut out = [0];
+    with_policy(
+        move |ip: IpAddr| ip == IpAddr::V4(documentation) || !is_bogon(ip),
+        || is_bogon_v4_batch(&ips, &mut out),
+    );
+    assert_eq!(out, [0b010]);
+}
@@ -0,0 +1,102 @@
+//! Checking strings with an error that says what was wrong with them.
+
+use core::{fmt, net::IpAddr};
+
+use crate::{is_bogon, Verdict};
+
+/// The error returned by [`check_str`] when a string is not an IP address, with a guess at what it
+/// is instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseCheckError {
+    /// The string is not an IP address, nor anything else recognized.
+    InvalidAddress {
+        /// The length of the string in bytes.
+        input_len: usize,
+    },
+    /// The string is an IP address followed by a `/`, as in the CIDR notation of a network.
+    LooksLikeCidr,
+    /// The string is made of labels of letters, digits, and hyphens separated by dots, as in a
+    /// hostname that needs to be resolved first.
+    LooksLikeHostname,
+    /// The string is empty, or only whitespace.
+    EmptyInput,
+}
+
+impl fmt::Display for ParseCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCheckError::InvalidAddress { input_len } => {
+                write!(f, "the input of {input_len} bytes is not an IP address")
+            }
+            ParseCheckError::LooksLikeCidr => {
+                f.write_str("the input is a network in CIDR notation, not an IP address")
+            }
+            ParseCheckError::LooksLikeHostname => {
+                f.write_str("the input is a hostname, not an IP address")
+            }
+            ParseCheckError::EmptyInput => f.write_str("the input is empty"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCheckError {}
+
+/// Returns a boolean indicating whether a string is a hostname: labels of up to 63 letters,
+/// digits, and hyphens separated by dots, at most 253 bytes and with an optional trailing dot.
+/// The last label must hold a letter, so that numbers such as `127.1` aren't hostnames.
+fn is_hostname(s: &str) -> bool {
+    let name = s.strip_suffix('.').unwrap_or(s);
+    let valid_labels = name.split('.').all(|label| {
+        (1..=63).contains(&label.len())
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    });
+    let top_level = name.rsplit('.').next().unwrap_or(name);
+    name.len() <= 253 && valid_labels && top_level.bytes().any(|byte| byte.is_ascii_alphabetic())
+}
+
+/// Returns whether an IP address is fine or bogus, with an error that tells a network in CIDR
+/// notation, a hostname, and an empty string apart from other invalid input.
+///
+/// Unlike [`is_bogon_str`](crate::is_bogon_str), whose [`AddrParseError`](core::net::AddrParseError)
+/// carries no information, the error can tell a user what to do instead, such as resolving a
+/// hostname first.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{check_str, ParseCheckError, Verdict};
+///
+/// assert_eq!(check_str("127.0.0.1"), Ok(Verdict::Deny));
+/// assert_eq!(check_str("2606:4700:4700::1111"), Ok(Verdict::Allow));
+///
+/// assert_eq!(check_str("10.0.0.0/8"), Err(ParseCheckError::LooksLikeCidr));
+/// assert_eq!(check_str("example.com"), Err(ParseCheckError::LooksLikeHostname));
+/// assert_eq!(check_str(""), Err(ParseCheckError::EmptyInput));
+/// assert_eq!(check_str("1.2.3"), Err(ParseCheckError::InvalidAddress { input_len: 5 }));
+/// ```
+pub fn check_str(s: &str) -> Result<Verdict, ParseCheckError> {
+    if let Ok(ip) = s.parse::<IpAddr>() {
+        return Ok(if is_bogon(ip) {
+            Verdict::Deny
+        } else {
+            Verdict::Allow
+        });
+    }
+    if s.trim().is_empty() {
+        Err(ParseCheckError::EmptyInput)
+    } else if s
+        .split_once('/')
+        .is_some_and(|(ip, _)| ip.parse::<IpAddr>().is_ok())
+    {
+        Err(ParseCheckError::LooksLikeCidr)
+    } else if is_hostname(s) {
+        Err(ParseCheckError::LooksLikeHostname)
+    } else {
+        Err(ParseCheckError::InvalidAddress { input_len: s.len() })
+    }
+}
@@ -0,0 +1,140 @@
+use std::string::ToString;
+
+use crate::{check_str, is_bogon_str, ParseCheckError, Verdict};
+
+#[test]
+fn addresses() {
+    for ip in [
+        "127.0.0.1",
+        "8.8.8.8",
+        "::1",
+        "2606:4700:4700::1111",
+        "fe80::1",
+    ] {
+        let expected = if is_bogon_str(ip).unwrap() {
+            Verdict::Deny
+        } else {
+            Verdict::Allow
+        };
+        assert_eq!(check_str(ip), Ok(expected), "{ip}");
+    }
+}
+
+#[test]
+fn empty_input() {
+    for input in ["", " ", "\t\n"] {
+        assert_eq!(
+            check_str(input),
+            Err(ParseCheckError::EmptyInput),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn cidr() {
+    for input in [
+        "10.0.0.0/8",
+        "192.168.1.1/24",
+        "2001:db8::/32",
+        "::/0",
+        "10.0.0.0/",
+    ] {
+        assert_eq!(
+            check_str(input),
+            Err(ParseCheckError::LooksLikeCidr),
+            "{input}"
+        );
+    }
+    // Only an IP address before the `/` makes a network.
+    assert_eq!(
+        check_str("example.com/8"),
+        Err(ParseCheckError::InvalidAddress { input_len: 13 })
+    );
+}
+
+#[test]
+fn hostnames() {
+    for input in [
+        "example.com",
+        "example.com.",
+        "localhost",
+        "metadata.google.internal",
+        "xn--bcher-kva.example",
+        "10.0.0.1.nip.io",
+        "a-b.c",
+    ] {
+        assert_eq!(
+            check_str(input),
+            Err(ParseCheckError::LooksLikeHostname),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn invalid_addresses() {
+    for input in [
+        "1.2.3",
+        "127.1",
+        "256.0.0.1",
+        "1.2.3.4.5",
+        "::1::",
+        "127.0.0.1:80",
+        "-example.com",
+        "example-.com",
+        "example..com",
+        "exa mple.com",
+        "example.com:443",
+        "fe80::1%eth0",
+        " 127.0.0.1",
+    ] {
+        assert_eq!(
+            check_str(input),
+            Err(ParseCheckError::InvalidAddress {
+                input_len: input.len()
+            }),
+            "{input}"
+        );
+    }
+    let long = "a.".repeat(127) + "com";
+    assert_eq!(
+        check_str(&long),
+        Err(ParseCheckError::InvalidAddress {
+            input_len: long.len()
+        })
+    );
+    let label = "a".repeat(64) + ".com";
+    assert_eq!(
+        check_str(&label),
+        Err(ParseCheckError::InvalidAddress {
+            input_len: label.len()
+        })
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        ParseCheckError::InvalidAddress { input_len: 5 }.to_string(),
+        "the input of 5 bytes is not an IP address"
+    );
+    assert_eq!(
+        ParseCheckError::LooksLikeCidr.to_string(),
+        "the input is a network in CIDR notation, not an IP address"
+    );
+    assert_eq!(
+        ParseCheckError::LooksLikeHostname.to_string(),
+        "the input is a hostname, not an IP address"
+    );
+    assert_eq!(
+        ParseCheckError::EmptyInput.to_string(),
+        "the input is empty"
+    );
+}
+
+#[test]
+fn is_an_error() {
+    let error: &dyn core::error::Error = &ParseCheckError::EmptyInput;
+    assert!(error.source().is_none());
+}
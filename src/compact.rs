@@ -0,0 +1,98 @@
+//! Searching the IPv6 allocation ranges encoded as deltas in LEB128, for the `compact-data` feature.
+//!
+//! `bogon_data::V6_ALLOCATION_BOUNDARIES` holds the bounds of the ranges in order, where each range
+//! starts and the /32 after it, so the top 32 bits of an IP address are inside of a range when an
+//! odd number of bounds are at most them. With the standard library the bounds are decoded into
+//! the same ranges as the uncompressed table the first time they're searched. Without it, the
+//! first bound of each block is binary searched, and only the one block that can hold the IP
+//! address is decoded.
+
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+static V6_ALLOCATION_BOUNDARIES: [u8; bogon_data::V6_ALLOCATION_BOUNDARIES.len()] =
+    bogon_data::V6_ALLOCATION_BOUNDARIES;
+static V6_ALLOCATION_BLOCKS: [u16; bogon_data::V6_ALLOCATION_BLOCKS.len()] =
+    bogon_data::V6_ALLOCATION_BLOCKS;
+/// The trailing zeros shifted out of the bounds.
+const SHIFT: u32 = bogon_data::V6_ALLOCATION_BOUNDARY_SHIFT;
+
+/// Returns the unsigned LEB128 value at an offset, and the offset after it.
+#[inline]
+fn read_leb128(mut offset: usize) -> (u32, usize) {
+    let (mut value, mut shift) = (0, 0);
+    loop {
+        let byte = V6_ALLOCATION_BOUNDARIES[offset];
+        offset += 1;
+        value |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return (value, offset);
+        }
+        shift += 7;
+    }
+}
+
+/// Returns the bounds of a block in order, still shifted right by [`SHIFT`].
+fn block(index: usize) -> impl Iterator<Item = u32> {
+    let end = V6_ALLOCATION_BLOCKS
+        .get(index + 1)
+        .map_or(V6_ALLOCATION_BOUNDARIES.len(), |&end| end.into());
+    let mut offset = usize::from(V6_ALLOCATION_BLOCKS[index]);
+    // The first bound of a block is a difference to zero.
+    let mut bound = 0;
+    core::iter::from_fn(move || {
+        (offset < end).then(|| {
+            let (delta, next) = read_leb128(offset);
+            offset = next;
+            bound += delta;
+            bound
+        })
+    })
+}
+
+/// Returns a boolean indicating whether the top 32 bits of an IPv6 address are inside of one of
+/// the ranges, searching the encoded bounds without decoding all of them.
+#[cfg(any(test, not(feature = "std")))]
+pub(crate) fn search(top: u32) -> bool {
+    let top = top >> SHIFT;
+    // The block holding the last bound at most `top`, which starts with the start of a range.
+    let blocks =
+        V6_ALLOCATION_BLOCKS.partition_point(|&offset| read_leb128(offset.into()).0 <= top);
+    let Some(index) = blocks.checked_sub(1) else {
+        return false;
+    };
+    block(index).take_while(|&bound| bound <= top).count() % 2 == 1
+}
+
+/// Returns the ranges the bounds encode, decoding them the first time it's called.
+#[cfg(feature = "std")]
+pub(crate) fn ranges() -> &'static [(u32, u32)] {
+    static RANGES: OnceLock<Vec<(u32, u32)>> = OnceLock::new();
+    RANGES.get_or_init(|| {
+        let bounds = (0..V6_ALLOCATION_BLOCKS.len())
+            .flat_map(block)
+            .map(|bound| bound << SHIFT)
+            .collect::<Vec<_>>();
+        // A range reaching `u32::MAX` has no end.
+        bounds
+            .chunks(2)
+            .map(|range| (range[0], range.get(1).map_or(u32::MAX, |&end| end - 1)))
+            .collect()
+    })
+}
+
+/// Returns a boolean indicating whether the top 32 bits of an IPv6 address are inside of one of
+/// the ranges.
+#[inline]
+pub(crate) fn is_allocated(top: u32) -> bool {
+    #[cfg(feature = "std")]
+    {
+        let ranges = ranges();
+        let index = ranges.partition_point(|&(_, last)| last < top);
+        ranges.get(index).is_some_and(|&(first, _)| first <= top)
+    }
+    #[cfg(not(feature = "std"))]
+    search(top)
+}
@@ -0,0 +1,48 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    compact::{is_allocated, search},
+    ipv6_unicast_address_allocations::V6_ALLOCATION_RANGES,
+};
+
+/// Returns a boolean indicating whether the top 32 bits are inside of one of the ranges of the
+/// uncompressed table.
+fn uncompressed(top: u32) -> bool {
+    V6_ALLOCATION_RANGES
+        .iter()
+        .any(|&(first, last)| (first..=last).contains(&top))
+}
+
+#[test]
+fn bounds_match_the_uncompressed_table() {
+    let edges = V6_ALLOCATION_RANGES
+        .iter()
+        .flat_map(|&(first, last)| [first, last])
+        .flat_map(|bound| [bound.wrapping_sub(1), bound, bound.wrapping_add(1)])
+        .chain([0, 1, 0x2000_0000, 0x3fff_ffff, u32::MAX]);
+    for top in edges {
+        assert_eq!(search(top), uncompressed(top), "{top:#010x}");
+        assert_eq!(is_allocated(top), uncompressed(top), "{top:#010x}");
+    }
+}
+
+#[test]
+fn random_addresses_match_the_uncompressed_table() {
+    let mut rng = StdRng::seed_from_u64(84);
+    for _ in 0..1_000_000 {
+        // Mostly inside of 2000::/3, where the ranges are.
+        let top = if rng.gen_ratio(7, 8) {
+            rng.gen::<u32>() >> 3 | 1 << 29
+        } else {
+            rng.gen()
+        };
+        assert_eq!(search(top), uncompressed(top), "{top:#010x}");
+        assert_eq!(is_allocated(top), uncompressed(top), "{top:#010x}");
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn decoded_ranges_are_the_uncompressed_table() {
+    assert_eq!(crate::compact::ranges(), V6_ALLOCATION_RANGES);
+}
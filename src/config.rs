@@ -0,0 +1,203 @@
+use core::{fmt, net::IpAddr};
+
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{network::parse_network, BogonFilter, BogonFilterBuilder, BogonKind};
+
+const FIELDS: &[&str] = &["allow", "extra_deny", "treat_mapped_as_v4"];
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+    Allow,
+    ExtraDeny,
+    TreatMappedAsV4,
+}
+
+/// A network written as `address/prefix`, or a bare address.
+pub(crate) struct Network(pub(crate) IpAddr, pub(crate) u8);
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+
+impl Serialize for Network {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Network {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NetworkVisitor;
+
+        impl Visitor<'_> for NetworkVisitor {
+            type Value = Network;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an IP address or a network like `203.0.113.0/24`")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Network, E> {
+                let (address, prefix_len) =
+                    parse_network(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))?;
+                Ok(Network(address, prefix_len))
+            }
+        }
+
+        deserializer.deserialize_str(NetworkVisitor)
+    }
+}
+
+/// Serializes the classifications a filter allows.
+struct Allowed<'a>(&'a BogonFilter);
+
+impl Serialize for Allowed<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(BogonKind::ALL.iter().filter(|&&kind| self.0.allows(kind)))
+    }
+}
+
+/// Serializes the extra networks a filter denies.
+struct Denied<'a>(&'a BogonFilter);
+
+impl Serialize for Denied<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.0
+                .denied_networks()
+                .map(|(network, prefix_len)| Network(network, prefix_len)),
+        )
+    }
+}
+
+/// Deserializes a list of classifications into a builder.
+struct Allow<'a>(&'a mut BogonFilterBuilder);
+
+impl<'de> DeserializeSeed<'de> for Allow<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Allow<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of classifications")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(kind) = seq.next_element::<BogonKind>()? {
+            *self.0 = self.0.allow(kind);
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes a list of networks into a builder.
+struct ExtraDeny<'a>(&'a mut BogonFilterBuilder);
+
+impl<'de> DeserializeSeed<'de> for ExtraDeny<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ExtraDeny<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a list of at most {} networks",
+            BogonFilter::MAX_DENIED_NETWORKS
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut len = self.0.build().denied_networks().count();
+        while let Some(Network(network, prefix_len)) = seq.next_element()? {
+            len += 1;
+            if len > BogonFilter::MAX_DENIED_NETWORKS {
+                return Err(de::Error::invalid_length(len, &self));
+            }
+            *self.0 = self.0.deny_network(network, prefix_len);
+        }
+        Ok(())
+    }
+}
+
+/// Serializes as a struct with the fields `allow`, `extra_deny`, and `treat_mapped_as_v4`.
+impl Serialize for BogonFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("BogonFilter", FIELDS.len())?;
+        state.serialize_field("allow", &Allowed(self))?;
+        state.serialize_field("extra_deny", &Denied(self))?;
+        state.serialize_field("treat_mapped_as_v4", &self.canonicalizes_mapped())?;
+        state.end()
+    }
+}
+
+/// Deserializes from a struct with the fields `allow`, `extra_deny`, and `treat_mapped_as_v4`,
+/// missing fields keep their default.
+impl<'de> Deserialize<'de> for BogonFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FilterVisitor;
+
+        impl<'de> Visitor<'de> for FilterVisitor {
+            type Value = BogonFilter;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a bogon filter")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<BogonFilter, A::Error> {
+                let mut builder = BogonFilter::default_rules();
+                let mut seen = [false; FIELDS.len()];
+
+                while let Some(field) = map.next_key::<Field>()? {
+                    let index = field as usize;
+                    if seen[index] {
+                        return Err(de::Error::duplicate_field(FIELDS[index]));
+                    }
+                    seen[index] = true;
+
+                    match field {
+                        Field::Allow => map.next_value_seed(Allow(&mut builder))?,
+                        Field::ExtraDeny => map.next_value_seed(ExtraDeny(&mut builder))?,
+                        Field::TreatMappedAsV4 => {
+                            builder = builder.canonicalize_mapped(map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(builder.build())
+            }
+        }
+
+        deserializer.deserialize_struct("BogonFilter", FIELDS, FilterVisitor)
+    }
+}
+
+impl Serialize for BogonFilterBuilder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.build().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BogonFilterBuilder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        BogonFilter::deserialize(deserializer).map(BogonFilter::to_builder)
+    }
+}
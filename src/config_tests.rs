@@ -0,0 +1,149 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{BogonFilter, BogonFilterBuilder, BogonKind, BogonProfile};
+
+fn from_json(json: &str) -> Result<BogonFilter, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Asserts that two filters agree on random addresses and a few interesting ones.
+fn assert_same_verdicts(a: &BogonFilter, b: &BogonFilter) {
+    let interesting = [
+        "10.0.0.1",
+        "100.64.0.1",
+        "203.0.113.1",
+        "::ffff:203.0.113.1",
+        "::ffff:8.8.8.8",
+        "2001:db8::1",
+        "8.8.8.8",
+    ]
+    .map(|ip| ip.parse::<IpAddr>().unwrap());
+
+    let random = (0..10_000).flat_map(|_| {
+        [
+            IpAddr::V4(Ipv4Addr::from_bits(rand::random())),
+            IpAddr::V6(Ipv6Addr::from_bits(rand::random())),
+        ]
+    });
+
+    for ip in interesting.into_iter().chain(random) {
+        assert_eq!(a.is_bogon(ip), b.is_bogon(ip), "{ip}");
+        assert_eq!(a.classify(ip), b.classify(ip), "{ip}");
+    }
+}
+
+#[test]
+fn deserialize_config() {
+    let filter = from_json(
+        r#"{
+            "allow": ["shared-address-space"],
+            "extra_deny": ["203.0.113.0/24", "2001:db8:1::/48", "192.0.31.7"],
+            "treat_mapped_as_v4": true
+        }"#,
+    )
+    .unwrap();
+
+    let expected = BogonFilter::default_rules()
+        .allow(BogonKind::SharedAddressSpace)
+        .deny_network(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 0)), 24)
+        .deny_network("2001:db8:1::".parse().unwrap(), 48)
+        .deny_network(IpAddr::V4(Ipv4Addr::new(192, 0, 31, 7)), 32)
+        .canonicalize_mapped(true)
+        .build();
+    assert_eq!(filter, expected);
+
+    assert!(!filter.is_bogon("100.64.0.1".parse().unwrap()));
+    assert!(filter.is_bogon("10.0.0.1".parse().unwrap()));
+    assert!(filter.is_bogon("192.0.31.7".parse().unwrap()));
+    assert!(!filter.is_bogon("::ffff:8.8.8.8".parse().unwrap()));
+    assert!(filter.is_bogon("::ffff:10.0.0.1".parse().unwrap()));
+}
+
+#[test]
+fn deserialize_defaults() {
+    assert_eq!(from_json("{}").unwrap(), BogonFilter::default());
+    assert_eq!(
+        serde_json::from_str::<BogonFilterBuilder>("{}")
+            .unwrap()
+            .build(),
+        BogonFilter::default()
+    );
+}
+
+#[test]
+fn deserialize_errors() {
+    let error = from_json(r#"{ "allow": ["shared-adress-space"] }"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("`shared-adress-space`"), "{error}");
+    assert!(error.contains("`shared-address-space`"), "{error}");
+    assert!(error.contains("`private-use`"), "{error}");
+
+    let error = from_json(r#"{ "extra_deny": ["203.0.113.0/33"] }"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("203.0.113.0/33"), "{error}");
+
+    let error = from_json(r#"{ "extra_deny": ["not an address"] }"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("not an address"), "{error}");
+
+    let error = from_json(r#"{ "denied": [] }"#).unwrap_err().to_string();
+    assert!(error.contains("`denied`"), "{error}");
+    assert!(error.contains("`extra_deny`"), "{error}");
+
+    let error = from_json(r#"{ "allow": [], "allow": [] }"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("duplicate field `allow`"), "{error}");
+
+    let too_many = (0..=BogonFilter::MAX_DENIED_NETWORKS)
+        .map(|i| format!("\"192.0.2.{i}\""))
+        .collect::<Vec<_>>()
+        .join(",");
+    assert!(from_json(&format!(r#"{{ "extra_deny": [{too_many}] }}"#)).is_err());
+}
+
+#[test]
+fn kind_names() {
+    for kind in BogonKind::ALL {
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(serde_json::from_str::<BogonKind>(&json).unwrap(), kind);
+    }
+
+    assert_eq!(
+        serde_json::to_string(&BogonKind::SharedAddressSpace).unwrap(),
+        r#""shared-address-space""#
+    );
+    assert_eq!(
+        serde_json::to_string(&BogonProfile::Loose).unwrap(),
+        r#""loose""#
+    );
+}
+
+#[test]
+fn round_trip() {
+    let filters = [
+        BogonFilter::default(),
+        BogonProfile::Loose.filter(),
+        BogonFilter::default_rules()
+            .allow(BogonKind::ReservedFutureUse)
+            .allow(BogonKind::Benchmarking)
+            .deny_network(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 0)), 24)
+            .deny_network("2606:4700::".parse().unwrap(), 32)
+            .canonicalize_mapped(true)
+            .build(),
+    ];
+
+    for filter in filters {
+        let json = serde_json::to_string(&filter).unwrap();
+        let parsed = from_json(&json).unwrap();
+        assert_eq!(parsed, filter, "{json}");
+        assert_same_verdicts(&parsed, &filter);
+
+        let builder: BogonFilterBuilder =
+            serde_json::from_str(&serde_json::to_string(&filter.to_builder()).unwrap()).unwrap();
+        assert_eq!(builder.build(), filter);
+    }
+}
@@ -0,0 +1,381 @@
+//! Converting the tables of the `bogon-data` crate to the types the lookups use.
+//!
+//! `bogon-data` only contains plain data, so that refreshing it doesn't depend on the types of this
+//! crate. The conversions run at compile time, and fail the build if `bogon-data` names a registry
+//! or classification this crate doesn't know. The lookup tables derived from the data are built
+//! here too, so they can't drift from it.
+
+use crate::{
+    network::{FourByteNetwork, SixteenByteNetwork},
+    special::SpecialPurposeBlock,
+    BogonKind, Rir,
+};
+
+/// The registries `bogon-data` names, by the names IANA uses.
+const RIRS: [Rir; 6] = [
+    Rir::Afrinic,
+    Rir::Apnic,
+    Rir::Arin,
+    Rir::Lacnic,
+    Rir::RipeNcc,
+    Rir::Legacy,
+];
+
+/// The classifications `bogon-data` names, by the names of their variants.
+pub(crate) const KINDS: [(&str, BogonKind); 25] = [
+    ("ThisNetwork", BogonKind::ThisNetwork),
+    ("PrivateUse", BogonKind::PrivateUse),
+    ("SharedAddressSpace", BogonKind::SharedAddressSpace),
+    ("Loopback", BogonKind::Loopback),
+    ("LinkLocal", BogonKind::LinkLocal),
+    ("ProtocolAssignments", BogonKind::ProtocolAssignments),
+    ("Documentation", BogonKind::Documentation),
+    ("Benchmarking", BogonKind::Benchmarking),
+    ("Multicast", BogonKind::Multicast),
+    ("Reserved", BogonKind::Reserved),
+    ("Broadcast", BogonKind::Broadcast),
+    ("UniqueLocal", BogonKind::UniqueLocal),
+    ("Unallocated", BogonKind::Unallocated),
+    ("Unspecified", BogonKind::Unspecified),
+    ("DiscardOnly", BogonKind::DiscardOnly),
+    ("CloudMetadata", BogonKind::CloudMetadata),
+    ("SixToFourRelayAnycast", BogonKind::SixToFourRelayAnycast),
+    ("SegmentRouting", BogonKind::SegmentRouting),
+    ("Teredo", BogonKind::Teredo),
+    ("OrchidV2", BogonKind::OrchidV2),
+    ("Nat64WellKnown", BogonKind::Nat64WellKnown),
+    ("Nat64LocalUse", BogonKind::Nat64LocalUse),
+    ("Ipv4Compatible", BogonKind::Ipv4Compatible),
+    ("ReservedFutureUse", BogonKind::ReservedFutureUse),
+    ("Ipv4Mapped", BogonKind::Ipv4Mapped),
+];
+
+/// What the first octet of an IPv4 address, or the first hextet of an IPv6 address, says about
+/// whether the address is bogus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Bucket {
+    /// None of the bogus networks overlap the /8 or /16.
+    Routable,
+    /// A bogus network covers the whole /8 or /16, and none of the exceptions are inside of it.
+    Bogon,
+    /// The bogus networks or their exceptions cover part of the /8 or /16, so the address needs the
+    /// full check.
+    Mixed,
+}
+
+/// Returns the bucket of the first hextet `hextet` from the table `bogon-data` packs two bits per
+/// /16 into, starting with the least significant bits of the first word.
+#[inline]
+pub(crate) const fn first_hextet(words: &[u64; 2048], hextet: u16) -> Bucket {
+    let hextet = hextet as usize;
+    match (words[hextet >> 5] >> ((hextet & 31) * 2)) & 0b11 {
+        0 => Bucket::Routable,
+        1 => Bucket::Bogon,
+        _ => Bucket::Mixed,
+    }
+}
+
+/// Returns a boolean indicating whether two strings are equal, which `==` can't do in a const fn.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the registry IANA names `name`.
+const fn rir(name: &str) -> Rir {
+    let mut i = 0;
+    while i < RIRS.len() {
+        if str_eq(RIRS[i].name(), name) {
+            return RIRS[i];
+        }
+        i += 1;
+    }
+    panic!("bogon-data names an unknown registry")
+}
+
+/// Returns the classification with the variant name `name`.
+const fn kind(name: &str) -> BogonKind {
+    let mut i = 0;
+    while i < KINDS.len() {
+        if str_eq(KINDS[i].0, name) {
+            return KINDS[i].1;
+        }
+        i += 1;
+    }
+    panic!("bogon-data names an unknown classification")
+}
+
+/// Converts a table of networks with at most 32-bit prefixes.
+pub(crate) const fn four_byte_networks<const N: usize>(
+    networks: [(u32, u8); N],
+) -> [FourByteNetwork; N] {
+    let mut converted = [FourByteNetwork::new(0, 0); N];
+    let mut i = 0;
+    while i < N {
+        converted[i] = FourByteNetwork::new(networks[i].0, networks[i].1);
+        i += 1;
+    }
+    converted
+}
+
+/// Converts a table of IPv6 networks.
+pub(crate) const fn sixteen_byte_networks<const N: usize>(
+    networks: [(u128, u8); N],
+) -> [SixteenByteNetwork; N] {
+    let mut converted = [SixteenByteNetwork::new(0, 0); N];
+    let mut i = 0;
+    while i < N {
+        converted[i] = SixteenByteNetwork::new(networks[i].0, networks[i].1);
+        i += 1;
+    }
+    converted
+}
+
+/// Converts a table of registry names.
+pub(crate) const fn rirs<const N: usize>(names: [&str; N]) -> [Rir; N] {
+    let mut converted = [Rir::Legacy; N];
+    let mut i = 0;
+    while i < N {
+        converted[i] = rir(names[i]);
+        i += 1;
+    }
+    converted
+}
+
+/// Converts a table of registry names, some of which may be missing.
+pub(crate) const fn optional_rirs<const N: usize>(names: [Option<&str>; N]) -> [Option<Rir>; N] {
+    let mut converted = [None; N];
+    let mut i = 0;
+    while i < N {
+        if let Some(name) = names[i] {
+            converted[i] = Some(rir(name));
+        }
+        i += 1;
+    }
+    converted
+}
+
+/// Converts a table of classification variant names.
+pub(crate) const fn kinds<const N: usize>(names: [&str; N]) -> [BogonKind; N] {
+    let mut converted = [BogonKind::Reserved; N];
+    let mut i = 0;
+    while i < N {
+        converted[i] = kind(names[i]);
+        i += 1;
+    }
+    converted
+}
+
+/// Converts the blocks of the IPv6 special-purpose registry and their networks.
+pub(crate) const fn special_purpose_blocks<const N: usize>(
+    networks: [(u128, u8); N],
+    blocks: [(&'static str, [Option<bool>; 4], bool); N],
+) -> [SpecialPurposeBlock; N] {
+    let mut converted = [SpecialPurposeBlock::new(0, 0, "", [None; 4], false); N];
+    let mut i = 0;
+    while i < N {
+        let (network, prefix_len) = networks[i];
+        let (name, flags, deprecated) = blocks[i];
+        converted[i] = SpecialPurposeBlock::new(network, prefix_len, name, flags, deprecated);
+        i += 1;
+    }
+    converted
+}
+
+/// Returns what each first octet says about whether an IPv4 address is bogus, given the bogus
+/// networks and the globally reachable exceptions inside of them.
+pub(crate) const fn first_octets<const N: usize, const M: usize>(
+    networks: [(u32, u8); N],
+    exceptions: [(u32, u8); M],
+) -> [Bucket; 256] {
+    let mut octets = [Bucket::Routable; 256];
+    let mut octet = 0;
+    while octet < 256 {
+        let (first, last) = ((octet as u32) << 24, (octet as u32) << 24 | 0x00ff_ffff);
+        let mut i = 0;
+        while i < N {
+            let (network, prefix_len) = networks[i];
+            let broadcast = match u32::MAX.checked_shr(prefix_len as u32) {
+                Some(host) => network | host,
+                None => network,
+            };
+            if network <= first && broadcast >= last {
+                octets[octet] = Bucket::Bogon;
+                break;
+            } else if network <= last && broadcast >= first {
+                octets[octet] = Bucket::Mixed;
+            }
+            i += 1;
+        }
+
+        // An exception punches a hole into an otherwise bogus /8.
+        let mut i = 0;
+        while i < M {
+            if matches!(octets[octet], Bucket::Bogon) && exceptions[i].0 >> 24 == octet as u32 {
+                octets[octet] = Bucket::Mixed;
+            }
+            i += 1;
+        }
+        octet += 1;
+    }
+    octets
+}
+
+/// Returns a boolean indicating whether a network overlaps the /8 of an octet.
+const fn overlaps_octet((network, prefix_len): (u32, u8), octet: usize) -> bool {
+    let (first, last) = ((octet as u32) << 24, (octet as u32) << 24 | 0x00ff_ffff);
+    let broadcast = match u32::MAX.checked_shr(prefix_len as u32) {
+        Some(host) => network | host,
+        None => network,
+    };
+    network <= last && broadcast >= first
+}
+
+/// Returns the number of /8s the first octet doesn't settle, and the most bogus networks any of
+/// them overlaps: the number and width of the rows [`mixed_octets`] lays out.
+pub(crate) const fn mixed_shape<const N: usize>(
+    networks: [(u32, u8); N],
+    octets: &[Bucket; 256],
+) -> (usize, usize) {
+    let (mut rows, mut width) = (0, 0);
+    let mut octet = 0;
+    while octet < 256 {
+        if matches!(octets[octet], Bucket::Mixed) {
+            let mut overlapping = 0;
+            let mut i = 0;
+            while i < N {
+                overlapping += overlaps_octet(networks[i], octet) as usize;
+                i += 1;
+            }
+            rows += 1;
+            if overlapping > width {
+                width = overlapping;
+            }
+        }
+        octet += 1;
+    }
+    (rows, width)
+}
+
+/// Lays out the bogus networks overlapping each /8 the first octet doesn't settle in a row of its
+/// own, clipped to the /8, so that only those are scanned. The rows are padded with networks that
+/// never match to the same width, so that a row can be scanned without branches. Returns the rows,
+/// and the row of each first octet.
+///
+/// # Panics
+///
+/// Panics if `R` and `W` aren't the shape [`mixed_shape`] returns.
+pub(crate) const fn mixed_octets<const N: usize, const R: usize, const W: usize>(
+    networks: [(u32, u8); N],
+    octets: &[Bucket; 256],
+) -> ([[FourByteNetwork; W]; R], [u8; 256]) {
+    // A network of `u32::MAX` under an empty mask never matches.
+    let mut rows = [[FourByteNetwork::new(u32::MAX, 0); W]; R];
+    let mut index = [0; 256];
+    let mut row = 0;
+    let mut octet = 0;
+    while octet < 256 {
+        if matches!(octets[octet], Bucket::Mixed) {
+            assert!(row < R, "the shape must be `mixed_shape`");
+            let mut column = 0;
+            let mut i = 0;
+            while i < N {
+                if overlaps_octet(networks[i], octet) {
+                    let (network, prefix_len) = networks[i];
+                    rows[row][column] = if prefix_len < 8 {
+                        FourByteNetwork::new((octet as u32) << 24, 8)
+                    } else {
+                        FourByteNetwork::new(network, prefix_len)
+                    };
+                    column += 1;
+                }
+                i += 1;
+            }
+            index[octet] = row as u8;
+            row += 1;
+        }
+        octet += 1;
+    }
+    assert!(row == R, "the shape must be `mixed_shape`");
+    (rows, index)
+}
+
+/// Returns a boolean indicating whether the network `outer` contains the network `inner`.
+const fn contains_network((outer, outer_len): (u32, u8), (inner, inner_len): (u32, u8)) -> bool {
+    let mask = match u32::MAX.checked_shl(32 - outer_len as u32) {
+        Some(mask) => mask,
+        None => 0,
+    };
+    outer_len <= inner_len && inner & mask == outer & mask
+}
+
+/// Splits the bogus networks around the globally reachable exceptions inside of them, into the
+/// fewest networks covering the rest of their addresses, each with the index of the network it was
+/// split from. The networks of each bogus network are in order, and the bogus networks keep their
+/// order. Returns the networks, padded to `L`, and their number.
+///
+/// # Panics
+///
+/// Panics if `L` is too small for the networks.
+pub(crate) const fn split_networks<const N: usize, const M: usize, const L: usize>(
+    networks: [(u32, u8); N],
+    exceptions: [(u32, u8); M],
+) -> ([(u32, u8, usize); L], usize) {
+    let mut split = [(0, 0, 0); L];
+    let mut len = 0;
+    let mut i = 0;
+    while i < N {
+        // The halves left to split, the lower half on top so that the networks stay in order.
+        let mut stack = [(0, 0); 33];
+        stack[0] = networks[i];
+        let mut depth = 1;
+        while depth > 0 {
+            depth -= 1;
+            let network = stack[depth];
+            let (mut excepted, mut holed) = (false, false);
+            let mut j = 0;
+            while j < M {
+                excepted |= contains_network(exceptions[j], network);
+                holed |= contains_network(network, exceptions[j]);
+                j += 1;
+            }
+            if excepted {
+                continue;
+            } else if holed {
+                let (address, prefix_len) = network;
+                stack[depth] = (address | 1 << (31 - prefix_len as u32), prefix_len + 1);
+                stack[depth + 1] = (address, prefix_len + 1);
+                depth += 2;
+            } else {
+                assert!(len < L, "`L` must hold the split networks");
+                split[len] = (network.0, network.1, i);
+                len += 1;
+            }
+        }
+        i += 1;
+    }
+    (split, len)
+}
+
+/// Returns the first `K` networks [`split_networks`] returns.
+pub(crate) const fn truncated<const L: usize, const K: usize>(
+    split: [(u32, u8, usize); L],
+) -> [(u32, u8, usize); K] {
+    let mut truncated = [(0, 0, 0); K];
+    let mut i = 0;
+    while i < K {
+        truncated[i] = split[i];
+        i += 1;
+    }
+    truncated
+}
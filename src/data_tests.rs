@@ -0,0 +1,336 @@
+use core::net::{IpAddr, Ipv6Addr};
+use std::format;
+
+use crate::{
+    data::{first_hextet, first_octets, mixed_octets, mixed_shape, split_networks, Bucket, KINDS},
+    ipv4_address_space::{
+        V4_REGISTRY_LEGACY, V4_REGISTRY_RDAP, V4_REGISTRY_RIRS, V4_REGISTRY_WHOIS,
+    },
+    ipv4_special_registry::{
+        V4_BOGON_EXCEPTIONS, V4_BOGON_KINDS, V4_BOGON_NETWORKS, V4_FIRST_OCTETS, V4_MIXED_INDEX,
+        V4_MIXED_ROWS,
+    },
+    ipv6_special_registry::{
+        V6_BOGON_EXCEPTIONS, V6_GLOBAL_BOGON_KINDS, V6_GLOBAL_BOGON_NETWORKS, V6_SPECIAL_REGISTRY,
+        V6_SPECIAL_REGISTRY_NETWORKS,
+    },
+    ipv6_unicast_address_allocations::{
+        V6_ALLOCATION_RANGES, V6_FIRST_HEXTETS, V6_REGISTRY_DATES, V6_REGISTRY_PREFIXES,
+        V6_REGISTRY_RDAP, V6_REGISTRY_RIRS, V6_REGISTRY_WHOIS, V6_UNALLOCATED, V6_WIDE_ALLOCATIONS,
+    },
+    network::{FourByteNetwork, SixteenByteNetwork},
+    registry_strings::{RDAP_URLS, WHOIS_SERVERS},
+    BogonKind, DATASET_DATE, DATASET_SHA256,
+};
+
+fn four_byte(networks: &[FourByteNetwork]) -> Vec<(u32, u8)> {
+    networks
+        .iter()
+        .map(|network| (network.network(), network.prefix()))
+        .collect()
+}
+
+fn sixteen_byte(networks: &[SixteenByteNetwork]) -> Vec<(u128, u8)> {
+    networks
+        .iter()
+        .map(|network| (network.network(), network.prefix()))
+        .collect()
+}
+
+fn kind_names(kinds: &[BogonKind]) -> Vec<String> {
+    kinds.iter().map(|kind| format!("{kind:?}")).collect()
+}
+
+#[test]
+fn kind_names_match_the_variants() {
+    assert_eq!(KINDS.len(), BogonKind::ALL.len());
+    for ((name, kind), expected) in KINDS.into_iter().zip(BogonKind::ALL) {
+        assert_eq!(kind, expected);
+        assert_eq!(name, format!("{kind:?}"));
+    }
+}
+
+#[test]
+fn dataset_is_reexported() {
+    assert_eq!(DATASET_SHA256, bogon_data::DATASET_SHA256);
+    assert_eq!(DATASET_DATE, bogon_data::DATASET_DATE);
+}
+
+#[test]
+fn ipv4_tables_match_bogon_data() {
+    let rirs = V4_REGISTRY_RIRS.map(|rir| rir.map(|rir| rir.name()));
+    assert_eq!(rirs, bogon_data::V4_REGISTRY_RIRS);
+    assert_eq!(V4_REGISTRY_LEGACY, bogon_data::V4_REGISTRY_LEGACY);
+    assert_eq!(V4_REGISTRY_RDAP, bogon_data::V4_REGISTRY_RDAP);
+    assert_eq!(V4_REGISTRY_WHOIS, bogon_data::V4_REGISTRY_WHOIS);
+
+    assert_eq!(four_byte(&V4_BOGON_NETWORKS), bogon_data::V4_BOGON_NETWORKS);
+    assert_eq!(kind_names(&V4_BOGON_KINDS), bogon_data::V4_BOGON_KINDS);
+    assert_eq!(
+        four_byte(&V4_BOGON_EXCEPTIONS),
+        bogon_data::V4_BOGON_EXCEPTIONS
+    );
+}
+
+#[test]
+fn ipv6_tables_match_bogon_data() {
+    assert_eq!(V6_ALLOCATION_RANGES, bogon_data::V6_ALLOCATION_RANGES);
+    assert_eq!(
+        sixteen_byte(&V6_WIDE_ALLOCATIONS),
+        bogon_data::V6_WIDE_ALLOCATIONS
+    );
+    assert_eq!(sixteen_byte(&V6_UNALLOCATED), bogon_data::V6_UNALLOCATED);
+    assert_eq!(
+        sixteen_byte(&V6_REGISTRY_PREFIXES),
+        bogon_data::V6_REGISTRY_PREFIXES
+    );
+    let rirs = V6_REGISTRY_RIRS.map(|rir| rir.name());
+    assert_eq!(rirs, bogon_data::V6_REGISTRY_RIRS);
+    assert_eq!(V6_REGISTRY_DATES, bogon_data::V6_REGISTRY_DATES);
+    assert_eq!(V6_REGISTRY_RDAP, bogon_data::V6_REGISTRY_RDAP);
+    assert_eq!(V6_REGISTRY_WHOIS, bogon_data::V6_REGISTRY_WHOIS);
+
+    assert_eq!(
+        sixteen_byte(&V6_GLOBAL_BOGON_NETWORKS),
+        bogon_data::V6_GLOBAL_BOGON_NETWORKS
+    );
+    assert_eq!(
+        kind_names(&V6_GLOBAL_BOGON_KINDS),
+        bogon_data::V6_GLOBAL_BOGON_KINDS
+    );
+    assert_eq!(
+        sixteen_byte(&V6_BOGON_EXCEPTIONS),
+        bogon_data::V6_BOGON_EXCEPTIONS
+    );
+
+    assert_eq!(RDAP_URLS, bogon_data::RDAP_URLS);
+    assert_eq!(WHOIS_SERVERS, bogon_data::WHOIS_SERVERS);
+}
+
+#[test]
+fn special_registry_matches_bogon_data() {
+    assert_eq!(
+        sixteen_byte(&V6_SPECIAL_REGISTRY_NETWORKS),
+        bogon_data::V6_SPECIAL_REGISTRY_NETWORKS
+    );
+    let blocks = V6_SPECIAL_REGISTRY
+        .iter()
+        .zip(bogon_data::V6_SPECIAL_REGISTRY);
+    let networks = bogon_data::V6_SPECIAL_REGISTRY_NETWORKS;
+    for ((block, (name, flags, deprecated)), (network, prefix_len)) in blocks.zip(networks) {
+        let network = (IpAddr::V6(Ipv6Addr::from_bits(network)), prefix_len);
+        assert_eq!(block.network, network);
+        assert_eq!(block.name, name);
+        let converted = [
+            block.source,
+            block.destination,
+            block.forwardable,
+            block.globally_reachable,
+        ];
+        assert_eq!(converted, flags, "{name}");
+        assert_eq!(block.deprecated, deprecated, "{name}");
+    }
+    assert_eq!(
+        V6_SPECIAL_REGISTRY.len(),
+        bogon_data::V6_SPECIAL_REGISTRY.len()
+    );
+}
+
+#[test]
+fn first_octets_of_the_bundled_rules() {
+    use Bucket::{Bogon, Mixed, Routable};
+
+    let cgnat = if cfg!(feature = "allow-cgnat") {
+        Routable
+    } else {
+        Mixed
+    };
+    let reserved = if cfg!(feature = "allow-reserved-240-4") {
+        Routable
+    } else {
+        Bogon
+    };
+    let broadcast = if cfg!(feature = "allow-reserved-240-4") {
+        Mixed
+    } else {
+        Bogon
+    };
+    let mut expected = [Routable; 256];
+    for octet in [0, 10, 127].into_iter().chain(224..=239) {
+        expected[octet] = Bogon;
+    }
+    for octet in [169, 172, 192, 198, 203] {
+        expected[octet] = Mixed;
+    }
+    expected[100] = cgnat;
+    expected[240..255].fill(reserved);
+    expected[255] = broadcast;
+    assert_eq!(V4_FIRST_OCTETS, expected);
+}
+
+#[test]
+fn first_octets_of_partial_networks() {
+    let octets = first_octets([(0x0a00_0000, 8), (0xc0a8_0000, 16), (0xe000_0000, 3)], []);
+    assert_eq!(octets[10], Bucket::Bogon);
+    assert_eq!(octets[11], Bucket::Routable);
+    assert_eq!(octets[192], Bucket::Mixed);
+    assert!(octets[224..].iter().all(|&octet| octet == Bucket::Bogon));
+    assert_eq!(octets[223], Bucket::Routable);
+
+    // Single addresses, and the whole address space.
+    let octets = first_octets([(0xffff_ffff, 32)], []);
+    assert_eq!(octets[255], Bucket::Mixed);
+    assert_eq!(octets[254], Bucket::Routable);
+    assert_eq!(first_octets([(0, 0)], []), [Bucket::Bogon; 256]);
+}
+
+#[test]
+fn mixed_rows_hold_the_networks_of_their_octet() {
+    let never = FourByteNetwork::new(u32::MAX, 0);
+    for (octet, bucket) in V4_FIRST_OCTETS.into_iter().enumerate() {
+        if bucket != Bucket::Mixed {
+            continue;
+        }
+        let row = V4_MIXED_ROWS[usize::from(V4_MIXED_INDEX[octet])];
+        let (first, last) = ((octet as u32) << 24, (octet as u32) << 24 | 0x00ff_ffff);
+        let overlapping = V4_BOGON_NETWORKS
+            .iter()
+            .filter(|network| {
+                network.network() <= last && (network.network() | !network.mask()) >= first
+            })
+            .map(|&network| {
+                // Clipped to the /8.
+                if network.prefix() < 8 {
+                    FourByteNetwork::new(first, 8)
+                } else {
+                    network
+                }
+            })
+            .collect::<Vec<_>>();
+        let padding = row.len() - overlapping.len();
+        assert_eq!(row[..overlapping.len()], overlapping, "{octet}");
+        assert_eq!(row[overlapping.len()..], vec![never; padding], "{octet}");
+    }
+}
+
+#[test]
+fn mixed_rows_of_partial_networks() {
+    let networks = [
+        (0x0a00_0000, 8),
+        (0xc000_0000, 24),
+        (0xc0a8_0000, 16),
+        (0xe000_0000, 3),
+    ];
+    let exceptions = [(0x0a00_0001, 32)];
+    let octets = first_octets(networks, exceptions);
+    assert_eq!(mixed_shape(networks, &octets), (2, 2));
+    let (rows, index) = mixed_octets::<4, 2, 2>(networks, &octets);
+    // 10.0.0.0/8 is mixed by its exception.
+    assert_eq!(
+        rows[usize::from(index[10])],
+        [
+            FourByteNetwork::new(0x0a00_0000, 8),
+            FourByteNetwork::new(u32::MAX, 0)
+        ]
+    );
+    assert_eq!(
+        rows[usize::from(index[192])],
+        [
+            FourByteNetwork::new(0xc000_0000, 24),
+            FourByteNetwork::new(0xc0a8_0000, 16)
+        ]
+    );
+
+    // A network shorter than a /8 is clipped to the /8.
+    let networks = [(0xe000_0000, 3)];
+    let exceptions = [(0xe000_0001, 32)];
+    let octets = first_octets(networks, exceptions);
+    assert_eq!(mixed_shape(networks, &octets), (1, 1));
+    let (rows, index) = mixed_octets::<1, 1, 1>(networks, &octets);
+    assert_eq!(
+        rows[usize::from(index[224])],
+        [FourByteNetwork::new(0xe000_0000, 8)]
+    );
+}
+
+#[test]
+fn first_octets_with_exceptions() {
+    // A globally reachable exception leaves only part of the /8 bogus.
+    let octets = first_octets([(0x0a00_0000, 8), (0x7f00_0000, 8)], [(0x0a00_0001, 32)]);
+    assert_eq!(octets[10], Bucket::Mixed);
+    assert_eq!(octets[127], Bucket::Bogon);
+}
+
+#[test]
+fn first_hextets_decode() {
+    let mut words = [0; 2048];
+    words[0] = 0b1001;
+    words[1] = 0b10 << 62;
+    words[2047] = 0b01 << 62;
+    assert_eq!(first_hextet(&words, 0x0000), Bucket::Bogon);
+    assert_eq!(first_hextet(&words, 0x0001), Bucket::Mixed);
+    assert_eq!(first_hextet(&words, 0x0002), Bucket::Routable);
+    assert_eq!(first_hextet(&words, 0x003f), Bucket::Mixed);
+    assert_eq!(first_hextet(&words, 0xfffe), Bucket::Routable);
+    assert_eq!(first_hextet(&words, 0xffff), Bucket::Bogon);
+}
+
+#[test]
+fn first_hextets_of_the_bundled_tables() {
+    let bucket = |hextet| first_hextet(&V6_FIRST_HEXTETS, hextet);
+    assert_eq!(bucket(0x0000), Bucket::Bogon);
+    assert_eq!(bucket(0xfe80), Bucket::Bogon);
+    assert_eq!(bucket(0x2001), Bucket::Mixed);
+    assert_eq!(bucket(0x2600), Bucket::Routable);
+    assert_eq!(bucket(0x3fff), Bucket::Bogon);
+}
+
+#[test]
+fn split_networks_around_exceptions() {
+    // No exceptions, or exceptions outside of the networks, leave the networks as they are.
+    let (split, len) = split_networks::<2, 1, 4>([(0x0a00_0000, 8), (0xe000_0000, 4)], [(0, 32)]);
+    assert_eq!(split[..len], [(0x0a00_0000, 8, 0), (0xe000_0000, 4, 1)]);
+
+    // One address cut out of a /30 leaves a /32 and a /31, in order.
+    let (split, len) = split_networks::<1, 1, 4>([(0xc000_0000, 30)], [(0xc000_0001, 32)]);
+    assert_eq!(split[..len], [(0xc000_0000, 32, 0), (0xc000_0002, 31, 0)]);
+
+    // The exceptions of `192.0.0.0/24`.
+    let (split, len) =
+        split_networks::<1, 2, 16>([(0xc000_0000, 24)], [(0xc000_0009, 32), (0xc000_000a, 32)]);
+    assert_eq!(
+        split[..len],
+        [
+            (0xc000_0000, 29, 0),
+            (0xc000_0008, 32, 0),
+            (0xc000_000b, 32, 0),
+            (0xc000_000c, 30, 0),
+            (0xc000_0010, 28, 0),
+            (0xc000_0020, 27, 0),
+            (0xc000_0040, 26, 0),
+            (0xc000_0080, 25, 0),
+        ]
+    );
+
+    // A network inside of an exception is dropped, and the whole space splits too.
+    let (split, len) = split_networks::<2, 1, 8>([(0x0a00_0000, 16), (0, 0)], [(0x0a00_0000, 8)]);
+    assert_eq!(
+        split[..len],
+        [
+            (0, 5, 1),
+            (0x0800_0000, 7, 1),
+            (0x0b00_0000, 8, 1),
+            (0x0c00_0000, 6, 1),
+            (0x1000_0000, 4, 1),
+            (0x2000_0000, 3, 1),
+            (0x4000_0000, 2, 1),
+            (0x8000_0000, 1, 1),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "`L` must hold the split networks")]
+fn split_networks_capacity() {
+    split_networks::<1, 1, 2>([(0xc000_0000, 24)], [(0xc000_0009, 32)]);
+}
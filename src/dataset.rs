@@ -0,0 +1,48 @@
+use core::fmt;
+
+/// The snapshot of the IANA registries a binary embeds.
+///
+/// Returned by [`dataset_info`]. The [`Display`](fmt::Display) implementation is a one-liner
+/// suitable for `--version` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DatasetInfo {
+    /// The date the registries were downloaded, as `YYYY-MM-DD`, see
+    /// [`DATASET_DATE`](crate::DATASET_DATE).
+    pub date: &'static str,
+    /// The number of bogus IPv4 networks, without the ones the `allow-*` features remove.
+    pub v4_rules: usize,
+    /// The number of IPv6 allocation ranges, after merging adjacent allocations.
+    pub v6_ranges: usize,
+    /// The SHA-256 digest of the registries, as lowercase hexadecimal, see
+    /// [`DATASET_SHA256`](crate::DATASET_SHA256).
+    pub sha256: &'static str,
+}
+
+impl fmt::Display for DatasetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IANA registries of {}, {} IPv4 rules, {} IPv6 allocation ranges, sha256 {}",
+            self.date, self.v4_rules, self.v6_ranges, self.sha256
+        )
+    }
+}
+
+/// Returns the snapshot of the IANA registries the compiled-in data was generated from.
+///
+/// # Examples
+///
+/// ```
+/// let info = bogon::dataset_info();
+/// assert_eq!(info.sha256, bogon::DATASET_SHA256);
+/// println!("my-tool 1.0.0 ({info})");
+/// ```
+pub const fn dataset_info() -> DatasetInfo {
+    DatasetInfo {
+        date: bogon_data::DATASET_DATE,
+        v4_rules: bogon_data::DATASET_V4_RULES,
+        v6_ranges: bogon_data::DATASET_V6_RANGES,
+        sha256: bogon_data::DATASET_SHA256,
+    }
+}
@@ -0,0 +1,42 @@
+use std::format;
+
+use crate::{
+    dataset_info,
+    ipv4_special_registry::V4_BOGON_NETWORKS,
+    ipv6_unicast_address_allocations::{V6_ALLOCATION_RANGES, V6_WIDE_ALLOCATIONS},
+    DATASET_DATE, DATASET_SHA256,
+};
+
+#[test]
+fn counts_match_the_tables() {
+    let info = dataset_info();
+    assert_eq!(info.v4_rules, V4_BOGON_NETWORKS.len());
+    assert_eq!(
+        info.v6_ranges,
+        V6_ALLOCATION_RANGES.len() + V6_WIDE_ALLOCATIONS.len()
+    );
+}
+
+#[test]
+fn identifies_the_snapshot() {
+    let info = dataset_info();
+    assert_eq!(info.date, DATASET_DATE);
+    assert_eq!(info.sha256, DATASET_SHA256);
+}
+
+#[test]
+fn display_is_one_line() {
+    let info = dataset_info();
+    let line = format!("{info}");
+    assert!(!line.contains('\n'));
+    assert_eq!(
+        line,
+        format!(
+            "IANA registries of {}, {} IPv4 rules, {} IPv6 allocation ranges, sha256 {}",
+            DATASET_DATE,
+            V4_BOGON_NETWORKS.len(),
+            V6_ALLOCATION_RANGES.len() + V6_WIDE_ALLOCATIONS.len(),
+            DATASET_SHA256
+        )
+    );
+}
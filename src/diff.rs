@@ -0,0 +1,127 @@
+//! Comparing two versions of bogon data.
+
+use core::{fmt, net::IpAddr};
+
+use crate::{range_to_cidrs_v4, range_to_cidrs_v6, BogonTable};
+
+/// The changes between two [`BogonTable`]s, returned by [`diff_tables`].
+///
+/// Displaying a diff writes a summary of the address counts, followed by the networks that became
+/// bogus, prefixed with `+`, and the networks that became routable, prefixed with `-`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableDiff {
+    became_bogus: BogonTable,
+    became_routable: BogonTable,
+    unchanged: BogonTable,
+}
+
+impl TableDiff {
+    /// Returns the addresses the new table flags, but the old one doesn't.
+    pub fn became_bogus(&self) -> &BogonTable {
+        &self.became_bogus
+    }
+
+    /// Returns the addresses the old table flags, but the new one doesn't.
+    pub fn became_routable(&self) -> &BogonTable {
+        &self.became_routable
+    }
+
+    /// Returns the addresses both tables flag.
+    pub fn unchanged(&self) -> &BogonTable {
+        &self.unchanged
+    }
+
+    /// Returns a boolean indicating whether both tables flag the same addresses.
+    pub fn is_empty(&self) -> bool {
+        self.became_bogus.is_empty() && self.became_routable.is_empty()
+    }
+}
+
+/// Returns the number of IPv4 and IPv6 addresses of a table, the IPv6 count saturating at
+/// `u128::MAX`.
+fn address_counts(table: &BogonTable) -> (u64, u128) {
+    let v4 = table
+        .v4_ranges()
+        .map(|(start, end)| u64::from(end.to_bits() - start.to_bits()) + 1)
+        .sum();
+    let v6 = table.v6_ranges().fold(0u128, |count, (start, end)| {
+        count
+            .saturating_add(end.to_bits() - start.to_bits())
+            .saturating_add(1)
+    });
+    (v4, v6)
+}
+
+/// Writes the networks of a table, one per line, each prefixed with a sign.
+fn write_networks(f: &mut fmt::Formatter<'_>, sign: char, table: &BogonTable) -> fmt::Result {
+    let v4 = table
+        .v4_ranges()
+        .flat_map(|(start, end)| range_to_cidrs_v4(start, end))
+        .map(|(network, prefix_len)| (IpAddr::V4(network), prefix_len));
+    let v6 = table
+        .v6_ranges()
+        .flat_map(|(start, end)| range_to_cidrs_v6(start, end))
+        .map(|(network, prefix_len)| (IpAddr::V6(network), prefix_len));
+    for (network, prefix_len) in v4.chain(v6) {
+        writeln!(f, "{sign} {network}/{prefix_len}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for TableDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bogus_v4, bogus_v6) = address_counts(&self.became_bogus);
+        let (routable_v4, routable_v6) = address_counts(&self.became_routable);
+        let (unchanged_v4, unchanged_v6) = address_counts(&self.unchanged);
+        writeln!(
+            f,
+            "IPv4: {bogus_v4} addresses became bogus, {routable_v4} became routable, {unchanged_v4} unchanged"
+        )?;
+        writeln!(
+            f,
+            "IPv6: {bogus_v6} addresses became bogus, {routable_v6} became routable, {unchanged_v6} unchanged"
+        )?;
+        write_networks(f, '+', &self.became_bogus)?;
+        write_networks(f, '-', &self.became_routable)
+    }
+}
+
+/// Compares an old and a new version of a table.
+///
+/// Combined with [`parse_iana_v6_csv`](crate::parse_iana_v6_csv) and
+/// [`build_v6_table`](crate::build_v6_table), this shows what a newer snapshot of the registry
+/// changes compared to the bundled one.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{diff_tables, BogonTable};
+///
+/// let old: BogonTable = [("10.0.0.0".parse().unwrap(), 8)].into_iter().collect();
+/// let new: BogonTable = [
+///     ("10.0.0.0".parse().unwrap(), 9),
+///     ("192.0.2.0".parse().unwrap(), 24),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let diff = diff_tables(&old, &new);
+/// assert!(diff.became_routable().contains("10.200.0.1".parse().unwrap()));
+/// assert!(diff.became_bogus().contains("192.0.2.1".parse().unwrap()));
+/// assert_eq!(
+///     diff.to_string(),
+///     "\
+/// IPv4: 256 addresses became bogus, 8388608 became routable, 8388608 unchanged
+/// IPv6: 0 addresses became bogus, 0 became routable, 0 unchanged
+/// + 192.0.2.0/24
+/// - 10.128.0.0/9
+/// "
+/// );
+/// ```
+pub fn diff_tables(old: &BogonTable, new: &BogonTable) -> TableDiff {
+    TableDiff {
+        became_bogus: new.difference(old),
+        became_routable: old.difference(new),
+        unchanged: old.intersection(new),
+    }
+}
@@ -0,0 +1,122 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{diff_tables, BogonTable};
+
+fn table(networks: &[(&str, u8)]) -> BogonTable {
+    networks
+        .iter()
+        .map(|&(network, prefix_len)| (network.parse::<IpAddr>().unwrap(), prefix_len))
+        .collect()
+}
+
+fn v4_ranges(table: &BogonTable) -> Vec<(Ipv4Addr, Ipv4Addr)> {
+    table.v4_ranges().collect()
+}
+
+fn v4(start: &str, end: &str) -> (Ipv4Addr, Ipv4Addr) {
+    (start.parse().unwrap(), end.parse().unwrap())
+}
+
+#[test]
+fn diff_added_and_removed() {
+    let old = table(&[("10.0.0.0", 8), ("192.168.0.0", 16)]);
+    let new = table(&[("10.0.0.0", 8), ("198.18.0.0", 15)]);
+    let diff = diff_tables(&old, &new);
+
+    assert_eq!(
+        v4_ranges(diff.became_bogus()),
+        [v4("198.18.0.0", "198.19.255.255")]
+    );
+    assert_eq!(
+        v4_ranges(diff.became_routable()),
+        [v4("192.168.0.0", "192.168.255.255")]
+    );
+    assert_eq!(
+        v4_ranges(diff.unchanged()),
+        [v4("10.0.0.0", "10.255.255.255")]
+    );
+    assert!(!diff.is_empty());
+}
+
+#[test]
+fn diff_shrunk_range() {
+    // The range shrinks from both ends instead of disappearing.
+    let old = table(&[("192.0.2.0", 24)]);
+    let new = table(&[("192.0.2.64", 26), ("192.0.2.128", 26)]);
+    let diff = diff_tables(&old, &new);
+
+    assert_eq!(diff.became_bogus(), &BogonTable::new());
+    assert_eq!(
+        v4_ranges(diff.became_routable()),
+        [
+            v4("192.0.2.0", "192.0.2.63"),
+            v4("192.0.2.192", "192.0.2.255")
+        ]
+    );
+    assert_eq!(
+        v4_ranges(diff.unchanged()),
+        [v4("192.0.2.64", "192.0.2.191")]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "\
+IPv4: 0 addresses became bogus, 128 became routable, 128 unchanged
+IPv6: 0 addresses became bogus, 0 became routable, 0 unchanged
+- 192.0.2.0/26
+- 192.0.2.192/26
+"
+    );
+}
+
+#[test]
+fn diff_grown_ipv6_range() {
+    let old = table(&[("2001:db8::", 33)]);
+    let new = table(&[("2001:db8::", 32), ("fc00::", 7)]);
+    let diff = diff_tables(&old, &new);
+
+    let v6 = |start: &str, end: &str| {
+        (
+            start.parse::<Ipv6Addr>().unwrap(),
+            end.parse::<Ipv6Addr>().unwrap(),
+        )
+    };
+    assert_eq!(
+        diff.became_bogus().v6_ranges().collect::<Vec<_>>(),
+        [
+            v6("2001:db8:8000::", "2001:db8:ffff:ffff:ffff:ffff:ffff:ffff"),
+            v6("fc00::", "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff"),
+        ]
+    );
+    assert_eq!(diff.became_routable(), &BogonTable::new());
+    assert_eq!(diff.unchanged(), &old);
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "\
+IPv4: 0 addresses became bogus, 0 became routable, 0 unchanged
+IPv6: {} addresses became bogus, 0 became routable, {} unchanged
++ 2001:db8:8000::/33
++ fc00::/7
+",
+            (1u128 << 95) + (1u128 << 121),
+            1u128 << 95
+        )
+    );
+}
+
+#[test]
+fn diff_identical() {
+    let old = table(&[("10.0.0.0", 8), ("fc00::", 7)]);
+    let diff = diff_tables(&old, &old.clone());
+    assert!(diff.is_empty());
+    assert_eq!(diff.unchanged(), &old);
+
+    // Every address is counted, up to the saturated IPv6 count.
+    let everything = table(&[("0.0.0.0", 0), ("::", 0)]);
+    let diff = diff_tables(&everything, &everything);
+    assert!(diff.to_string().starts_with(&format!(
+        "IPv4: 0 addresses became bogus, 0 became routable, 4294967296 unchanged\n\
+         IPv6: 0 addresses became bogus, 0 became routable, {} unchanged\n",
+        u128::MAX
+    )));
+}
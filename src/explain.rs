@@ -0,0 +1,154 @@
+use core::{fmt, net::IpAddr};
+
+use crate::{registry, BogonKind, RoutableNote};
+
+/// Everything this crate knows about an IP address.
+///
+/// Returned by [`explain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Explanation {
+    /// The IP address being explained.
+    pub ip: IpAddr,
+    /// Whether the IP address is bogus.
+    pub is_bogon: bool,
+    /// The network that decided the verdict.
+    ///
+    /// For classified addresses this is the most specific classified network containing the
+    /// address, and for noted addresses the noted network. For other good IPv6 addresses this is
+    /// the IANA allocation block containing the address. Other good IPv4 addresses have no
+    /// matching network.
+    pub network: Option<(IpAddr, u8)>,
+    /// The reason the IP address is bogus.
+    pub kind: Option<BogonKind>,
+    /// The note on a good IP address, see [`annotate`](crate::annotate).
+    pub note: Option<RoutableNote>,
+    /// The registry the IANA allocation block was designated to (for example `"ARIN"`), only
+    /// available for good IPv6 addresses.
+    pub designation: Option<&'static str>,
+}
+
+/// Returns a structured verdict explaining whether an IP address is bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::explain;
+///
+/// let loopback = explain("127.0.0.1".parse().unwrap());
+/// assert!(loopback.is_bogon);
+/// assert_eq!(loopback.to_string(), "127.0.0.1 is bogus: Loopback (127.0.0.0/8, RFC 1122)");
+///
+/// let google = explain("8.8.8.8".parse().unwrap());
+/// assert!(!google.is_bogon);
+/// assert_eq!(google.to_string(), "8.8.8.8 is not bogus");
+///
+/// let cloudflare = explain("2606:4700::1111".parse().unwrap());
+/// assert_eq!(cloudflare.designation, Some("ARIN"));
+/// assert_eq!(cloudflare.to_string(), "2606:4700::1111 is not bogus: allocated to ARIN (2600::/12)");
+///
+/// let as112 = explain("192.175.48.1".parse().unwrap());
+/// assert!(!as112.is_bogon);
+/// assert_eq!(as112.to_string(), "192.175.48.1 is not bogus: AS112 Direct Delegation (192.175.48.0/24, RFC 7534)");
+/// ```
+pub fn explain(ip_address: IpAddr) -> Explanation {
+    let matched = match ip_address {
+        IpAddr::V4(ip) => crate::matched_v4(ip),
+        IpAddr::V6(ip) => crate::matched_v6(ip),
+    };
+    let (network, kind, note) = match matched {
+        Some((network, kind)) => (Some(network), Some(kind), None),
+        None => match crate::matched_routable(ip_address) {
+            Some((network, note)) => (Some(network), None, Some(note)),
+            None => (None, None, None),
+        },
+    };
+    let (network, designation) = match ip_address {
+        IpAddr::V6(ip) if kind.is_none() => (
+            network.or_else(|| {
+                registry::covering_allocation_v6(ip)
+                    .map(|(network, prefix)| (IpAddr::V6(network), prefix))
+            }),
+            registry::rir_v6(ip).map(|rir| rir.name()),
+        ),
+        _ => (network, None),
+    };
+
+    Explanation {
+        ip: ip_address,
+        is_bogon: kind.is_some(),
+        network,
+        kind,
+        note,
+        designation,
+    }
+}
+
+/// The label [`describe`] returns for addresses that are not bogus.
+const ROUTABLE_LABEL: &str = "globally routable";
+
+/// Returns a short, human-readable label describing why an IP address is or isn't bogus.
+///
+/// Classified addresses are labeled with [`BogonKind::label_for`], noted addresses with
+/// [`RoutableNote::label`], and every other address is labeled `"globally routable"`. Labels are
+/// stable and safe to search logs for.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::describe;
+///
+/// assert_eq!(describe("192.168.0.1".parse().unwrap()), "private-use (RFC 1918)");
+/// assert_eq!(describe("3000::1".parse().unwrap()), "unallocated IPv6 space");
+/// assert_eq!(describe("8.8.8.8".parse().unwrap()), "globally routable");
+/// ```
+pub fn describe(ip_address: IpAddr) -> &'static str {
+    match crate::classify(ip_address) {
+        Some(kind) => kind.label_for(ip_address),
+        None => crate::annotate(ip_address).map_or(ROUTABLE_LABEL, |note| note.label()),
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let (Some(note), Some((network, prefix))) = (self.note, self.network) {
+            return write!(
+                f,
+                "{} is not bogus: {} ({}/{}, {})",
+                self.ip,
+                note.description(),
+                network,
+                prefix,
+                note.rfc()
+            );
+        }
+
+        match (self.kind, self.network, self.designation) {
+            (Some(kind), Some((network, prefix)), _) => write!(
+                f,
+                "{} is bogus: {} ({}/{}, {})",
+                self.ip,
+                kind.description(),
+                network,
+                prefix,
+                kind.rfc_for(self.ip)
+            ),
+            (Some(kind), None, _) => {
+                write!(
+                    f,
+                    "{} is bogus: {} ({})",
+                    self.ip,
+                    kind.description(),
+                    kind.rfc_for(self.ip)
+                )
+            }
+            (None, Some((network, prefix)), Some(designation)) => write!(
+                f,
+                "{} is not bogus: allocated to {} ({}/{})",
+                self.ip, designation, network, prefix
+            ),
+            _ => write!(f, "{} is not bogus", self.ip),
+        }
+    }
+}
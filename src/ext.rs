@@ -1,6 +1,32 @@
+use crate::BogonKind;
+
 /// An extension trait for checking if an IP address is in a bogon network.
 ///
+/// It is implemented for the IP addresses, and for the socket addresses by checking their IP
+/// address, so that the peer of a connection can be checked as it's accepted. The scope ID and
+/// flow info of a [`SocketAddrV6`](core::net::SocketAddrV6) are ignored.
+///
+/// The category predicates, like [`is_private`](BogonExt::is_private), test whether the IP address
+/// is inside of the category's networks, so a cloud instance metadata endpoint inside of
+/// Unique-Local space is private, even though [`bogon_kind`](BogonExt::bogon_kind) returns the more
+/// specific [`BogonKind::CloudMetadata`].
+///
 /// This trait is [sealed](https://rust-lang.github.io/api-guidelines/future-proofing.html) and cannot be implemented for types outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::{TcpListener, TcpStream};
+/// use bogon::{BogonExt, BogonKind};
+///
+/// let listener = TcpListener::bind("127.0.0.1:0")?;
+/// let _client = TcpStream::connect(listener.local_addr()?)?;
+///
+/// let (_stream, peer) = listener.accept()?;
+/// assert!(peer.is_bogon());
+/// assert_eq!(peer.bogon_kind(), Some(BogonKind::Loopback));
+/// # Ok::<(), std::io::Error>(())
+/// ```
 pub trait BogonExt: sealed::Sealed {
     /// Returns a boolean indicating whether an IP address is bogus.
     ///
@@ -20,6 +46,140 @@ pub trait BogonExt: sealed::Sealed {
     /// assert_eq!(Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0x1111, 0, 0, 0, 2).is_bogon(), false);
     /// ```
     fn is_bogon(&self) -> bool;
+
+    /// Returns the reason an IP address is bogus, or `None` if the IP address is good.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// use bogon::{BogonExt, BogonKind};
+    ///
+    /// assert_eq!(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)).bogon_kind(), Some(BogonKind::Loopback));
+    /// assert_eq!(IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)).bogon_kind(), Some(BogonKind::UniqueLocal));
+    ///
+    /// assert_eq!(Ipv4Addr::new(192, 168, 1, 1).bogon_kind(), Some(BogonKind::PrivateUse));
+    /// assert_eq!(Ipv6Addr::new(0x3000, 0, 0, 0, 0, 0, 0, 1).bogon_kind(), Some(BogonKind::Unallocated));
+    ///
+    /// assert_eq!(Ipv4Addr::new(8, 8, 8, 8).bogon_kind(), None);
+    /// assert_eq!(Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0x1111, 0, 0, 0, 2).bogon_kind(), None);
+    /// ```
+    fn bogon_kind(&self) -> Option<BogonKind>;
+
+    /// Returns `true` if the IP address is in a Private-Use network (`10.0.0.0/8`,
+    /// `172.16.0.0/12`, `192.168.0.0/16`) or is an IPv6 Unique-Local address (`fc00::/7`).
+    ///
+    /// [`Ipv4Addr`](core::net::Ipv4Addr) has an inherent `is_private` method with the same
+    /// meaning, call this one with `BogonExt::is_private(&ip)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// use bogon::BogonExt;
+    ///
+    /// assert!(BogonExt::is_private(&Ipv4Addr::new(172, 31, 255, 255)));
+    /// assert!(BogonExt::is_private(&Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)));
+    /// assert!(!BogonExt::is_private(&IpAddr::V4(Ipv4Addr::new(172, 32, 0, 0))));
+    /// ```
+    #[inline]
+    fn is_private(&self) -> bool {
+        matches!(
+            self.containing_kind(),
+            Some(BogonKind::PrivateUse | BogonKind::UniqueLocal)
+        )
+    }
+
+    /// Returns `true` if the IP address is in the Shared Address Space (`100.64.0.0/10`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use bogon::BogonExt;
+    ///
+    /// # #[cfg(not(feature = "allow-cgnat"))]
+    /// assert!(BogonExt::is_shared(&Ipv4Addr::new(100, 64, 0, 0)));
+    /// assert!(!BogonExt::is_shared(&Ipv4Addr::new(100, 128, 0, 0)));
+    /// ```
+    #[inline]
+    fn is_shared(&self) -> bool {
+        self.containing_kind() == Some(BogonKind::SharedAddressSpace)
+    }
+
+    /// Returns `true` if the IP address is reserved for documentation (`192.0.2.0/24`,
+    /// `198.51.100.0/24`, `203.0.113.0/24`, `2001:db8::/32`, `3fff::/20`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{Ipv4Addr, Ipv6Addr};
+    /// use bogon::BogonExt;
+    ///
+    /// assert!(BogonExt::is_documentation(&Ipv4Addr::new(203, 0, 113, 7)));
+    /// assert!(BogonExt::is_documentation(&Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    /// ```
+    #[inline]
+    fn is_documentation(&self) -> bool {
+        self.containing_kind() == Some(BogonKind::Documentation)
+    }
+
+    /// Returns `true` if the IP address is reserved for benchmarking (`198.18.0.0/15`,
+    /// `2001:2::/48`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{Ipv4Addr, Ipv6Addr};
+    /// use bogon::BogonExt;
+    ///
+    /// # #[cfg(not(feature = "allow-benchmarking"))]
+    /// assert!(BogonExt::is_benchmarking(&Ipv4Addr::new(198, 19, 255, 255)));
+    /// assert!(BogonExt::is_benchmarking(&Ipv6Addr::new(0x2001, 2, 0, 0, 0, 0, 0, 1)));
+    /// ```
+    #[inline]
+    fn is_benchmarking(&self) -> bool {
+        self.containing_kind() == Some(BogonKind::Benchmarking)
+    }
+
+    /// Returns `true` if the IP address is reserved for future use (`240.0.0.0/4`, except for the
+    /// limited broadcast address) or is IPv6 space reserved by the IETF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use bogon::BogonExt;
+    ///
+    /// # #[cfg(not(feature = "allow-reserved-240-4"))]
+    /// assert!(BogonExt::is_reserved(&Ipv4Addr::new(240, 0, 0, 0)));
+    /// assert!(!BogonExt::is_reserved(&Ipv4Addr::BROADCAST));
+    /// ```
+    #[inline]
+    fn is_reserved(&self) -> bool {
+        matches!(
+            self.containing_kind(),
+            Some(BogonKind::Reserved | BogonKind::ReservedFutureUse)
+        )
+    }
+
+    /// Returns `true` if the IP address is a cloud instance metadata endpoint (`169.254.169.254`,
+    /// `169.254.170.2`, `fd00:ec2::254`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{Ipv4Addr, Ipv6Addr};
+    /// use bogon::BogonExt;
+    ///
+    /// assert!(Ipv4Addr::new(169, 254, 169, 254).is_cloud_metadata());
+    /// assert!(Ipv6Addr::new(0xfd00, 0xec2, 0, 0, 0, 0, 0, 0x254).is_cloud_metadata());
+    /// assert!(!Ipv4Addr::new(169, 254, 169, 253).is_cloud_metadata());
+    /// ```
+    #[inline]
+    fn is_cloud_metadata(&self) -> bool {
+        self.bogon_kind() == Some(BogonKind::CloudMetadata)
+    }
 }
 
 impl BogonExt for core::net::IpAddr {
@@ -27,6 +187,11 @@ impl BogonExt for core::net::IpAddr {
     fn is_bogon(&self) -> bool {
         crate::is_bogon(*self)
     }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        crate::classify(*self)
+    }
 }
 
 impl BogonExt for core::net::Ipv4Addr {
@@ -34,6 +199,11 @@ impl BogonExt for core::net::Ipv4Addr {
     fn is_bogon(&self) -> bool {
         crate::is_bogon_v4(*self)
     }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        crate::classify_v4(*self)
+    }
 }
 
 impl BogonExt for core::net::Ipv6Addr {
@@ -41,14 +211,98 @@ impl BogonExt for core::net::Ipv6Addr {
     fn is_bogon(&self) -> bool {
         crate::is_bogon_v6(*self)
     }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        crate::classify_v6(*self)
+    }
+}
+
+impl BogonExt for core::net::SocketAddr {
+    #[inline]
+    fn is_bogon(&self) -> bool {
+        self.ip().is_bogon()
+    }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        self.ip().bogon_kind()
+    }
+}
+
+impl BogonExt for core::net::SocketAddrV4 {
+    #[inline]
+    fn is_bogon(&self) -> bool {
+        self.ip().is_bogon()
+    }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        self.ip().bogon_kind()
+    }
+}
+
+impl BogonExt for core::net::SocketAddrV6 {
+    #[inline]
+    fn is_bogon(&self) -> bool {
+        self.ip().is_bogon()
+    }
+
+    #[inline]
+    fn bogon_kind(&self) -> Option<BogonKind> {
+        self.ip().bogon_kind()
+    }
 }
 
 mod sealed {
-    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    use crate::BogonKind;
+
+    pub trait Sealed {
+        /// Returns the classification of the IP address, see `crate::containing_kind`.
+        fn containing_kind(&self) -> Option<BogonKind>;
+    }
+
+    impl Sealed for IpAddr {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            crate::containing_kind(*self)
+        }
+    }
 
-    pub trait Sealed {}
+    impl Sealed for Ipv4Addr {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            crate::containing_kind(IpAddr::V4(*self))
+        }
+    }
 
-    impl Sealed for IpAddr {}
-    impl Sealed for Ipv4Addr {}
-    impl Sealed for Ipv6Addr {}
+    impl Sealed for Ipv6Addr {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            crate::containing_kind(IpAddr::V6(*self))
+        }
+    }
+
+    impl Sealed for SocketAddr {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            self.ip().containing_kind()
+        }
+    }
+
+    impl Sealed for SocketAddrV4 {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            self.ip().containing_kind()
+        }
+    }
+
+    impl Sealed for SocketAddrV6 {
+        #[inline]
+        fn containing_kind(&self) -> Option<BogonKind> {
+            self.ip().containing_kind()
+        }
+    }
 }
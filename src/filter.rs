@@ -0,0 +1,357 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::BogonKind;
+
+/// Returns the bit of a classification in a [`BogonFilter`] rule set.
+const fn bit(kind: BogonKind) -> u64 {
+    1 << kind as u64
+}
+
+/// Returns the mask of an IPv4 prefix length.
+const fn mask_v4(prefix_len: u8) -> u32 {
+    match u32::MAX.checked_shl(32 - prefix_len as u32) {
+        Some(mask) => mask,
+        None => 0,
+    }
+}
+
+/// Returns the mask of an IPv6 prefix length.
+const fn mask_v6(prefix_len: u8) -> u128 {
+    match u128::MAX.checked_shl(128 - prefix_len as u32) {
+        Some(mask) => mask,
+        None => 0,
+    }
+}
+
+/// Returns a boolean indicating whether a network contains an IP address of the same family.
+const fn network_contains(network: IpAddr, prefix_len: u8, ip_address: IpAddr) -> bool {
+    match (network, ip_address) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            ip.to_bits() & mask_v4(prefix_len) == network.to_bits()
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            ip.to_bits() & mask_v6(prefix_len) == network.to_bits()
+        }
+        _ => false,
+    }
+}
+
+// Every classification must have a bit.
+const _: () = assert!(BogonKind::ALL.len() <= u64::BITS as usize);
+
+/// Returns the bits of a list of classifications.
+const fn bits(kinds: &[BogonKind]) -> u64 {
+    let mut bits = 0;
+    let mut i = 0;
+    while i < kinds.len() {
+        bits |= bit(kinds[i]);
+        i += 1;
+    }
+    bits
+}
+
+/// The classifications flagged by [`BogonProfile::Loose`].
+const LOOSE: &[BogonKind] = &[
+    BogonKind::ThisNetwork,
+    BogonKind::PrivateUse,
+    BogonKind::Loopback,
+    BogonKind::LinkLocal,
+    BogonKind::Multicast,
+    BogonKind::Broadcast,
+    BogonKind::UniqueLocal,
+    BogonKind::Unspecified,
+    BogonKind::CloudMetadata,
+];
+
+/// A preset of what counts as a bogon, a starting point for a [`BogonFilter`].
+///
+/// [`BogonProfile::Loose`] only flags addresses that can never be routed on the internet:
+///
+/// - "This network" (`0.0.0.0/8`) and the Unspecified Address (`::/128`)
+/// - Private-Use networks (`10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`)
+/// - Loopback (`127.0.0.0/8`, `::1/128`)
+/// - Link Local (`169.254.0.0/16`, `fe80::/10`), including the cloud metadata endpoints
+/// - Multicast (`224.0.0.0/4`, `ff00::/8`)
+/// - Limited Broadcast (`255.255.255.255/32`)
+/// - Unique Local (`fc00::/7`), including the cloud metadata endpoint
+///
+/// [`BogonProfile::Strict`] flags everything [`is_bogon`](crate::is_bogon) does. On top of the
+/// loose profile that is:
+///
+/// - Shared Address Space (`100.64.0.0/10`)
+/// - Documentation (`192.0.2.0/24`, `198.51.100.0/24`, `203.0.113.0/24`, `2001:db8::/32`,
+///   `3fff::/20`)
+/// - Benchmarking (`198.18.0.0/15`, `2001:2::/48`)
+/// - Reserved for future use (`240.0.0.0/4`)
+/// - Reserved by the IETF (IPv6 space outside of `2000::/3` without a more specific
+///   classification)
+/// - IETF Protocol Assignments (`192.0.0.0/24`, `2001::/23`)
+/// - 6to4 Relay Anycast (`192.88.99.0/24`)
+/// - Unallocated IPv6 space inside of `2000::/3`
+/// - Discard-Only (`100::/64`), SRv6 SIDs (`5f00::/16`), Teredo (`2001::/32`), ORCHIDv2
+///   (`2001:20::/28`), NAT64 (`64:ff9b::/96`, `64:ff9b:1::/48`), deprecated IPv4-compatible
+///   addresses (`::/96`), and IPv4-mapped addresses (`::ffff:0:0/96`)
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon_profile, BogonProfile};
+///
+/// let cgnat = "100.64.0.1".parse().unwrap();
+/// # #[cfg(not(feature = "allow-cgnat"))]
+/// assert!(is_bogon_profile(cgnat, BogonProfile::Strict));
+/// assert!(!is_bogon_profile(cgnat, BogonProfile::Loose));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum BogonProfile {
+    /// Only flag addresses that can never be routed on the internet.
+    Loose,
+    /// Flag everything [`is_bogon`](crate::is_bogon) does.
+    #[default]
+    Strict,
+}
+
+impl BogonProfile {
+    /// Returns a builder that starts from this profile.
+    pub const fn rules(self) -> BogonFilterBuilder {
+        let allowed = match self {
+            BogonProfile::Loose => bits(&BogonKind::ALL) & !bits(LOOSE),
+            BogonProfile::Strict => 0,
+        };
+        BogonFilterBuilder {
+            filter: BogonFilter {
+                allowed,
+                denied_networks: [None; BogonFilter::MAX_DENIED_NETWORKS],
+                canonicalize_mapped: false,
+            },
+        }
+    }
+
+    /// Returns the filter for this profile.
+    pub const fn filter(self) -> BogonFilter {
+        self.rules().build()
+    }
+}
+
+/// Returns a boolean indicating whether an IP address is bogus under a [`BogonProfile`].
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon, is_bogon_profile, BogonProfile};
+///
+/// let ip = "240.0.0.1".parse().unwrap();
+/// assert_eq!(is_bogon_profile(ip, BogonProfile::Strict), is_bogon(ip));
+/// assert!(!is_bogon_profile(ip, BogonProfile::Loose));
+/// assert!(is_bogon_profile("192.168.1.1".parse().unwrap(), BogonProfile::Loose));
+/// ```
+#[inline]
+pub fn is_bogon_profile(ip_address: IpAddr, profile: BogonProfile) -> bool {
+    profile.filter().is_bogon(ip_address)
+}
+
+/// A bogon checker that skips some classifications and flags some extra networks.
+///
+/// An address is judged by its most specific classification, so allowing
+/// [`BogonKind::Benchmarking`] makes `2001:2::/48` fine even though the rest of `2001::/23` is
+/// still bogus. IPv4-mapped addresses are [`BogonKind::Ipv4Mapped`] whatever the IPv4 address, so
+/// allowing [`BogonKind::ReservedFutureUse`] doesn't let `::ffff:127.0.0.1` through. Networks denied with [`BogonFilterBuilder::deny_network`] are bogus no matter
+/// their classification. Filters don't change the behavior of the free functions like
+/// [`is_bogon`](crate::is_bogon).
+///
+/// With the `serde` feature filters can be loaded from a configuration file:
+///
+/// ```toml
+/// allow = ["shared-address-space"]
+/// extra_deny = ["203.0.113.0/24"]
+/// treat_mapped_as_v4 = true
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{BogonFilter, BogonKind};
+///
+/// static FILTER: BogonFilter = BogonFilter::default_rules()
+///     .allow(BogonKind::SharedAddressSpace)
+///     .allow(BogonKind::ReservedFutureUse)
+///     .build();
+///
+/// assert!(!FILTER.is_bogon("100.64.0.1".parse().unwrap()));
+/// assert!(!FILTER.is_bogon("240.0.0.1".parse().unwrap()));
+/// assert!(FILTER.is_bogon("10.0.0.1".parse().unwrap()));
+/// assert!(FILTER.is_bogon("::ffff:127.0.0.1".parse().unwrap()));
+/// assert!(FILTER.is_bogon("4000::1".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BogonFilter {
+    allowed: u64,
+    denied_networks: [Option<(IpAddr, u8)>; BogonFilter::MAX_DENIED_NETWORKS],
+    canonicalize_mapped: bool,
+}
+
+impl BogonFilter {
+    /// The number of extra networks a filter can deny.
+    pub const MAX_DENIED_NETWORKS: usize = 16;
+
+    /// Returns a builder that starts from the default rules, which flag every bogus
+    /// classification. This is the same as [`BogonProfile::Strict`].
+    pub const fn default_rules() -> BogonFilterBuilder {
+        BogonProfile::Strict.rules()
+    }
+
+    /// Returns a builder that starts from a [`BogonProfile`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::{BogonFilter, BogonKind, BogonProfile};
+    ///
+    /// const FILTER: BogonFilter = BogonFilter::profile_rules(BogonProfile::Loose)
+    ///     .deny(BogonKind::Documentation)
+    ///     .build();
+    ///
+    /// assert!(FILTER.is_bogon("192.0.2.1".parse().unwrap()));
+    /// assert!(!FILTER.is_bogon("198.18.0.1".parse().unwrap()));
+    /// ```
+    pub const fn profile_rules(profile: BogonProfile) -> BogonFilterBuilder {
+        profile.rules()
+    }
+
+    /// Returns a builder that starts from this filter.
+    pub const fn to_builder(self) -> BogonFilterBuilder {
+        BogonFilterBuilder { filter: self }
+    }
+
+    /// Returns a boolean indicating whether the filter skips a classification.
+    pub const fn allows(&self, kind: BogonKind) -> bool {
+        self.allowed & bit(kind) != 0
+    }
+
+    /// Returns the extra networks the filter denies, in the order they were added.
+    pub fn denied_networks(&self) -> impl Iterator<Item = (IpAddr, u8)> + '_ {
+        self.denied_networks.iter().flatten().copied()
+    }
+
+    /// Returns a boolean indicating whether IPv4-mapped addresses are judged as IPv4 addresses,
+    /// see [`BogonFilterBuilder::canonicalize_mapped`].
+    pub const fn canonicalizes_mapped(&self) -> bool {
+        self.canonicalize_mapped
+    }
+
+    /// Returns the IP address as it is judged by this filter.
+    #[inline]
+    fn judged(&self, ip_address: IpAddr) -> IpAddr {
+        if self.canonicalize_mapped {
+            ip_address.to_canonical()
+        } else {
+            ip_address
+        }
+    }
+
+    /// Returns a boolean indicating whether an IP address is bogus under this filter.
+    #[inline]
+    pub fn is_bogon(&self, ip_address: IpAddr) -> bool {
+        let ip_address = self.judged(ip_address);
+        self.denied_networks()
+            .any(|(network, prefix_len)| network_contains(network, prefix_len, ip_address))
+            || self.classify(ip_address).is_some()
+    }
+
+    /// Returns the classification of an IP address, or `None` if it is unclassified or its
+    /// classification is allowed.
+    ///
+    /// Unclassified addresses inside of a denied network are bogus, but still don't have a
+    /// classification.
+    #[inline]
+    pub fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        crate::classify(self.judged(ip_address)).filter(|&kind| !self.allows(kind))
+    }
+}
+
+impl Default for BogonFilter {
+    fn default() -> Self {
+        BogonFilter::default_rules().build()
+    }
+}
+
+/// A builder for a [`BogonFilter`], see [`BogonFilter::default_rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct BogonFilterBuilder {
+    filter: BogonFilter,
+}
+
+impl BogonFilterBuilder {
+    /// Stops flagging addresses with a classification.
+    pub const fn allow(mut self, kind: BogonKind) -> Self {
+        self.filter.allowed |= bit(kind);
+        self
+    }
+
+    /// Flags addresses with a classification again, undoing [`BogonFilterBuilder::allow`].
+    pub const fn deny(mut self, kind: BogonKind) -> Self {
+        self.filter.allowed &= !bit(kind);
+        self
+    }
+
+    /// Flags every address inside of a network, no matter its classification.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix length is longer than the address, or if the filter already denies
+    /// [`BogonFilter::MAX_DENIED_NETWORKS`] networks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::{IpAddr, Ipv4Addr};
+    /// use bogon::BogonFilter;
+    ///
+    /// const FILTER: BogonFilter = BogonFilter::default_rules()
+    ///     .deny_network(IpAddr::V4(Ipv4Addr::new(192, 0, 31, 0)), 24)
+    ///     .build();
+    ///
+    /// assert!(FILTER.is_bogon("192.0.31.1".parse().unwrap()));
+    /// assert!(!FILTER.is_bogon("192.0.32.1".parse().unwrap()));
+    /// ```
+    pub const fn deny_network(mut self, network: IpAddr, prefix_len: u8) -> Self {
+        let network = match network {
+            IpAddr::V4(ip) => {
+                assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+                IpAddr::V4(Ipv4Addr::from_bits(ip.to_bits() & mask_v4(prefix_len)))
+            }
+            IpAddr::V6(ip) => {
+                assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+                IpAddr::V6(Ipv6Addr::from_bits(ip.to_bits() & mask_v6(prefix_len)))
+            }
+        };
+
+        let mut i = 0;
+        while i < self.filter.denied_networks.len() {
+            if self.filter.denied_networks[i].is_none() {
+                self.filter.denied_networks[i] = Some((network, prefix_len));
+                return self;
+            }
+            i += 1;
+        }
+        panic!("too many denied networks");
+    }
+
+    /// Judges IPv4-mapped addresses (`::ffff:0:0/96`) by the IPv4 address in the low 32 bits.
+    pub const fn canonicalize_mapped(mut self, enabled: bool) -> Self {
+        self.filter.canonicalize_mapped = enabled;
+        self
+    }
+
+    /// Returns the filter.
+    pub const fn build(self) -> BogonFilter {
+        self.filter
+    }
+}
@@ -0,0 +1,296 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{classify, is_bogon, is_bogon_profile, BogonFilter, BogonKind, BogonProfile};
+
+#[test]
+fn default_rules_match_is_bogon() {
+    let filter = BogonFilter::default_rules().build();
+    assert_eq!(filter, BogonFilter::default());
+
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        let global = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+
+        for ip in [v4, v6, global] {
+            assert_eq!(filter.is_bogon(ip), is_bogon(ip), "{ip}");
+            assert_eq!(filter.classify(ip), classify(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "allow-cgnat"))]
+fn allow_shared_address_space() {
+    const FILTER: BogonFilter = BogonFilter::default_rules()
+        .allow(BogonKind::SharedAddressSpace)
+        .build();
+
+    let cgnat: IpAddr = "100.64.0.1".parse().unwrap();
+    let private: IpAddr = "10.0.0.1".parse().unwrap();
+
+    assert!(is_bogon(cgnat));
+    assert!(!FILTER.is_bogon(cgnat));
+    assert_eq!(FILTER.classify(cgnat), None);
+
+    assert!(FILTER.is_bogon(private));
+    assert_eq!(FILTER.classify(private), Some(BogonKind::PrivateUse));
+
+    // The free functions are unaffected.
+    assert!(is_bogon(cgnat));
+    assert_eq!(classify(cgnat), Some(BogonKind::SharedAddressSpace));
+}
+
+#[test]
+fn allow_most_specific() {
+    let filter = BogonFilter::default_rules()
+        .allow(BogonKind::Benchmarking)
+        .build();
+
+    for ip in ["198.18.0.1", "2001:2::1"] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(!filter.is_bogon(ip), "{ip}");
+    }
+
+    // The rest of 2001::/23 is still bogus.
+    assert!(filter.is_bogon("2001:5::1".parse().unwrap()));
+}
+
+#[test]
+fn allow_and_deny() {
+    let filter = BogonFilter::default_rules()
+        .allow(BogonKind::ReservedFutureUse)
+        .allow(BogonKind::Documentation)
+        .deny(BogonKind::Documentation)
+        .build();
+
+    assert!(filter.allows(BogonKind::ReservedFutureUse));
+    assert!(!filter.allows(BogonKind::Documentation));
+    assert!(!filter.is_bogon("240.0.0.1".parse().unwrap()));
+    assert!(filter.is_bogon("192.0.2.1".parse().unwrap()));
+
+    // Allowing every classification lets every address through.
+    let filter = BogonKind::ALL
+        .iter()
+        .fold(BogonFilter::default_rules(), |builder, &kind| {
+            builder.allow(kind)
+        })
+        .build();
+    for _ in 0..10_000 {
+        let ip = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        assert_eq!(filter.classify(ip), None, "{ip}");
+    }
+}
+
+#[test]
+fn allow_reserved_future_use_only() {
+    let filter = BogonFilter::default_rules()
+        .allow(BogonKind::ReservedFutureUse)
+        .build();
+
+    #[cfg(not(feature = "allow-reserved-240-4"))]
+    assert!(!filter.is_bogon("240.0.0.1".parse().unwrap()));
+    assert!(filter.is_bogon("255.255.255.255".parse().unwrap()));
+
+    // IPv4-mapped addresses and the IPv6 space reserved by the IETF are kinds of their own.
+    for ip in [
+        "::ffff:127.0.0.1",
+        "::ffff:10.0.0.1",
+        "::ffff:240.0.0.1",
+        "4000::1",
+        "8000::1",
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(filter.is_bogon(ip), "{ip}");
+    }
+    assert_eq!(
+        filter.classify("::ffff:127.0.0.1".parse().unwrap()),
+        Some(BogonKind::Ipv4Mapped)
+    );
+    assert_eq!(
+        filter.classify("4000::1".parse().unwrap()),
+        Some(BogonKind::Reserved)
+    );
+}
+
+#[test]
+fn strict_profile_matches_is_bogon() {
+    assert_eq!(BogonProfile::Strict.filter(), BogonFilter::default());
+
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        for ip in [v4, v6] {
+            assert_eq!(
+                is_bogon_profile(ip, BogonProfile::Strict),
+                is_bogon(ip),
+                "{ip}"
+            );
+        }
+    }
+}
+
+#[test]
+fn loose_profile() {
+    // The complete list of classifications the loose profile flags.
+    let flagged = [
+        BogonKind::ThisNetwork,
+        BogonKind::PrivateUse,
+        BogonKind::Loopback,
+        BogonKind::LinkLocal,
+        BogonKind::Multicast,
+        BogonKind::Broadcast,
+        BogonKind::UniqueLocal,
+        BogonKind::Unspecified,
+        BogonKind::CloudMetadata,
+    ];
+    let filter = BogonProfile::Loose.filter();
+    for kind in BogonKind::ALL {
+        assert_eq!(filter.allows(kind), !flagged.contains(&kind), "{kind:?}");
+    }
+
+    let bogus = [
+        "0.0.0.1",
+        "10.0.0.1",
+        "127.0.0.1",
+        "169.254.169.254",
+        "172.16.0.1",
+        "192.168.1.1",
+        "224.0.0.1",
+        "255.255.255.255",
+        "::",
+        "::1",
+        "fc00::1",
+        "fd00:ec2::254",
+        "fe80::1",
+        "ff02::1",
+    ];
+    for ip in bogus {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(is_bogon_profile(ip, BogonProfile::Loose), "{ip}");
+    }
+
+    // These differ between the profiles.
+    let strict_only = [
+        #[cfg(not(feature = "allow-cgnat"))]
+        "100.64.0.1",
+        "192.0.0.1",
+        "192.0.2.1",
+        "192.88.99.1",
+        #[cfg(not(feature = "allow-benchmarking"))]
+        "198.18.0.1",
+        "198.51.100.1",
+        "203.0.113.1",
+        #[cfg(not(feature = "allow-reserved-240-4"))]
+        "240.0.0.1",
+        "::192.0.2.1",
+        "::ffff:8.8.8.8",
+        "64:ff9b::808:808",
+        "100::1",
+        "2001::1",
+        "2001:2::1",
+        "2001:20::1",
+        "2001:db8::1",
+        "3000::1",
+        "3fff::1",
+        "5f00::1",
+        "4000::1",
+        "f000::1",
+    ];
+    for ip in strict_only {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(is_bogon_profile(ip, BogonProfile::Strict), "{ip}");
+        assert!(!is_bogon_profile(ip, BogonProfile::Loose), "{ip}");
+    }
+
+    // Globally routable addresses are fine under both profiles.
+    for ip in ["8.8.8.8", "2606:4700::1111"] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(!is_bogon_profile(ip, BogonProfile::Strict), "{ip}");
+        assert!(!is_bogon_profile(ip, BogonProfile::Loose), "{ip}");
+    }
+}
+
+#[test]
+fn profile_rules() {
+    let filter = BogonFilter::profile_rules(BogonProfile::Loose)
+        .deny(BogonKind::Documentation)
+        .build();
+    assert!(filter.is_bogon("192.0.2.1".parse().unwrap()));
+    assert!(!filter.is_bogon("240.0.0.1".parse().unwrap()));
+}
+
+#[test]
+fn deny_network() {
+    let filter = BogonFilter::default_rules()
+        .allow(BogonKind::Documentation)
+        .deny_network("203.0.113.77".parse().unwrap(), 24)
+        .deny_network("2606:4700::".parse().unwrap(), 32)
+        .build();
+
+    // Host bits are cleared.
+    assert_eq!(
+        filter.denied_networks().collect::<Vec<_>>(),
+        [
+            ("203.0.113.0".parse().unwrap(), 24),
+            ("2606:4700::".parse().unwrap(), 32)
+        ]
+    );
+
+    // Denied networks win over allowed classifications, but don't change them.
+    let ip: IpAddr = "203.0.113.1".parse().unwrap();
+    assert!(filter.is_bogon(ip));
+    assert_eq!(filter.classify(ip), None);
+    assert!(!filter.is_bogon("192.0.2.1".parse().unwrap()));
+
+    let ip: IpAddr = "2606:4700:ffff::1".parse().unwrap();
+    assert!(filter.is_bogon(ip));
+    assert!(!filter.is_bogon("2606:4701::1".parse().unwrap()));
+
+    // Networks only contain addresses of the same family, 38.6.71.0 shares the bits of 2606:4700::.
+    assert!(!filter.is_bogon("38.6.71.0".parse().unwrap()));
+
+    // A /0 denies the whole family.
+    let filter = BogonFilter::default_rules()
+        .deny_network(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+        .build();
+    assert!(filter.is_bogon("8.8.8.8".parse().unwrap()));
+    assert!(!filter.is_bogon("2606:4700::1111".parse().unwrap()));
+}
+
+#[test]
+#[should_panic = "too many denied networks"]
+fn deny_network_too_many() {
+    let mut builder = BogonFilter::default_rules();
+    for i in 0..=BogonFilter::MAX_DENIED_NETWORKS {
+        builder = builder.deny_network(IpAddr::V4(Ipv4Addr::new(192, 0, 2, i as u8)), 32);
+    }
+}
+
+#[test]
+#[should_panic = "IPv4 prefix length must be at most 32"]
+fn deny_network_prefix_too_long() {
+    let _ = BogonFilter::default_rules().deny_network(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 33);
+}
+
+#[test]
+fn canonicalize_mapped() {
+    let filter = BogonFilter::default_rules()
+        .canonicalize_mapped(true)
+        .deny_network("192.0.31.0".parse().unwrap(), 24)
+        .build();
+    assert!(filter.canonicalizes_mapped());
+
+    let mapped = |ip: Ipv4Addr| IpAddr::V6(ip.to_ipv6_mapped());
+    assert!(!filter.is_bogon(mapped(Ipv4Addr::new(8, 8, 8, 8))));
+    assert!(filter.is_bogon(mapped(Ipv4Addr::new(192, 0, 31, 1))));
+    assert_eq!(
+        filter.classify(mapped(Ipv4Addr::new(10, 0, 0, 1))),
+        Some(BogonKind::PrivateUse)
+    );
+
+    // Without the option mapped addresses are reserved IPv6 space.
+    assert!(BogonFilter::default().is_bogon(mapped(Ipv4Addr::new(8, 8, 8, 8))));
+}
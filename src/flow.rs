@@ -0,0 +1,74 @@
+use core::net::IpAddr;
+
+use crate::{classify, BogonKind};
+
+/// The verdict on a flow between a source and a destination address.
+///
+/// Returned by [`check_flow`]. Each bogus side carries its classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowVerdict {
+    /// Neither address is bogus.
+    Clean,
+    /// Only the source address is bogus.
+    BogonSource(BogonKind),
+    /// Only the destination address is bogus.
+    BogonDestination(BogonKind),
+    /// Both addresses are bogus, source first.
+    BothBogon(BogonKind, BogonKind),
+}
+
+impl FlowVerdict {
+    /// Returns a boolean indicating whether neither address is bogus.
+    pub const fn is_clean(&self) -> bool {
+        matches!(self, FlowVerdict::Clean)
+    }
+
+    /// Returns the classification of the source address if it is bogus.
+    pub const fn source(&self) -> Option<BogonKind> {
+        match *self {
+            FlowVerdict::BogonSource(kind) | FlowVerdict::BothBogon(kind, _) => Some(kind),
+            FlowVerdict::Clean | FlowVerdict::BogonDestination(_) => None,
+        }
+    }
+
+    /// Returns the classification of the destination address if it is bogus.
+    pub const fn destination(&self) -> Option<BogonKind> {
+        match *self {
+            FlowVerdict::BogonDestination(kind) | FlowVerdict::BothBogon(_, kind) => Some(kind),
+            FlowVerdict::Clean | FlowVerdict::BogonSource(_) => None,
+        }
+    }
+
+    /// Returns a boolean indicating whether the source address is bogus, which is what ingress
+    /// filtering (BCP 38) drops as likely spoofed.
+    pub const fn is_spoof_candidate(&self) -> bool {
+        self.source().is_some()
+    }
+}
+
+/// Checks both ends of a flow in one call.
+///
+/// Ingress filtering (BCP 38) only cares about bogus sources, egress filtering about bogus
+/// destinations, so the verdict keeps them apart.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{check_flow, BogonKind, FlowVerdict};
+///
+/// let verdict = check_flow("10.0.0.1".parse().unwrap(), "8.8.8.8".parse().unwrap());
+/// assert_eq!(verdict, FlowVerdict::BogonSource(BogonKind::PrivateUse));
+/// assert!(verdict.is_spoof_candidate());
+///
+/// let verdict = check_flow("8.8.8.8".parse().unwrap(), "2001:db8::1".parse().unwrap());
+/// assert_eq!(verdict.destination(), Some(BogonKind::Documentation));
+/// assert!(!verdict.is_spoof_candidate());
+/// ```
+pub fn check_flow(source: IpAddr, destination: IpAddr) -> FlowVerdict {
+    match (classify(source), classify(destination)) {
+        (None, None) => FlowVerdict::Clean,
+        (Some(source), None) => FlowVerdict::BogonSource(source),
+        (None, Some(destination)) => FlowVerdict::BogonDestination(destination),
+        (Some(source), Some(destination)) => FlowVerdict::BothBogon(source, destination),
+    }
+}
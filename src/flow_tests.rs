@@ -0,0 +1,51 @@
+use core::net::IpAddr;
+
+use crate::{check_flow, is_bogon, BogonKind, FlowVerdict};
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+#[test]
+fn quadrants() {
+    let cases = [
+        ("8.8.8.8", "1.1.1.1", FlowVerdict::Clean),
+        (
+            "192.168.1.1",
+            "1.1.1.1",
+            FlowVerdict::BogonSource(BogonKind::PrivateUse),
+        ),
+        (
+            "2606:4700::1111",
+            "::1",
+            FlowVerdict::BogonDestination(BogonKind::Loopback),
+        ),
+        (
+            "0.0.0.0",
+            "224.0.0.1",
+            FlowVerdict::BothBogon(BogonKind::ThisNetwork, BogonKind::Multicast),
+        ),
+    ];
+
+    for (source, destination, expected) in cases {
+        let verdict = check_flow(ip(source), ip(destination));
+        assert_eq!(verdict, expected, "{source} -> {destination}");
+        assert_eq!(verdict.is_clean(), expected == FlowVerdict::Clean);
+        assert_eq!(verdict.is_spoof_candidate(), is_bogon(ip(source)));
+        assert_eq!(verdict.source().is_some(), is_bogon(ip(source)));
+        assert_eq!(verdict.destination().is_some(), is_bogon(ip(destination)));
+    }
+
+    let verdict = check_flow(ip("fe80::1"), ip("192.0.2.1"));
+    assert_eq!(verdict.source(), Some(BogonKind::LinkLocal));
+    assert_eq!(verdict.destination(), Some(BogonKind::Documentation));
+}
+
+#[test]
+fn as112_is_clean() {
+    // AS112 addresses are noted, but not bogus.
+    assert_eq!(
+        check_flow(ip("192.175.48.1"), ip("2620:4f:8000::1")),
+        FlowVerdict::Clean
+    );
+}
@@ -0,0 +1,162 @@
+//! Team Cymru's fullbogons feeds.
+//!
+//! The compiled-in data covers reserved space and IPv6 space IANA hasn't allocated. The fullbogons
+//! feeds also track space the regional internet registries haven't assigned yet, which changes
+//! daily, so it can only be downloaded at runtime.
+
+use core::{fmt, net::IpAddr};
+use std::{io::BufRead, time::SystemTime};
+
+use crate::{BogonList, BogonSet, ParseListError, Verdict};
+
+/// The URL of the IPv4 fullbogons feed.
+pub const FULLBOGONS_V4_URL: &str =
+    "https://www.team-cymru.org/Services/Bogons/fullbogons-ipv4.txt";
+
+/// The URL of the IPv6 fullbogons feed.
+pub const FULLBOGONS_V6_URL: &str =
+    "https://www.team-cymru.org/Services/Bogons/fullbogons-ipv6.txt";
+
+/// The error returned when loading the fullbogons feeds fails.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FullBogonsError {
+    /// Downloading a feed failed.
+    Http(reqwest::Error),
+    /// A feed has a line that isn't a network.
+    List(ParseListError),
+    /// A feed has fewer networks than a complete feed ever has, it was likely truncated.
+    TooFewNetworks {
+        /// The address family of the feed, `"IPv4"` or `"IPv6"`.
+        family: &'static str,
+        /// The number of networks in the feed.
+        networks: usize,
+    },
+}
+
+impl fmt::Display for FullBogonsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FullBogonsError::Http(error) => write!(f, "failed to download fullbogons: {error}"),
+            FullBogonsError::List(error) => write!(f, "invalid fullbogons feed: {error}"),
+            FullBogonsError::TooFewNetworks { family, networks } => write!(
+                f,
+                "the {family} fullbogons feed has only {networks} networks, expected at least {}",
+                FullBogons::MIN_NETWORKS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FullBogonsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FullBogonsError::Http(error) => Some(error),
+            FullBogonsError::List(error) => Some(error),
+            FullBogonsError::TooFewNetworks { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FullBogonsError {
+    fn from(error: reqwest::Error) -> Self {
+        FullBogonsError::Http(error)
+    }
+}
+
+impl From<ParseListError> for FullBogonsError {
+    fn from(error: ParseListError) -> Self {
+        FullBogonsError::List(error)
+    }
+}
+
+/// The networks of Team Cymru's fullbogons feeds.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bogon::FullBogons;
+///
+/// let fullbogons = FullBogons::fetch()?;
+/// assert!(fullbogons.is_fullbogon("10.0.0.1".parse().unwrap()));
+/// println!("fetched at {:?}", fullbogons.fetched_at());
+/// # Ok::<_, bogon::FullBogonsError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FullBogons {
+    set: BogonSet,
+    fetched_at: SystemTime,
+}
+
+impl FullBogons {
+    /// The fewest networks a feed of each address family must have.
+    ///
+    /// The complete feeds have thousands of networks, a feed with fewer was cut off.
+    pub const MIN_NETWORKS: usize = 500;
+
+    /// Downloads both feeds, blocking until they are downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a download fails, or a feed is invalid or truncated.
+    pub fn fetch() -> Result<Self, FullBogonsError> {
+        let download = |url| -> Result<_, FullBogonsError> {
+            Ok(reqwest::blocking::get(url)?.error_for_status()?.bytes()?)
+        };
+
+        let v4 = download(FULLBOGONS_V4_URL)?;
+        let v6 = download(FULLBOGONS_V6_URL)?;
+        FullBogons::from_feeds(&*v4, &*v6, SystemTime::now())
+    }
+
+    /// Reads copies of the IPv4 and IPv6 feeds that were fetched at a given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a feed is invalid or truncated.
+    pub fn from_feeds(
+        v4: impl BufRead,
+        v6: impl BufRead,
+        fetched_at: SystemTime,
+    ) -> Result<Self, FullBogonsError> {
+        let mut set = BogonSet::new();
+        let feeds = [
+            (BogonList::from_reader(v4)?, "IPv4", true),
+            (BogonList::from_reader(v6)?, "IPv6", false),
+        ];
+        for (feed, family, is_ipv4) in feeds {
+            // Only networks of the right family count, so swapped feeds are caught too.
+            let networks = feed
+                .networks()
+                .filter(|(network, _)| network.is_ipv4() == is_ipv4)
+                .count();
+            if networks < FullBogons::MIN_NETWORKS {
+                return Err(FullBogonsError::TooFewNetworks { family, networks });
+            }
+            set.extend(feed.networks().map(|network| (network, Verdict::Deny)));
+        }
+
+        Ok(FullBogons { set, fetched_at })
+    }
+
+    /// Returns a boolean indicating whether a network of the feeds contains an IP address.
+    #[inline]
+    pub fn is_fullbogon(&self, ip_address: IpAddr) -> bool {
+        self.set.is_bogon(ip_address)
+    }
+
+    /// Returns the time the feeds were fetched.
+    pub fn fetched_at(&self) -> SystemTime {
+        self.fetched_at
+    }
+
+    /// Returns the networks of the feeds, all denied.
+    pub fn set(&self) -> &BogonSet {
+        &self.set
+    }
+
+    /// Converts the feeds into their networks, all denied.
+    pub fn into_set(self) -> BogonSet {
+        self.set
+    }
+}
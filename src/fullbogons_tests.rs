@@ -0,0 +1,94 @@
+use core::net::IpAddr;
+use std::time::{Duration, SystemTime};
+
+use crate::{is_bogon, FullBogons, FullBogonsError};
+
+const V4: &str = include_str!("../tests/fixtures/fullbogons-ipv4.txt");
+const V6: &str = include_str!("../tests/fixtures/fullbogons-ipv6.txt");
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+#[test]
+fn from_feeds() {
+    let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_728_981_601);
+    let fullbogons = FullBogons::from_feeds(V4.as_bytes(), V6.as_bytes(), fetched_at).unwrap();
+    assert_eq!(fullbogons.fetched_at(), fetched_at);
+
+    // Reserved space and allocated, but unassigned, space.
+    for bogus in [
+        "10.0.0.1",
+        "224.0.0.1",
+        "41.200.1.1",
+        "102.201.43.9",
+        "2c0f:f000:1000::1",
+    ] {
+        assert!(fullbogons.is_fullbogon(ip(bogus)), "{bogus}");
+    }
+
+    // Assigned space, including some the compiled-in data can't tell apart from it.
+    for assigned in [
+        "8.8.8.8",
+        "41.199.255.255",
+        "2606:4700::1111",
+        "2c0f:f100::1",
+    ] {
+        assert!(!fullbogons.is_fullbogon(ip(assigned)), "{assigned}");
+        assert!(!is_bogon(ip(assigned)), "{assigned}");
+    }
+    assert!(!is_bogon(ip("41.200.1.1")));
+
+    let set = fullbogons.clone().into_set();
+    assert_eq!(&set, fullbogons.set());
+    assert_eq!(set.len(), 613 + 610);
+}
+
+#[test]
+fn truncated() {
+    let truncated = V6.lines().take(100).collect::<Vec<_>>().join("\n");
+    let error =
+        FullBogons::from_feeds(V4.as_bytes(), truncated.as_bytes(), SystemTime::now()).unwrap_err();
+    assert!(
+        matches!(
+            error,
+            FullBogonsError::TooFewNetworks {
+                family: "IPv6",
+                networks: 98
+            }
+        ),
+        "{error}"
+    );
+
+    // Only the header made it.
+    let error = FullBogons::from_feeds(
+        &b"# last updated 1728981601\n"[..],
+        V6.as_bytes(),
+        SystemTime::now(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "the IPv4 fullbogons feed has only 0 networks, expected at least 500"
+    );
+
+    // Swapped feeds.
+    let error =
+        FullBogons::from_feeds(V6.as_bytes(), V4.as_bytes(), SystemTime::now()).unwrap_err();
+    assert!(
+        matches!(
+            error,
+            FullBogonsError::TooFewNetworks { family: "IPv4", .. }
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn invalid() {
+    // A download cut off in the middle of a line.
+    let cut = &V4[..V4.len() - 2];
+    let error =
+        FullBogons::from_feeds(cut.as_bytes(), V6.as_bytes(), SystemTime::now()).unwrap_err();
+    assert!(matches!(error, FullBogonsError::List(_)), "{error}");
+}
@@ -0,0 +1,170 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::network::{FourByteNetwork, SixteenByteNetwork};
+
+// IPv4 networks that are not globally reachable according to the IANA IPv4 Special-Purpose Address
+// Registry, as interpreted by `Ipv4Addr::is_global`.
+static V4_NON_GLOBAL_NETWORKS: [FourByteNetwork; 13] = [
+    // "This network"
+    FourByteNetwork::new(Ipv4Addr::new(0, 0, 0, 0).to_bits(), 8),
+    // Private-Use
+    FourByteNetwork::new(Ipv4Addr::new(10, 0, 0, 0).to_bits(), 8),
+    // Shared Address Space
+    FourByteNetwork::new(Ipv4Addr::new(100, 64, 0, 0).to_bits(), 10),
+    // Loopback
+    FourByteNetwork::new(Ipv4Addr::new(127, 0, 0, 0).to_bits(), 8),
+    // Link Local
+    FourByteNetwork::new(Ipv4Addr::new(169, 254, 0, 0).to_bits(), 16),
+    // Private-Use
+    FourByteNetwork::new(Ipv4Addr::new(172, 16, 0, 0).to_bits(), 12),
+    // IETF Protocol Assignments
+    FourByteNetwork::new(Ipv4Addr::new(192, 0, 0, 0).to_bits(), 24),
+    // Documentation (TEST-NET-1)
+    FourByteNetwork::new(Ipv4Addr::new(192, 0, 2, 0).to_bits(), 24),
+    // Private-Use
+    FourByteNetwork::new(Ipv4Addr::new(192, 168, 0, 0).to_bits(), 16),
+    // Benchmarking
+    FourByteNetwork::new(Ipv4Addr::new(198, 18, 0, 0).to_bits(), 15),
+    // Documentation (TEST-NET-2)
+    FourByteNetwork::new(Ipv4Addr::new(198, 51, 100, 0).to_bits(), 24),
+    // Documentation (TEST-NET-3)
+    FourByteNetwork::new(Ipv4Addr::new(203, 0, 113, 0).to_bits(), 24),
+    // Reserved, including Limited Broadcast
+    FourByteNetwork::new(Ipv4Addr::new(240, 0, 0, 0).to_bits(), 4),
+];
+
+// Globally reachable IPv4 networks inside of `V4_NON_GLOBAL_NETWORKS`.
+static V4_GLOBAL_EXCEPTIONS: [FourByteNetwork; 2] = [
+    // Port Control Protocol Anycast
+    FourByteNetwork::new(Ipv4Addr::new(192, 0, 0, 9).to_bits(), 32),
+    // Traversal Using Relays around NAT Anycast
+    FourByteNetwork::new(Ipv4Addr::new(192, 0, 0, 10).to_bits(), 32),
+];
+
+// IPv6 networks that are not globally reachable according to the IANA IPv6 Special-Purpose Address
+// Registry, as interpreted by `Ipv6Addr::is_global`.
+static V6_NON_GLOBAL_NETWORKS: [SixteenByteNetwork; 12] = [
+    // Unspecified Address
+    SixteenByteNetwork::new(0, 128),
+    // Loopback Address
+    SixteenByteNetwork::new(1, 128),
+    // IPv4-mapped Address
+    SixteenByteNetwork::new(0xffff << 32, 96),
+    // IPv4-IPv6 Translation
+    SixteenByteNetwork::new(0x0064_ff9b_0001 << 80, 48),
+    // Discard-Only Address Block
+    SixteenByteNetwork::new(0x0100 << 112, 64),
+    // IETF Protocol Assignments
+    SixteenByteNetwork::new(0x2001 << 112, 23),
+    // Documentation
+    SixteenByteNetwork::new(0x2001_0db8 << 96, 32),
+    // 6to4
+    SixteenByteNetwork::new(0x2002 << 112, 16),
+    // Documentation
+    SixteenByteNetwork::new(0x3fff << 112, 20),
+    // Segment Routing (SRv6) SIDs
+    SixteenByteNetwork::new(0x5f00 << 112, 16),
+    // Unique-Local
+    SixteenByteNetwork::new(0xfc00 << 112, 7),
+    // Link-Local Unicast
+    SixteenByteNetwork::new(0xfe80 << 112, 10),
+];
+
+// Globally reachable IPv6 networks inside of `V6_NON_GLOBAL_NETWORKS`.
+static V6_GLOBAL_EXCEPTIONS: [SixteenByteNetwork; 6] = [
+    // Port Control Protocol Anycast
+    SixteenByteNetwork::new(0x2001_0001 << 96 | 1, 128),
+    // Traversal Using Relays around NAT Anycast
+    SixteenByteNetwork::new(0x2001_0001 << 96 | 2, 128),
+    // AMT
+    SixteenByteNetwork::new(0x2001_0003 << 96, 32),
+    // AS112-v6
+    SixteenByteNetwork::new(0x2001_0004_0112 << 80, 48),
+    // ORCHIDv2
+    SixteenByteNetwork::new(0x2001_0020 << 96, 28),
+    // Drone Remote ID Protocol Entity Tags (DETs) Prefix
+    SixteenByteNetwork::new(0x2001_0030 << 96, 28),
+];
+
+/// Returns a boolean indicating whether an IP address is globally reachable.
+///
+/// This tracks the semantics of the unstable `Ipv4Addr::is_global` and `Ipv6Addr::is_global`
+/// methods from the standard library, which follow the "Globally Reachable" column of the IANA
+/// special-purpose address registries. It is not the same as `!is_bogon(ip)`:
+///
+/// - Multicast addresses (`224.0.0.0/4`, `ff00::/8`) are global, but bogus.
+/// - `192.0.0.9` and `192.0.0.10` are global.
+/// - `192.88.99.0/24` (deprecated 6to4 relay anycast) is global, but bogus.
+/// - IPv6 addresses outside of `2000::/3` that aren't listed in the special-purpose registry (for
+///   example `4000::1` or `fec0::1`) are global, but bogus.
+/// - IPv6 addresses in `2000::/3` that aren't allocated to a regional internet registry (for
+///   example `3000::1`) are global, but bogus.
+/// - `2001:3::/32`, `2001:4:112::/48`, `2001:20::/28`, and `2001:30::/28` are global.
+/// - `64:ff9b::/96` (the NAT64 well-known prefix) and IPv4-compatible addresses (`::/96`) are
+///   global, but bogus.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon, is_global};
+///
+/// assert!(is_global("8.8.8.8".parse().unwrap()));
+/// assert!(!is_global("10.0.0.1".parse().unwrap()));
+///
+/// // Multicast is globally reachable, but not a valid unicast source or destination.
+/// assert!(is_global("224.0.0.1".parse().unwrap()));
+/// assert!(is_bogon("224.0.0.1".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_global(ip_address: IpAddr) -> bool {
+    match ip_address {
+        IpAddr::V4(ip) => is_global_v4(ip),
+        IpAddr::V6(ip) => is_global_v6(ip),
+    }
+}
+
+/// Returns a boolean indicating whether an IPv4 address is globally reachable.
+///
+/// See [`is_global`] for how this differs from `!is_bogon_v4(ip)`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::is_global_v4;
+///
+/// assert!(is_global_v4(Ipv4Addr::new(192, 0, 0, 9)));
+/// assert!(!is_global_v4(Ipv4Addr::new(192, 0, 0, 8)));
+/// ```
+#[inline]
+pub fn is_global_v4(ip_address: Ipv4Addr) -> bool {
+    !V4_NON_GLOBAL_NETWORKS
+        .iter()
+        .any(|network| network.contains_v4(ip_address))
+        || V4_GLOBAL_EXCEPTIONS
+            .iter()
+            .any(|network| network.contains_v4(ip_address))
+}
+
+/// Returns a boolean indicating whether an IPv6 address is globally reachable.
+///
+/// See [`is_global`] for how this differs from `!is_bogon_v6(ip)`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::is_global_v6;
+///
+/// assert!(is_global_v6(Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 1)));
+/// assert!(!is_global_v6(Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 3)));
+/// ```
+#[inline]
+pub fn is_global_v6(ip_address: Ipv6Addr) -> bool {
+    !V6_NON_GLOBAL_NETWORKS
+        .iter()
+        .any(|network| network.contains_v6(ip_address))
+        || V6_GLOBAL_EXCEPTIONS
+            .iter()
+            .any(|network| network.contains_v6(ip_address))
+}
@@ -0,0 +1,158 @@
+use core::net::IpAddr;
+
+use crate::{is_bogon, is_global};
+
+/// Addresses and their expected verdicts, checked against `IpAddr::is_global` on nightly.
+const EXPECTED: &[(&str, bool)] = &[
+    ("0.0.0.0", false),
+    ("0.1.2.3", false),
+    ("8.8.8.8", true),
+    ("192.0.0.8", false),
+    ("192.0.0.9", true),
+    ("192.0.0.10", true),
+    ("192.0.0.11", false),
+    ("192.0.0.170", false),
+    ("192.88.99.1", true),
+    ("224.0.0.1", true),
+    ("255.255.255.255", false),
+    ("::", false),
+    ("::1", false),
+    ("::8.8.8.8", true),
+    ("::ffff:8.8.8.8", false),
+    ("64:ff9b::1", true),
+    ("64:ff9b:1::1", false),
+    ("100::1", false),
+    ("100:0:0:1::", true),
+    ("2001:1::1", true),
+    ("2001:1::2", true),
+    ("2001:1::3", false),
+    ("2001:2::1", false),
+    ("2001:2:1::1", false),
+    ("2001:3::1", true),
+    ("2001:4:112::1", true),
+    ("2001:4:113::1", false),
+    ("2001:20::1", true),
+    ("2001:30::1", true),
+    ("2001:40::1", false),
+    ("2001:db8::1", false),
+    ("2002::1", false),
+    ("3000::1", true),
+    ("3fff::1", false),
+    ("3fff:1000::", true),
+    ("4000::1", true),
+    ("5f00::1", false),
+    ("fc00::1", false),
+    ("fe80::1", false),
+    ("fec0::1", true),
+    ("ff0e::1", true),
+];
+
+#[test]
+fn is_global_expected() {
+    for &(ip, expected) in EXPECTED {
+        assert_eq!(is_global(ip.parse().unwrap()), expected, "{ip}");
+    }
+}
+
+#[test]
+fn is_global_divergences() {
+    // Global, but bogus.
+    for ip in [
+        "224.0.0.1",
+        "ff0e::1",
+        "4000::1",
+        "fec0::1",
+        "3000::1",
+        "64:ff9b::1",
+        "::8.8.8.8",
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(is_global(ip) && is_bogon(ip), "{ip}");
+    }
+
+    // Globally reachable carve-outs of bogus space.
+    for ip in [
+        "192.0.0.9",
+        "192.0.0.10",
+        "2001:3::1",
+        "2001:4:112::1",
+        "2001:20::1",
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(is_global(ip), "{ip}");
+    }
+
+    // Routable, and global.
+    for ip in ["8.8.8.8", "2606:4700::1111"] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert!(is_global(ip) && !is_bogon(ip), "{ip}");
+    }
+}
+
+/// Differential tests against the standard library, run with
+/// `RUSTFLAGS="--cfg bogon_nightly" cargo +nightly test`.
+#[cfg(bogon_nightly)]
+mod nightly {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::EXPECTED;
+    use crate::{is_global_v4, is_global_v6};
+
+    #[test]
+    fn expected_matches_std() {
+        for &(ip, expected) in EXPECTED {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert_eq!(ip.is_global(), expected, "{ip}");
+        }
+    }
+
+    #[test]
+    fn random_v4_matches_std() {
+        for _ in 0..4_000_000 {
+            let ip = Ipv4Addr::from_bits(rand::random());
+            assert_eq!(is_global_v4(ip), ip.is_global(), "{ip}");
+        }
+    }
+
+    #[test]
+    fn random_v6_matches_std() {
+        for _ in 0..4_000_000 {
+            // Keep a random number of leading bits from a special-purpose prefix so that the
+            // interesting ranges are actually hit.
+            let prefix = [
+                0u128,
+                0xffff << 32,
+                0x0064_ff9b << 96,
+                0x0100 << 112,
+                0x2001 << 112,
+            ][rand::random::<usize>() % 5];
+            let shift = rand::random::<u32>() % 128;
+            let bits = prefix | (rand::random::<u128>() >> shift);
+
+            for ip in [
+                Ipv6Addr::from_bits(bits),
+                Ipv6Addr::from_bits(rand::random()),
+            ] {
+                assert_eq!(is_global_v6(ip), ip.is_global(), "{ip}");
+            }
+        }
+    }
+
+    #[test]
+    fn boundaries_match_std() {
+        // The first and last addresses of every /8 and of the networks around them.
+        for octet in 0..=255u32 {
+            for offset in [0u32, 1, 0x00ff_ffff, 0x00ff_fffe] {
+                let ip = Ipv4Addr::from_bits(octet << 24 | offset);
+                assert_eq!(is_global_v4(ip), ip.is_global(), "{ip}");
+            }
+        }
+
+        for hextet in 0..=0xffffu128 {
+            for offset in [0u128, 1, u128::MAX >> 16] {
+                let ip = Ipv6Addr::from_bits(hextet << 112 | offset);
+                assert_eq!(is_global_v6(ip), ip.is_global(), "{ip}");
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Building the IPv6 data from a snapshot of the IANA registry at runtime.
+//!
+//! The `bogon-data` build script generates the compiled-in data with the same code, so a snapshot
+//! identical to the bundled one gives the same verdicts as the compiled-in data.
+
+use core::net::{IpAddr, Ipv6Addr};
+use std::vec::Vec;
+
+use bogon_data::iana_csv::{merge_networks, rir_allocations, CsvError};
+
+use crate::{BogonTable, Rir, V6_BOGON_EXCEPTIONS, V6_GLOBAL_BOGON_NETWORKS};
+
+/// Returns the `Rir` of a registry designation.
+fn rir(designation: &str) -> Option<Rir> {
+    match designation {
+        "AFRINIC" => Some(Rir::Afrinic),
+        "APNIC" => Some(Rir::Apnic),
+        "ARIN" => Some(Rir::Arin),
+        "LACNIC" => Some(Rir::Lacnic),
+        "RIPE NCC" => Some(Rir::RipeNcc),
+        _ => None,
+    }
+}
+
+/// Parses the [IPv6 unicast address assignments] registry CSV file.
+///
+/// Returns the networks allocated to a regional internet registry, sorted by network. The rest of
+/// the registry is reserved or designated to IANA, so it isn't globally reachable.
+///
+/// [IPv6 unicast address assignments]: https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.csv
+///
+/// # Errors
+///
+/// Returns an error if the file isn't valid CSV, lacks one of the registry's columns, or has a
+/// prefix that isn't an IPv6 network.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{parse_iana_v6_csv, Rir};
+///
+/// let csv = "\
+/// Prefix,Designation,Date,WHOIS,RDAP,Status,Note
+/// 2001:0200::/23,APNIC,1999-07-01,whois.apnic.net,https://rdap.apnic.net/,ALLOCATED,
+/// 2001:0400::/23,ARIN,1999-07-01,whois.arin.net,https://rdap.arin.net/registry,ALLOCATED,
+/// 2001:db8::/32,IANA,2004-07-01,,,RESERVED,\"Documentation, see [RFC3849].\"
+/// ";
+///
+/// let allocations = parse_iana_v6_csv(csv).unwrap();
+/// assert_eq!(
+///     allocations,
+///     [
+///         ("2001:200::".parse().unwrap(), 23, Rir::Apnic),
+///         ("2001:400::".parse().unwrap(), 23, Rir::Arin),
+///     ]
+/// );
+/// ```
+pub fn parse_iana_v6_csv(text: &str) -> Result<Vec<(Ipv6Addr, u8, Rir)>, CsvError> {
+    // Rows with an unknown status aren't allocations, so they can be skipped silently.
+    let allocations = rir_allocations(text, &mut Vec::new())
+        .map_err(|errors| errors.into_iter().next().unwrap())?;
+    Ok(allocations
+        .into_iter()
+        .filter_map(|allocation| {
+            let rir = rir(&allocation.designation)?;
+            Some((allocation.network, allocation.prefix_len, rir))
+        })
+        .collect())
+}
+
+/// Returns a table of the bogus IPv6 networks, given the allocations of the IANA registry.
+///
+/// Like [`is_bogon_v6`](crate::is_bogon_v6), everything outside of 2000::/3 is bogus, and so is
+/// global unicast space that isn't allocated to a regional internet registry or is reserved for a
+/// special purpose. The table has no IPv4 networks, insert them before installing the table with
+/// `set_runtime_table`.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{build_v6_table, parse_iana_v6_csv};
+///
+/// let csv = std::fs::read_to_string("bogon-data/ipv6-unicast-address-assignments.csv").unwrap();
+/// let table = build_v6_table(&parse_iana_v6_csv(&csv).unwrap());
+///
+/// assert!(table.contains("fe80::1".parse().unwrap()));
+/// assert!(table.contains("3fff::1".parse().unwrap()));
+/// assert!(!table.contains("2606:4700:4700::1111".parse().unwrap()));
+/// ```
+pub fn build_v6_table(allocations: &[(Ipv6Addr, u8, Rir)]) -> BogonTable {
+    let mut table = BogonTable::new();
+
+    // Everything is bogus except for the allocated global unicast space.
+    table.insert_v6_range(0, u128::MAX);
+    let allocations = allocations
+        .iter()
+        .map(|&(network, prefix_len, _)| (network.to_bits(), prefix_len))
+        .collect();
+    for (network, prefix_len) in merge_networks(allocations) {
+        let (start, end) = range(network, prefix_len);
+        // Allocations outside of global unicast space are ignored.
+        if start >> 125 == 1 {
+            table.remove_v6_range(start, end);
+        }
+    }
+
+    // Special-purpose networks are carved out of the allocations, with a few globally reachable
+    // exceptions carved back out of them.
+    for network in &V6_GLOBAL_BOGON_NETWORKS {
+        let (network, prefix_len) = network.to_v6();
+        let (start, end) = range(bits(network), prefix_len);
+        table.insert_v6_range(start, end);
+    }
+    for exception in &V6_BOGON_EXCEPTIONS {
+        let (exception, exception_len) = exception.to_v6();
+        let exception = range(bits(exception), exception_len);
+        for network in &V6_GLOBAL_BOGON_NETWORKS {
+            let (network, prefix_len) = network.to_v6();
+            let network = range(bits(network), prefix_len);
+            let (start, end) = (exception.0.max(network.0), exception.1.min(network.1));
+            if start <= end {
+                table.remove_v6_range(start, end);
+            }
+        }
+    }
+
+    table
+}
+
+/// Returns the integer of an IPv6 address.
+fn bits(address: IpAddr) -> u128 {
+    match address {
+        IpAddr::V6(address) => address.to_bits(),
+        IpAddr::V4(_) => unreachable!("IPv6 networks have IPv6 addresses"),
+    }
+}
+
+/// Returns the first and last address of a network.
+fn range(network: u128, prefix_len: u8) -> (u128, u128) {
+    let host = u128::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+    (network & !host, network | host)
+}
@@ -0,0 +1,432 @@
+use core::net::{IpAddr, Ipv6Addr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use bogon_data::{
+    iana_csv::{merge_networks, parse_address_space, parse_special_registry, rir_allocations},
+    ranges::aggregate,
+};
+
+use crate::{
+    build_v6_table,
+    ipv6_unicast_address_allocations::{
+        V6_ALLOCATION_RANGES, V6_REGISTRY_PREFIXES, V6_REGISTRY_RIRS, V6_WIDE_ALLOCATIONS,
+    },
+    is_bogon_v6, parse_iana_v6_csv, CsvError, Rir,
+};
+
+const BUNDLED: &str = include_str!("../bogon-data/ipv6-unicast-address-assignments.csv");
+
+const HEADER: &str = "Prefix,Designation,Date,WHOIS,RDAP,Status,Note\n";
+
+fn net(network: &str, prefix_len: u8) -> (u128, u8) {
+    (network.parse::<Ipv6Addr>().unwrap().to_bits(), prefix_len)
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn bundled_rows_match_build_script() {
+    let allocations = parse_iana_v6_csv(BUNDLED).unwrap();
+    let expected = V6_REGISTRY_PREFIXES
+        .iter()
+        .zip(V6_REGISTRY_RIRS)
+        .map(|(network, rir)| match network.to_v6() {
+            (IpAddr::V6(network), prefix_len) => (network, prefix_len, rir),
+            (IpAddr::V4(_), _) => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(allocations, expected);
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn bundled_merge_matches_build_script() {
+    let allocations = parse_iana_v6_csv(BUNDLED).unwrap();
+    let merged = merge_networks(
+        allocations
+            .iter()
+            .map(|&(network, prefix_len, _)| (network.to_bits(), prefix_len))
+            .collect(),
+    );
+    let ranges = V6_ALLOCATION_RANGES
+        .iter()
+        .map(|&(first, last)| {
+            (
+                u128::from(first) << 96,
+                u128::from(last) << 96 | u128::MAX >> 32,
+            )
+        })
+        .chain(
+            V6_WIDE_ALLOCATIONS
+                .iter()
+                .map(|network| (network.network(), network.broadcast())),
+        );
+    assert_eq!(merged, aggregate(ranges, 128));
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn bundled_table_matches_is_bogon_v6() {
+    let table = build_v6_table(&parse_iana_v6_csv(BUNDLED).unwrap());
+
+    // Every range edge, and its neighbors, is where the two are most likely to disagree.
+    let edges = table
+        .v6_ranges()
+        .flat_map(|(start, end)| [start.to_bits(), end.to_bits()])
+        .flat_map(|edge| [edge.wrapping_sub(1), edge, edge.wrapping_add(1)]);
+    for ip in edges.map(Ipv6Addr::from_bits) {
+        assert_eq!(table.contains_v6(ip), is_bogon_v6(ip), "{ip}");
+    }
+
+    let mut rng = StdRng::seed_from_u64(49);
+    for _ in 0..100_000 {
+        // Most of the interesting space is in 2000::/3.
+        let bits: u128 = rng.gen();
+        let bits = if rng.gen() {
+            bits >> 3 | 1 << 125
+        } else {
+            bits
+        };
+        let ip = Ipv6Addr::from_bits(bits);
+        assert_eq!(table.contains_v6(ip), is_bogon_v6(ip), "{ip}");
+    }
+}
+
+#[test]
+fn table_has_no_ipv4() {
+    let table = build_v6_table(&[]);
+    assert_eq!(table.v4_ranges().count(), 0);
+    assert!(table.contains("2606:4700::1".parse().unwrap()));
+    assert!(!table.contains("10.0.0.1".parse().unwrap()));
+}
+
+#[test]
+fn allocations_outside_global_unicast_are_ignored() {
+    let table = build_v6_table(&[("fe80::".parse().unwrap(), 10, Rir::Arin)]);
+    assert!(table.contains("fe80::1".parse().unwrap()));
+}
+
+#[test]
+fn merge_adjacent_networks() {
+    assert_eq!(
+        merge_networks(vec![net("2001:600::", 23), net("2001:400::", 23)]),
+        [net("2001:400::", 22)]
+    );
+    assert_eq!(
+        merge_networks(vec![
+            net("2400::", 12),
+            net("2400::", 13),
+            net("2410::", 12)
+        ]),
+        [net("2400::", 11)]
+    );
+    assert_eq!(
+        merge_networks(vec![net("2001:200::", 23), net("2001:800::", 22)]),
+        [net("2001:200::", 23), net("2001:800::", 22)]
+    );
+    assert_eq!(merge_networks(Vec::new()), []);
+}
+
+#[test]
+fn merge_unaligned_ranges() {
+    // 2001:200::/23 and 2001:400::/23 are adjacent, but not the halves of a /22.
+    assert_eq!(
+        merge_networks(vec![net("2001:200::", 23), net("2001:400::", 23)]),
+        [net("2001:200::", 23), net("2001:400::", 23)]
+    );
+    assert_eq!(
+        merge_networks(vec![net("2600::", 12), net("2610::", 23)]),
+        [net("2600::", 12), net("2610::", 23)]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "download"))]
+fn merged_allocations_stay_within_the_registry() {
+    // Merging these used to cover unallocated space next to them.
+    for ip in ["2001:1ff::1", "2610:200::1", "261f::1"] {
+        assert!(is_bogon_v6(ip.parse().unwrap()), "{ip}");
+    }
+    for ip in ["2001:200::1", "2610:1ff::1"] {
+        assert!(!is_bogon_v6(ip.parse().unwrap()), "{ip}");
+    }
+}
+
+#[test]
+fn parse_quoted_fields() {
+    let csv = [
+        HEADER,
+        "2001:0200::/23,APNIC,1999-07-01,whois.apnic.net,\"https://rdap.apnic.net/\n",
+        "http://rdap.apnic.net/\",ALLOCATED,\"A \"\"note\"\", with a comma\"\r\n",
+        "2c00:0000::/12,AFRINIC,2006-10-03,whois.afrinic.net,https://rdap.afrinic.net/rdap/,ALLOCATED,\r\n",
+        "\n",
+    ]
+    .concat();
+    assert_eq!(
+        parse_iana_v6_csv(&csv).unwrap(),
+        [
+            ("2001:200::".parse().unwrap(), 23, Rir::Apnic),
+            ("2c00::".parse().unwrap(), 12, Rir::Afrinic),
+        ]
+    );
+}
+
+#[test]
+fn parse_skips_non_rir_rows() {
+    let csv = [
+        HEADER,
+        "2001:0000::/23,IANA,1999-07-01,whois.iana.org,,ALLOCATED,\n",
+        "2001:0200::/23,APNIC,1999-07-01,whois.apnic.net,,RESERVED,\n",
+        "2001:0400::/23,ARIN,1999-07-01,whois.arin.net,,ALLOCATED,\n",
+    ]
+    .concat();
+    assert_eq!(
+        parse_iana_v6_csv(&csv).unwrap(),
+        [("2001:400::".parse().unwrap(), 23, Rir::Arin)]
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let error = |csv: &str| {
+        let error = parse_iana_v6_csv(csv).unwrap_err();
+        (error.line(), error.to_string())
+    };
+
+    assert_eq!(error(""), (1, "line 1: missing header".to_string()));
+    assert_eq!(
+        error("Prefix,Designation,Date,WHOIS,RDAP\n"),
+        (1, "line 1: missing column `Status`".to_string())
+    );
+    assert_eq!(
+        error(&[HEADER, "2001:0200::/23,APNIC,1999-07-01\n"].concat()),
+        (2, "line 2: expected 7 fields, found 3".to_string())
+    );
+    assert_eq!(
+        error(&[HEADER, "2001:0200::/129,APNIC,1999-07-01,,,ALLOCATED,\n"].concat()),
+        (2, "line 2: invalid prefix `2001:0200::/129`".to_string())
+    );
+    assert_eq!(
+        error(&[HEADER, "10.0.0.0/8,APNIC,1999-07-01,,,ALLOCATED,\n"].concat()),
+        (2, "line 2: invalid prefix `10.0.0.0/8`".to_string())
+    );
+
+    // Line breaks inside of quoted fields are counted.
+    let csv = [
+        HEADER,
+        "2001:0200::/23,APNIC,1999-07-01,,\"a\nb\",ALLOCATED,\n",
+        "2001:0400::/23,ARIN,1999-07-01,,,ALLOCATED,\"unterminated\n",
+    ]
+    .concat();
+    assert_eq!(
+        error(&csv),
+        (4, "line 4: unterminated quoted field".to_string())
+    );
+}
+
+#[test]
+fn parse_special_registry_rows() {
+    let csv = [
+        "Address Block,Name,RFC,Allocation Date,Termination Date,Source,Destination,Forwardable,Globally Reachable,Reserved-by-Protocol\r\n",
+        "127.0.0.0/8,Loopback,[RFC1122],1981-09,N/A,False [1],False [1],False [1],False [1],True\r\n",
+        "\"192.0.0.170/32, 192.0.0.171/32\",NAT64/DNS64 Discovery,[RFC7050],2013-02,N/A,False,False,False,False,True\r\n",
+        "192.0.0.0/24 [2],IETF Protocol Assignments,[RFC6890],2010-01,N/A,False,False,False,False,False\r\n",
+        "192.88.99.0/24,Deprecated (6to4 Relay Anycast),[RFC7526],2001-06,2015-03,,,,,\r\n",
+        "2001:3::/32,AMT,[RFC7450],2014-12,N/A,True,True,True,True,N/A\r\n",
+    ]
+    .concat();
+    let rows = parse_special_registry(&csv).unwrap();
+
+    let networks = rows
+        .iter()
+        .map(|row| (row.networks.clone(), row.globally_reachable))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        networks,
+        [
+            (vec![("127.0.0.0".parse().unwrap(), 8)], Some(false)),
+            (
+                vec![
+                    ("192.0.0.170".parse().unwrap(), 32),
+                    ("192.0.0.171".parse().unwrap(), 32)
+                ],
+                Some(false)
+            ),
+            (vec![("192.0.0.0".parse().unwrap(), 24)], Some(false)),
+            (vec![("192.88.99.0".parse().unwrap(), 24)], None),
+            (vec![("2001:3::".parse().unwrap(), 32)], Some(true)),
+        ]
+    );
+    assert_eq!(rows[0].source, Some(false));
+    assert_eq!(rows[4].forwardable, Some(true));
+    assert_eq!(
+        rows.iter().map(|row| row.terminated).collect::<Vec<_>>(),
+        [false, false, false, true, false]
+    );
+}
+
+/// Returns the messages of a list of errors.
+fn messages(errors: Vec<CsvError>) -> Vec<String> {
+    errors.iter().map(CsvError::to_string).collect()
+}
+
+#[test]
+fn parse_special_registry_errors() {
+    let header =
+        "Address Block,Name,Termination Date,Source,Destination,Forwardable,Globally Reachable\n";
+    let errors = |rows: &[&str]| {
+        messages(parse_special_registry(&[&[header], rows].concat().concat()).unwrap_err())
+    };
+
+    assert_eq!(
+        errors(&["10.0.0.0/33,Private-Use,N/A,True,True,True,False\n"]),
+        ["line 2: invalid prefix `10.0.0.0/33`"]
+    );
+    assert_eq!(
+        errors(&["10.0.0.0/8,Private-Use,N/A,True,True,Yes,False\n"]),
+        ["line 2: invalid value `Yes` in column `Forwardable`"]
+    );
+
+    // Every malformed record is reported, not only the first.
+    assert_eq!(
+        errors(&[
+            "10.0.0.0/33,Private-Use,N/A,True,True,True,False\n",
+            "127.0.0.0/8,Loopback,N/A,False,False,False,False\n",
+            "169.254.0.0/16,Link Local,N/A,True,True,False,False,True\n",
+        ]),
+        [
+            "line 2: invalid prefix `10.0.0.0/33`",
+            "line 4: expected 7 fields, found 8",
+        ]
+    );
+
+    // So is every missing column, like after the registry renamed its header.
+    assert_eq!(
+        messages(
+            parse_special_registry("Address Block,Name,Termination,Source,Destination\n")
+                .unwrap_err()
+        ),
+        [
+            "line 1: missing column `Termination Date`",
+            "line 1: missing column `Forwardable`",
+            "line 1: missing column `Globally Reachable`",
+        ]
+    );
+}
+
+#[test]
+fn parse_ignores_byte_order_mark() {
+    let csv = [
+        "\u{feff}",
+        HEADER,
+        "2001:0400::/23,ARIN,1999-07-01,whois.arin.net,,ALLOCATED,\n",
+    ]
+    .concat();
+    assert_eq!(
+        parse_iana_v6_csv(&csv).unwrap(),
+        [("2001:400::".parse().unwrap(), 23, Rir::Arin)]
+    );
+}
+
+#[test]
+fn parse_skips_unknown_status_with_warning() {
+    let csv = [
+        "Prefix,Designation,Date,WHOIS,RDAP,Status [1],Note\n",
+        "2001:0200::/23,APNIC,1999-07-01,,,ALLOCATED,\n",
+        "2001:0400::/23,ARIN,1999-07-01,,,ALLOCATED-PENDING,\n",
+    ]
+    .concat();
+    let mut warnings = Vec::new();
+    let allocations = rir_allocations(&csv, &mut warnings).unwrap();
+
+    assert_eq!(
+        allocations
+            .iter()
+            .map(|allocation| allocation.network)
+            .collect::<Vec<_>>(),
+        ["2001:200::".parse::<Ipv6Addr>().unwrap()]
+    );
+    assert_eq!(
+        messages(warnings),
+        ["line 3: unknown value `ALLOCATED-PENDING` in column `Status [1]`, skipping the row"]
+    );
+}
+
+/// Returns the bundled IPv4 address space registry, with the records of some /8s replaced.
+fn address_space(replace: &[(usize, &str)]) -> String {
+    // Records with quoted line breaks span several lines, every other line starts a record.
+    let mut records: Vec<String> = Vec::new();
+    for line in include_str!("../bogon-data/ipv4-address-space.csv").split_inclusive('\n') {
+        let starts_record = line.get(3..6) == Some("/8,") || records.is_empty();
+        match records.last_mut() {
+            Some(record) if !starts_record => record.push_str(line),
+            _ => records.push(line.to_string()),
+        }
+    }
+    for &(octet, record) in replace {
+        records[octet + 1] = record.to_string();
+    }
+    records.concat()
+}
+
+#[test]
+fn parse_address_space_rows() {
+    let mut warnings = Vec::new();
+    let blocks = parse_address_space(&address_space(&[]), &mut warnings).unwrap();
+
+    assert_eq!(blocks.len(), 256);
+    assert!(warnings.is_empty());
+    let block = blocks[1].as_ref().unwrap();
+    assert_eq!(block.designation, "APNIC");
+    assert_eq!(block.status, "ALLOCATED");
+    assert_eq!(blocks[10].as_ref().unwrap().status, "RESERVED");
+}
+
+#[test]
+fn parse_address_space_skips_unknown_status() {
+    let csv = address_space(&[(1, "001/8,APNIC,2010-01,whois.apnic.net,,TRANSFERRED,\n")]);
+    let mut warnings = Vec::new();
+    let blocks = parse_address_space(&csv, &mut warnings).unwrap();
+
+    assert_eq!(blocks.len(), 256);
+    assert_eq!(blocks[1], None);
+    assert!(blocks[2].is_some());
+    assert_eq!(
+        messages(warnings),
+        ["line 3: unknown value `TRANSFERRED` in column `Status [1]`, skipping the row"]
+    );
+}
+
+#[test]
+fn parse_address_space_errors() {
+    let errors = |replace: &[(usize, &str)]| {
+        messages(parse_address_space(&address_space(replace), &mut Vec::new()).unwrap_err())
+    };
+
+    assert_eq!(errors(&[(2, "")]), ["line 4: missing row for `002/8`"]);
+    assert_eq!(
+        errors(&[
+            (1, "1/8,APNIC,2010-01,,,ALLOCATED,\n"),
+            (3, "003/8,Apple,1994-05\n")
+        ]),
+        [
+            "line 3: invalid prefix `1/8`",
+            "line 5: expected 7 fields, found 3",
+        ]
+    );
+    assert_eq!(errors(&[(255, "")]), ["line 373: missing row for `255/8`"]);
+    assert_eq!(
+        errors(&[(3, "004/8,Administered by ARIN,1992-12,,,LEGACY,\n")]),
+        [
+            "line 5: missing row for `003/8`",
+            "line 6: duplicate or out of order row for `004/8`",
+        ]
+    );
+    assert_eq!(
+        messages(
+            parse_address_space("Prefix,Designation,Date,WHOIS,RDAP,Note\n", &mut Vec::new())
+                .unwrap_err()
+        ),
+        ["line 1: missing column `Status`"]
+    );
+}
@@ -0,0 +1,484 @@
+//! Listing the bogus address space as sorted ranges of addresses.
+//!
+//! The ranges are found by sweeping the boundaries of the networks in the tables, the only
+//! addresses where a verdict can change, and checking the verdict of each stretch between two
+//! boundaries once. Stretches with the same verdict are merged as they're swept, so nothing is
+//! allocated, and counting the addresses of the ranges never counts an address twice.
+
+use core::{
+    iter::{self, FusedIterator},
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::RangeInclusive,
+};
+
+use crate::{is_bogon_v4_bits, is_bogon_v6_bits};
+
+/// Returns the first and one past the last address of a network of `bits`-bit addresses, or `None`
+/// for one past the last address of the IPv6 space.
+fn bounds(network: u128, prefix_len: u8, bits: u32) -> [Option<u128>; 2] {
+    let size = 1u128.checked_shl(bits - u32::from(prefix_len));
+    [
+        Some(network),
+        size.and_then(|size| network.checked_add(size)),
+    ]
+}
+
+/// Returns the addresses where the IPv4 verdict may change: the bounds of the bogus networks and of
+/// the exceptions inside of them.
+fn v4_boundaries() -> impl Iterator<Item = u128> {
+    bogon_data::V4_BOGON_NETWORKS
+        .into_iter()
+        .chain(bogon_data::V4_BOGON_EXCEPTIONS)
+        .flat_map(|(network, prefix_len)| bounds(network.into(), prefix_len, 32))
+        .flatten()
+        .filter(|&boundary| boundary <= u32::MAX.into())
+}
+
+/// Returns the addresses where the IPv6 verdict may change: the bounds of 2000::/3, of the
+/// unallocated networks inside of it, and of the bogus networks and the exceptions inside of them.
+fn v6_boundaries() -> impl Iterator<Item = u128> {
+    bogon_data::V6_UNALLOCATED
+        .into_iter()
+        .chain(bogon_data::V6_GLOBAL_BOGON_NETWORKS)
+        .chain(bogon_data::V6_BOGON_EXCEPTIONS)
+        .chain([(0x2000 << 112, 3)])
+        .flat_map(|(network, prefix_len)| bounds(network, prefix_len, 128))
+        .flatten()
+}
+
+/// Returns the next range of bogus addresses at or after `cursor`, and moves `cursor` past it.
+///
+/// The verdict is the same for every address between two consecutive boundaries, so checking the
+/// first address of each stretch is enough.
+fn next_interval<I: Iterator<Item = u128>>(
+    cursor: &mut Option<u128>,
+    last: u128,
+    boundaries: impl Fn() -> I,
+    is_bogon: impl Fn(u128) -> bool,
+) -> Option<RangeInclusive<u128>> {
+    let next_boundary = |address: u128| boundaries().filter(|&b| b > address).min();
+    loop {
+        let start = (*cursor)?;
+        let mut end = next_boundary(start);
+        if is_bogon(start) {
+            while let Some(boundary) = end.filter(|&boundary| is_bogon(boundary)) {
+                end = next_boundary(boundary);
+            }
+            *cursor = end;
+            return Some(start..=end.map_or(last, |end| end - 1));
+        }
+        *cursor = end;
+    }
+}
+
+/// Returns the bogus IPv4 address space as sorted, non-overlapping ranges of addresses, with no
+/// two ranges adjacent.
+///
+/// An address is inside of one of the ranges exactly when [`is_bogon_v4`](crate::is_bogon_v4)
+/// returns `true` for it: the bogus networks with the globally reachable exceptions inside of them
+/// cut out. Pass the ranges to [`range_to_cidrs_v4`](crate::range_to_cidrs_v4) for networks
+/// instead. Like [`is_bogon_v4_bits`], only the compiled-in tables are consulted: the overrides of
+/// the `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::v4_bogon_intervals;
+///
+/// let first = v4_bogon_intervals().next().unwrap();
+/// assert_eq!(first, Ipv4Addr::new(0, 0, 0, 0)..=Ipv4Addr::new(0, 255, 255, 255));
+///
+/// let loopback = Ipv4Addr::new(127, 0, 0, 1);
+/// assert!(v4_bogon_intervals().any(|range| range.contains(&loopback)));
+/// ```
+pub fn v4_bogon_intervals() -> impl Iterator<Item = RangeInclusive<Ipv4Addr>> {
+    let mut cursor = Some(0);
+    iter::from_fn(move || {
+        let range = next_interval(&mut cursor, u32::MAX.into(), v4_boundaries, |address| {
+            is_bogon_v4_bits(address as u32)
+        })?;
+        Some(Ipv4Addr::from_bits(*range.start() as u32)..=Ipv4Addr::from_bits(*range.end() as u32))
+    })
+}
+
+/// Returns the bogus IPv6 address space as sorted, non-overlapping ranges of addresses, with no
+/// two ranges adjacent.
+///
+/// An address is inside of one of the ranges exactly when [`is_bogon_v6`](crate::is_bogon_v6)
+/// returns `true` for it: everything outside of `2000::/3`, and inside of it the space that isn't
+/// allocated to a regional internet registry and the bogus networks, with the globally reachable
+/// exceptions inside of them cut out. Pass the ranges to
+/// [`range_to_cidrs_v6`](crate::range_to_cidrs_v6) for networks instead. Like
+/// [`is_bogon_v6_bits`], only the compiled-in tables are consulted: the overrides of the
+/// `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::v6_bogon_intervals;
+///
+/// let first = v6_bogon_intervals().next().unwrap();
+/// assert_eq!(*first.start(), Ipv6Addr::UNSPECIFIED);
+///
+/// let last = v6_bogon_intervals().last().unwrap();
+/// assert_eq!(*last.end(), Ipv6Addr::from_bits(u128::MAX));
+/// ```
+pub fn v6_bogon_intervals() -> impl Iterator<Item = RangeInclusive<Ipv6Addr>> {
+    let mut cursor = Some(0);
+    iter::from_fn(move || {
+        let range = next_interval(&mut cursor, u128::MAX, v6_boundaries, is_bogon_v6_bits)?;
+        Some(Ipv6Addr::from_bits(*range.start())..=Ipv6Addr::from_bits(*range.end()))
+    })
+}
+
+/// Returns the number of bogus IPv4 addresses, the addresses of [`v4_bogon_intervals`].
+///
+/// Networks nested inside of each other are counted once, and the globally reachable exceptions
+/// aren't counted. The count is computed from the tables on every call, which takes a few
+/// microseconds.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{bogon_address_count_v4, routable_address_count_v4};
+///
+/// assert!(bogon_address_count_v4() > 1 << 28);
+/// assert_eq!(bogon_address_count_v4() + routable_address_count_v4(), 1 << 32);
+/// ```
+pub fn bogon_address_count_v4() -> u64 {
+    v4_bogon_intervals()
+        .map(|range| u64::from(range.end().to_bits() - range.start().to_bits()) + 1)
+        .sum()
+}
+
+/// Returns the number of IPv4 addresses that aren't bogus, out of the 2<sup>32</sup> addresses.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::routable_address_count_v4;
+///
+/// assert!(routable_address_count_v4() > 3_000_000_000);
+/// ```
+pub fn routable_address_count_v4() -> u64 {
+    (1 << 32) - bogon_address_count_v4()
+}
+
+/// Returns the fraction of the IPv4 address space that is bogus, between 0 and 1.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::bogon_fraction_v4;
+///
+/// assert!((0.05..0.2).contains(&bogon_fraction_v4()));
+/// ```
+pub fn bogon_fraction_v4() -> f64 {
+    bogon_address_count_v4() as f64 / (1u64 << 32) as f64
+}
+
+/// Returns the number of bogus IPv6 addresses, the addresses of [`v6_bogon_intervals`].
+///
+/// Networks nested inside of each other are counted once, and the globally reachable exceptions
+/// aren't counted. The count is computed from the tables on every call, which takes a few tens of
+/// microseconds.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{bogon_address_count_v6, routable_address_count_v6};
+///
+/// // Everything outside of 2000::/3 is bogus.
+/// assert!(bogon_address_count_v6() > 7 << 125);
+/// assert_eq!(bogon_address_count_v6().wrapping_add(routable_address_count_v6()), 0);
+/// ```
+pub fn bogon_address_count_v6() -> u128 {
+    v6_bogon_intervals()
+        .map(|range| range.end().to_bits() - range.start().to_bits() + 1)
+        .sum()
+}
+
+/// Returns the number of IPv6 addresses that aren't bogus, out of the 2<sup>128</sup> addresses.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::routable_address_count_v6;
+///
+/// // The regional internet registries hold a few /12s of 2000::/3.
+/// assert!(routable_address_count_v6() > 1 << 116);
+/// assert!(routable_address_count_v6() < 1 << 125);
+/// ```
+pub fn routable_address_count_v6() -> u128 {
+    // The count of all 2^128 addresses doesn't fit in a `u128`.
+    u128::MAX - bogon_address_count_v6() + 1
+}
+
+/// Returns the fraction of the IPv6 address space that is bogus, between 0 and 1.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::bogon_fraction_v6;
+///
+/// assert!((0.875..1.0).contains(&bogon_fraction_v6()));
+/// ```
+pub fn bogon_fraction_v6() -> f64 {
+    // `u128::MAX` rounds up to exactly 2^128 as an `f64`.
+    bogon_address_count_v6() as f64 / u128::MAX as f64
+}
+
+/// Returns the `n`th bogus IPv4 address in numeric order, counting from zero, or `None` if there
+/// are only `n` or fewer, see [`bogon_address_count_v4`].
+///
+/// Drawing `n` uniformly from `0..bogon_address_count_v4()` samples the bogus addresses uniformly,
+/// without rejecting good addresses. [`bogon_rank_v4`] is the inverse. The ranges of
+/// [`v4_bogon_intervals`] are skipped by their lengths, so `n` is found without counting every
+/// address before it.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{bogon_address_count_v4, nth_bogon_v4};
+///
+/// assert_eq!(nth_bogon_v4(0), Some(Ipv4Addr::new(0, 0, 0, 0)));
+/// // 0.0.0.0/8 is followed by 10.0.0.0/8.
+/// assert_eq!(nth_bogon_v4(1 << 24), Some(Ipv4Addr::new(10, 0, 0, 0)));
+/// assert_eq!(nth_bogon_v4(bogon_address_count_v4()), None);
+/// ```
+pub fn nth_bogon_v4(mut n: u64) -> Option<Ipv4Addr> {
+    v4_bogon_intervals().find_map(|range| {
+        let (start, end) = (range.start().to_bits(), range.end().to_bits());
+        let len = u64::from(end - start) + 1;
+        if n < len {
+            Some(Ipv4Addr::from_bits(start + n as u32))
+        } else {
+            n -= len;
+            None
+        }
+    })
+}
+
+/// Returns the index of a bogus IPv4 address among the bogus addresses in numeric order, or `None`
+/// if the address is good.
+///
+/// The inverse of [`nth_bogon_v4`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{bogon_rank_v4, nth_bogon_v4};
+///
+/// let ip = Ipv4Addr::new(10, 0, 0, 1);
+/// assert_eq!(bogon_rank_v4(ip), Some((1 << 24) + 1));
+/// assert_eq!(nth_bogon_v4(bogon_rank_v4(ip).unwrap()), Some(ip));
+/// assert_eq!(bogon_rank_v4(Ipv4Addr::new(8, 8, 8, 8)), None);
+/// ```
+pub fn bogon_rank_v4(ip_address: Ipv4Addr) -> Option<u64> {
+    let bits = ip_address.to_bits();
+    let mut rank = 0;
+    for range in v4_bogon_intervals() {
+        let (start, end) = (range.start().to_bits(), range.end().to_bits());
+        if bits < start {
+            return None;
+        }
+        if bits <= end {
+            return Some(rank + u64::from(bits - start));
+        }
+        rank += u64::from(end - start) + 1;
+    }
+    None
+}
+
+/// Returns the `n`th bogus IPv6 address in numeric order, counting from zero, or `None` if there
+/// are only `n` or fewer, see [`bogon_address_count_v6`].
+///
+/// Drawing `n` uniformly from `0..bogon_address_count_v6()` samples the bogus addresses uniformly,
+/// without rejecting good addresses. [`bogon_rank_v6`] is the inverse.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::{bogon_address_count_v6, nth_bogon_v6};
+///
+/// assert_eq!(nth_bogon_v6(1), Some(Ipv6Addr::LOCALHOST));
+/// assert_eq!(nth_bogon_v6(bogon_address_count_v6()), None);
+/// ```
+pub fn nth_bogon_v6(mut n: u128) -> Option<Ipv6Addr> {
+    v6_bogon_intervals().find_map(|range| {
+        let (start, end) = (range.start().to_bits(), range.end().to_bits());
+        let len = end - start + 1;
+        if n < len {
+            Some(Ipv6Addr::from_bits(start + n))
+        } else {
+            n -= len;
+            None
+        }
+    })
+}
+
+/// Returns the index of a bogus IPv6 address among the bogus addresses in numeric order, or `None`
+/// if the address is good.
+///
+/// The inverse of [`nth_bogon_v6`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::{bogon_rank_v6, nth_bogon_v6};
+///
+/// assert_eq!(bogon_rank_v6(Ipv6Addr::LOCALHOST), Some(1));
+/// let multicast: Ipv6Addr = "ff02::1".parse().unwrap();
+/// assert_eq!(nth_bogon_v6(bogon_rank_v6(multicast).unwrap()), Some(multicast));
+///
+/// let cloudflare = Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111);
+/// assert_eq!(bogon_rank_v6(cloudflare), None);
+/// ```
+pub fn bogon_rank_v6(ip_address: Ipv6Addr) -> Option<u128> {
+    let bits = ip_address.to_bits();
+    let mut rank = 0;
+    for range in v6_bogon_intervals() {
+        let (start, end) = (range.start().to_bits(), range.end().to_bits());
+        if bits < start {
+            return None;
+        }
+        if bits <= end {
+            return Some(rank + (bits - start));
+        }
+        rank += end - start + 1;
+    }
+    None
+}
+
+/// The most ranges [`v4_bogon_intervals`] can return: each exception splits at most one range in two.
+const MAX_V4_INTERVALS: usize =
+    bogon_data::V4_BOGON_NETWORKS.len() + bogon_data::V4_BOGON_EXCEPTIONS.len();
+
+/// Returns an iterator over every bogus IPv4 address, in numeric order.
+///
+/// The addresses are generated from the ranges of [`v4_bogon_intervals`] as they're iterated, so
+/// the hundreds of millions of them are never held in memory. The iterator knows its length,
+/// [`bogon_address_count_v4`] at the start, can be iterated from both ends, and skips whole ranges
+/// in [`Iterator::nth`] and [`DoubleEndedIterator::nth_back`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{bogon_address_count_v4, iter_bogon_v4_addresses};
+///
+/// let mut addresses = iter_bogon_v4_addresses();
+/// assert_eq!(addresses.len() as u64, bogon_address_count_v4());
+/// assert_eq!(addresses.next(), Some(Ipv4Addr::new(0, 0, 0, 0)));
+/// assert_eq!(addresses.next_back(), Some(Ipv4Addr::new(255, 255, 255, 255)));
+/// assert_eq!(addresses.nth((1 << 24) - 1), Some(Ipv4Addr::new(10, 0, 0, 0)));
+/// ```
+pub fn iter_bogon_v4_addresses() -> BogonAddressesV4 {
+    let mut ranges = [(0, 0); MAX_V4_INTERVALS];
+    let mut len = 0;
+    for range in v4_bogon_intervals() {
+        ranges[len] = (range.start().to_bits(), range.end().to_bits());
+        len += 1;
+    }
+    BogonAddressesV4 {
+        ranges,
+        front: 0,
+        back: len,
+        remaining: bogon_address_count_v4(),
+    }
+}
+
+/// An iterator over every bogus IPv4 address, in numeric order.
+///
+/// Returned by [`iter_bogon_v4_addresses`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BogonAddressesV4 {
+    /// The ranges left to iterate are `ranges[front..back]`, with the addresses already returned
+    /// cut off of their ends, so that no range ever runs past 255.255.255.255.
+    ranges: [(u32, u32); MAX_V4_INTERVALS],
+    front: usize,
+    back: usize,
+    remaining: u64,
+}
+
+impl Iterator for BogonAddressesV4 {
+    type Item = Ipv4Addr;
+
+    fn next(&mut self) -> Option<Ipv4Addr> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Ipv4Addr> {
+        while self.front < self.back {
+            let (start, end) = &mut self.ranges[self.front];
+            let len = u64::from(*end - *start) + 1;
+            if (n as u64) < len {
+                let ip = *start + n as u32;
+                self.remaining -= n as u64 + 1;
+                if ip == *end {
+                    self.front += 1;
+                } else {
+                    *start = ip + 1;
+                }
+                return Some(Ipv4Addr::from_bits(ip));
+            }
+            n -= len as usize;
+            self.remaining -= len;
+            self.front += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.remaining as usize
+    }
+
+    fn last(mut self) -> Option<Ipv4Addr> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for BogonAddressesV4 {
+    fn next_back(&mut self) -> Option<Ipv4Addr> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, mut n: usize) -> Option<Ipv4Addr> {
+        while self.front < self.back {
+            let (start, end) = &mut self.ranges[self.back - 1];
+            let len = u64::from(*end - *start) + 1;
+            if (n as u64) < len {
+                let ip = *end - n as u32;
+                self.remaining -= n as u64 + 1;
+                if ip == *start {
+                    self.back -= 1;
+                } else {
+                    *end = ip - 1;
+                }
+                return Some(Ipv4Addr::from_bits(ip));
+            }
+            n -= len as usize;
+            self.remaining -= len;
+            self.back -= 1;
+        }
+        None
+    }
+}
+
+impl ExactSizeIterator for BogonAddressesV4 {}
+
+impl FusedIterator for BogonAddressesV4 {}
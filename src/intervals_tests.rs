@@ -0,0 +1,437 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    bogon_address_count_v4, bogon_address_count_v6, bogon_fraction_v4, bogon_fraction_v6,
+    bogon_rank_v4, bogon_rank_v6, classify_v4, is_bogon_v4, is_bogon_v6, iter_bogon_v4_addresses,
+    nth_bogon_v4, nth_bogon_v6, routable_address_count_v4, routable_address_count_v6,
+    v4_bogon_intervals, v4_bogon_networks, v6_bogon_intervals,
+};
+
+/// Returns the intervals as integers, asserting that they are sorted, disjoint, and not adjacent.
+fn assert_merged(intervals: impl Iterator<Item = (u128, u128)>) -> Vec<(u128, u128)> {
+    let intervals: Vec<_> = intervals.collect();
+    for &(start, end) in &intervals {
+        assert!(start <= end, "{start:#x}..={end:#x} is empty");
+    }
+    for pair in intervals.windows(2) {
+        let ((_, end), (start, _)) = (pair[0], pair[1]);
+        assert!(end + 1 < start, "{end:#x} and {start:#x} aren't apart");
+    }
+    intervals
+}
+
+fn contains(intervals: &[(u128, u128)], address: u128) -> bool {
+    let index = intervals.partition_point(|&(_, end)| end < address);
+    intervals
+        .get(index)
+        .is_some_and(|&(start, _)| start <= address)
+}
+
+fn v4_intervals() -> Vec<(u128, u128)> {
+    assert_merged(
+        v4_bogon_intervals()
+            .map(|range| (range.start().to_bits().into(), range.end().to_bits().into())),
+    )
+}
+
+fn v6_intervals() -> Vec<(u128, u128)> {
+    assert_merged(
+        v6_bogon_intervals().map(|range| (range.start().to_bits(), range.end().to_bits())),
+    )
+}
+
+#[test]
+fn v4_boundaries_match_is_bogon() {
+    let intervals = v4_intervals();
+    assert!(!intervals.is_empty());
+    for &(start, end) in &intervals {
+        for address in [
+            start.checked_sub(1),
+            Some(start),
+            Some(end),
+            end.checked_add(1),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|&address| address <= u32::MAX.into())
+        {
+            let ip = Ipv4Addr::from_bits(address as u32);
+            assert_eq!(contains(&intervals, address), is_bogon_v4(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn v6_boundaries_match_is_bogon() {
+    let intervals = v6_intervals();
+    assert!(!intervals.is_empty());
+    for &(start, end) in &intervals {
+        for address in [
+            start.checked_sub(1),
+            Some(start),
+            Some(end),
+            end.checked_add(1),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let ip = Ipv6Addr::from_bits(address);
+            assert_eq!(contains(&intervals, address), is_bogon_v6(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn random_samples_match_is_bogon() {
+    let (v4, v6) = (v4_intervals(), v6_intervals());
+    let mut rng = StdRng::seed_from_u64(95);
+    for _ in 0..100_000 {
+        let ip = Ipv4Addr::from_bits(rng.gen());
+        assert_eq!(contains(&v4, ip.to_bits().into()), is_bogon_v4(ip), "{ip}");
+
+        // Most random IPv6 addresses are outside of 2000::/3, so half of them are drawn inside.
+        let bits: u128 = rng.gen();
+        let bits = if rng.gen() {
+            bits
+        } else {
+            0x2000 << 112 | bits >> 3
+        };
+        let ip = Ipv6Addr::from_bits(bits);
+        assert_eq!(contains(&v6, bits), is_bogon_v6(ip), "{ip}");
+    }
+}
+
+#[test]
+fn well_known_intervals() {
+    let v4: Vec<_> = v4_bogon_intervals().collect();
+    assert!(v4.contains(&(Ipv4Addr::new(127, 0, 0, 0)..=Ipv4Addr::new(127, 255, 255, 255))));
+    assert!(v4.contains(&(Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 255, 255, 255))));
+
+    let v6: Vec<_> = v6_bogon_intervals().collect();
+    let below_global = Ipv6Addr::from_bits((0x2000 << 112) - 1);
+    assert_eq!(*v6[0].start(), Ipv6Addr::UNSPECIFIED);
+    assert!(v6[0].contains(&below_global));
+    assert_eq!(*v6.last().unwrap().end(), Ipv6Addr::from_bits(u128::MAX));
+}
+
+#[test]
+fn intervals_are_fused() {
+    let mut v4 = v4_bogon_intervals();
+    v4.by_ref().for_each(drop);
+    assert_eq!(v4.next(), None);
+
+    let mut v6 = v6_bogon_intervals();
+    v6.by_ref().for_each(drop);
+    assert_eq!(v6.next(), None);
+}
+
+#[test]
+fn v4_address_count() {
+    let mut expected = [
+        1 << 24, // 0.0.0.0/8
+        1 << 24, // 10.0.0.0/8
+        1 << 22, // 100.64.0.0/10
+        1 << 24, // 127.0.0.0/8
+        1 << 16, // 169.254.0.0/16
+        1 << 20, // 172.16.0.0/12
+        256 - 2, // 192.0.0.0/24, without 192.0.0.9 and 192.0.0.10
+        256,     // 192.0.2.0/24
+        256,     // 192.88.99.0/24
+        1 << 16, // 192.168.0.0/16
+        1 << 17, // 198.18.0.0/15
+        256,     // 198.51.100.0/24
+        256,     // 203.0.113.0/24
+        1 << 28, // 224.0.0.0/4
+        1 << 28, // 240.0.0.0/4, with 255.255.255.255 inside of it
+    ]
+    .iter()
+    .sum::<u64>();
+    assert_eq!(expected, 592_708_862);
+    if cfg!(feature = "allow-cgnat") {
+        expected -= 1 << 22;
+    }
+    if cfg!(feature = "allow-benchmarking") {
+        expected -= 1 << 17;
+    }
+    if cfg!(feature = "allow-reserved-240-4") {
+        // Only 255.255.255.255 is left.
+        expected -= (1 << 28) - 1;
+    }
+
+    assert_eq!(bogon_address_count_v4(), expected);
+    assert_eq!(routable_address_count_v4(), (1 << 32) - expected);
+    assert_eq!(bogon_fraction_v4(), expected as f64 / 4_294_967_296.0);
+}
+
+#[test]
+fn v6_address_count() {
+    let bogons = bogon_address_count_v6();
+    let routable = routable_address_count_v6();
+    assert_eq!(bogons.wrapping_add(routable), 0);
+
+    // Everything outside of 2000::/3 is bogus, and most of 2000::/3 isn't allocated yet.
+    assert!(bogons > 7 << 125);
+    assert!(routable < 1 << 125);
+    assert!(routable > 1 << 116);
+    let fraction = bogon_fraction_v6();
+    assert!((0.875..1.0).contains(&fraction), "{fraction}");
+    assert!(fraction > 0.98, "{fraction}");
+
+    let gaps = v6_bogon_intervals()
+        .zip(v6_bogon_intervals().skip(1))
+        .map(|(bogons, next)| next.start().to_bits() - bogons.end().to_bits() - 1)
+        .sum::<u128>();
+    assert_eq!(routable, gaps);
+}
+
+#[test]
+fn nth_and_rank_are_inverses() {
+    let (count_v4, count_v6) = (bogon_address_count_v4(), bogon_address_count_v6());
+    let mut rng = StdRng::seed_from_u64(98);
+    // Every call sweeps the tables, so fewer samples than elsewhere.
+    for _ in 0..1_000 {
+        let n = rng.gen_range(0..count_v4);
+        let ip = nth_bogon_v4(n).unwrap();
+        assert!(is_bogon_v4(ip), "{ip}");
+        assert_eq!(bogon_rank_v4(ip), Some(n), "{ip}");
+
+        let ip = Ipv4Addr::from_bits(rng.gen());
+        let rank = bogon_rank_v4(ip);
+        assert_eq!(rank.is_some(), is_bogon_v4(ip), "{ip}");
+        if let Some(rank) = rank {
+            assert_eq!(nth_bogon_v4(rank), Some(ip));
+        }
+
+        let n = rng.gen_range(0..count_v6);
+        let ip = nth_bogon_v6(n).unwrap();
+        assert!(is_bogon_v6(ip), "{ip}");
+        assert_eq!(bogon_rank_v6(ip), Some(n), "{ip}");
+
+        let ip = Ipv6Addr::from_bits(0x2000 << 112 | rng.gen::<u128>() >> 3);
+        let rank = bogon_rank_v6(ip);
+        assert_eq!(rank.is_some(), is_bogon_v6(ip), "{ip}");
+        if let Some(rank) = rank {
+            assert_eq!(nth_bogon_v6(rank), Some(ip));
+        }
+    }
+}
+
+#[test]
+fn nth_and_rank_at_interval_bounds() {
+    let mut rank = 0;
+    for range in v4_bogon_intervals() {
+        assert_eq!(nth_bogon_v4(rank), Some(*range.start()));
+        assert_eq!(bogon_rank_v4(*range.start()), Some(rank));
+        rank += u64::from(range.end().to_bits() - range.start().to_bits());
+        assert_eq!(nth_bogon_v4(rank), Some(*range.end()));
+        assert_eq!(bogon_rank_v4(*range.end()), Some(rank));
+        rank += 1;
+    }
+    assert_eq!(rank, bogon_address_count_v4());
+
+    let mut rank = 0;
+    for range in v6_bogon_intervals() {
+        assert_eq!(nth_bogon_v6(rank), Some(*range.start()));
+        assert_eq!(bogon_rank_v6(*range.start()), Some(rank));
+        rank += range.end().to_bits() - range.start().to_bits();
+        assert_eq!(nth_bogon_v6(rank), Some(*range.end()));
+        assert_eq!(bogon_rank_v6(*range.end()), Some(rank));
+        rank += 1;
+    }
+    assert_eq!(rank, bogon_address_count_v6());
+}
+
+#[test]
+fn nth_out_of_range() {
+    let count = bogon_address_count_v4();
+    assert_eq!(
+        nth_bogon_v4(count - 1),
+        Some(Ipv4Addr::new(255, 255, 255, 255))
+    );
+    assert_eq!(nth_bogon_v4(count), None);
+    assert_eq!(nth_bogon_v4(u64::MAX), None);
+
+    let count = bogon_address_count_v6();
+    assert_eq!(
+        nth_bogon_v6(count - 1),
+        Some(Ipv6Addr::from_bits(u128::MAX))
+    );
+    assert_eq!(nth_bogon_v6(count), None);
+    assert_eq!(nth_bogon_v6(u128::MAX), None);
+}
+
+#[test]
+fn bogon_v4_addresses_ends() {
+    let count = bogon_address_count_v4();
+    let mut addresses = iter_bogon_v4_addresses();
+    assert_eq!(addresses.len() as u64, count);
+    assert_eq!(
+        addresses.size_hint(),
+        (count as usize, Some(count as usize))
+    );
+    assert_eq!(addresses.next(), Some(Ipv4Addr::new(0, 0, 0, 0)));
+    assert_eq!(addresses.next(), Some(Ipv4Addr::new(0, 0, 0, 1)));
+    assert_eq!(addresses.next_back(), Some(Ipv4Addr::BROADCAST));
+    assert_eq!(addresses.len() as u64, count - 3);
+
+    assert_eq!(iter_bogon_v4_addresses().last(), Some(Ipv4Addr::BROADCAST));
+    assert_eq!(iter_bogon_v4_addresses().count() as u64, count);
+}
+
+#[test]
+fn bogon_v4_addresses_walk() {
+    // Every address, one at a time, without the shortcuts of `count` and `nth`.
+    let mut previous = None;
+    let mut walked = 0;
+    for ip in iter_bogon_v4_addresses() {
+        assert!(previous < Some(ip));
+        previous = Some(ip);
+        walked += 1;
+    }
+    assert_eq!(walked, bogon_address_count_v4());
+    assert_eq!(previous, Some(Ipv4Addr::BROADCAST));
+}
+
+#[test]
+fn bogon_v4_addresses_top_of_the_space() {
+    // 255.255.255.255 is returned once, and the iterator is fused after it.
+    let count = bogon_address_count_v4() as usize;
+    let mut addresses = iter_bogon_v4_addresses();
+    assert_eq!(addresses.nth(count - 1), Some(Ipv4Addr::BROADCAST));
+    assert_eq!(addresses.len(), 0);
+    assert_eq!(addresses.next(), None);
+    assert_eq!(addresses.next_back(), None);
+    assert_eq!(addresses.next(), None);
+
+    let mut addresses = iter_bogon_v4_addresses();
+    assert_eq!(addresses.next_back(), Some(Ipv4Addr::BROADCAST));
+    assert_eq!(
+        addresses.next_back(),
+        nth_bogon_v4(count as u64 - 2),
+        "the address before 255.255.255.255"
+    );
+    assert_eq!(addresses.nth(count - 2), None);
+    assert_eq!(addresses.next_back(), None);
+
+    assert_eq!(iter_bogon_v4_addresses().nth(count), None);
+    assert_eq!(iter_bogon_v4_addresses().nth(usize::MAX), None);
+}
+
+#[test]
+fn bogon_v4_addresses_nth_in_the_middle() {
+    let mut rng = StdRng::seed_from_u64(99);
+    let count = bogon_address_count_v4();
+    for _ in 0..100 {
+        let n = rng.gen_range(0..count);
+        let mut addresses = iter_bogon_v4_addresses();
+        let ip = addresses.nth(n as usize).unwrap();
+        assert_eq!(Some(ip), nth_bogon_v4(n));
+        assert_eq!(addresses.len() as u64, count - n - 1);
+
+        // The next addresses continue in order, across the ends of ranges.
+        let mut rank = n;
+        for ip in addresses.take(100) {
+            rank += 1;
+            assert!(is_bogon_v4(ip), "{ip}");
+            assert_eq!(bogon_rank_v4(ip), Some(rank), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn bogon_v4_addresses_meet_in_the_middle() {
+    // Both ends inside of 192.0.0.0/24, around its exceptions 192.0.0.9 and 192.0.0.10.
+    let first = bogon_rank_v4(Ipv4Addr::new(192, 0, 0, 0)).unwrap();
+    let last = bogon_rank_v4(Ipv4Addr::new(192, 0, 0, 255)).unwrap();
+    assert_eq!(last - first, 253);
+
+    let mut addresses = iter_bogon_v4_addresses();
+    let count = addresses.len() as u64;
+    assert_eq!(
+        addresses.nth(first as usize),
+        Some(Ipv4Addr::new(192, 0, 0, 0))
+    );
+    assert_eq!(
+        addresses.nth_back((count - last - 1) as usize),
+        Some(Ipv4Addr::new(192, 0, 0, 255))
+    );
+    assert_eq!(addresses.len(), 252);
+
+    let mut seen = Vec::new();
+    while let Some(ip) = addresses.next() {
+        seen.push(ip);
+        seen.extend(addresses.next_back());
+    }
+    assert_eq!(addresses.next_back(), None);
+    seen.sort_unstable();
+    let expected = (1..255)
+        .filter(|&octet| octet != 9 && octet != 10)
+        .map(|octet| Ipv4Addr::new(192, 0, 0, octet))
+        .collect::<Vec<_>>();
+    assert_eq!(seen, expected);
+}
+
+#[test]
+fn bogon_v4_addresses_nth_back() {
+    let count = bogon_address_count_v4();
+    let mut rng = StdRng::seed_from_u64(199);
+    for _ in 0..100 {
+        let n = rng.gen_range(0..count);
+        let mut addresses = iter_bogon_v4_addresses();
+        assert_eq!(addresses.nth_back(n as usize), nth_bogon_v4(count - n - 1));
+        assert_eq!(addresses.len() as u64, count - n - 1);
+    }
+}
+
+/// Returns the first and last address of an IPv4 network.
+fn network_range(network: Ipv4Addr, prefix_len: u8) -> (u128, u128) {
+    let host = u32::MAX.checked_shr(prefix_len.into()).unwrap_or(0);
+    (network.to_bits().into(), (network.to_bits() | host).into())
+}
+
+#[test]
+fn v4_bogon_networks_cover_the_bogus_addresses() {
+    let networks: Vec<_> = v4_bogon_networks().collect();
+    assert_eq!(v4_bogon_networks().len(), networks.len());
+    for pair in networks.windows(2) {
+        assert!((pair[0].0, pair[0].1) < (pair[1].0, pair[1].1), "{pair:?}");
+    }
+
+    // Merged, the networks are exactly the intervals.
+    let mut merged: Vec<(u128, u128)> = Vec::new();
+    for &(network, prefix_len, _) in &networks {
+        let (first, last) = network_range(network, prefix_len);
+        match merged.last_mut() {
+            Some((_, end)) if first <= *end + 1 => *end = last.max(*end),
+            _ => merged.push((first, last)),
+        }
+    }
+    assert_eq!(merged, v4_intervals());
+
+    for &(network, prefix_len, kind) in &networks {
+        let (first, last) = network_range(network, prefix_len);
+        assert_eq!(
+            network.to_bits() & !u32::MAX.checked_shr(prefix_len.into()).unwrap_or(0),
+            network.to_bits(),
+            "{network}/{prefix_len} has host bits"
+        );
+        for address in [first, last] {
+            let ip = Ipv4Addr::from_bits(address as u32);
+            assert!(is_bogon_v4(ip), "{ip} in {network}/{prefix_len}");
+        }
+        // No network starts at the address of a more specific one.
+        assert_eq!(classify_v4(network), Some(kind), "{network}/{prefix_len}");
+    }
+
+    let mut rng = StdRng::seed_from_u64(100);
+    for _ in 0..100_000 {
+        let ip = Ipv4Addr::from_bits(rng.gen());
+        let covered = networks.iter().any(|&(network, prefix_len, _)| {
+            let (first, last) = network_range(network, prefix_len);
+            (first..=last).contains(&u128::from(ip.to_bits()))
+        });
+        assert_eq!(covered, is_bogon_v4(ip), "{ip}");
+    }
+}
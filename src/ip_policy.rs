@@ -0,0 +1,111 @@
+use core::net::IpAddr;
+
+use crate::{BogonFilter, BogonKind, BogonProfile, Policy};
+
+/// Something that can judge an IP address.
+///
+/// Helpers that judge addresses, like [`summarize_with`](crate::summarize_with), accept any
+/// `IpPolicy`. [`DefaultBogonPolicy`] follows [`is_bogon`](crate::is_bogon), and closures taking an
+/// [`IpAddr`] and returning whether it is allowed are policies too.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::{DefaultBogonPolicy, IpPolicy};
+///
+/// // Allow 10.0.0.0/8 on top of the globally routable addresses.
+/// let policy = |ip: IpAddr| match ip {
+///     IpAddr::V4(v4) if v4.octets()[0] == 10 => true,
+///     ip => DefaultBogonPolicy.is_allowed(ip),
+/// };
+///
+/// assert!(policy.is_allowed("10.0.0.1".parse().unwrap()));
+/// assert!(!policy.is_allowed("192.168.1.1".parse().unwrap()));
+/// assert!(policy.is_allowed("8.8.8.8".parse().unwrap()));
+/// ```
+pub trait IpPolicy {
+    /// Returns `true` if the IP address is acceptable, or `false` if it should be rejected.
+    fn is_allowed(&self, ip_address: IpAddr) -> bool;
+
+    /// Returns the classification of a rejected IP address, or `None` if the IP address is allowed
+    /// or the policy doesn't know why it was rejected.
+    ///
+    /// The default implementation reports the classification from
+    /// [`classify`](crate::classify) for rejected addresses.
+    #[inline]
+    fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        if self.is_allowed(ip_address) {
+            None
+        } else {
+            crate::classify(ip_address)
+        }
+    }
+}
+
+/// The policy of [`is_bogon`](crate::is_bogon) and [`classify`](crate::classify).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DefaultBogonPolicy;
+
+impl IpPolicy for DefaultBogonPolicy {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        !crate::is_bogon(ip_address)
+    }
+
+    #[inline]
+    fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        crate::classify(ip_address)
+    }
+}
+
+impl IpPolicy for BogonFilter {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        !self.is_bogon(ip_address)
+    }
+
+    #[inline]
+    fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        BogonFilter::classify(self, ip_address)
+    }
+}
+
+impl IpPolicy for BogonProfile {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        IpPolicy::is_allowed(&self.filter(), ip_address)
+    }
+
+    #[inline]
+    fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        IpPolicy::classify(&self.filter(), ip_address)
+    }
+}
+
+impl IpPolicy for Policy {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        !crate::is_bogon_with(ip_address, *self)
+    }
+
+    #[inline]
+    fn classify(&self, ip_address: IpAddr) -> Option<BogonKind> {
+        crate::classify_with(ip_address, *self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl IpPolicy for crate::BogonSet {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        !self.is_bogon(ip_address)
+    }
+}
+
+impl<F: Fn(IpAddr) -> bool> IpPolicy for F {
+    #[inline]
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        self(ip_address)
+    }
+}
@@ -0,0 +1,81 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    classify, is_bogon, summarize, summarize_with, BogonFilter, BogonKind, BogonProfile,
+    DefaultBogonPolicy, IpPolicy, Policy,
+};
+
+/// Allows `10.0.0.0/8` on top of the default policy.
+struct AllowTenSlashEight;
+
+impl IpPolicy for AllowTenSlashEight {
+    fn is_allowed(&self, ip_address: IpAddr) -> bool {
+        match ip_address {
+            IpAddr::V4(ip) if ip.octets()[0] == 10 => true,
+            ip => DefaultBogonPolicy.is_allowed(ip),
+        }
+    }
+}
+
+fn parse(ips: &[&str]) -> Vec<IpAddr> {
+    ips.iter().map(|ip| ip.parse().unwrap()).collect()
+}
+
+#[test]
+fn default_policy_matches_is_bogon() {
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+
+        for ip in [v4, v6] {
+            assert_eq!(DefaultBogonPolicy.is_allowed(ip), !is_bogon(ip), "{ip}");
+            assert_eq!(DefaultBogonPolicy.classify(ip), classify(ip), "{ip}");
+            assert_eq!(BogonFilter::default().is_allowed(ip), !is_bogon(ip), "{ip}");
+            assert_eq!(BogonProfile::Strict.is_allowed(ip), !is_bogon(ip), "{ip}");
+            assert_eq!(Policy::DEFAULT.is_allowed(ip), !is_bogon(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn custom_policy() {
+    let ten: IpAddr = "10.0.0.1".parse().unwrap();
+    let closure =
+        |ip: IpAddr| matches!(ip, IpAddr::V4(v4) if v4.octets()[0] == 10) || !is_bogon(ip);
+
+    assert!(!DefaultBogonPolicy.is_allowed(ten));
+    for policy in [&AllowTenSlashEight as &dyn IpPolicy, &closure] {
+        assert!(policy.is_allowed(ten));
+        assert_eq!(policy.classify(ten), None);
+
+        assert!(!policy.is_allowed("192.168.1.1".parse().unwrap()));
+        assert_eq!(
+            policy.classify("192.168.1.1".parse().unwrap()),
+            Some(BogonKind::PrivateUse)
+        );
+        assert!(policy.is_allowed("8.8.8.8".parse().unwrap()));
+    }
+}
+
+#[test]
+fn summarize_with_policy() {
+    let ips = parse(&["10.0.0.1", "10.255.0.1", "192.168.1.1", "8.8.8.8"]);
+
+    let summary = summarize_with(ips.iter().copied(), &AllowTenSlashEight);
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.bogons, 1);
+    assert_eq!(summary.count(BogonKind::PrivateUse), 1);
+    assert_eq!(summary.unclassified(), 3);
+
+    assert_eq!(
+        summarize_with(ips.iter().copied(), &DefaultBogonPolicy),
+        summarize(ips.iter().copied())
+    );
+
+    // A policy that rejects an address without a classification still counts it as bogus.
+    let summary = summarize_with(ips, &|ip: IpAddr| {
+        ip != IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8))
+    });
+    assert_eq!(summary.bogons, 1);
+    assert_eq!(summary.unclassified(), 4);
+}
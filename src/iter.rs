@@ -0,0 +1,251 @@
+//! Checking the addresses of iterators as they're iterated.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::{BogonExt, BogonKind};
+
+/// An extension trait for iterators of IP addresses, or of anything else [`BogonExt`] checks.
+///
+/// The adapters are lazy: nothing is checked until the iterator they return is iterated.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::{BogonIteratorExt, BogonKind};
+///
+/// let ips = ["8.8.8.8", "10.0.0.1", "1.1.1.1", "::1"].map(|ip| ip.parse::<IpAddr>().unwrap());
+///
+/// let routable: Vec<_> = ips.into_iter().filter_routable().collect();
+/// assert_eq!(routable, ["8.8.8.8".parse::<IpAddr>().unwrap(), "1.1.1.1".parse().unwrap()]);
+///
+/// let kinds: Vec<_> = ips.into_iter().classify_items().map(|(_, kind)| kind).collect();
+/// assert_eq!(kinds, [None, Some(BogonKind::PrivateUse), None, Some(BogonKind::Loopback)]);
+/// ```
+pub trait BogonIteratorExt: Iterator + Sized
+where
+    Self::Item: BogonExt,
+{
+    /// Returns an iterator over the good addresses, skipping the bogus ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use bogon::BogonIteratorExt;
+    ///
+    /// let ips = [Ipv4Addr::new(8, 8, 8, 8), Ipv4Addr::LOCALHOST];
+    /// assert!(ips.into_iter().filter_routable().eq([Ipv4Addr::new(8, 8, 8, 8)]));
+    /// ```
+    fn filter_routable(self) -> FilterRoutable<Self> {
+        FilterRoutable { iter: self }
+    }
+
+    /// Returns an iterator over the bogus addresses, skipping the good ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv6Addr;
+    /// use bogon::BogonIteratorExt;
+    ///
+    /// let cloudflare = Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111);
+    /// let ips = [cloudflare, Ipv6Addr::LOCALHOST];
+    /// assert!(ips.into_iter().filter_bogons().eq([Ipv6Addr::LOCALHOST]));
+    /// ```
+    fn filter_bogons(self) -> FilterBogons<Self> {
+        FilterBogons { iter: self }
+    }
+
+    /// Returns an iterator over the addresses with their classification, see
+    /// [`classify`](crate::classify).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use bogon::{BogonIteratorExt, BogonKind};
+    ///
+    /// let mut classified = [Ipv4Addr::new(192, 168, 1, 1)].into_iter().classify_items();
+    /// assert_eq!(classified.next(), Some((Ipv4Addr::new(192, 168, 1, 1), Some(BogonKind::PrivateUse))));
+    /// assert_eq!(classified.next(), None);
+    /// ```
+    fn classify_items(self) -> ClassifyItems<Self> {
+        ClassifyItems { iter: self }
+    }
+
+    /// Collects the addresses into the bogus ones and the good ones, in their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::IpAddr;
+    /// use bogon::BogonIteratorExt;
+    ///
+    /// let ips = ["10.0.0.1", "8.8.8.8", "::1"].map(|ip| ip.parse::<IpAddr>().unwrap());
+    /// let (bogons, routable) = ips.into_iter().partition_bogons();
+    /// assert_eq!(bogons, [ips[0], ips[2]]);
+    /// assert_eq!(routable, [ips[1]]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn partition_bogons(self) -> (Vec<Self::Item>, Vec<Self::Item>) {
+        self.partition(BogonExt::is_bogon)
+    }
+}
+
+impl<I> BogonIteratorExt for I
+where
+    I: Iterator,
+    I::Item: BogonExt,
+{
+}
+
+/// An iterator over the good addresses of another iterator.
+///
+/// Returned by [`BogonIteratorExt::filter_routable`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterRoutable<I> {
+    iter: I,
+}
+
+impl<I> Iterator for FilterRoutable<I>
+where
+    I: Iterator,
+    I::Item: BogonExt,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.find(|ip| !ip.is_bogon())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> DoubleEndedIterator for FilterRoutable<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: BogonExt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.rfind(|ip| !ip.is_bogon())
+    }
+}
+
+impl<I> FusedIterator for FilterRoutable<I>
+where
+    I: FusedIterator,
+    I::Item: BogonExt,
+{
+}
+
+/// An iterator over the bogus addresses of another iterator.
+///
+/// Returned by [`BogonIteratorExt::filter_bogons`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterBogons<I> {
+    iter: I,
+}
+
+impl<I> Iterator for FilterBogons<I>
+where
+    I: Iterator,
+    I::Item: BogonExt,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.find(BogonExt::is_bogon)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> DoubleEndedIterator for FilterBogons<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: BogonExt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.rfind(BogonExt::is_bogon)
+    }
+}
+
+impl<I> FusedIterator for FilterBogons<I>
+where
+    I: FusedIterator,
+    I::Item: BogonExt,
+{
+}
+
+/// An iterator over the addresses of another iterator with their classification.
+///
+/// Returned by [`BogonIteratorExt::classify_items`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ClassifyItems<I> {
+    iter: I,
+}
+
+impl<I> Iterator for ClassifyItems<I>
+where
+    I: Iterator,
+    I::Item: BogonExt,
+{
+    type Item = (I::Item, Option<BogonKind>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|ip| {
+            let kind = ip.bogon_kind();
+            (ip, kind)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for ClassifyItems<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: BogonExt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|ip| {
+            let kind = ip.bogon_kind();
+            (ip, kind)
+        })
+    }
+}
+
+impl<I> ExactSizeIterator for ClassifyItems<I>
+where
+    I: ExactSizeIterator,
+    I::Item: BogonExt,
+{
+}
+
+impl<I> FusedIterator for ClassifyItems<I>
+where
+    I: FusedIterator,
+    I::Item: BogonExt,
+{
+}
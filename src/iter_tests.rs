@@ -0,0 +1,134 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::{cell::Cell, vec::Vec};
+
+use crate::{classify, is_bogon, BogonIteratorExt, BogonKind};
+
+fn ips() -> [IpAddr; 6] {
+    [
+        "8.8.8.8",
+        "10.0.0.1",
+        "::1",
+        "2606:4700:4700::1111",
+        "169.254.169.254",
+        "1.1.1.1",
+    ]
+    .map(|ip| ip.parse().unwrap())
+}
+
+#[test]
+fn filters_match_is_bogon() {
+    let ips = ips();
+    let routable: Vec<_> = ips.into_iter().filter_routable().collect();
+    let bogons: Vec<_> = ips.into_iter().filter_bogons().collect();
+    assert_eq!(routable, [ips[0], ips[3], ips[5]]);
+    assert_eq!(bogons, [ips[1], ips[2], ips[4]]);
+
+    let random: Vec<IpAddr> = (0..10_000)
+        .flat_map(|_| {
+            [
+                IpAddr::V4(Ipv4Addr::from_bits(rand::random())),
+                IpAddr::V6(Ipv6Addr::from_bits(
+                    (rand::random::<u128>() >> 3) | (1 << 125),
+                )),
+            ]
+        })
+        .collect();
+    let expected: Vec<_> = random.iter().copied().filter(|&ip| !is_bogon(ip)).collect();
+    assert_eq!(
+        random.iter().copied().filter_routable().collect::<Vec<_>>(),
+        expected
+    );
+    let expected: Vec<_> = random.iter().copied().filter(|&ip| is_bogon(ip)).collect();
+    assert_eq!(
+        random.iter().copied().filter_bogons().collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn classify_items_matches_classify() {
+    let classified: Vec<_> = ips().into_iter().classify_items().collect();
+    assert_eq!(classified, ips().map(|ip| (ip, classify(ip))));
+    assert_eq!(classified[1].1, Some(BogonKind::PrivateUse));
+    assert_eq!(classified[4].1, Some(BogonKind::CloudMetadata));
+    assert_eq!(ips().into_iter().classify_items().len(), 6);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partition_bogons() {
+    let ips = ips();
+    let (bogons, routable) = ips.into_iter().partition_bogons();
+    assert_eq!(bogons, [ips[1], ips[2], ips[4]]);
+    assert_eq!(routable, [ips[0], ips[3], ips[5]]);
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(core::iter::empty::<IpAddr>().filter_routable().next(), None);
+    assert_eq!(core::iter::empty::<IpAddr>().filter_bogons().next(), None);
+    assert_eq!(core::iter::empty::<IpAddr>().classify_items().next(), None);
+    #[cfg(feature = "alloc")]
+    {
+        let (bogons, routable) = core::iter::empty::<IpAddr>().partition_bogons();
+        assert!(bogons.is_empty() && routable.is_empty());
+    }
+}
+
+#[test]
+fn typed_items() {
+    let v4 = [Ipv4Addr::LOCALHOST, Ipv4Addr::new(8, 8, 8, 8)];
+    assert!(v4.into_iter().filter_routable().eq([v4[1]]));
+    assert!(v4.into_iter().filter_bogons().eq([v4[0]]));
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        v4.into_iter().partition_bogons(),
+        (std::vec![v4[0]], std::vec![v4[1]])
+    );
+
+    let v6 = [
+        Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111),
+        Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1),
+    ];
+    assert!(v6.into_iter().filter_routable().eq([v6[0]]));
+    assert!(v6
+        .into_iter()
+        .classify_items()
+        .eq([(v6[0], None), (v6[1], Some(BogonKind::UniqueLocal))]));
+
+    let sockets: [SocketAddr; 2] = ["127.0.0.1:80", "8.8.8.8:53"].map(|s| s.parse().unwrap());
+    assert!(sockets.into_iter().filter_routable().eq([sockets[1]]));
+}
+
+#[test]
+fn adapters_are_lazy() {
+    let checked = Cell::new(0);
+    let counted = ips()
+        .into_iter()
+        .inspect(|_| checked.set(checked.get() + 1));
+    let mut routable = counted.filter_routable();
+    assert_eq!(checked.get(), 0);
+    assert_eq!(routable.next(), Some(ips()[0]));
+    assert_eq!(checked.get(), 1);
+    assert_eq!(routable.next(), Some(ips()[3]));
+    assert_eq!(checked.get(), 4);
+}
+
+#[test]
+fn double_ended() {
+    let ips = ips();
+    assert!(ips
+        .into_iter()
+        .filter_routable()
+        .rev()
+        .eq([ips[5], ips[3], ips[0]]));
+    assert!(ips
+        .into_iter()
+        .filter_bogons()
+        .rev()
+        .eq([ips[4], ips[2], ips[1]]));
+    assert_eq!(
+        ips.into_iter().classify_items().next_back(),
+        Some((ips[5], None))
+    );
+}
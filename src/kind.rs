@@ -0,0 +1,388 @@
+use core::net::{IpAddr, Ipv6Addr};
+
+/// The reason an IP address is considered bogus.
+///
+/// Returned by [`classify`](crate::classify), [`classify_v4`](crate::classify_v4), and
+/// [`classify_v6`](crate::classify_v6).
+///
+/// With the `serde` feature classifications are serialized as kebab-case names, for example
+/// `"shared-address-space"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum BogonKind {
+    /// "This network" (`0.0.0.0/8`).
+    ThisNetwork,
+    /// Private-Use networks (`10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`).
+    PrivateUse,
+    /// Shared Address Space used for carrier-grade NAT (`100.64.0.0/10`).
+    ///
+    /// The `allow-cgnat` feature treats this network as routable.
+    SharedAddressSpace,
+    /// Loopback (`127.0.0.0/8`, `::1/128`).
+    Loopback,
+    /// Link Local (`169.254.0.0/16`, `fe80::/10`).
+    LinkLocal,
+    /// IETF Protocol Assignments (`192.0.0.0/24`, `2001::/23`).
+    ProtocolAssignments,
+    /// Documentation (`192.0.2.0/24`, `198.51.100.0/24`, `203.0.113.0/24`, `2001:db8::/32`,
+    /// `3fff::/20`).
+    Documentation,
+    /// Benchmarking (`198.18.0.0/15`, `2001:2::/48`).
+    ///
+    /// The `allow-benchmarking` feature treats `198.18.0.0/15` as routable.
+    Benchmarking,
+    /// Multicast (`224.0.0.0/4`, `ff00::/8`).
+    Multicast,
+    /// IPv6 space outside of `2000::/3` reserved by the IETF that has no more specific
+    /// classification, or a special-purpose block this crate doesn't know yet.
+    ///
+    /// IPv4 space reserved for future use is [`BogonKind::ReservedFutureUse`], so allowing one
+    /// never allows the other.
+    Reserved,
+    /// Limited Broadcast (`255.255.255.255/32`).
+    Broadcast,
+    /// Unique Local IPv6 unicast addresses (`fc00::/7`).
+    UniqueLocal,
+    /// Global unicast IPv6 space (`2000::/3`) that has not been allocated to a regional internet
+    /// registry.
+    Unallocated,
+    /// The IPv6 Unspecified Address (`::/128`).
+    Unspecified,
+    /// The IPv6 Discard-Only Address Block (`100::/64`).
+    DiscardOnly,
+    /// Cloud instance metadata endpoints (`169.254.169.254/32`, `169.254.170.2/32`,
+    /// `fd00:ec2::254/128`), a common target of server-side request forgery.
+    ///
+    /// These addresses are inside of Link Local and Unique-Local space, but the more specific
+    /// classification wins. [`is_private`](crate::is_private) is still `true` for `fd00:ec2::254`.
+    CloudMetadata,
+    /// The deprecated 6to4 Relay Anycast network (`192.88.99.0/24`).
+    SixToFourRelayAnycast,
+    /// Segment Routing (SRv6) SIDs (`5f00::/16`).
+    SegmentRouting,
+    /// TEREDO tunneling (`2001::/32`).
+    Teredo,
+    /// Overlay Routable Cryptographic Hash Identifiers, version 2 (`2001:20::/28`).
+    OrchidV2,
+    /// The NAT64 well-known prefix (`64:ff9b::/96`), which translators may announce.
+    Nat64WellKnown,
+    /// Local-use IPv4/IPv6 translation (`64:ff9b:1::/48`), never globally routed.
+    Nat64LocalUse,
+    /// Deprecated IPv4-compatible IPv6 addresses (`::/96`), except for the unspecified and
+    /// loopback addresses.
+    Ipv4Compatible,
+    /// IPv4 space reserved for future use (`240.0.0.0/4`), except for the limited broadcast
+    /// address.
+    ///
+    /// The `allow-reserved-240-4` feature treats this network as routable.
+    ReservedFutureUse,
+    /// IPv4-mapped IPv6 addresses (`::ffff:0:0/96`), which only exist inside of dual-stack hosts.
+    Ipv4Mapped,
+}
+
+impl BogonKind {
+    /// Every classification, in declaration order.
+    pub const ALL: [BogonKind; 25] = [
+        BogonKind::ThisNetwork,
+        BogonKind::PrivateUse,
+        BogonKind::SharedAddressSpace,
+        BogonKind::Loopback,
+        BogonKind::LinkLocal,
+        BogonKind::ProtocolAssignments,
+        BogonKind::Documentation,
+        BogonKind::Benchmarking,
+        BogonKind::Multicast,
+        BogonKind::Reserved,
+        BogonKind::Broadcast,
+        BogonKind::UniqueLocal,
+        BogonKind::Unallocated,
+        BogonKind::Unspecified,
+        BogonKind::DiscardOnly,
+        BogonKind::CloudMetadata,
+        BogonKind::SixToFourRelayAnycast,
+        BogonKind::SegmentRouting,
+        BogonKind::Teredo,
+        BogonKind::OrchidV2,
+        BogonKind::Nat64WellKnown,
+        BogonKind::Nat64LocalUse,
+        BogonKind::Ipv4Compatible,
+        BogonKind::ReservedFutureUse,
+        BogonKind::Ipv4Mapped,
+    ];
+
+    /// Returns the RFC that governs this classification.
+    ///
+    /// Classifications of both IPv4 and IPv6 networks, like [`BogonKind::Loopback`] and
+    /// [`BogonKind::Documentation`], return the RFC of the IPv4 networks. Use
+    /// [`BogonKind::rfc_for`] to get the RFC of an IP address's family.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// assert_eq!(BogonKind::PrivateUse.rfc(), "RFC 1918");
+    /// assert_eq!(BogonKind::SharedAddressSpace.rfc(), "RFC 6598");
+    /// ```
+    pub const fn rfc(&self) -> &'static str {
+        match self {
+            BogonKind::ThisNetwork => "RFC 791",
+            BogonKind::PrivateUse => "RFC 1918",
+            BogonKind::SharedAddressSpace => "RFC 6598",
+            BogonKind::Loopback => "RFC 1122",
+            BogonKind::LinkLocal => "RFC 3927",
+            BogonKind::ProtocolAssignments => "RFC 6890",
+            BogonKind::Documentation => "RFC 5737",
+            BogonKind::Benchmarking => "RFC 2544",
+            BogonKind::Multicast => "RFC 5771",
+            BogonKind::Reserved => "RFC 4291",
+            BogonKind::Broadcast => "RFC 919",
+            BogonKind::UniqueLocal => "RFC 4193",
+            BogonKind::Unallocated => "RFC 4291",
+            BogonKind::Unspecified => "RFC 4291",
+            BogonKind::DiscardOnly => "RFC 6666",
+            BogonKind::CloudMetadata => "RFC 3927",
+            BogonKind::SixToFourRelayAnycast => "RFC 7526",
+            BogonKind::SegmentRouting => "RFC 9602",
+            BogonKind::Teredo => "RFC 4380",
+            BogonKind::OrchidV2 => "RFC 7343",
+            BogonKind::Nat64WellKnown => "RFC 6052",
+            BogonKind::Nat64LocalUse => "RFC 8215",
+            BogonKind::Ipv4Compatible => "RFC 4291",
+            BogonKind::ReservedFutureUse => "RFC 1112",
+            BogonKind::Ipv4Mapped => "RFC 4291",
+        }
+    }
+
+    /// Returns the RFC that governs this classification for the family of an IP address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// assert_eq!(BogonKind::Loopback.rfc_for("127.0.0.1".parse().unwrap()), "RFC 1122");
+    /// assert_eq!(BogonKind::Loopback.rfc_for("::1".parse().unwrap()), "RFC 4291");
+    /// assert_eq!(BogonKind::Documentation.rfc_for("2001:db8::1".parse().unwrap()), "RFC 3849");
+    /// assert_eq!(BogonKind::Documentation.rfc_for("3fff::1".parse().unwrap()), "RFC 9637");
+    /// ```
+    pub const fn rfc_for(&self, ip_address: IpAddr) -> &'static str {
+        let ip = match ip_address {
+            IpAddr::V4(_) => return self.rfc(),
+            IpAddr::V6(ip) => ip,
+        };
+
+        match self {
+            BogonKind::Loopback | BogonKind::LinkLocal | BogonKind::Multicast => "RFC 4291",
+            BogonKind::ProtocolAssignments => "RFC 2928",
+            BogonKind::Documentation if is_documentation_3fff(ip) => "RFC 9637",
+            BogonKind::Documentation => "RFC 3849",
+            BogonKind::Benchmarking => "RFC 5180",
+            BogonKind::CloudMetadata => "RFC 4193",
+            _ => self.rfc(),
+        }
+    }
+
+    /// Returns the name IANA uses for this classification in its registries.
+    ///
+    /// [`BogonKind::CloudMetadata`] isn't an IANA designation and is described as
+    /// `"Cloud Metadata"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// assert_eq!(BogonKind::SharedAddressSpace.description(), "Shared Address Space");
+    /// assert_eq!(BogonKind::Broadcast.description(), "Limited Broadcast");
+    /// ```
+    pub const fn description(&self) -> &'static str {
+        match self {
+            BogonKind::ThisNetwork => "\"This network\"",
+            BogonKind::PrivateUse => "Private-Use",
+            BogonKind::SharedAddressSpace => "Shared Address Space",
+            BogonKind::Loopback => "Loopback",
+            BogonKind::LinkLocal => "Link Local",
+            BogonKind::ProtocolAssignments => "IETF Protocol Assignments",
+            BogonKind::Documentation => "Documentation",
+            BogonKind::Benchmarking => "Benchmarking",
+            BogonKind::Multicast => "Multicast",
+            BogonKind::Reserved => "Reserved by IETF",
+            BogonKind::Broadcast => "Limited Broadcast",
+            BogonKind::UniqueLocal => "Unique-Local",
+            BogonKind::Unallocated => "Unallocated Global Unicast",
+            BogonKind::Unspecified => "Unspecified Address",
+            BogonKind::DiscardOnly => "Discard-Only Address Block",
+            BogonKind::CloudMetadata => "Cloud Metadata",
+            BogonKind::SixToFourRelayAnycast => "Deprecated (6to4 Relay Anycast)",
+            BogonKind::SegmentRouting => "Segment Routing (SRv6) SIDs",
+            BogonKind::Teredo => "TEREDO",
+            BogonKind::OrchidV2 => "ORCHIDv2",
+            BogonKind::Nat64WellKnown => "IPv4-IPv6 Translation",
+            BogonKind::Nat64LocalUse => "Local-Use IPv4/IPv6 Translation",
+            BogonKind::Ipv4Compatible => "IPv4-Compatible IPv6 Address",
+            BogonKind::ReservedFutureUse => "Reserved",
+            BogonKind::Ipv4Mapped => "IPv4-mapped Address",
+        }
+    }
+
+    /// Returns a short, lowercase label for this classification, suitable for log fields.
+    ///
+    /// Labels are stable and safe to search logs for. Like [`BogonKind::rfc`], they name the RFC of
+    /// the IPv4 networks. Use [`BogonKind::label_for`] to get the label of an IP address's family.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// assert_eq!(BogonKind::PrivateUse.label(), "private-use (RFC 1918)");
+    /// assert_eq!(BogonKind::Unallocated.label(), "unallocated IPv6 space");
+    /// ```
+    pub const fn label(&self) -> &'static str {
+        match self {
+            BogonKind::ThisNetwork => "this network (RFC 791)",
+            BogonKind::PrivateUse => "private-use (RFC 1918)",
+            BogonKind::SharedAddressSpace => "shared address space (RFC 6598)",
+            BogonKind::Loopback => "loopback (RFC 1122)",
+            BogonKind::LinkLocal => "link-local (RFC 3927)",
+            BogonKind::ProtocolAssignments => "IETF protocol assignments (RFC 6890)",
+            BogonKind::Documentation => "documentation (RFC 5737)",
+            BogonKind::Benchmarking => "benchmarking (RFC 2544)",
+            BogonKind::Multicast => "multicast (RFC 5771)",
+            BogonKind::Reserved => "reserved by IETF (RFC 4291)",
+            BogonKind::Broadcast => "limited broadcast (RFC 919)",
+            BogonKind::UniqueLocal => "unique-local (RFC 4193)",
+            BogonKind::Unallocated => "unallocated IPv6 space",
+            BogonKind::Unspecified => "unspecified address (RFC 4291)",
+            BogonKind::DiscardOnly => "discard-only (RFC 6666)",
+            BogonKind::CloudMetadata => "cloud metadata endpoint",
+            BogonKind::SixToFourRelayAnycast => "6to4 relay anycast (RFC 7526)",
+            BogonKind::SegmentRouting => "SRv6 SIDs (RFC 9602)",
+            BogonKind::Teredo => "Teredo (RFC 4380)",
+            BogonKind::OrchidV2 => "ORCHIDv2 (RFC 7343)",
+            BogonKind::Nat64WellKnown => "NAT64 well-known prefix (RFC 6052)",
+            BogonKind::Nat64LocalUse => "NAT64 local-use (RFC 8215)",
+            BogonKind::Ipv4Compatible => "deprecated IPv4-compatible (RFC 4291)",
+            BogonKind::ReservedFutureUse => "reserved for future use (RFC 1112)",
+            BogonKind::Ipv4Mapped => "IPv4-mapped (RFC 4291)",
+        }
+    }
+
+    /// Returns a short, lowercase label for this classification, naming the RFC of the family of an
+    /// IP address, see [`BogonKind::rfc_for`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// let label = BogonKind::LinkLocal.label_for("169.254.0.1".parse().unwrap());
+    /// assert_eq!(label, "link-local (RFC 3927)");
+    /// let label = BogonKind::LinkLocal.label_for("fe80::1".parse().unwrap());
+    /// assert_eq!(label, "link-local (RFC 4291)");
+    /// ```
+    pub const fn label_for(&self, ip_address: IpAddr) -> &'static str {
+        let ip = match ip_address {
+            IpAddr::V4(_) => return self.label(),
+            IpAddr::V6(ip) => ip,
+        };
+
+        match self {
+            BogonKind::Loopback => "loopback (RFC 4291)",
+            BogonKind::LinkLocal => "link-local (RFC 4291)",
+            BogonKind::Multicast => "multicast (RFC 4291)",
+            BogonKind::ProtocolAssignments => "IETF protocol assignments (RFC 2928)",
+            BogonKind::Documentation if is_documentation_3fff(ip) => "documentation (RFC 9637)",
+            BogonKind::Documentation => "documentation (RFC 3849)",
+            BogonKind::Benchmarking => "benchmarking (RFC 5180)",
+            _ => self.label(),
+        }
+    }
+
+    /// Returns the URL of the IANA registry that lists the networks behind this classification.
+    ///
+    /// Like [`BogonKind::rfc`], classifications of both IPv4 and IPv6 networks return the registry
+    /// of the IPv4 networks. Use [`BogonKind::registry_url_for`] to get the registry of an IP
+    /// address's family.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// assert!(BogonKind::Unallocated.registry_url().contains("ipv6-unicast-address-assignments"));
+    /// ```
+    pub const fn registry_url(&self) -> &'static str {
+        match self {
+            BogonKind::Multicast => {
+                "https://www.iana.org/assignments/multicast-addresses/multicast-addresses.xhtml"
+            }
+            BogonKind::ReservedFutureUse => {
+                "https://www.iana.org/assignments/ipv4-address-space/ipv4-address-space.xhtml"
+            }
+            BogonKind::Reserved => {
+                "https://www.iana.org/assignments/ipv6-address-space/ipv6-address-space.xhtml"
+            }
+            BogonKind::UniqueLocal
+            | BogonKind::Unspecified
+            | BogonKind::DiscardOnly
+            | BogonKind::SegmentRouting
+            | BogonKind::Teredo
+            | BogonKind::OrchidV2
+            | BogonKind::Nat64WellKnown
+            | BogonKind::Nat64LocalUse
+            | BogonKind::Ipv4Compatible
+            | BogonKind::Ipv4Mapped => {
+                "https://www.iana.org/assignments/iana-ipv6-special-registry/iana-ipv6-special-registry.xhtml"
+            }
+            BogonKind::Unallocated => {
+                "https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.xhtml"
+            }
+            _ => "https://www.iana.org/assignments/iana-ipv4-special-registry/iana-ipv4-special-registry.xhtml",
+        }
+    }
+
+    /// Returns the URL of the IANA registry that lists the networks behind this classification, for
+    /// the family of an IP address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonKind;
+    ///
+    /// let url = BogonKind::Loopback.registry_url_for("::1".parse().unwrap());
+    /// assert!(url.contains("iana-ipv6-special-registry"));
+    /// ```
+    pub const fn registry_url_for(&self, ip_address: IpAddr) -> &'static str {
+        if ip_address.is_ipv4() {
+            return self.registry_url();
+        }
+
+        match self {
+            BogonKind::Multicast => {
+                "https://www.iana.org/assignments/ipv6-multicast-addresses/ipv6-multicast-addresses.xhtml"
+            }
+            BogonKind::Loopback
+            | BogonKind::LinkLocal
+            | BogonKind::ProtocolAssignments
+            | BogonKind::Documentation
+            | BogonKind::Benchmarking
+            | BogonKind::CloudMetadata => {
+                "https://www.iana.org/assignments/iana-ipv6-special-registry/iana-ipv6-special-registry.xhtml"
+            }
+            _ => self.registry_url(),
+        }
+    }
+}
+
+/// Returns a boolean indicating whether an IPv6 address is inside of the `3fff::/20` documentation
+/// network of RFC 9637, rather than the `2001:db8::/32` one of RFC 3849.
+const fn is_documentation_3fff(ip_address: Ipv6Addr) -> bool {
+    ip_address.to_bits() >> 108 == 0x3fff0
+}
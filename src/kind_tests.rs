@@ -0,0 +1,681 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{
+    classify, classify_v4, classify_v6, describe, explain,
+    ipv6_unicast_address_allocations::{V6_ALLOCATION_RANGES, V6_WIDE_ALLOCATIONS},
+    is_bogon, is_excepted_v4, is_excepted_v6, matched_bogon_network, networks_for,
+    unallocated_v6_networks, BogonExt, BogonKind, V4_BOGON_KINDS, V4_BOGON_NETWORKS,
+};
+
+#[test]
+fn classify_matches_is_bogon() {
+    for _ in 0..1_000_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        assert_eq!(is_bogon(v4), classify(v4).is_some(), "{v4}");
+
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        assert_eq!(is_bogon(v6), classify(v6).is_some(), "{v6}");
+
+        // Uniformly random IPv6 addresses almost never land inside 2000::/3.
+        let global = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+        assert_eq!(is_bogon(global), classify(global).is_some(), "{global}");
+
+        // The extension trait must agree with the free functions.
+        for ip in [v4, v6, global] {
+            assert_eq!(ip.bogon_kind(), classify(ip), "{ip}");
+        }
+        if let IpAddr::V4(ip) = v4 {
+            assert_eq!(ip.bogon_kind(), classify_v4(ip), "{ip}");
+        }
+        if let IpAddr::V6(ip) = global {
+            assert_eq!(ip.bogon_kind(), classify_v6(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn socket_addresses_check_their_ip() {
+    for _ in 0..100_000 {
+        let v4 = Ipv4Addr::from_bits(rand::random());
+        let v6 = Ipv6Addr::from_bits((rand::random::<u128>() >> 3) | (1 << 125));
+        let port = rand::random();
+        for socket in [SocketAddr::from((v4, port)), SocketAddr::from((v6, port))] {
+            assert_eq!(socket.is_bogon(), socket.ip().is_bogon(), "{socket}");
+            assert_eq!(socket.bogon_kind(), classify(socket.ip()), "{socket}");
+        }
+        let socket = SocketAddrV4::new(v4, port);
+        assert_eq!(socket.is_bogon(), v4.is_bogon(), "{socket}");
+        assert_eq!(socket.bogon_kind(), classify_v4(v4), "{socket}");
+        let socket = SocketAddrV6::new(v6, port, rand::random(), rand::random());
+        assert_eq!(socket.is_bogon(), v6.is_bogon(), "{socket}");
+        assert_eq!(socket.bogon_kind(), classify_v6(v6), "{socket}");
+    }
+}
+
+#[test]
+fn socket_address_scope_id_is_ignored() {
+    let link_local = Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1);
+    let global = Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111);
+    for scope_id in [0, 1, u32::MAX] {
+        let socket = SocketAddrV6::new(link_local, 443, 0, scope_id);
+        assert!(socket.is_bogon());
+        assert_eq!(socket.bogon_kind(), Some(BogonKind::LinkLocal));
+        assert!(SocketAddr::V6(socket).is_bogon());
+
+        let socket = SocketAddrV6::new(global, 443, 0, scope_id);
+        assert!(!socket.is_bogon());
+        assert_eq!(socket.bogon_kind(), None);
+        assert!(!SocketAddr::V6(socket).is_bogon());
+    }
+}
+
+#[test]
+fn classify_v4_table() {
+    for (network, kind) in V4_BOGON_NETWORKS.iter().zip(V4_BOGON_KINDS) {
+        let first = Ipv4Addr::from_bits(network.network());
+        let last = Ipv4Addr::from_bits(network.network() | !network.mask());
+        assert_eq!(classify_v4(first), Some(kind), "{first}");
+        // 255.255.255.255 is the end of 240.0.0.0/4 but the more specific broadcast network wins.
+        let expected = if last == Ipv4Addr::BROADCAST {
+            BogonKind::Broadcast
+        } else {
+            kind
+        };
+        assert_eq!(classify_v4(last), Some(expected), "{last}");
+    }
+
+    assert_eq!(classify_v4(Ipv4Addr::new(172, 15, 255, 255)), None);
+    assert_eq!(classify_v4(Ipv4Addr::new(172, 32, 0, 0)), None);
+    #[cfg(not(feature = "allow-reserved-240-4"))]
+    assert_eq!(
+        classify_v4(Ipv4Addr::new(255, 255, 255, 254)),
+        Some(BogonKind::ReservedFutureUse)
+    );
+}
+
+#[test]
+fn classify_v6_ranges() {
+    assert_eq!(
+        classify_v6("fc00::".parse().unwrap()),
+        Some(BogonKind::UniqueLocal)
+    );
+    assert_eq!(
+        classify_v6("fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()),
+        Some(BogonKind::UniqueLocal)
+    );
+    assert_eq!(
+        classify_v6("fe00::".parse().unwrap()),
+        Some(BogonKind::Reserved)
+    );
+    assert_eq!(
+        classify_v6("3000::1".parse().unwrap()),
+        Some(BogonKind::Unallocated)
+    );
+    assert_eq!(classify_v6("2001:4860:4860::8888".parse().unwrap()), None);
+}
+
+#[test]
+fn classify_v6_boundaries() {
+    let expected = [
+        ("::", "::", BogonKind::Unspecified),
+        ("::1", "::1", BogonKind::Loopback),
+        ("100::", "100::ffff:ffff:ffff:ffff", BogonKind::DiscardOnly),
+        (
+            "fc00::",
+            "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::UniqueLocal,
+        ),
+        (
+            "fe80::",
+            "febf:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::LinkLocal,
+        ),
+        (
+            "ff00::",
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::Multicast,
+        ),
+    ];
+
+    for (first, last, kind) in expected {
+        let first: Ipv6Addr = first.parse().unwrap();
+        let last: Ipv6Addr = last.parse().unwrap();
+        assert_eq!(classify_v6(first), Some(kind), "{first}");
+        assert_eq!(classify_v6(last), Some(kind), "{last}");
+
+        // The neighbouring addresses belong to a different network.
+        for ip in [
+            first.to_bits().checked_sub(1),
+            last.to_bits().checked_add(1),
+        ]
+        .into_iter()
+        .flatten()
+        .map(Ipv6Addr::from_bits)
+        {
+            assert_ne!(classify_v6(ip), Some(kind), "{ip}");
+        }
+    }
+
+    // Everything else outside of 2000::/3 is reserved.
+    for ip in [
+        "::1:0:0",
+        "ff::ffff:ffff:ffff:ffff",
+        "100:0:0:1::",
+        "fbff::",
+        "fec0::",
+    ] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(classify_v6(ip), Some(BogonKind::Reserved), "{ip}");
+    }
+}
+
+#[test]
+fn reserved_networks_are_exact() {
+    // The most specific network of reserved space is the gap between the special-purpose blocks.
+    for (ip, network, prefix) in [
+        ("fec0::1", "fec0::", 10),
+        ("fb00::1", "f800::", 6),
+        ("4000::1", "4000::", 4),
+        ("::1:0:0", "::1:0:0", 96),
+        ("100::1:0:0:0:0", "100::1:0:0:0:0", 64),
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        let network: IpAddr = network.parse().unwrap();
+        assert_eq!(matched_bogon_network(ip), Some((network, prefix)), "{ip}");
+    }
+
+    // Reserved networks don't overlap the networks of any other classification.
+    let reserved = networks_for(BogonKind::Reserved).collect::<Vec<_>>();
+    for kind in BogonKind::ALL {
+        if kind == BogonKind::Reserved {
+            continue;
+        }
+        for (network, prefix) in networks_for(kind) {
+            for &(reserved, reserved_prefix) in &reserved {
+                assert!(
+                    !contains(network, prefix, reserved)
+                        && !contains(reserved, reserved_prefix, network),
+                    "{reserved}/{reserved_prefix} overlaps {network}/{prefix}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn cloud_metadata_precedence() {
+    // The metadata endpoints win over the Link Local and Unique-Local networks around them.
+    for (ip, surrounding) in [
+        ("169.254.169.254", BogonKind::LinkLocal),
+        ("169.254.170.2", BogonKind::LinkLocal),
+        ("fd00:ec2::254", BogonKind::UniqueLocal),
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(classify(ip), Some(BogonKind::CloudMetadata), "{ip}");
+        assert!(is_bogon(ip), "{ip}");
+
+        let (network, prefix) = matched_bogon_network(ip).unwrap();
+        assert_eq!((network, prefix), (ip, if ip.is_ipv4() { 32 } else { 128 }));
+
+        // The rest of the surrounding network keeps its classification.
+        let neighbour = match ip {
+            IpAddr::V4(ip) => IpAddr::V4(Ipv4Addr::from_bits(ip.to_bits() + 1)),
+            IpAddr::V6(ip) => IpAddr::V6(Ipv6Addr::from_bits(ip.to_bits() + 1)),
+        };
+        assert_eq!(classify(neighbour), Some(surrounding), "{neighbour}");
+    }
+}
+
+#[test]
+fn classify_v6_global_boundaries() {
+    let expected = [
+        (
+            "2fff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::Unallocated),
+        ),
+        (
+            "3ffe:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::Unallocated),
+        ),
+        ("3fff::", Some(BogonKind::Documentation)),
+        (
+            "3fff:fff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::Documentation),
+        ),
+        ("3fff:1000::", Some(BogonKind::Unallocated)),
+        (
+            "3fff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::Unallocated),
+        ),
+        ("4000::", Some(BogonKind::Reserved)),
+        (
+            "5eff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::Reserved),
+        ),
+        ("5f00::", Some(BogonKind::SegmentRouting)),
+        (
+            "5f00:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(BogonKind::SegmentRouting),
+        ),
+        ("5f01::", Some(BogonKind::Reserved)),
+    ];
+
+    for (ip, kind) in expected {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(classify_v6(ip), kind, "{ip}");
+    }
+}
+
+#[test]
+fn classify_v6_protocol_assignments() {
+    let ranges = [
+        (
+            "2001::",
+            "2001:0:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::Teredo,
+        ),
+        (
+            "2001:2::",
+            "2001:2:0:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::Benchmarking,
+        ),
+        (
+            "2001:20::",
+            "2001:2f:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::OrchidV2,
+        ),
+        (
+            "2001:db8::",
+            "2001:db8:ffff:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::Documentation,
+        ),
+    ];
+
+    for (first, last, kind) in ranges {
+        let first: Ipv6Addr = first.parse().unwrap();
+        let last: Ipv6Addr = last.parse().unwrap();
+        let before = Ipv6Addr::from_bits(first.to_bits() - 1);
+        let after = Ipv6Addr::from_bits(last.to_bits() + 1);
+
+        for ip in [first, last] {
+            assert_eq!(classify_v6(ip), Some(kind), "{ip}");
+        }
+        for ip in [before, after] {
+            assert_ne!(classify_v6(ip), Some(kind), "{ip}");
+        }
+        // The finer classification doesn't change what is bogus.
+        for ip in [before, first, last, after] {
+            assert_eq!(is_bogon(ip.into()), classify_v6(ip).is_some(), "{ip}");
+        }
+    }
+
+    // The rest of 2001::/23 keeps the generic classification.
+    for ip in ["2001:1::", "2001:2:1::", "2001:1f:ffff::", "2001:40::"] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(
+            classify_v6(ip),
+            Some(BogonKind::ProtocolAssignments),
+            "{ip}"
+        );
+    }
+
+    // Drone Remote ID Protocol Entity Tags are globally reachable.
+    assert_eq!(classify_v6("2001:30::".parse().unwrap()), None);
+}
+
+#[test]
+fn classify_v6_nat64() {
+    let expected = [
+        ("64:ff9a:ffff:ffff:ffff:ffff:ffff:ffff", BogonKind::Reserved),
+        ("64:ff9b::", BogonKind::Nat64WellKnown),
+        ("64:ff9b::192.0.2.1", BogonKind::Nat64WellKnown),
+        ("64:ff9b::255.255.255.255", BogonKind::Nat64WellKnown),
+        ("64:ff9b:0:0:0:1::", BogonKind::Reserved),
+        ("64:ff9b:0:ffff:ffff:ffff:ffff:ffff", BogonKind::Reserved),
+        ("64:ff9b:1::", BogonKind::Nat64LocalUse),
+        ("64:ff9b:1::1", BogonKind::Nat64LocalUse),
+        (
+            "64:ff9b:1:ffff:ffff:ffff:ffff:ffff",
+            BogonKind::Nat64LocalUse,
+        ),
+        ("64:ff9b:2::", BogonKind::Reserved),
+        ("64:ff9c::1", BogonKind::Reserved),
+    ];
+
+    for (ip, kind) in expected {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(classify_v6(ip), Some(kind), "{ip}");
+        // Both prefixes are bogus by default.
+        assert!(is_bogon(ip.into()), "{ip}");
+    }
+}
+
+#[test]
+fn kind_metadata() {
+    for kind in BogonKind::ALL {
+        assert!(kind.rfc().starts_with("RFC "), "{kind:?}");
+        assert!(!kind.description().is_empty(), "{kind:?}");
+        assert!(
+            kind.registry_url()
+                .starts_with("https://www.iana.org/assignments/"),
+            "{kind:?}"
+        );
+    }
+
+    // Every classification must be listed exactly once.
+    for (i, a) in BogonKind::ALL.iter().enumerate() {
+        assert!(BogonKind::ALL[i + 1..].iter().all(|b| a != b), "{a:?}");
+    }
+}
+
+#[test]
+fn references_follow_the_family() {
+    let expected = [
+        (
+            "127.0.0.1",
+            BogonKind::Loopback,
+            "RFC 1122",
+            "iana-ipv4-special-registry",
+        ),
+        (
+            "::1",
+            BogonKind::Loopback,
+            "RFC 4291",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "169.254.0.1",
+            BogonKind::LinkLocal,
+            "RFC 3927",
+            "iana-ipv4-special-registry",
+        ),
+        (
+            "fe80::1",
+            BogonKind::LinkLocal,
+            "RFC 4291",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "192.0.2.1",
+            BogonKind::Documentation,
+            "RFC 5737",
+            "iana-ipv4-special-registry",
+        ),
+        (
+            "2001:db8::1",
+            BogonKind::Documentation,
+            "RFC 3849",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "3fff::1",
+            BogonKind::Documentation,
+            "RFC 9637",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "224.0.0.1",
+            BogonKind::Multicast,
+            "RFC 5771",
+            "/multicast-addresses",
+        ),
+        (
+            "ff02::1",
+            BogonKind::Multicast,
+            "RFC 4291",
+            "ipv6-multicast-addresses",
+        ),
+        (
+            "2001:2::1",
+            BogonKind::Benchmarking,
+            "RFC 5180",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "2001:1ff::1",
+            BogonKind::ProtocolAssignments,
+            "RFC 2928",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "fd00:ec2::254",
+            BogonKind::CloudMetadata,
+            "RFC 4193",
+            "iana-ipv6-special-registry",
+        ),
+        (
+            "3000::1",
+            BogonKind::Unallocated,
+            "RFC 4291",
+            "ipv6-unicast-address-assignments",
+        ),
+    ];
+
+    for (ip, kind, rfc, registry) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(classify(ip), Some(kind), "{ip}");
+        assert_eq!(kind.rfc_for(ip), rfc, "{ip}");
+        assert!(kind.registry_url_for(ip).contains(registry), "{ip}");
+    }
+
+    // Labels name the same RFC as `rfc_for`.
+    for kind in BogonKind::ALL {
+        for ip in ["192.0.2.1", "2001:db8::1", "3fff::1"] {
+            let ip: IpAddr = ip.parse().unwrap();
+            let label = kind.label_for(ip);
+            if let Some((_, rfc)) = label
+                .strip_suffix(')')
+                .and_then(|label| label.rsplit_once(" ("))
+            {
+                if rfc.starts_with("RFC") {
+                    assert_eq!(rfc, kind.rfc_for(ip), "{kind:?} {ip}");
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        explain("::1".parse().unwrap()).to_string(),
+        "::1 is bogus: Loopback (::1/128, RFC 4291)"
+    );
+}
+
+#[test]
+fn describe_labels() {
+    // Labels are meant to be searched for in logs, changing any of them is a breaking change.
+    let expected = [
+        ("0.0.0.1", "this network (RFC 791)"),
+        ("10.0.0.1", "private-use (RFC 1918)"),
+        #[cfg(not(feature = "allow-cgnat"))]
+        ("100.64.0.1", "shared address space (RFC 6598)"),
+        ("127.0.0.1", "loopback (RFC 1122)"),
+        ("169.254.0.1", "link-local (RFC 3927)"),
+        ("192.0.0.1", "IETF protocol assignments (RFC 6890)"),
+        ("192.0.2.1", "documentation (RFC 5737)"),
+        #[cfg(not(feature = "allow-benchmarking"))]
+        ("198.18.0.1", "benchmarking (RFC 2544)"),
+        ("2001:2::1", "benchmarking (RFC 5180)"),
+        ("224.0.0.1", "multicast (RFC 5771)"),
+        #[cfg(not(feature = "allow-reserved-240-4"))]
+        ("240.0.0.1", "reserved for future use (RFC 1112)"),
+        ("4000::1", "reserved by IETF (RFC 4291)"),
+        ("::1", "loopback (RFC 4291)"),
+        ("fe80::1", "link-local (RFC 4291)"),
+        ("ff02::1", "multicast (RFC 4291)"),
+        ("2001:db8::1", "documentation (RFC 3849)"),
+        ("3fff::1", "documentation (RFC 9637)"),
+        ("2001:1ff::1", "IETF protocol assignments (RFC 2928)"),
+        ("::ffff:10.0.0.1", "IPv4-mapped (RFC 4291)"),
+        ("255.255.255.255", "limited broadcast (RFC 919)"),
+        ("fc00::1", "unique-local (RFC 4193)"),
+        ("3000::1", "unallocated IPv6 space"),
+        ("5f00::1", "SRv6 SIDs (RFC 9602)"),
+        ("2001::1", "Teredo (RFC 4380)"),
+        ("2001:20::1", "ORCHIDv2 (RFC 7343)"),
+        ("64:ff9b::808:808", "NAT64 well-known prefix (RFC 6052)"),
+        ("64:ff9b:1::1", "NAT64 local-use (RFC 8215)"),
+        ("::192.0.2.33", "deprecated IPv4-compatible (RFC 4291)"),
+        ("::", "unspecified address (RFC 4291)"),
+        ("100::1", "discard-only (RFC 6666)"),
+        ("169.254.169.254", "cloud metadata endpoint"),
+        ("192.175.48.1", "AS112 anycast (RFC 7534)"),
+        ("192.88.99.1", "6to4 relay anycast (RFC 7526)"),
+        ("8.8.8.8", "globally routable"),
+        ("2001:4860:4860::8888", "globally routable"),
+    ];
+
+    for (ip, label) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(describe(ip), label, "{ip}");
+    }
+
+    // Every classification is covered above.
+    for kind in BogonKind::ALL {
+        // The `allow-cgnat` feature removes the only Shared Address Space network, and the
+        // `allow-reserved-240-4` feature the only network reserved for future use.
+        if cfg!(feature = "allow-cgnat") && kind == BogonKind::SharedAddressSpace
+            || cfg!(feature = "allow-reserved-240-4") && kind == BogonKind::ReservedFutureUse
+        {
+            continue;
+        }
+        assert!(
+            expected
+                .iter()
+                .any(|(ip, _)| classify(ip.parse().unwrap()) == Some(kind)),
+            "{kind:?}"
+        );
+    }
+}
+
+/// Returns true if `ip` is inside of `network/prefix`.
+fn contains(network: IpAddr, prefix: u8, ip: IpAddr) -> bool {
+    match (network, ip) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+            ip.to_bits() & mask == network.to_bits()
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+            ip.to_bits() & mask == network.to_bits()
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `ip` is a globally reachable exception inside of a bogus network.
+fn is_excepted(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_excepted_v4(ip),
+        IpAddr::V6(ip) => is_excepted_v6(ip),
+    }
+}
+
+/// Returns the first address, the last address, and a random address of a network.
+fn sample(network: IpAddr, prefix: u8) -> [IpAddr; 3] {
+    match network {
+        IpAddr::V4(network) => {
+            let host = u32::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+            let bits = network.to_bits();
+            [bits, bits | host, bits | (rand::random::<u32>() & host)]
+                .map(|bits| Ipv4Addr::from_bits(bits).into())
+        }
+        IpAddr::V6(network) => {
+            let host = u128::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+            let bits = network.to_bits();
+            [bits, bits | host, bits | (rand::random::<u128>() & host)]
+                .map(|bits| Ipv6Addr::from_bits(bits).into())
+        }
+    }
+}
+
+#[test]
+fn networks_for_matches_classify() {
+    let all = BogonKind::ALL
+        .iter()
+        .flat_map(|&kind| networks_for(kind).map(move |(network, prefix)| (network, prefix, kind)))
+        .collect::<Vec<_>>();
+
+    for kind in BogonKind::ALL {
+        // The `allow-cgnat` feature removes the only Shared Address Space network, and the
+        // `allow-reserved-240-4` feature the only network reserved for future use.
+        if cfg!(feature = "allow-cgnat") && kind == BogonKind::SharedAddressSpace
+            || cfg!(feature = "allow-reserved-240-4") && kind == BogonKind::ReservedFutureUse
+        {
+            continue;
+        }
+        assert!(networks_for(kind).next().is_some(), "{kind:?}");
+    }
+
+    for &(network, prefix, kind) in &all {
+        for _ in 0..100 {
+            for ip in sample(network, prefix) {
+                // Globally reachable exceptions aren't classified.
+                if is_excepted(ip) {
+                    assert_eq!(classify(ip), None, "{ip}");
+                    continue;
+                }
+
+                // A more specific network of a different classification takes precedence, and
+                // special-purpose networks take precedence over unallocated space of the same size.
+                let outranks = |p: u8, k: BogonKind| {
+                    p > prefix || (p == prefix && kind == BogonKind::Unallocated && k != kind)
+                };
+                let expected = all
+                    .iter()
+                    .filter(|(n, p, _)| contains(*n, *p, ip))
+                    .max_by_key(|(_, p, k)| (*p, *k != BogonKind::Unallocated))
+                    .map(|(_, _, k)| *k);
+                assert_eq!(classify(ip), expected, "{ip} in {network}/{prefix}");
+                assert!(
+                    expected == Some(kind)
+                        || all.iter().any(|(n, p, k)| *k != kind
+                            && outranks(*p, *k)
+                            && contains(*n, *p, ip))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn matched_bogon_network_contains_address() {
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        let global = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+
+        for ip in [v4, v6, global] {
+            let matched = matched_bogon_network(ip);
+            assert_eq!(matched.is_some(), is_bogon(ip), "{ip}");
+
+            if let Some((network, prefix)) = matched {
+                assert!(contains(network, prefix, ip), "{ip} in {network}/{prefix}");
+                // The matched network is one of the networks behind the classification.
+                let kind = classify(ip).unwrap();
+                assert!(networks_for(kind).any(|n| n == (network, prefix)), "{ip}");
+            }
+        }
+    }
+}
+
+#[test]
+fn unallocated_networks_are_maximal() {
+    // Growing an unallocated network by one bit must overlap an allocation or leave 2000::/3.
+    for network in unallocated_v6_networks() {
+        let size = 1u128 << (129 - u32::from(network.prefix()));
+        let start = network.network() & !(size - 1);
+        let end = start + (size - 1);
+
+        let outside = start < 0x2000 << 112 || end >= 0x4000 << 112;
+        let allocated = V6_ALLOCATION_RANGES.iter().any(|&(first, last)| {
+            u128::from(first) << 96 <= end && (u128::from(last) << 96 | u128::MAX >> 32) >= start
+        }) || V6_WIDE_ALLOCATIONS
+            .iter()
+            .any(|allocation| allocation.network() <= end && allocation.broadcast() >= start);
+        assert!(outside || allocated, "{:?}", network.to_v6());
+    }
+}
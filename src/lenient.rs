@@ -0,0 +1,145 @@
+//! Parsing the alternative notations of IPv4 addresses that `inet_aton` and browsers accept, so
+//! that a filter can't be bypassed with `0x7f000001` or `127.1` where `127.0.0.1` is caught.
+
+use core::{
+    fmt::{self, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{is_bogon, ParseError};
+
+/// Returns a number of `inet_aton`: hexadecimal after `0x` or `0X`, octal after a leading `0`, and
+/// decimal otherwise. `0x` alone is zero, like browsers read it.
+fn parse_part(part: &str) -> Option<u64> {
+    let (digits, radix) = if let Some(hex) = part.strip_prefix("0x").or(part.strip_prefix("0X")) {
+        (hex, 16)
+    } else if part.len() > 1 && part.starts_with('0') {
+        (&part[1..], 8)
+    } else {
+        (part, 10)
+    };
+    if radix == 10 && digits.is_empty() {
+        return None;
+    }
+    digits.chars().try_fold(0u64, |value, digit| {
+        value
+            .checked_mul(radix)?
+            .checked_add(digit.to_digit(radix as u32)?.into())
+    })
+}
+
+/// Returns an IPv4 address in any of the notations of `inet_aton`: `a.b.c.d`, `a.b.c` where `c`
+/// fills the last 16 bits, `a.b` where `b` fills the last 24 bits, or `a` alone, each part in
+/// decimal, octal, or hexadecimal. A single trailing dot is ignored, like browsers do.
+///
+/// Returns `None` if a part is malformed or too large for the bits it fills.
+pub(crate) fn parse_lenient_v4(s: &str) -> Option<Ipv4Addr> {
+    let s = s.strip_suffix('.').unwrap_or(s);
+    let mut parts = [0u64; 4];
+    let mut count = 0;
+    for part in s.split('.') {
+        *parts.get_mut(count)? = parse_part(part)?;
+        count += 1;
+    }
+    let (last, leading) = parts[..count].split_last()?;
+    if leading.iter().any(|&part| part > 0xff) || *last >> (8 * (5 - count)) != 0 {
+        return None;
+    }
+    let bits = leading
+        .iter()
+        .enumerate()
+        .fold(*last as u32, |bits, (index, &part)| {
+            bits | (part as u32) << (24 - 8 * index)
+        });
+    Some(Ipv4Addr::from_bits(bits))
+}
+
+/// A buffer on the stack long enough for any IPv6 address with an embedded IPv4 address.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Returns an IPv6 address whose last 32 bits are an IPv4 address in any of the notations of
+/// [`parse_lenient_v4`], such as `::ffff:127.1` or `::ffff:0x7f000001`.
+fn parse_lenient_v6(s: &str) -> Option<Ipv6Addr> {
+    let (head, tail) = s.rsplit_once(':')?;
+    let v4 = parse_lenient_v4(tail)?;
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{head}:{v4}").ok()?;
+    core::str::from_utf8(&buffer.bytes[..buffer.len])
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Returns an IP address in the standard notation, or in any of the alternative notations of
+/// `inet_aton` for IPv4 addresses, alone or as the last 32 bits of an IPv6 address.
+pub(crate) fn parse_lenient(s: &str) -> Result<IpAddr, ParseError> {
+    let error = match s.parse::<IpAddr>() {
+        Ok(ip) => return Ok(ip),
+        Err(error) => error,
+    };
+    let ip = if s.contains(':') {
+        parse_lenient_v6(s).map(IpAddr::V6)
+    } else {
+        parse_lenient_v4(s).map(IpAddr::V4)
+    };
+    ip.ok_or(ParseError::InvalidAddress(error))
+}
+
+/// Returns a boolean indicating whether an IP address is bogus, accepting the alternative IPv4
+/// notations of `inet_aton` and browsers that [`is_bogon_str`](crate::is_bogon_str) rejects.
+///
+/// A URL such as `http://0x7f000001/`, `http://017700000001/`, `http://127.1/`, or
+/// `http://2130706433/` reaches `127.0.0.1`, so a filter against server-side request forgery
+/// must check its host with this function rather than `is_bogon_str`. An IPv4 address is made of
+/// one to four parts separated by dots, each in decimal, in octal after a leading `0`, or in
+/// hexadecimal after `0x`:
+///
+/// - `a.b.c.d`: each part is an octet.
+/// - `a.b.c`: `c` fills the last 16 bits, as in `127.0.1`.
+/// - `a.b`: `b` fills the last 24 bits, as in `127.1`.
+/// - `a`: `a` fills all 32 bits, as in `2130706433`.
+///
+/// A part too large for the bits it fills is an error rather than wrapping around. The same
+/// notations are accepted for an IPv4 address ending an IPv6 address, as in `::ffff:127.1`.
+///
+/// Returns `Ok(true)` if the IP address is bogus.
+/// Returns `Ok(false)` if the IP address is good.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_str_lenient;
+///
+/// assert_eq!(is_bogon_str_lenient("0x7f000001"), Ok(true));
+/// assert_eq!(is_bogon_str_lenient("017700000001"), Ok(true));
+/// assert_eq!(is_bogon_str_lenient("127.1"), Ok(true));
+/// assert_eq!(is_bogon_str_lenient("2130706433"), Ok(true));
+/// assert_eq!(is_bogon_str_lenient("::ffff:0x7f.1"), Ok(true));
+/// assert_eq!(is_bogon_str_lenient("0x8.0x8.0x8.0x8"), Ok(false));
+///
+/// assert!(is_bogon_str_lenient("4294967296").is_err());
+/// assert!(is_bogon_str_lenient("127.0.0.256").is_err());
+/// assert!(is_bogon_str_lenient("09.0.0.1").is_err());
+/// ```
+#[inline]
+pub fn is_bogon_str_lenient(s: &str) -> Result<bool, ParseError> {
+    parse_lenient(s).map(is_bogon)
+}
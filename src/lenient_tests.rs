@@ -0,0 +1,183 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    is_bogon_str, is_bogon_str_lenient,
+    lenient::{parse_lenient, parse_lenient_v4},
+    ParseError,
+};
+
+/// Known payloads for bypassing filters on server-side request forgery, and the address they
+/// reach.
+const BYPASSES: [(&str, &str); 34] = [
+    ("0x7f000001", "127.0.0.1"),
+    ("0X7F000001", "127.0.0.1"),
+    ("017700000001", "127.0.0.1"),
+    ("0000000000017700000001", "127.0.0.1"),
+    ("2130706433", "127.0.0.1"),
+    ("127.1", "127.0.0.1"),
+    ("127.0.1", "127.0.0.1"),
+    ("127.000.000.001", "127.0.0.1"),
+    ("0177.0.0.1", "127.0.0.1"),
+    ("0x7f.0.0.1", "127.0.0.1"),
+    ("0x7f.1", "127.0.0.1"),
+    ("0x7f.0x0.0x0.0x1", "127.0.0.1"),
+    ("0177.0x0.0.01", "127.0.0.1"),
+    ("127.0.0.1.", "127.0.0.1"),
+    ("127.0x1", "127.0.0.1"),
+    ("0", "0.0.0.0"),
+    ("0x0", "0.0.0.0"),
+    ("0x", "0.0.0.0"),
+    ("0.0", "0.0.0.0"),
+    ("0xa9fea9fe", "169.254.169.254"),
+    ("2852039166", "169.254.169.254"),
+    ("0251.0376.0251.0376", "169.254.169.254"),
+    ("169.254.43518", "169.254.169.254"),
+    ("169.16689662", "169.254.169.254"),
+    ("0xa9.0xfe.0xa9.0xfe", "169.254.169.254"),
+    ("012.1", "10.0.0.1"),
+    ("10.1", "10.0.0.1"),
+    ("167772161", "10.0.0.1"),
+    ("0xc0a80001", "192.168.0.1"),
+    ("192.168.1", "192.168.0.1"),
+    ("0300.0250.0.1", "192.168.0.1"),
+    ("0x08080808", "8.8.8.8"),
+    ("134744072", "8.8.8.8"),
+    ("8.526344", "8.8.8.8"),
+];
+
+#[test]
+fn bypasses_resolve_to_their_address() {
+    for (payload, canonical) in BYPASSES {
+        let ip: Ipv4Addr = canonical.parse().unwrap();
+        assert_eq!(parse_lenient_v4(payload), Some(ip), "{payload}");
+        assert_eq!(
+            is_bogon_str_lenient(payload),
+            Ok(is_bogon_str(canonical).unwrap()),
+            "{payload}"
+        );
+    }
+}
+
+#[test]
+fn ipv6_with_lenient_ipv4_tail() {
+    for (payload, canonical) in [
+        ("::ffff:127.1", "::ffff:127.0.0.1"),
+        ("::ffff:0x7f000001", "::ffff:127.0.0.1"),
+        ("::ffff:2130706433", "::ffff:127.0.0.1"),
+        ("::ffff:0177.0.0.01", "::ffff:127.0.0.1"),
+        ("::127.1", "::127.0.0.1"),
+        ("64:ff9b::0xa9fea9fe", "64:ff9b::169.254.169.254"),
+        ("2001:db8::8.526344", "2001:db8::8.8.8.8"),
+    ] {
+        let ip: Ipv6Addr = canonical.parse().unwrap();
+        assert_eq!(parse_lenient(payload), Ok(IpAddr::V6(ip)), "{payload}");
+        assert_eq!(
+            is_bogon_str_lenient(payload),
+            Ok(is_bogon_str(canonical).unwrap()),
+            "{payload}"
+        );
+    }
+}
+
+#[test]
+fn standard_notation_is_unchanged() {
+    for ip in [
+        "127.0.0.1",
+        "8.8.8.8",
+        "0.0.0.0",
+        "::1",
+        "2606:4700:4700::1111",
+    ] {
+        assert_eq!(is_bogon_str_lenient(ip).ok(), is_bogon_str(ip).ok(), "{ip}");
+    }
+}
+
+#[test]
+fn overflow() {
+    for input in [
+        "4294967296",
+        "0x100000000",
+        "040000000000",
+        "99999999999999999999999999",
+        "0xffffffffffffffffffffffff",
+        "256.0.0.1",
+        "127.0.0.256",
+        "127.0.65536",
+        "127.16777216",
+        "0x100.0.0.1",
+        "0400.0.0.1",
+    ] {
+        assert_eq!(parse_lenient_v4(input), None, "{input}");
+        assert!(
+            matches!(
+                is_bogon_str_lenient(input),
+                Err(ParseError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        parse_lenient_v4("4294967295"),
+        Some(Ipv4Addr::new(255, 255, 255, 255))
+    );
+    assert_eq!(
+        parse_lenient_v4("1.16777215"),
+        Some(Ipv4Addr::new(1, 255, 255, 255))
+    );
+}
+
+#[test]
+fn malformed() {
+    for input in [
+        "",
+        ".",
+        "..",
+        "127..1",
+        ".127.0.0.1",
+        "127.0.0.1..",
+        "1.2.3.4.5",
+        "08",
+        "09.0.0.1",
+        "0xg",
+        "0x7f.0y1",
+        "127.0.0.1 ",
+        " 127.0.0.1",
+        "+127.0.0.1",
+        "-1",
+        "localhost",
+        "::ffff:127.0.0.256",
+        "::ffff:",
+        "1:2:3:4:5:6:7:127.1",
+        "::ffff:127.1%eth0",
+    ] {
+        assert!(
+            matches!(
+                is_bogon_str_lenient(input),
+                Err(ParseError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn random_notations() {
+    let mut rng = StdRng::seed_from_u64(89);
+    for _ in 0..100_000 {
+        let ip = Ipv4Addr::from_bits(rng.gen());
+        let [a, b, c, d] = ip.octets();
+        let bits = ip.to_bits();
+        for notation in [
+            format!("{bits}"),
+            format!("{bits:#x}"),
+            format!("0{bits:o}"),
+            format!("{a}.{}", bits & 0xff_ffff),
+            format!("{a:#x}.0{b:o}.{}", u16::from_be_bytes([c, d])),
+            format!("0{a:o}.0{b:o}.0{c:o}.0{d:o}"),
+        ] {
+            assert_eq!(parse_lenient_v4(&notation), Some(ip), "{notation}");
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(all(test, bogon_nightly), feature(ip))]
 
 //! Functions for checking whether an IP address is bogus.
 //!
@@ -8,7 +9,53 @@
 //!
 //! # Cargo Features
 //!
-//! - `download`: Download the latest IPv6 address allocations from the IANA website during the build process. Requires a network connection.
+//! - `download`: Download the latest IPv6 address allocations, IPv4 address space registry, and IPv4 and IPv6 special-purpose address registries from the IANA website during the build process. Requires a network connection.
+//! - `pregenerated`: Compile the checked-in copies of the generated tables in `bogon-data/src/generated` instead of generating them, for builds that can't run the build script. The build script does nothing, so the `download` feature and the environment variables below are ignored.
+//! - `codegen-match`: Look up the IPv6 allocation ranges with a tree of `match` expressions the build script generates from the same ranges, instead of binary searching them. This trades code size for data cache, and only matters for the few /16s the first hextet doesn't settle.
+//! - `compact-data`: Compile the IPv6 allocation ranges as the deltas between their bounds in LEB128, 53 bytes instead of 96 for the current registry, for firmware with a tight flash budget. With `std` they're decoded into RAM the first time they're searched, and without it the encoded bounds are searched in place. Takes precedence over `codegen-match`, while `simd` keeps its own copy of the ranges.
+//! - `simd`: Check an IPv6 address against eight of the bogus networks, or allocation ranges, at once with the portable vectors of the `wide` crate, on stable Rust. This only matters for the few /16s the first hextet doesn't settle, and takes precedence over `codegen-match` in `is_bogon_v6` and the batch checks.
+//! - `allow-cgnat`, `allow-benchmarking`, `allow-reserved-240-4`: Treat `100.64.0.0/10`, `198.18.0.0/15`, or `240.0.0.0/4` as routable.
+//! - `rayon`: Check large slices of IP addresses on every core with `par_classify`, `par_classify_iter`, and `par_is_bogon_v4_batch` and `par_is_bogon_v6_batch`, which fill disjoint parts of the bitmap in parallel.
+//! - `arrow`: Check Arrow arrays of IPv4 addresses as `UInt32` and IPv6 addresses as `FixedSizeBinary(16)` with `is_bogon_v4_array` and `is_bogon_v6_array`, keeping their nulls.
+//! - `datafusion`: Register the `is_bogon` and `bogon_kind` SQL functions in a DataFusion session with `register_bogon_udfs`, built on the `arrow` kernels.
+//! - `serde`: Serialize and deserialize [`BogonFilter`], [`BogonKind`], [`RoutableNote`], [`RoutableIp`], and [`RoutableSocketAddr`], which check the address again when it's deserialized.
+//! - `fullbogons`: Download Team Cymru's fullbogons feeds at runtime with `FullBogons::fetch`.
+//! - `rand`: Generate Unique-Local prefixes with `generate_ula_prefix`.
+//! - `alloc`: Use the `alloc` crate, for aggregating networks with `aggregate_v4` and converting ranges to networks with `range_to_cidrs_v4`, listing the routable networks with `routable_networks_v4`, and their IPv6 twins, and unpacking the verdicts of `is_bogon_v4_batch` with `is_bogon_v4_batch_vec`.
+//! - `std`: Use the standard library, for `BogonSet`, `BogonList`, parsing the IANA registry at runtime with `parse_iana_v6_csv`, auditing external bogon lists with `audit_external_list`, and checking IPv4 addresses with a bitmap of the bogus /24s with `BitmapChecker`, among others.
+//! - `runtime`: Replace the compiled-in data at runtime, see `set_runtime_table`.
+//! - `tokio`: Refresh runtime data in the background with `spawn_refresher`.
+//! - `watch`: Reload a list file whenever it changes with `watch_list`.
+//! - `test-util`: Override the verdicts of the free functions in tests, see `bogon::testing`. **Never enable this outside of `[dev-dependencies]`.**
+//!
+//! # Build Environment
+//!
+//! The tables are generated by the build script of the `bogon-data` crate, so `cargo update -p
+//! bogon-data` refreshes them without a new release of this crate. The build script reads the IANA
+//! registries from the copies bundled with `bogon-data`, or downloads them with the `download`
+//! feature. A local copy takes precedence over both when an environment variable names it:
+//! `BOGON_IPV6_CSV` for the IPv6 unicast address assignments, `BOGON_IPV4_CSV` for the IPv4 address
+//! space, and `BOGON_IPV6_SPECIAL_CSV` and `BOGON_IPV4_SPECIAL_CSV` for the special-purpose address
+//! registries.
+//!
+//! The `download` feature caches the downloaded registries in
+//! `target/<profile>/build/bogon-registry-cache`, or in the directory named by `BOGON_CACHE_DIR`.
+//! Later builds only download a registry again when it changed, and use the cached copy when the
+//! IANA website is unreachable, or the bundled copy when nothing was cached yet, with a warning.
+//! Set `BOGON_REQUIRE_FRESH_DATA=1` to fail the build instead.
+//!
+//! For reproducible builds, set `BOGON_CSV_SHA256` to the SHA-256 digest of the IPv6 unicast
+//! address assignments snapshot you vetted, and the build fails if the registry it reads differs.
+//! The bundled copy is always checked against its recorded digest. `DATASET_SHA256` and
+//! `DATASET_DATE` report the snapshot a binary embeds: the digest covers all four registries, and
+//! the date is the one in `snapshot-date` for the bundled copies, or the build date otherwise, and `dataset_info` summarizes it for
+//! `--version` output.
+//!
+//! Builds that compile the bundled registries warn when they were downloaded more than 12 months
+//! ago, or more than the number of months in `BOGON_STALE_AFTER_MONTHS`, measured from
+//! `SOURCE_DATE_EPOCH` when it is set. Set `BOGON_SUPPRESS_STALE_WARNING=1` to silence the
+//! warning. Cargo only shows the warnings of build scripts of path dependencies, so check
+//! `DATASET_DATE` as well.
 //!
 //! # Example
 //!
@@ -36,57 +83,628 @@
 //! ```
 use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+#[cfg(feature = "arrow")]
+pub use arrow::{is_bogon_v4_array, is_bogon_v6_array};
+#[cfg(feature = "std")]
+pub use audit::{audit_external_list, AuditEntry, AuditReport, AuditStatus};
+pub use batch::{is_bogon_v4_batch, is_bogon_v6_batch};
+#[cfg(feature = "alloc")]
+pub use batch::{is_bogon_v4_batch_vec, is_bogon_v6_batch_vec};
+#[cfg(feature = "std")]
+pub use bogon_data::iana_csv::CsvError;
+pub use check::{check_str, ParseCheckError};
+pub use dataset::{dataset_info, DatasetInfo};
+#[cfg(feature = "std")]
+pub use diff::{diff_tables, TableDiff};
+pub use explain::{describe, explain, Explanation};
 pub use ext::BogonExt;
-use network::FourByteNetwork;
+pub use filter::{is_bogon_profile, BogonFilter, BogonFilterBuilder, BogonProfile};
+pub use flow::{check_flow, FlowVerdict};
+#[cfg(feature = "fullbogons")]
+pub use fullbogons::{FullBogons, FullBogonsError, FULLBOGONS_V4_URL, FULLBOGONS_V6_URL};
+pub use global::{is_global, is_global_v4, is_global_v6};
+#[cfg(feature = "std")]
+pub use iana::{build_v6_table, parse_iana_v6_csv};
+pub use intervals::{
+    bogon_address_count_v4, bogon_address_count_v6, bogon_fraction_v4, bogon_fraction_v6,
+    bogon_rank_v4, bogon_rank_v6, iter_bogon_v4_addresses, nth_bogon_v4, nth_bogon_v6,
+    routable_address_count_v4, routable_address_count_v6, v4_bogon_intervals, v6_bogon_intervals,
+    BogonAddressesV4,
+};
+pub use ip_policy::{DefaultBogonPolicy, IpPolicy};
+pub use iter::{BogonIteratorExt, ClassifyItems, FilterBogons, FilterRoutable};
+pub use kind::BogonKind;
+pub use lenient::is_bogon_str_lenient;
+#[cfg(feature = "std")]
+pub use list::{BogonList, ListOptions, ParseListError};
+pub use multicast::{multicast_scope, MulticastScope};
+use network::{FourByteNetwork, SixteenByteNetwork};
+pub use note::{annotate, RoutableNote};
+#[cfg(feature = "rayon")]
+pub use par::{par_classify, par_classify_iter, par_is_bogon_v4_batch, par_is_bogon_v6_batch};
+pub use policy::{classify_with, is_bogon_with, Policy};
+pub use predicates::{
+    is_benchmarking, is_cloud_metadata, is_documentation, is_private, is_reserved, is_shared,
+};
+pub use private::{ensure_private_v4, ensure_private_v6, NotPrivateError};
+#[cfg(feature = "alloc")]
+pub use ranges::{
+    aggregate_v4, aggregate_v6, range_to_cidrs_v4, range_to_cidrs_v6, routable_networks_v4,
+    routable_networks_v6,
+};
+#[cfg(all(feature = "tokio", feature = "fullbogons"))]
+pub use refresh::FullBogonsSource;
+#[cfg(feature = "tokio")]
+pub use refresh::{spawn_refresher, DataSource, FileSource, RefreshHandle, SourceError};
+pub use registry::{
+    allocation_date_v6, covering_allocation_v6, rdap_url_v4, rdap_url_v6, rir_v4, rir_v6,
+    whois_server,
+};
+pub use rir::Rir;
+pub use routable::{
+    ensure_routable, ensure_routable_str, ensure_routable_v4, ensure_routable_v6, BogonError,
+    EnsureRoutableError, RoutableIp, RoutableIpv4, RoutableIpv6, RoutableSocketAddr,
+    RoutableSocketAddrError,
+};
+#[cfg(feature = "runtime")]
+pub use runtime::{clear_runtime_table, runtime_table, set_runtime_table};
+#[cfg(feature = "std")]
+pub use set::BogonSet;
+#[cfg(feature = "std")]
+pub use slash24::{v4_bogon_slash24_bitmap, BitmapChecker};
+pub use socket::{is_bogon_socket_str, is_bogon_str_zoned, ParseError};
+pub use special::{special_purpose_v6, SpecialPurposeBlock};
+pub use summary::{summarize, summarize_with, Summary};
+#[cfg(feature = "std")]
+pub use table::{BogonTable, DecodeTableError};
+pub use transition::{
+    embedded_ipv4, ipv4_compatible, is_bogon_canonical, nat64_ipv4, teredo_parts, TeredoParts,
+    TransitionKind,
+};
+#[cfg(feature = "datafusion")]
+pub use udf::{register_bogon_udfs, UdfOptions};
+#[cfg(feature = "rand")]
+pub use ula::generate_ula_prefix;
+pub use ula::is_ula;
+#[cfg(feature = "watch")]
+pub use watch::{watch_list, watch_list_with, WatchedBogonSet};
+pub use wire::{from_wire, is_bogon_v4_octets, is_bogon_v6_octets, Verdict, WireError};
 
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(all(test, feature = "arrow"))]
+mod arrow_tests;
+#[cfg(feature = "std")]
+mod audit;
+#[cfg(all(test, feature = "std"))]
+mod audit_tests;
+mod batch;
+#[cfg(test)]
+mod batch_tests;
+mod check;
+#[cfg(test)]
+mod check_tests;
+#[cfg(feature = "compact-data")]
+mod compact;
+#[cfg(all(test, feature = "compact-data"))]
+mod compact_tests;
+#[cfg(feature = "serde")]
+mod config;
+#[cfg(all(test, feature = "serde"))]
+mod config_tests;
+mod data;
+#[cfg(test)]
+mod data_tests;
+mod dataset;
+#[cfg(test)]
+mod dataset_tests;
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(all(test, feature = "std"))]
+mod diff_tests;
+mod explain;
 mod ext;
+mod filter;
+#[cfg(test)]
+mod filter_tests;
+mod flow;
+#[cfg(test)]
+mod flow_tests;
+#[cfg(feature = "fullbogons")]
+mod fullbogons;
+#[cfg(all(test, feature = "fullbogons"))]
+mod fullbogons_tests;
+mod global;
+#[cfg(test)]
+mod global_tests;
+#[cfg(feature = "std")]
+mod iana;
+#[cfg(all(test, feature = "std"))]
+mod iana_tests;
+mod intervals;
+#[cfg(test)]
+mod intervals_tests;
+mod ip_policy;
+#[cfg(test)]
+mod ip_policy_tests;
+mod iter;
+#[cfg(test)]
+mod iter_tests;
+mod kind;
+#[cfg(test)]
+mod kind_tests;
+mod lenient;
+#[cfg(test)]
+mod lenient_tests;
+#[cfg(feature = "std")]
+mod list;
+#[cfg(all(test, feature = "std"))]
+mod list_tests;
+mod multicast;
+#[cfg(test)]
+mod multicast_tests;
 #[cfg(test)]
 mod net_tests;
 mod network;
+mod note;
+#[cfg(test)]
+mod note_tests;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(all(test, feature = "rayon"))]
+mod par_tests;
+mod policy;
+#[cfg(test)]
+mod policy_tests;
+mod predicates;
+#[cfg(test)]
+mod predicates_tests;
+#[cfg(test)]
+mod pregenerated_tests;
+mod private;
+#[cfg(test)]
+mod private_tests;
+#[cfg(feature = "alloc")]
+mod ranges;
+#[cfg(all(test, feature = "alloc"))]
+mod ranges_tests;
+#[cfg(feature = "tokio")]
+mod refresh;
+#[cfg(all(test, feature = "tokio"))]
+mod refresh_tests;
+mod registry;
+#[cfg(test)]
+mod registry_tests;
+mod rir;
+mod routable;
+#[cfg(test)]
+mod routable_tests;
+#[cfg(feature = "runtime")]
+mod runtime;
+#[cfg(feature = "std")]
+mod set;
+#[cfg(all(test, feature = "std"))]
+mod set_tests;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(all(test, feature = "simd"))]
+mod simd_tests;
+#[cfg(feature = "std")]
+mod slash24;
+#[cfg(all(test, feature = "std"))]
+mod slash24_tests;
+mod socket;
+#[cfg(test)]
+mod socket_tests;
+mod special;
+#[cfg(test)]
+mod special_tests;
+mod summary;
+#[cfg(test)]
+mod summary_tests;
+#[cfg(feature = "std")]
+mod table;
+#[cfg(all(test, feature = "std"))]
+mod table_tests;
+#[cfg(feature = "test-util")]
+pub mod testing;
+#[cfg(all(test, feature = "test-util"))]
+mod testing_tests;
+mod transition;
+#[cfg(test)]
+mod transition_tests;
+#[cfg(feature = "datafusion")]
+mod udf;
+mod ula;
+#[cfg(test)]
+mod ula_tests;
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(all(test, feature = "watch"))]
+mod watch_tests;
+mod wire;
+#[cfg(test)]
+mod wire_tests;
+
+mod ipv4_address_space {
+    use crate::{data, Rir};
+
+    pub(crate) static V4_REGISTRY_RIRS: [Option<Rir>; 256] =
+        data::optional_rirs(bogon_data::V4_REGISTRY_RIRS);
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) static V4_REGISTRY_LEGACY: [bool; 256] = bogon_data::V4_REGISTRY_LEGACY;
+    pub(crate) static V4_REGISTRY_RDAP: [Option<u8>; 256] = bogon_data::V4_REGISTRY_RDAP;
+    pub(crate) static V4_REGISTRY_WHOIS: [Option<u8>; 256] = bogon_data::V4_REGISTRY_WHOIS;
+}
+
+mod registry_strings {
+    pub(crate) static RDAP_URLS: [&str; bogon_data::RDAP_URLS.len()] = bogon_data::RDAP_URLS;
+    pub(crate) static WHOIS_SERVERS: [&str; bogon_data::WHOIS_SERVERS.len()] =
+        bogon_data::WHOIS_SERVERS;
+}
 
+// The bogus IPv4 networks `V4_BOGON_NETWORKS`, their classifications `V4_BOGON_KINDS` in the same
+// order, and the globally reachable networks inside of them `V4_BOGON_EXCEPTIONS`, which are only
+// consulted after an IP address matched one of the bogus networks. `V4_FIRST_OCTETS` rules out or
+// confirms most IP addresses by their first octet alone, and `V4_MIXED_ROWS` holds the bogus
+// networks of each of the rest of the /8s, in the row `V4_MIXED_INDEX` names for the first octet.
+// `V4_SPLIT_NETWORKS` holds the bogus networks split around the exceptions, with the index of the
+// network each was split from.
+// The tables `is_bogon_v4_bits` reads are constants, so that it can be a `const fn`.
+mod ipv4_special_registry {
+    use crate::{
+        data::{self, Bucket},
+        network::FourByteNetwork,
+        BogonKind,
+    };
+
+    pub(crate) const V4_BOGON_NETWORKS: [FourByteNetwork; bogon_data::V4_BOGON_NETWORKS.len()] =
+        data::four_byte_networks(bogon_data::V4_BOGON_NETWORKS);
+    pub(crate) static V4_BOGON_KINDS: [BogonKind; bogon_data::V4_BOGON_KINDS.len()] =
+        data::kinds(bogon_data::V4_BOGON_KINDS);
+    pub(crate) const V4_BOGON_EXCEPTIONS: [FourByteNetwork; bogon_data::V4_BOGON_EXCEPTIONS.len()] =
+        data::four_byte_networks(bogon_data::V4_BOGON_EXCEPTIONS);
+    pub(crate) const V4_FIRST_OCTETS: [Bucket; 256] = data::first_octets(
+        bogon_data::V4_BOGON_NETWORKS,
+        bogon_data::V4_BOGON_EXCEPTIONS,
+    );
+    const V4_MIXED_SHAPE: (usize, usize) =
+        data::mixed_shape(bogon_data::V4_BOGON_NETWORKS, &V4_FIRST_OCTETS);
+    const V4_MIXED: (
+        [[FourByteNetwork; V4_MIXED_SHAPE.1]; V4_MIXED_SHAPE.0],
+        [u8; 256],
+    ) = data::mixed_octets(bogon_data::V4_BOGON_NETWORKS, &V4_FIRST_OCTETS);
+    pub(crate) const V4_MIXED_ROWS: [[FourByteNetwork; V4_MIXED_SHAPE.1]; V4_MIXED_SHAPE.0] =
+        V4_MIXED.0;
+    pub(crate) const V4_MIXED_INDEX: [u8; 256] = V4_MIXED.1;
+    // Each exception splits a network into at most 32 networks.
+    const V4_SPLIT_CAPACITY: usize =
+        bogon_data::V4_BOGON_NETWORKS.len() + 32 * bogon_data::V4_BOGON_EXCEPTIONS.len();
+    const V4_SPLIT: ([(u32, u8, usize); V4_SPLIT_CAPACITY], usize) = data::split_networks(
+        bogon_data::V4_BOGON_NETWORKS,
+        bogon_data::V4_BOGON_EXCEPTIONS,
+    );
+    pub(crate) static V4_SPLIT_NETWORKS: [(u32, u8, usize); V4_SPLIT.1] =
+        data::truncated(V4_SPLIT.0);
+}
+
+use data::Bucket;
+use ipv4_special_registry::{
+    V4_BOGON_EXCEPTIONS, V4_BOGON_KINDS, V4_BOGON_NETWORKS, V4_FIRST_OCTETS, V4_MIXED_INDEX,
+    V4_MIXED_ROWS, V4_SPLIT_NETWORKS,
+};
+
+// The blocks of the IPv6 special-purpose registry `V6_SPECIAL_REGISTRY`, from the most to the
+// least specific, and their networks `V6_SPECIAL_REGISTRY_NETWORKS`. The bogus IPv6 networks
+// outside of 2000::/3 `V6_BOGON_NETWORKS`, which fit into the top 32 bits, and the more specific
+// `V6_SPECIAL_BOGON_NETWORKS`, with their classifications `V6_BOGON_KINDS` and
+// `V6_SPECIAL_BOGON_KINDS`. Together they cover everything outside of 2000::/3. The bogus IPv6
+// networks inside of 2000::/3 `V6_GLOBAL_BOGON_NETWORKS`, their classifications
+// `V6_GLOBAL_BOGON_KINDS`, and the globally reachable networks inside of them
+// `V6_BOGON_EXCEPTIONS`. The bogus networks inside of 2000::/3 are carved out of allocated space,
+// so they are checked before the allocations. More specific networks come first, so the first match
+// wins.
+mod ipv6_special_registry {
+    use crate::{
+        data,
+        network::{FourByteNetwork, SixteenByteNetwork},
+        special::SpecialPurposeBlock,
+        BogonKind,
+    };
+
+    pub(crate) static V6_SPECIAL_REGISTRY_NETWORKS: [SixteenByteNetwork;
+        bogon_data::V6_SPECIAL_REGISTRY_NETWORKS.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_SPECIAL_REGISTRY_NETWORKS);
+    pub(crate) static V6_SPECIAL_REGISTRY: [SpecialPurposeBlock;
+        bogon_data::V6_SPECIAL_REGISTRY.len()] = data::special_purpose_blocks(
+        bogon_data::V6_SPECIAL_REGISTRY_NETWORKS,
+        bogon_data::V6_SPECIAL_REGISTRY,
+    );
+    pub(crate) static V6_BOGON_NETWORKS: [FourByteNetwork; bogon_data::V6_BOGON_NETWORKS.len()] =
+        data::four_byte_networks(bogon_data::V6_BOGON_NETWORKS);
+    pub(crate) static V6_BOGON_KINDS: [BogonKind; bogon_data::V6_BOGON_KINDS.len()] =
+        data::kinds(bogon_data::V6_BOGON_KINDS);
+    pub(crate) static V6_SPECIAL_BOGON_NETWORKS: [SixteenByteNetwork;
+        bogon_data::V6_SPECIAL_BOGON_NETWORKS.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_SPECIAL_BOGON_NETWORKS);
+    pub(crate) static V6_SPECIAL_BOGON_KINDS: [BogonKind;
+        bogon_data::V6_SPECIAL_BOGON_KINDS.len()] = data::kinds(bogon_data::V6_SPECIAL_BOGON_KINDS);
+    pub(crate) static V6_GLOBAL_BOGON_NETWORKS: [SixteenByteNetwork;
+        bogon_data::V6_GLOBAL_BOGON_NETWORKS.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_GLOBAL_BOGON_NETWORKS);
+    pub(crate) static V6_GLOBAL_BOGON_KINDS: [BogonKind; bogon_data::V6_GLOBAL_BOGON_KINDS.len()] =
+        data::kinds(bogon_data::V6_GLOBAL_BOGON_KINDS);
+    pub(crate) static V6_BOGON_EXCEPTIONS: [SixteenByteNetwork;
+        bogon_data::V6_BOGON_EXCEPTIONS.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_BOGON_EXCEPTIONS);
+}
+
+use ipv6_special_registry::{
+    V6_BOGON_EXCEPTIONS, V6_BOGON_KINDS, V6_BOGON_NETWORKS, V6_GLOBAL_BOGON_KINDS,
+    V6_GLOBAL_BOGON_NETWORKS, V6_SPECIAL_BOGON_KINDS, V6_SPECIAL_BOGON_NETWORKS,
+};
+
+// `V6_FIRST_HEXTETS` packs the bucket of every /16 into two bits, and rules out or confirms most
+// IPv6 addresses by their first hextet alone.
 mod ipv6_unicast_address_allocations {
-    include!(concat!(
-        env!("OUT_DIR"),
-        "/ipv6-unicast-address-allocations.rs"
-    ));
+    use crate::{data, network::SixteenByteNetwork, Rir};
+
+    pub(crate) static V6_FIRST_HEXTETS: [u64; bogon_data::V6_FIRST_HEXTETS.len()] =
+        bogon_data::V6_FIRST_HEXTETS;
+    // The `codegen-match` feature compiles the ranges into `bogon_data::is_bogon_v6_generated`, and
+    // the `compact-data` feature into `bogon_data::V6_ALLOCATION_BOUNDARIES`.
+    #[cfg(any(test, not(any(feature = "codegen-match", feature = "compact-data"))))]
+    pub(crate) static V6_ALLOCATION_RANGES: [(u32, u32); bogon_data::V6_ALLOCATION_RANGES.len()] =
+        bogon_data::V6_ALLOCATION_RANGES;
+    pub(crate) static V6_WIDE_ALLOCATIONS: [SixteenByteNetwork;
+        bogon_data::V6_WIDE_ALLOCATIONS.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_WIDE_ALLOCATIONS);
+    pub(crate) static V6_UNALLOCATED: [SixteenByteNetwork; bogon_data::V6_UNALLOCATED.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_UNALLOCATED);
+    pub(crate) static V6_REGISTRY_PREFIXES: [SixteenByteNetwork;
+        bogon_data::V6_REGISTRY_PREFIXES.len()] =
+        data::sixteen_byte_networks(bogon_data::V6_REGISTRY_PREFIXES);
+    pub(crate) static V6_REGISTRY_RIRS: [Rir; bogon_data::V6_REGISTRY_RIRS.len()] =
+        data::rirs(bogon_data::V6_REGISTRY_RIRS);
+    pub(crate) static V6_REGISTRY_DATES: [&str; bogon_data::V6_REGISTRY_DATES.len()] =
+        bogon_data::V6_REGISTRY_DATES;
+    pub(crate) static V6_REGISTRY_RDAP: [u8; bogon_data::V6_REGISTRY_RDAP.len()] =
+        bogon_data::V6_REGISTRY_RDAP;
+    pub(crate) static V6_REGISTRY_WHOIS: [u8; bogon_data::V6_REGISTRY_WHOIS.len()] =
+        bogon_data::V6_REGISTRY_WHOIS;
 }
 
-// Bogus IPv4 networks.
+pub use bogon_data::{DATASET_DATE, DATASET_SHA256};
+
+// Cloud instance metadata endpoints inside of the Link Local network.
 //
-// SAFETY: FourByteNetwork::new_unchecked is safe here as long as the prefix length is less than or equal to 32
-static V4_BOGON_NETWORKS: [FourByteNetwork; 15] = [
-    // "This Network"
-    FourByteNetwork::new(Ipv4Addr::new(0, 0, 0, 0).to_bits(), 8),
-    // Private-Use
-    FourByteNetwork::new(Ipv4Addr::new(10, 0, 0, 0).to_bits(), 8),
-    // Shared Address Space
-    FourByteNetwork::new(Ipv4Addr::new(100, 64, 0, 0).to_bits(), 10),
-    // Loopback
-    FourByteNetwork::new(Ipv4Addr::new(127, 0, 0, 0).to_bits(), 8),
-    // Link Local
-    FourByteNetwork::new(Ipv4Addr::new(169, 254, 0, 0).to_bits(), 16),
-    // Private-Use
-    FourByteNetwork::new(Ipv4Addr::new(172, 16, 0, 0).to_bits(), 12),
-    // IETF Protocol Assignments
-    FourByteNetwork::new(Ipv4Addr::new(192, 0, 0, 0).to_bits(), 24),
-    // Documentation (TEST-NET-1)
-    FourByteNetwork::new(Ipv4Addr::new(192, 0, 2, 0).to_bits(), 24),
-    // Private-Use
-    FourByteNetwork::new(Ipv4Addr::new(192, 168, 0, 0).to_bits(), 16),
-    // "Benchmarking"
-    FourByteNetwork::new(Ipv4Addr::new(198, 18, 0, 0).to_bits(), 15),
-    // TEST-NET-2
-    FourByteNetwork::new(Ipv4Addr::new(198, 51, 100, 0).to_bits(), 24),
-    // TEST-NET-3
-    FourByteNetwork::new(Ipv4Addr::new(203, 0, 113, 0).to_bits(), 24),
-    // Multicast
-    FourByteNetwork::new(Ipv4Addr::new(224, 0, 0, 0).to_bits(), 4),
-    // Reserved
-    FourByteNetwork::new(Ipv4Addr::new(240, 0, 0, 0).to_bits(), 4),
-    // Limited Broadcast
-    FourByteNetwork::new(Ipv4Addr::new(255, 255, 255, 255).to_bits(), 32),
+// These are all more specific than the networks in `V4_BOGON_NETWORKS`, so they take precedence.
+// `is_bogon_v4` doesn't need to check them.
+static V4_CLOUD_METADATA_NETWORKS: [FourByteNetwork; 2] = [
+    // Instance metadata service (AWS, Azure, GCP's metadata.google.internal, ...)
+    FourByteNetwork::new(Ipv4Addr::new(169, 254, 169, 254).to_bits(), 32),
+    // AWS ECS task metadata endpoint
+    FourByteNetwork::new(Ipv4Addr::new(169, 254, 170, 2).to_bits(), 32),
+];
+
+// Cloud instance metadata endpoints inside of the Unique-Local network.
+//
+// These are more specific than the networks in `V6_SPECIAL_BOGON_NETWORKS` and `V6_BOGON_NETWORKS`,
+// so they take precedence. `is_bogon_v6` doesn't need to check them.
+static V6_CLOUD_METADATA_NETWORKS: [SixteenByteNetwork; 1] = [
+    // AWS instance metadata service
+    SixteenByteNetwork::new(0xfd00_0ec2 << 96 | 0x254, 128),
+];
+
+// Globally routed IPv4 networks that are noted, but not bogus.
+static V4_ROUTABLE_NETWORKS: [FourByteNetwork; 1] = [
+    // AS112 direct delegation
+    FourByteNetwork::new(Ipv4Addr::new(192, 175, 48, 0).to_bits(), 24),
 ];
 
+// The note on each entry in `V4_ROUTABLE_NETWORKS`, in the same order.
+static V4_ROUTABLE_NOTES: [RoutableNote; 1] = [RoutableNote::As112];
+
+// Globally routed IPv6 networks that are noted, but not bogus.
+static V6_ROUTABLE_NETWORKS: [SixteenByteNetwork; 1] = [
+    // AS112 direct delegation
+    SixteenByteNetwork::new(0x2620_004f_8000 << 80, 48),
+];
+
+// The note on each entry in `V6_ROUTABLE_NETWORKS`, in the same order.
+static V6_ROUTABLE_NOTES: [RoutableNote; 1] = [RoutableNote::As112];
+
+/// Returns the networks inside of 2000::/3 that are not allocated to a regional internet registry,
+/// computed by the `bogon-data` build script from the gaps between the allocations.
+fn unallocated_v6_networks() -> impl Iterator<Item = SixteenByteNetwork> + Clone {
+    ipv6_unicast_address_allocations::V6_UNALLOCATED
+        .iter()
+        .copied()
+}
+
+/// Returns a boolean indicating whether an IPv4 address is a globally reachable exception inside of
+/// a bogus network.
+#[inline]
+fn is_excepted_v4(ip_address: Ipv4Addr) -> bool {
+    V4_BOGON_EXCEPTIONS
+        .iter()
+        .any(|network| network.contains_v4(ip_address))
+}
+
+/// Returns the cloud instance metadata endpoint containing the address.
+fn matched_cloud_metadata(ip_address: IpAddr) -> Option<(IpAddr, u8)> {
+    match ip_address {
+        IpAddr::V4(ip) => V4_CLOUD_METADATA_NETWORKS
+            .iter()
+            .find(|network| network.contains_v4(ip))
+            .map(|network| network.to_v4()),
+        IpAddr::V6(ip) => V6_CLOUD_METADATA_NETWORKS
+            .iter()
+            .find(|network| network.contains_v6(ip))
+            .map(|network| network.to_v6()),
+    }
+}
+
+/// Returns the classification of an IP address, where cloud instance metadata endpoints get the
+/// classification of the network they are inside of.
+///
+/// The category predicates like [`is_private`] test the membership of this classification, so that
+/// the more specific [`BogonKind::CloudMetadata`] doesn't hide it.
+pub(crate) fn containing_kind(ip_address: IpAddr) -> Option<BogonKind> {
+    match classify(ip_address)? {
+        BogonKind::CloudMetadata => {
+            let containing = match ip_address {
+                IpAddr::V4(ip) => matched_network_v4(ip),
+                IpAddr::V6(ip) => matched_network_v6(ip),
+            };
+            Some(containing.map_or(BogonKind::CloudMetadata, |(_, kind)| kind))
+        }
+        kind => Some(kind),
+    }
+}
+
+/// Returns the most specific bogus IPv4 network containing the address.
+fn matched_v4(ip_address: Ipv4Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    if is_excepted_v4(ip_address) {
+        return None;
+    }
+
+    if let Some(network) = matched_cloud_metadata(IpAddr::V4(ip_address)) {
+        return Some((network, BogonKind::CloudMetadata));
+    }
+
+    matched_network_v4(ip_address)
+}
+
+/// Returns the most specific of the bogus IPv4 networks containing the address, without the cloud
+/// instance metadata endpoints and exceptions.
+///
+/// The networks may overlap (255.255.255.255/32 is inside of 240.0.0.0/4), so every network is
+/// checked.
+fn matched_network_v4(ip_address: Ipv4Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    V4_BOGON_NETWORKS
+        .iter()
+        .zip(V4_BOGON_KINDS)
+        .filter(|(network, _)| network.contains_v4(ip_address))
+        .max_by_key(|(network, _)| network.prefix())
+        .map(|(network, kind)| (network.to_v4(), kind))
+}
+
+/// Returns the noted, but not bogus, network containing the address.
+fn matched_routable(ip_address: IpAddr) -> Option<((IpAddr, u8), RoutableNote)> {
+    match ip_address {
+        IpAddr::V4(ip) => V4_ROUTABLE_NETWORKS
+            .iter()
+            .zip(V4_ROUTABLE_NOTES)
+            .find(|(network, _)| network.contains_v4(ip))
+            .map(|(network, note)| (network.to_v4(), note)),
+        IpAddr::V6(ip) => V6_ROUTABLE_NETWORKS
+            .iter()
+            .zip(V6_ROUTABLE_NOTES)
+            .find(|(network, _)| network.contains_v6(ip))
+            .map(|(network, note)| (network.to_v6(), note)),
+    }
+}
+
+/// Returns the most specific bogus IPv6 network outside of 2000::/3 containing the address.
+fn matched_special_v6(ip_address: Ipv6Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    if let Some(network) = matched_cloud_metadata(IpAddr::V6(ip_address)) {
+        return Some((network, BogonKind::CloudMetadata));
+    }
+
+    matched_network_v6(ip_address)
+}
+
+/// Returns the most specific of the bogus IPv6 networks outside of 2000::/3 containing the address,
+/// without the cloud instance metadata endpoints.
+fn matched_network_v6(ip_address: Ipv6Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    let special = V6_SPECIAL_BOGON_NETWORKS
+        .iter()
+        .zip(V6_SPECIAL_BOGON_KINDS)
+        .find(|(network, _)| network.contains_v6(ip_address));
+    if let Some((network, kind)) = special {
+        return Some((network.to_v6(), kind));
+    }
+
+    V6_BOGON_NETWORKS
+        .iter()
+        .zip(V6_BOGON_KINDS)
+        .find(|(network, _)| network.contains_v6(ip_address))
+        .map(|(network, kind)| (network.to_v6(), kind))
+}
+
+/// Returns the bogus IPv6 network inside of 2000::/3 containing the address.
+fn matched_global_v6(ip_address: Ipv6Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    V6_GLOBAL_BOGON_NETWORKS
+        .iter()
+        .zip(V6_GLOBAL_BOGON_KINDS)
+        .find(|(network, _)| network.contains_v6(ip_address))
+        .map(|(network, kind)| (network.to_v6(), kind))
+}
+
+/// Returns a boolean indicating whether an IPv6 address is a globally reachable exception inside of
+/// a bogus network.
+#[inline]
+fn is_excepted_v6(ip_address: Ipv6Addr) -> bool {
+    V6_BOGON_EXCEPTIONS
+        .iter()
+        .any(|network| network.contains_v6(ip_address))
+}
+
+/// Returns the most specific bogus IPv6 network containing the address.
+fn matched_v6(ip_address: Ipv6Addr) -> Option<((IpAddr, u8), BogonKind)> {
+    if ip_address.segments()[0] & 0xe000 != 0x2000 {
+        return matched_special_v6(ip_address);
+    }
+
+    if is_excepted_v6(ip_address) {
+        return None;
+    }
+
+    if let Some(matched) = matched_global_v6(ip_address) {
+        return Some(matched);
+    }
+
+    if is_allocated_v6(ip_address) {
+        return None;
+    }
+
+    unallocated_v6_networks()
+        .find(|network| network.contains_v6(ip_address))
+        .map(|network| (network.to_v6(), BogonKind::Unallocated))
+}
+
+/// Returns a boolean indicating whether an IPv6 address inside of 2000::/3 is inside of one of the
+/// RIR allocations.
+#[inline]
+fn is_allocated_v6(ip_address: Ipv6Addr) -> bool {
+    // Binary search the sorted, disjoint ranges by the top 32 bits of the IP address. The few
+    // allocations with longer prefixes are only checked when none of the ranges match.
+    let top = (ip_address.to_bits() >> 96) as u32;
+    #[cfg(not(any(feature = "codegen-match", feature = "compact-data")))]
+    let allocated = {
+        let ranges = &ipv6_unicast_address_allocations::V6_ALLOCATION_RANGES;
+        let index = ranges.partition_point(|&(_, last)| last < top);
+        ranges.get(index).is_some_and(|&(first, _)| first <= top)
+    };
+    // The same ranges, compiled into a tree of `match` expressions.
+    #[cfg(all(feature = "codegen-match", not(feature = "compact-data")))]
+    let allocated = !bogon_data::is_bogon_v6_generated(top);
+    // The bounds of the same ranges, as deltas in LEB128.
+    #[cfg(feature = "compact-data")]
+    let allocated = compact::is_allocated(top);
+
+    allocated
+        || ipv6_unicast_address_allocations::V6_WIDE_ALLOCATIONS
+            .iter()
+            .any(|network| network.contains_v6(ip_address))
+}
+
 /// Returns a boolean indicating whether an IP address is bogus.
 ///
 /// Returns `true` if the IP address is bogus.
@@ -147,12 +765,85 @@ pub fn is_bogon_str(ip_address: impl AsRef<str>) -> Result<bool, core::net::Addr
 ///
 /// assert_eq!(is_bogon_v4(Ipv4Addr::new(127, 0, 0, 1)), true);
 /// assert_eq!(is_bogon_v4(Ipv4Addr::new(8, 8, 8, 8)), false);
+///
+/// // The 6to4 relay anycast network is deprecated.
+/// assert_eq!(is_bogon_v4(Ipv4Addr::new(192, 88, 99, 1)), true);
+///
+/// // Port Control Protocol anycast is globally reachable, unlike the rest of 192.0.0.0/24.
+/// assert_eq!(is_bogon_v4(Ipv4Addr::new(192, 0, 0, 9)), false);
+/// assert_eq!(is_bogon_v4(Ipv4Addr::new(192, 0, 0, 8)), true);
 /// ```
 #[inline]
 pub fn is_bogon_v4(ip_address: Ipv4Addr) -> bool {
-    V4_BOGON_NETWORKS
-        .iter()
-        .any(|network| network.contains_v4(ip_address))
+    #[cfg(feature = "test-util")]
+    if let Some(bogus) = testing::is_bogon_override(IpAddr::V4(ip_address)) {
+        return bogus;
+    }
+
+    #[cfg(feature = "runtime")]
+    if let Some(bogus) = runtime::is_bogon_override(IpAddr::V4(ip_address)) {
+        return bogus;
+    }
+
+    is_bogon_v4_bits(ip_address.to_bits())
+}
+
+/// Returns a boolean indicating whether an IPv4 address, as an integer in host order like
+/// [`Ipv4Addr::to_bits`] returns it, is bogus.
+///
+/// Like [`is_bogon_v4`], without converting the integer to an [`Ipv4Addr`], and usable in
+/// constants. Only the compiled-in tables are consulted: the overrides of the `test-util` and
+/// `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_v4_bits;
+///
+/// assert_eq!(is_bogon_v4_bits(0x7f00_0001), true);
+/// assert_eq!(is_bogon_v4_bits(0x0808_0808), false);
+///
+/// const PCP_ANYCAST: bool = is_bogon_v4_bits(0xc000_0009);
+/// assert_eq!(PCP_ANYCAST, false);
+/// ```
+#[inline]
+pub const fn is_bogon_v4_bits(bits: u32) -> bool {
+    // Only a few /8s are partly bogus, the rest are settled by the first octet.
+    match V4_FIRST_OCTETS[(bits >> 24) as usize] {
+        Bucket::Routable => false,
+        Bucket::Bogon => true,
+        Bucket::Mixed => is_mixed_bogon_v4(bits),
+    }
+}
+
+/// Returns a boolean indicating whether an IPv4 address, in host order, inside of one of the /8s
+/// the first octet doesn't settle is inside of one of the bogus networks, and none of the
+/// exceptions carved out of them. Only the few networks overlapping its /8 are checked, all of
+/// them, so that the compiler can check them at once without branching on each.
+#[inline]
+const fn is_mixed_bogon_v4(bits: u32) -> bool {
+    let row = &V4_MIXED_ROWS[V4_MIXED_INDEX[(bits >> 24) as usize] as usize];
+    let mut bogus = false;
+    let mut i = 0;
+    while i < row.len() {
+        bogus |= row[i].contains_bits(bits);
+        i += 1;
+    }
+    bogus && !contains_any_v4(&V4_BOGON_EXCEPTIONS, bits)
+}
+
+/// Returns a boolean indicating whether any of the networks contains the 32 bits, in host order.
+/// Iterators can't be used in a `const fn`, so it is a loop.
+#[inline]
+const fn contains_any_v4(networks: &[FourByteNetwork], bits: u32) -> bool {
+    let mut i = 0;
+    while i < networks.len() {
+        if networks[i].contains_bits(bits) {
+            return true;
+        }
+        i += 1;
+    }
+    false
 }
 
 /// Returns a boolean indicating whether an IPv6 address is bogus.
@@ -168,16 +859,333 @@ pub fn is_bogon_v4(ip_address: Ipv4Addr) -> bool {
 ///
 /// assert_eq!(is_bogon_v6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)), true);
 /// assert_eq!(is_bogon_v6(Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0x1111, 0, 0, 0, 2)), false);
+///
+/// // AMT is globally reachable, unlike the rest of 2001::/23.
+/// assert_eq!(is_bogon_v6(Ipv6Addr::new(0x2001, 3, 0, 0, 0, 0, 0, 1)), false);
+/// assert_eq!(is_bogon_v6(Ipv6Addr::new(0x2001, 5, 0, 0, 0, 0, 0, 1)), true);
 /// ```
 #[inline]
 pub fn is_bogon_v6(ip_address: Ipv6Addr) -> bool {
-    // If the IP is outside 2000::/3, it is not a global unicast address.
-    if ip_address.segments()[0] & 0xe000 != 0x2000 {
-        return true;
+    #[cfg(feature = "test-util")]
+    if let Some(bogus) = testing::is_bogon_override(IpAddr::V6(ip_address)) {
+        return bogus;
+    }
+
+    #[cfg(feature = "runtime")]
+    if let Some(bogus) = runtime::is_bogon_override(IpAddr::V6(ip_address)) {
+        return bogus;
     }
 
-    // Bring the IP address into the IPv4 space for comparison.
-    !ipv6_unicast_address_allocations::V6_ALLOCATIONS
+    is_bogon_v6_bits(ip_address.to_bits())
+}
+
+/// Returns a boolean indicating whether an IPv6 address, as an integer in host order like
+/// [`Ipv6Addr::to_bits`] returns it, is bogus.
+///
+/// Like [`is_bogon_v6`], without converting the integer to an [`Ipv6Addr`]. Only the compiled-in
+/// tables are consulted: the overrides of the `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_v6_bits;
+///
+/// assert_eq!(is_bogon_v6_bits(1), true);
+/// assert_eq!(is_bogon_v6_bits(0x2606_4700_4700_0000_0000_0000_0000_1111), false);
+/// ```
+#[inline]
+pub fn is_bogon_v6_bits(bits: u128) -> bool {
+    // Most /16s are wholly unallocated, or outside of 2000::/3, and are settled by the first
+    // hextet.
+    let hextets = &ipv6_unicast_address_allocations::V6_FIRST_HEXTETS;
+    match data::first_hextet(hextets, (bits >> 112) as u16) {
+        Bucket::Routable => false,
+        Bucket::Bogon => true,
+        #[cfg(not(feature = "simd"))]
+        Bucket::Mixed => is_mixed_bogon_v6(Ipv6Addr::from_bits(bits)),
+        #[cfg(feature = "simd")]
+        Bucket::Mixed => simd::is_mixed_bogon_v6(Ipv6Addr::from_bits(bits)),
+    }
+}
+
+/// Returns a boolean indicating whether an IPv6 address inside of 2000::/3 is bogus. The `simd`
+/// feature replaces it with `simd::is_mixed_bogon_v6`.
+#[cfg(any(test, not(feature = "simd")))]
+#[inline]
+fn is_mixed_bogon_v6(ip_address: Ipv6Addr) -> bool {
+    // Special-purpose networks are carved out of the allocations, with a few globally reachable
+    // exceptions carved back out of them.
+    if V6_GLOBAL_BOGON_NETWORKS
         .iter()
         .any(|network| network.contains_v6(ip_address))
+    {
+        return !is_excepted_v6(ip_address);
+    }
+
+    !is_allocated_v6(ip_address)
+}
+
+/// Returns the reason an IP address is bogus, or `None` if the IP address is good.
+///
+/// `is_bogon(ip)` is equal to `classify(ip).is_some()`, except while a runtime table of the
+/// `runtime` feature is installed: addresses the table flags that have no compiled-in
+/// classification are bogus, but `classify` returns `None` for them. Notes on globally routed
+/// networks are returned by [`annotate`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use bogon::{classify, BogonKind};
+///
+/// assert_eq!(classify(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))), Some(BogonKind::Loopback));
+/// assert_eq!(classify(IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))), Some(BogonKind::PrivateUse));
+/// assert_eq!(classify(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8))), None);
+/// assert_eq!(classify(IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1))), Some(BogonKind::UniqueLocal));
+/// assert_eq!(classify(IpAddr::V6(Ipv6Addr::LOCALHOST)), Some(BogonKind::Loopback));
+/// assert_eq!(classify(IpAddr::V4(Ipv4Addr::new(192, 175, 48, 1))), None);
+/// ```
+#[inline]
+pub fn classify(ip_address: IpAddr) -> Option<BogonKind> {
+    match ip_address {
+        IpAddr::V4(ip) => classify_v4(ip),
+        IpAddr::V6(ip) => classify_v6(ip),
+    }
+}
+
+/// Returns the reason an IPv4 address is bogus, or `None` if the IP address is good.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{classify_v4, BogonKind};
+///
+/// # #[cfg(not(feature = "allow-cgnat"))]
+/// assert_eq!(classify_v4(Ipv4Addr::new(100, 64, 0, 1)), Some(BogonKind::SharedAddressSpace));
+/// assert_eq!(classify_v4(Ipv4Addr::new(192, 0, 2, 1)), Some(BogonKind::Documentation));
+/// assert_eq!(classify_v4(Ipv4Addr::new(8, 8, 8, 8)), None);
+/// ```
+#[inline]
+pub fn classify_v4(ip_address: Ipv4Addr) -> Option<BogonKind> {
+    #[cfg(feature = "test-util")]
+    if let Some(kind) = testing::classify_override(IpAddr::V4(ip_address)) {
+        return kind;
+    }
+
+    let kind = matched_v4(ip_address).map(|(_, kind)| kind);
+
+    #[cfg(feature = "runtime")]
+    if let Some(bogus) = runtime::is_bogon_override(IpAddr::V4(ip_address)) {
+        return kind.filter(|_| bogus);
+    }
+
+    kind
+}
+
+/// Returns the reason an IPv6 address is bogus, or `None` if the IP address is good.
+///
+/// Global unicast addresses (`2000::/3`) that are not allocated to a regional internet registry
+/// are classified as [`BogonKind::Unallocated`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::{classify_v6, BogonKind};
+///
+/// assert_eq!(classify_v6(Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 1)), Some(BogonKind::UniqueLocal));
+/// assert_eq!(classify_v6(Ipv6Addr::UNSPECIFIED), Some(BogonKind::Unspecified));
+/// assert_eq!(classify_v6(Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 1)), Some(BogonKind::DiscardOnly));
+/// assert_eq!(classify_v6(Ipv6Addr::new(0x3000, 0, 0, 0, 0, 0, 0, 1)), Some(BogonKind::Unallocated));
+/// assert_eq!(classify_v6(Ipv6Addr::new(0x3fff, 0, 0, 0, 0, 0, 0, 1)), Some(BogonKind::Documentation));
+/// assert_eq!(classify_v6(Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0x1111, 0, 0, 0, 2)), None);
+/// ```
+#[inline]
+pub fn classify_v6(ip_address: Ipv6Addr) -> Option<BogonKind> {
+    #[cfg(feature = "test-util")]
+    if let Some(kind) = testing::classify_override(IpAddr::V6(ip_address)) {
+        return kind;
+    }
+
+    let kind = classified_v6(ip_address);
+
+    #[cfg(feature = "runtime")]
+    if let Some(bogus) = runtime::is_bogon_override(IpAddr::V6(ip_address)) {
+        return kind.filter(|_| bogus);
+    }
+
+    kind
+}
+
+/// Returns the compiled-in classification of an IPv6 address.
+#[inline]
+fn classified_v6(ip_address: Ipv6Addr) -> Option<BogonKind> {
+    // Everything outside of 2000::/3 is bogus.
+    if ip_address.segments()[0] & 0xe000 != 0x2000 {
+        return matched_special_v6(ip_address).map(|(_, kind)| kind);
+    }
+
+    if is_excepted_v6(ip_address) {
+        return None;
+    }
+
+    if let Some((_, kind)) = matched_global_v6(ip_address) {
+        return Some(kind);
+    }
+
+    if is_allocated_v6(ip_address) {
+        None
+    } else {
+        Some(BogonKind::Unallocated)
+    }
+}
+
+/// Returns the built-in bogus IPv4 networks with their classification, in the order of their
+/// addresses, and of their prefix lengths for networks with the same address.
+///
+/// `is_bogon_v4(ip)` is `true` exactly when `ip` is inside of one of the networks: the globally
+/// reachable exceptions, such as `192.0.0.9` inside of `192.0.0.0/24`, are cut out by splitting the
+/// networks around them. A network may be inside of a less specific one of a different
+/// classification, for example `255.255.255.255/32` ([`BogonKind::Broadcast`]) inside of
+/// `240.0.0.0/4` ([`BogonKind::ReservedFutureUse`]), like [`networks_for`] yields them. The networks are
+/// those the `allow-*` features leave, and like [`is_bogon_v4_bits`], the overrides of the
+/// `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{v4_bogon_networks, BogonKind};
+///
+/// let mut networks = v4_bogon_networks();
+/// assert_eq!(networks.next(), Some((Ipv4Addr::new(0, 0, 0, 0), 8, BogonKind::ThisNetwork)));
+/// assert_eq!(networks.next(), Some((Ipv4Addr::new(10, 0, 0, 0), 8, BogonKind::PrivateUse)));
+///
+/// // 192.0.0.9 and 192.0.0.10 are globally reachable.
+/// let protocol = v4_bogon_networks()
+///     .filter(|(_, _, kind)| *kind == BogonKind::ProtocolAssignments)
+///     .map(|(network, prefix_len, _)| (network, prefix_len))
+///     .collect::<Vec<_>>();
+/// assert_eq!(protocol[..3], [
+///     (Ipv4Addr::new(192, 0, 0, 0), 29),
+///     (Ipv4Addr::new(192, 0, 0, 8), 32),
+///     (Ipv4Addr::new(192, 0, 0, 11), 32),
+/// ]);
+/// ```
+pub fn v4_bogon_networks() -> impl ExactSizeIterator<Item = (Ipv4Addr, u8, BogonKind)> + Clone {
+    V4_SPLIT_NETWORKS
+        .iter()
+        .map(|&(network, prefix_len, index)| {
+            (
+                Ipv4Addr::from_bits(network),
+                prefix_len,
+                V4_BOGON_KINDS[index],
+            )
+        })
+}
+
+/// Returns the networks behind a classification.
+///
+/// IPv4 networks are yielded before IPv6 networks, each in the order they are checked. A network
+/// may contain more specific networks of a different classification, for example `240.0.0.0/4`
+/// ([`BogonKind::ReservedFutureUse`]) contains `255.255.255.255/32` ([`BogonKind::Broadcast`]). The more
+/// specific network always wins in [`classify`]. A few globally reachable addresses inside of the
+/// networks, such as `192.0.0.9` and `2001:3::/32`, are not classified at all.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use bogon::{networks_for, BogonKind};
+///
+/// let private = networks_for(BogonKind::PrivateUse).collect::<Vec<_>>();
+/// assert_eq!(private, [
+///     (IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8),
+///     (IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12),
+///     (IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), 16),
+/// ]);
+///
+/// let unique_local = networks_for(BogonKind::UniqueLocal).collect::<Vec<_>>();
+/// assert_eq!(unique_local, [(IpAddr::V6(Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0)), 7)]);
+/// ```
+pub fn networks_for(kind: BogonKind) -> impl Iterator<Item = (IpAddr, u8)> {
+    let v4 = V4_BOGON_NETWORKS
+        .iter()
+        .zip(V4_BOGON_KINDS)
+        .filter(move |(_, k)| *k == kind)
+        .map(|(network, _)| network.to_v4());
+
+    let metadata = V4_CLOUD_METADATA_NETWORKS
+        .iter()
+        .filter(move |_| kind == BogonKind::CloudMetadata)
+        .map(|network| network.to_v4());
+
+    let v6 = V6_BOGON_NETWORKS
+        .iter()
+        .zip(V6_BOGON_KINDS)
+        .filter(move |(_, k)| *k == kind)
+        .map(|(network, _)| network.to_v6());
+
+    let special = V6_SPECIAL_BOGON_NETWORKS
+        .iter()
+        .zip(V6_SPECIAL_BOGON_KINDS)
+        .filter(move |(_, k)| *k == kind)
+        .map(|(network, _)| network.to_v6());
+
+    let metadata_v6 = V6_CLOUD_METADATA_NETWORKS
+        .iter()
+        .filter(move |_| kind == BogonKind::CloudMetadata)
+        .map(|network| network.to_v6());
+
+    let global = V6_GLOBAL_BOGON_NETWORKS
+        .iter()
+        .zip(V6_GLOBAL_BOGON_KINDS)
+        .filter(move |(_, k)| *k == kind)
+        .map(|(network, _)| network.to_v6());
+
+    let unallocated = (kind == BogonKind::Unallocated)
+        .then(unallocated_v6_networks)
+        .into_iter()
+        .flatten()
+        .map(SixteenByteNetwork::to_v6);
+
+    v4.chain(metadata)
+        .chain(v6)
+        .chain(special)
+        .chain(metadata_v6)
+        .chain(global)
+        .chain(unallocated)
+}
+
+/// Returns the most specific bogus network containing an IP address, or `None` if the IP address
+/// is good.
+///
+/// Unallocated IPv6 addresses return the largest unallocated network that contains them.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use bogon::matched_bogon_network;
+///
+/// assert_eq!(
+///     matched_bogon_network(IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))),
+///     Some((IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8))
+/// );
+/// assert_eq!(
+///     matched_bogon_network(IpAddr::V4(Ipv4Addr::BROADCAST)),
+///     Some((IpAddr::V4(Ipv4Addr::BROADCAST), 32))
+/// );
+/// assert_eq!(
+///     matched_bogon_network(IpAddr::V6(Ipv6Addr::new(0x3000, 0, 0, 0, 0, 0, 0, 1))),
+///     Some((IpAddr::V6(Ipv6Addr::new(0x3000, 0, 0, 0, 0, 0, 0, 0)), 4))
+/// );
+/// assert_eq!(matched_bogon_network(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8))), None);
+/// ```
+pub fn matched_bogon_network(ip_address: IpAddr) -> Option<(IpAddr, u8)> {
+    match ip_address {
+        IpAddr::V4(ip) => matched_v4(ip).map(|(network, _)| network),
+        IpAddr::V6(ip) => matched_v6(ip).map(|(network, _)| network),
+    }
 }
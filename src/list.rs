@@ -0,0 +1,182 @@
+//! Reading lists of networks from text files.
+
+use core::{fmt, net::IpAddr};
+use std::{
+    io::{self, BufRead},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::network::parse_network;
+
+/// The error returned when reading a [`BogonList`] fails.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseListError {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+    /// A line is neither an IP address nor a network.
+    InvalidLine {
+        /// The line number, starting at 1.
+        line: usize,
+        /// The line without surrounding whitespace and comments.
+        text: String,
+    },
+}
+
+impl ParseListError {
+    /// Returns the line number of an invalid line.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            ParseListError::Io(_) => None,
+            ParseListError::InvalidLine { line, .. } => Some(*line),
+        }
+    }
+}
+
+impl fmt::Display for ParseListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseListError::Io(error) => write!(f, "failed to read the list: {error}"),
+            ParseListError::InvalidLine { line, text } => {
+                write!(f, "line {line}: `{text}` is not an IP address or network")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseListError::Io(error) => Some(error),
+            ParseListError::InvalidLine { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseListError {
+    fn from(error: io::Error) -> Self {
+        ParseListError::Io(error)
+    }
+}
+
+/// Options for reading a [`BogonList`].
+///
+/// Returned by [`BogonList::options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[must_use]
+pub struct ListOptions {
+    lenient: bool,
+}
+
+impl ListOptions {
+    /// Collects invalid lines into [`BogonList::warnings`] instead of failing.
+    pub const fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+
+    /// Reads a list with these options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, or if a line is invalid and the options aren't lenient.
+    pub fn read(self, reader: impl BufRead) -> Result<BogonList, ParseListError> {
+        let mut list = BogonList::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let text = line.split_once('#').map_or(&*line, |(text, _)| text).trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            match parse_network(text) {
+                Some(network) => list.networks.push(network),
+                None => {
+                    let error = ParseListError::InvalidLine {
+                        line: index + 1,
+                        text: text.to_string(),
+                    };
+                    if !self.lenient {
+                        return Err(error);
+                    }
+                    list.warnings.push(error);
+                }
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+/// A list of networks read from a text file.
+///
+/// Lists have one network per line, written as `address/prefix` or as a bare address for a
+/// single-address network. Everything after a `#` is a comment. Blank lines, surrounding
+/// whitespace, and `\r\n` line endings are fine.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{BogonFilter, BogonList, BogonSet, Verdict};
+///
+/// let list = BogonList::from_reader(
+///     "# Peering LAN\n198.51.100.0/24\n2001:db8:1::/48 # IXP\n".as_bytes(),
+/// )
+/// .unwrap();
+/// assert_eq!(list.len(), 2);
+///
+/// let mut set = BogonSet::new();
+/// set.extend(list.networks().map(|network| (network, Verdict::Deny)));
+/// assert!(set.is_bogon("198.51.100.7".parse().unwrap()));
+///
+/// let filter = list
+///     .networks()
+///     .fold(BogonFilter::default_rules(), |rules, (network, prefix_len)| {
+///         rules.deny_network(network, prefix_len)
+///     })
+///     .build();
+/// assert!(filter.is_bogon("2001:db8:1::1".parse().unwrap()));
+/// ```
+#[derive(Debug, Default)]
+pub struct BogonList {
+    networks: Vec<(IpAddr, u8)>,
+    warnings: Vec<ParseListError>,
+}
+
+impl BogonList {
+    /// Reads a list, failing on the first invalid line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or a line is invalid.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseListError> {
+        ListOptions::default().read(reader)
+    }
+
+    /// Returns the default options for reading a list.
+    pub const fn options() -> ListOptions {
+        ListOptions { lenient: false }
+    }
+
+    /// Returns the networks in the order they were listed, as written.
+    pub fn networks(&self) -> impl Iterator<Item = (IpAddr, u8)> + '_ {
+        self.networks.iter().copied()
+    }
+
+    /// Returns the invalid lines skipped by a lenient read.
+    pub fn warnings(&self) -> &[ParseListError] {
+        &self.warnings
+    }
+
+    /// Returns the number of networks in the list.
+    pub fn len(&self) -> usize {
+        self.networks.len()
+    }
+
+    /// Returns a boolean indicating whether the list contains no networks.
+    pub fn is_empty(&self) -> bool {
+        self.networks.is_empty()
+    }
+}
@@ -0,0 +1,96 @@
+use core::net::IpAddr;
+
+use crate::{BogonList, BogonSet, ParseListError, Verdict};
+
+const BOGONS: &str = include_str!("../tests/fixtures/bogons.txt");
+const INVALID: &str = include_str!("../tests/fixtures/bogons-invalid.txt");
+
+fn net(network: &str, prefix_len: u8) -> (IpAddr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+#[test]
+fn from_reader() {
+    let list = BogonList::from_reader(BOGONS.as_bytes()).unwrap();
+    assert_eq!(
+        list.networks().collect::<Vec<_>>(),
+        [
+            net("10.0.0.0", 8),
+            net("172.16.0.0", 12),
+            net("192.0.2.1", 32),
+            net("2001:db8::", 32),
+            net("fd00::1", 128),
+        ]
+    );
+    assert!(list.warnings().is_empty());
+
+    let mut set = BogonSet::new();
+    set.extend(list.networks().map(|network| (network, Verdict::Deny)));
+    assert!(set.is_bogon("172.31.255.255".parse().unwrap()));
+    assert!(!set.is_bogon("192.0.2.2".parse().unwrap()));
+    assert!(set.is_bogon("2001:db8::1".parse().unwrap()));
+}
+
+#[test]
+fn empty() {
+    for text in ["", "\n\n", "# only comments\r\n   # indented\n"] {
+        let list = BogonList::from_reader(text.as_bytes()).unwrap();
+        assert!(list.is_empty(), "{text:?}");
+    }
+}
+
+#[test]
+fn invalid_line() {
+    let error = BogonList::from_reader(INVALID.as_bytes()).unwrap_err();
+    assert_eq!(error.line(), Some(3));
+    assert_eq!(
+        error.to_string(),
+        "line 3: `203.0.113.0/33` is not an IP address or network"
+    );
+
+    for (text, line) in [
+        ("10.0.0.0/8\n10.0.0.0/", 2),
+        ("::1/129", 1),
+        ("10.0.0.0 /8", 1),
+        ("\n\n10.0.0.0/8 10.0.0.0/16", 3),
+        ("# 10.0.0.0/8\n10.0.0.0/-1", 2),
+    ] {
+        let error = BogonList::from_reader(text.as_bytes()).unwrap_err();
+        assert_eq!(error.line(), Some(line), "{text:?}");
+    }
+}
+
+#[test]
+fn lenient() {
+    let list = BogonList::options()
+        .lenient()
+        .read(INVALID.as_bytes())
+        .unwrap();
+    assert_eq!(
+        list.networks().collect::<Vec<_>>(),
+        [net("198.51.100.0", 24), net("2001:db8::", 32)]
+    );
+
+    let warnings = list
+        .warnings()
+        .iter()
+        .map(|warning| match warning {
+            ParseListError::InvalidLine { line, text } => (*line, text.as_str()),
+            ParseListError::Io(error) => panic!("{error}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(warnings, [(3, "203.0.113.0/33"), (4, "not-an-address")]);
+}
+
+#[test]
+fn io_error() {
+    let error = BogonList::from_reader(&[0xff, b'\n'][..]).unwrap_err();
+    assert!(matches!(error, ParseListError::Io(_)), "{error:?}");
+    assert_eq!(error.line(), None);
+
+    // Lenient reads still fail on I/O errors.
+    assert!(BogonList::options()
+        .lenient()
+        .read(&[b'#', 0xff][..])
+        .is_err());
+}
@@ -0,0 +1,111 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::network::FourByteNetwork;
+
+/// The scope of a multicast address.
+///
+/// Returned by [`multicast_scope`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum MulticastScope {
+    /// IPv4 Local Network Control Block (`224.0.0.0/24`), never forwarded by routers.
+    LocalNetworkControl,
+    /// IPv4 Source-Specific Multicast Block (`232.0.0.0/8`).
+    SourceSpecific,
+    /// IPv4 GLOP Block (`233.0.0.0/8`), statically assigned from AS numbers.
+    Glop,
+    /// IPv4 Administratively Scoped Block (`239.0.0.0/8`).
+    AdministrativelyScoped,
+    /// IPv6 interface-local scope (`ff01::/16`).
+    InterfaceLocal,
+    /// IPv6 link-local scope (`ff02::/16`).
+    LinkLocal,
+    /// IPv6 realm-local scope (`ff03::/16`).
+    RealmLocal,
+    /// IPv6 admin-local scope (`ff04::/16`).
+    AdminLocal,
+    /// IPv6 site-local scope (`ff05::/16`).
+    SiteLocal,
+    /// IPv6 organization-local scope (`ff08::/16`).
+    OrganizationLocal,
+    /// IPv6 global scope (`ff0e::/16`), or any IPv4 multicast address outside of the more specific
+    /// blocks.
+    Global,
+    /// An IPv6 scope that is reserved or unassigned, holding the raw 4-bit scope value.
+    Unassigned(u8),
+}
+
+// IPv4 multicast blocks with a specific scope, anything else in 224.0.0.0/4 is global.
+static V4_MULTICAST_NETWORKS: [FourByteNetwork; 4] = [
+    // Local Network Control Block
+    FourByteNetwork::new(Ipv4Addr::new(224, 0, 0, 0).to_bits(), 24),
+    // Source-Specific Multicast Block
+    FourByteNetwork::new(Ipv4Addr::new(232, 0, 0, 0).to_bits(), 8),
+    // GLOP Block
+    FourByteNetwork::new(Ipv4Addr::new(233, 0, 0, 0).to_bits(), 8),
+    // Administratively Scoped Block
+    FourByteNetwork::new(Ipv4Addr::new(239, 0, 0, 0).to_bits(), 8),
+];
+
+// The scope of each entry in `V4_MULTICAST_NETWORKS`, in the same order.
+static V4_MULTICAST_SCOPES: [MulticastScope; 4] = [
+    MulticastScope::LocalNetworkControl,
+    MulticastScope::SourceSpecific,
+    MulticastScope::Glop,
+    MulticastScope::AdministrativelyScoped,
+];
+
+/// Returns the scope of a multicast address, or `None` if the IP address is not multicast.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{multicast_scope, MulticastScope};
+///
+/// assert_eq!(multicast_scope("224.0.0.251".parse().unwrap()), Some(MulticastScope::LocalNetworkControl));
+/// assert_eq!(multicast_scope("239.255.255.250".parse().unwrap()), Some(MulticastScope::AdministrativelyScoped));
+/// assert_eq!(multicast_scope("ff02::1".parse().unwrap()), Some(MulticastScope::LinkLocal));
+/// assert_eq!(multicast_scope("8.8.8.8".parse().unwrap()), None);
+/// ```
+#[inline]
+pub fn multicast_scope(ip_address: IpAddr) -> Option<MulticastScope> {
+    match ip_address {
+        IpAddr::V4(ip) => multicast_scope_v4(ip),
+        IpAddr::V6(ip) => multicast_scope_v6(ip),
+    }
+}
+
+/// Returns the scope of an IPv4 multicast address (`224.0.0.0/4`).
+fn multicast_scope_v4(ip_address: Ipv4Addr) -> Option<MulticastScope> {
+    if !ip_address.is_multicast() {
+        return None;
+    }
+
+    let scope = V4_MULTICAST_NETWORKS
+        .iter()
+        .zip(V4_MULTICAST_SCOPES)
+        .find(|(network, _)| network.contains_v4(ip_address))
+        .map_or(MulticastScope::Global, |(_, scope)| scope);
+    Some(scope)
+}
+
+/// Returns the scope of an IPv6 multicast address (`ff00::/8`), decoded from the low nibble of
+/// the second byte.
+fn multicast_scope_v6(ip_address: Ipv6Addr) -> Option<MulticastScope> {
+    let [first, flags_and_scope, ..] = ip_address.octets();
+    if first != 0xff {
+        return None;
+    }
+
+    let scope = match flags_and_scope & 0x0f {
+        0x1 => MulticastScope::InterfaceLocal,
+        0x2 => MulticastScope::LinkLocal,
+        0x3 => MulticastScope::RealmLocal,
+        0x4 => MulticastScope::AdminLocal,
+        0x5 => MulticastScope::SiteLocal,
+        0x8 => MulticastScope::OrganizationLocal,
+        0xe => MulticastScope::Global,
+        scope => MulticastScope::Unassigned(scope),
+    };
+    Some(scope)
+}
@@ -0,0 +1,76 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{classify, multicast_scope, BogonKind, MulticastScope};
+
+#[test]
+fn multicast_scope_v4_edges() {
+    let expected = [
+        ("223.255.255.255", None),
+        ("224.0.0.0", Some(MulticastScope::LocalNetworkControl)),
+        ("224.0.0.255", Some(MulticastScope::LocalNetworkControl)),
+        ("224.0.1.0", Some(MulticastScope::Global)),
+        ("231.255.255.255", Some(MulticastScope::Global)),
+        ("232.0.0.0", Some(MulticastScope::SourceSpecific)),
+        ("232.255.255.255", Some(MulticastScope::SourceSpecific)),
+        ("233.0.0.0", Some(MulticastScope::Glop)),
+        ("233.255.255.255", Some(MulticastScope::Glop)),
+        ("234.0.0.0", Some(MulticastScope::Global)),
+        ("238.255.255.255", Some(MulticastScope::Global)),
+        ("239.0.0.0", Some(MulticastScope::AdministrativelyScoped)),
+        (
+            "239.255.255.255",
+            Some(MulticastScope::AdministrativelyScoped),
+        ),
+        ("240.0.0.0", None),
+    ];
+
+    for (ip, scope) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(multicast_scope(ip), scope, "{ip}");
+    }
+}
+
+#[test]
+fn multicast_scope_v6_nibbles() {
+    let expected = [
+        ("feff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", None),
+        ("ff00::", Some(MulticastScope::Unassigned(0))),
+        ("ff01::1", Some(MulticastScope::InterfaceLocal)),
+        ("ff02::1", Some(MulticastScope::LinkLocal)),
+        ("ff03::1", Some(MulticastScope::RealmLocal)),
+        ("ff04::1", Some(MulticastScope::AdminLocal)),
+        ("ff05::1:3", Some(MulticastScope::SiteLocal)),
+        ("ff06::1", Some(MulticastScope::Unassigned(6))),
+        ("ff08::1", Some(MulticastScope::OrganizationLocal)),
+        ("ff0e::1", Some(MulticastScope::Global)),
+        ("ff0f::1", Some(MulticastScope::Unassigned(0xf))),
+        // The flag bits don't change the scope.
+        ("ff12::1", Some(MulticastScope::LinkLocal)),
+        ("ff3e::8000:1", Some(MulticastScope::Global)),
+        (
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            Some(MulticastScope::Unassigned(0xf)),
+        ),
+    ];
+
+    for (ip, scope) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(multicast_scope(ip), scope, "{ip}");
+    }
+}
+
+#[test]
+fn multicast_scope_matches_classify() {
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random::<u128>() | (0xff << 120)));
+
+        for ip in [v4, v6] {
+            assert_eq!(
+                multicast_scope(ip).is_some(),
+                classify(ip) == Some(BogonKind::Multicast),
+                "{ip}"
+            );
+        }
+    }
+}
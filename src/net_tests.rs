@@ -1,38 +1,351 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+#[cfg(not(feature = "download"))]
 use ipnetwork::Ipv4Network;
 
-use crate::{network::FourByteNetwork, V4_BOGON_NETWORKS};
+#[cfg(not(feature = "download"))]
+use crate::network::FourByteNetwork;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    classify_v4, classify_v6, ipv6_unicast_address_allocations::V6_ALLOCATION_RANGES,
+    is_allocated_v6, is_bogon_v4, is_bogon_v4_bits, is_bogon_v6, is_bogon_v6_bits, is_excepted_v6,
+    BogonKind, V4_BOGON_EXCEPTIONS, V4_BOGON_NETWORKS, V6_BOGON_EXCEPTIONS,
+    V6_GLOBAL_BOGON_NETWORKS,
+};
+
+/// Returns the networks of the bundled IPv4 special-purpose registry, each with a boolean
+/// indicating whether it is globally reachable.
+#[cfg(not(feature = "download"))]
+fn special_registry_v4() -> Vec<(Ipv4Network, bool)> {
+    let csv = include_str!("../bogon-data/iana-ipv4-special-registry-1.csv");
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let headers = reader.headers().unwrap().clone();
+    let column = |name| headers.iter().position(|header| header == name).unwrap();
+    let (block, reachable) = (column("Address Block"), column("Globally Reachable"));
+
+    reader
+        .records()
+        .flat_map(|record| {
+            let record = record.unwrap();
+            let reachable = record[reachable].starts_with("True");
+            // Footnote references follow the networks, like `192.0.0.0/24 [2]`.
+            record[block]
+                .split(',')
+                .map(|network| network.split_whitespace().next().unwrap().parse().unwrap())
+                .map(|network| (network, reachable))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
 
 #[test]
+#[cfg(not(feature = "download"))]
 fn check_v4_networks() {
-    let bogus = &[
-        "0.0.0.0/8",
-        "10.0.0.0/8",
-        "100.64.0.0/10",
-        "127.0.0.0/8",
-        "169.254.0.0/16",
-        "172.16.0.0/12",
-        "192.0.0.0/24",
-        "192.0.2.0/24",
-        "192.168.0.0/16",
-        "198.18.0.0/15",
-        "198.51.100.0/24",
-        "203.0.113.0/24",
-        "224.0.0.0/4",
-        "240.0.0.0/4",
-        "255.255.255.255/32",
+    let registry = special_registry_v4();
+    let multicast: Ipv4Network = "224.0.0.0/4".parse().unwrap();
+    let allowed = [
+        (cfg!(feature = "allow-cgnat"), "100.64.0.0/10"),
+        (cfg!(feature = "allow-benchmarking"), "198.18.0.0/15"),
+        (cfg!(feature = "allow-reserved-240-4"), "240.0.0.0/4"),
     ]
-    .iter()
-    .map(|&s| s.parse().unwrap())
-    .map(|n: Ipv4Network| FourByteNetwork::new(n.network().to_bits(), n.prefix()))
+    .into_iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, network)| network.parse::<Ipv4Network>().unwrap())
     .collect::<Vec<_>>();
 
-    // Compare to the unsafe static V4_NETWORKS.
-    for (a, b) in bogus.iter().zip(V4_BOGON_NETWORKS) {
-        assert_eq!(a, &b);
-    }
-
-    // Double check that the prefix length is less than or equal to 32.
+    // Every bogus network is a row of the registry, except for multicast, which has a registry of
+    // its own.
     for network in V4_BOGON_NETWORKS {
         assert!(network.prefix() <= 32);
+        let network =
+            Ipv4Network::new(Ipv4Addr::from_bits(network.network()), network.prefix()).unwrap();
+        assert!(
+            network == multicast || registry.contains(&(network, false)),
+            "{network}"
+        );
+    }
+
+    // Every row is bogus as a whole, unless it is globally reachable or an `allow-*` feature
+    // removes it.
+    for (network, reachable) in &registry {
+        if allowed.contains(network) {
+            assert!(!is_bogon_v4(network.network()), "{network}");
+            continue;
+        }
+        for ip in [network.network(), network.broadcast()] {
+            assert_eq!(is_bogon_v4(ip), !reachable, "{ip} in {network}");
+        }
+    }
+
+    // The globally reachable rows inside of bogus networks are exactly the exceptions.
+    let exceptions = registry
+        .iter()
+        .filter(|&&(network, reachable)| {
+            reachable
+                && V4_BOGON_NETWORKS
+                    .iter()
+                    .any(|bogon| bogon.contains_v4(network.network()))
+        })
+        .map(|(network, _)| FourByteNetwork::new(network.network().to_bits(), network.prefix()))
+        .collect::<Vec<_>>();
+    assert_eq!(exceptions, V4_BOGON_EXCEPTIONS);
+}
+
+#[test]
+fn allow_features() {
+    let allowed = [
+        (cfg!(feature = "allow-cgnat"), Ipv4Addr::new(100, 64, 0, 1)),
+        (
+            cfg!(feature = "allow-benchmarking"),
+            Ipv4Addr::new(198, 18, 0, 1),
+        ),
+        (
+            cfg!(feature = "allow-reserved-240-4"),
+            Ipv4Addr::new(240, 0, 0, 1),
+        ),
+    ];
+
+    let removed = allowed.iter().filter(|(enabled, _)| *enabled).count();
+    assert_eq!(V4_BOGON_NETWORKS.len(), 16 - removed);
+
+    for (enabled, ip) in allowed {
+        assert_eq!(is_bogon_v4(ip), !enabled, "{ip}");
+        assert_eq!(classify_v4(ip).is_none(), enabled, "{ip}");
+    }
+
+    // The neighbouring networks are never affected.
+    assert!(is_bogon_v4(Ipv4Addr::new(10, 0, 0, 1)));
+    assert!(is_bogon_v4(Ipv4Addr::new(198, 51, 100, 1)));
+    assert!(is_bogon_v4(Ipv4Addr::BROADCAST));
+}
+
+#[test]
+fn v4_bogon_exceptions() {
+    // Port Control Protocol and TURN anycast are globally reachable.
+    for ip in [Ipv4Addr::new(192, 0, 0, 9), Ipv4Addr::new(192, 0, 0, 10)] {
+        assert!(!is_bogon_v4(ip), "{ip}");
+        assert_eq!(classify_v4(ip), None, "{ip}");
+    }
+
+    // The rest of 192.0.0.0/24 is still bogus.
+    for ip in [
+        Ipv4Addr::new(192, 0, 0, 0),
+        Ipv4Addr::new(192, 0, 0, 8),
+        Ipv4Addr::new(192, 0, 0, 11),
+        Ipv4Addr::new(192, 0, 0, 255),
+    ] {
+        assert!(is_bogon_v4(ip), "{ip}");
+        assert_eq!(
+            classify_v4(ip),
+            Some(BogonKind::ProtocolAssignments),
+            "{ip}"
+        );
+    }
+}
+
+#[test]
+fn v6_bogon_exceptions() {
+    let reachable = [
+        // Port Control Protocol Anycast
+        "2001:1::1",
+        // Traversal Using Relays around NAT Anycast
+        "2001:1::2",
+        // AMT
+        "2001:3::",
+        "2001:3:ffff:ffff:ffff:ffff:ffff:ffff",
+        // AS112-v6
+        "2001:4:112::",
+        "2001:4:112:ffff:ffff:ffff:ffff:ffff",
+        // Drone Remote ID Protocol Entity Tags (DETs) Prefix
+        "2001:30::",
+        "2001:3f:ffff:ffff:ffff:ffff:ffff:ffff",
+    ];
+    for ip in reachable {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert!(!is_bogon_v6(ip), "{ip}");
+        assert_eq!(classify_v6(ip), None, "{ip}");
+    }
+
+    // The rest of 2001::/23 is still bogus.
+    let bogus = [
+        "2001:5::",
+        "2001:1::",
+        "2001:1::3",
+        "2001:2:ffff:ffff:ffff:ffff:ffff:ffff",
+        "2001:4:111:ffff:ffff:ffff:ffff:ffff",
+        "2001:4:113::",
+        "2001:40::",
+        "2001:1ff:ffff:ffff:ffff:ffff:ffff:ffff",
+    ];
+    for ip in bogus {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert!(is_bogon_v6(ip), "{ip}");
+        assert_eq!(
+            classify_v6(ip),
+            Some(BogonKind::ProtocolAssignments),
+            "{ip}"
+        );
+    }
+
+    // Benchmarking is more specific than the rest of 2001::/23.
+    assert_eq!(
+        classify_v6("2001:2::1".parse().unwrap()),
+        Some(BogonKind::Benchmarking)
+    );
+}
+
+#[test]
+fn v6_allocations_sorted() {
+    // The build script merges the allocations into sorted, disjoint ranges.
+    for (first, last) in V6_ALLOCATION_RANGES {
+        assert!(first <= last, "{first:#x}-{last:#x}");
+    }
+    for pair in V6_ALLOCATION_RANGES.windows(2) {
+        assert!(pair[0].1 < pair[1].0, "{pair:x?}");
+    }
+}
+
+/// The full check `is_bogon_v4` falls back to for the /8s the first octet doesn't settle.
+fn is_bogon_v4_by_scan(ip: Ipv4Addr) -> bool {
+    V4_BOGON_NETWORKS
+        .iter()
+        .any(|network| network.contains_v4(ip))
+        && !V4_BOGON_EXCEPTIONS
+            .iter()
+            .any(|network| network.contains_v4(ip))
+}
+
+#[test]
+fn first_octet_fast_path_matches_the_scan() {
+    // The first and last address of every /24, and every address of the /24s the bogus networks and
+    // exceptions start or end in.
+    let mut edges = Vec::new();
+    for network in V4_BOGON_NETWORKS.iter().chain(&V4_BOGON_EXCEPTIONS) {
+        let broadcast = network.network() | !network.mask();
+        edges.push(network.network() & !0xff);
+        edges.push(broadcast & !0xff);
+    }
+    for block in 0..1u32 << 24 {
+        let ips = [block << 8, block << 8 | 0xff];
+        for ip in ips.map(Ipv4Addr::from_bits) {
+            assert_eq!(is_bogon_v4(ip), is_bogon_v4_by_scan(ip), "{ip}");
+        }
+    }
+    for edge in edges {
+        for ip in (edge..=edge | 0xff).map(Ipv4Addr::from_bits) {
+            assert_eq!(is_bogon_v4(ip), is_bogon_v4_by_scan(ip), "{ip}");
+        }
+    }
+}
+
+/// `is_bogon_v6` without the first hextet fast path.
+fn is_bogon_v6_by_scan(ip: Ipv6Addr) -> bool {
+    if ip.segments()[0] & 0xe000 != 0x2000 {
+        return true;
+    }
+    if V6_GLOBAL_BOGON_NETWORKS
+        .iter()
+        .any(|network| network.contains_v6(ip))
+    {
+        return !is_excepted_v6(ip);
+    }
+    !is_allocated_v6(ip)
+}
+
+#[test]
+fn first_hextet_fast_path_matches_the_scan() {
+    // The first and last address of every /16, and a few random addresses inside of it.
+    let mut rng = StdRng::seed_from_u64(16);
+    for hextet in 0..=u16::MAX {
+        let network = u128::from(hextet) << 112;
+        let ips = [network, network | u128::MAX >> 16]
+            .into_iter()
+            .chain((0..8).map(|_| network | rng.gen::<u128>() >> 16));
+        for ip in ips.map(Ipv6Addr::from_bits) {
+            assert_eq!(is_bogon_v6(ip), is_bogon_v6_by_scan(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn raw_v4_matches_the_wrapper() {
+    // Both sides of the bounds of every /8, bogus network, and exception.
+    let octets = (0..=255u32).flat_map(|octet| [octet << 24, octet << 24 | 0x00ff_ffff]);
+    let networks = V4_BOGON_NETWORKS
+        .iter()
+        .chain(&V4_BOGON_EXCEPTIONS)
+        .flat_map(|network| [network.network(), network.network() | !network.mask()]);
+    for edge in octets.chain(networks) {
+        for bits in [edge.wrapping_sub(1), edge, edge.wrapping_add(1)] {
+            let ip = Ipv4Addr::from_bits(bits);
+            assert_eq!(is_bogon_v4_bits(bits), is_bogon_v4(ip), "{ip}");
+        }
+    }
+
+    // It can be evaluated at compile time.
+    const { assert!(is_bogon_v4_bits(0x7f00_0001) && !is_bogon_v4_bits(0xc000_0009)) };
+}
+
+#[test]
+fn raw_v6_matches_the_wrapper() {
+    // Both sides of the bounds of every /16, bogus network, exception, and allocation range.
+    let hextets = (0..=u16::MAX).flat_map(|hextet| {
+        let network = u128::from(hextet) << 112;
+        [network, network | u128::MAX >> 16]
+    });
+    let networks = V6_GLOBAL_BOGON_NETWORKS
+        .iter()
+        .chain(&V6_BOGON_EXCEPTIONS)
+        .flat_map(|network| [network.network(), network.broadcast()]);
+    let ranges = V6_ALLOCATION_RANGES.iter().flat_map(|&(first, last)| {
+        [
+            u128::from(first) << 96,
+            u128::from(last) << 96 | u128::from(u32::MAX) << 64 | u128::from(u64::MAX),
+        ]
+    });
+    for edge in hextets.chain(networks).chain(ranges) {
+        for bits in [edge.wrapping_sub(1), edge, edge.wrapping_add(1)] {
+            let ip = Ipv6Addr::from_bits(bits);
+            assert_eq!(is_bogon_v6_bits(bits), is_bogon_v6(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "codegen-match")]
+fn generated_tree_matches_the_ranges() {
+    let by_search = |top: u32| {
+        let index = V6_ALLOCATION_RANGES.partition_point(|&(_, last)| last < top);
+        let allocated = V6_ALLOCATION_RANGES
+            .get(index)
+            .is_some_and(|&(first, _)| first <= top);
+        !allocated
+    };
+
+    // Both sides of the bounds of every range.
+    let edges = V6_ALLOCATION_RANGES
+        .iter()
+        .flat_map(|&(first, last)| [first, last])
+        .flat_map(|edge| [edge.wrapping_sub(1), edge, edge.wrapping_add(1)])
+        .chain([0, u32::MAX]);
+    for top in edges {
+        assert_eq!(
+            bogon_data::is_bogon_v6_generated(top),
+            by_search(top),
+            "{top:#x}"
+        );
+    }
+
+    // Random addresses, half of them inside of 2000::/3 where the ranges are.
+    let mut rng = StdRng::seed_from_u64(75);
+    for _ in 0..4_000_000 {
+        let top: u32 = rng.gen();
+        let top = if rng.gen() { top >> 3 | 1 << 29 } else { top };
+        assert_eq!(
+            bogon_data::is_bogon_v6_generated(top),
+            by_search(top),
+            "{top:#x}"
+        );
     }
 }
@@ -1,7 +1,7 @@
-use core::net::{Ipv4Addr, Ipv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-/// Since all of the IPv4 and IPv6 bogon networks have at most 32-bit prefixes we can preform
-/// all of our network calculations with 32-bit integers.
+/// Since all of the IPv4 bogon networks, and nearly all of the IPv6 allocations, have at most
+/// 32-bit prefixes we can preform most of our network calculations with 32-bit integers.
 ///
 /// When it comes time to check if an IP address is contained within a slice of networks
 /// the compiler will generate SIMD instructions to check multiple networks at once.
@@ -13,12 +13,21 @@ pub(crate) struct FourByteNetwork {
 
 impl FourByteNetwork {
     pub(crate) const fn new(network: u32, prefix: u8) -> Self {
-        let mask = u32::MAX << (32 - prefix);
+        let mask = if prefix == 0 {
+            0
+        } else {
+            u32::MAX << (32 - prefix)
+        };
         Self { network, mask }
     }
 
     pub(crate) const fn contains_v4(&self, ip: Ipv4Addr) -> bool {
-        (ip.to_bits() & self.mask) == self.network
+        self.contains_bits(ip.to_bits())
+    }
+
+    /// Returns a boolean indicating whether the network contains the 32 bits, in host order.
+    pub(crate) const fn contains_bits(&self, bits: u32) -> bool {
+        (bits & self.mask) == self.network
     }
 
     pub(crate) const fn contains_v6(&self, ip: Ipv6Addr) -> bool {
@@ -27,7 +36,91 @@ impl FourByteNetwork {
     }
 
     #[cfg(test)]
-    pub const fn prefix(&self) -> u8 {
-        32 - self.mask.leading_zeros() as u8
+    pub(crate) const fn network(&self) -> u32 {
+        self.network
+    }
+
+    #[cfg(test)]
+    pub(crate) const fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Returns the network as an IPv4 address and prefix length.
+    pub(crate) const fn to_v4(self) -> (IpAddr, u8) {
+        (IpAddr::V4(Ipv4Addr::from_bits(self.network)), self.prefix())
+    }
+
+    /// Returns the network as the top 32 bits of an IPv6 address and prefix length.
+    pub(crate) const fn to_v6(self) -> (IpAddr, u8) {
+        let network = Ipv6Addr::from_bits((self.network as u128) << 96);
+        (IpAddr::V6(network), self.prefix())
+    }
+
+    pub(crate) const fn prefix(&self) -> u8 {
+        self.mask.count_ones() as u8
+    }
+}
+
+/// An IPv6 network with a prefix longer than 32 bits, which can't be represented by a
+/// [`FourByteNetwork`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SixteenByteNetwork {
+    network: u128,
+    mask: u128,
+}
+
+impl SixteenByteNetwork {
+    pub(crate) const fn new(network: u128, prefix: u8) -> Self {
+        let mask = if prefix == 0 {
+            0
+        } else {
+            u128::MAX << (128 - prefix)
+        };
+        Self { network, mask }
     }
+
+    pub(crate) const fn contains_v6(&self, ip: Ipv6Addr) -> bool {
+        (ip.to_bits() & self.mask) == self.network
+    }
+
+    pub(crate) const fn network(&self) -> u128 {
+        self.network
+    }
+
+    #[cfg(feature = "simd")]
+    pub(crate) const fn mask(&self) -> u128 {
+        self.mask
+    }
+
+    /// The last address in the network.
+    #[cfg(test)]
+    pub(crate) const fn broadcast(&self) -> u128 {
+        self.network | !self.mask
+    }
+
+    /// Returns the network as an IPv6 address and prefix length.
+    pub(crate) const fn to_v6(self) -> (IpAddr, u8) {
+        (IpAddr::V6(Ipv6Addr::from_bits(self.network)), self.prefix())
+    }
+
+    pub(crate) const fn prefix(&self) -> u8 {
+        self.mask.count_ones() as u8
+    }
+}
+
+/// Parses a network written as `address/prefix`, or a bare address as a single-address network.
+#[cfg(any(feature = "serde", feature = "std"))]
+pub(crate) fn parse_network(s: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix_len) = match s.split_once('/') {
+        Some((address, prefix_len)) => (address, Some(prefix_len)),
+        None => (s, None),
+    };
+
+    let address: IpAddr = address.parse().ok()?;
+    let max = if address.is_ipv4() { 32 } else { 128 };
+    let prefix_len = match prefix_len {
+        Some(prefix_len) => prefix_len.parse().ok()?,
+        None => max,
+    };
+    (prefix_len <= max).then_some((address, prefix_len))
 }
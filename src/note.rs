@@ -0,0 +1,94 @@
+use core::net::IpAddr;
+
+/// A note on a globally routed network that deserves one.
+///
+/// Returned by [`annotate`]. Addresses with a note are not bogus, so they are never classified by
+/// [`classify`](crate::classify).
+///
+/// With the `serde` feature notes are serialized as kebab-case names, for example `"as112"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum RoutableNote {
+    /// AS112 direct delegation anycast networks (`192.175.48.0/24`, `2620:4f:8000::/48`).
+    As112,
+}
+
+impl RoutableNote {
+    /// Every note, in declaration order.
+    pub const ALL: [RoutableNote; 1] = [RoutableNote::As112];
+
+    /// Returns the RFC that governs this note.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::RoutableNote;
+    ///
+    /// assert_eq!(RoutableNote::As112.rfc(), "RFC 7534");
+    /// ```
+    pub const fn rfc(&self) -> &'static str {
+        match self {
+            RoutableNote::As112 => "RFC 7534",
+        }
+    }
+
+    /// Returns a description of the networks behind this note.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::RoutableNote;
+    ///
+    /// assert_eq!(RoutableNote::As112.description(), "AS112 Direct Delegation");
+    /// ```
+    pub const fn description(&self) -> &'static str {
+        match self {
+            RoutableNote::As112 => "AS112 Direct Delegation",
+        }
+    }
+
+    /// Returns a short label for this note, suitable for log fields.
+    ///
+    /// Labels are stable and safe to search logs for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::RoutableNote;
+    ///
+    /// assert_eq!(RoutableNote::As112.label(), "AS112 anycast (RFC 7534)");
+    /// ```
+    pub const fn label(&self) -> &'static str {
+        match self {
+            RoutableNote::As112 => "AS112 anycast (RFC 7534)",
+        }
+    }
+}
+
+/// Returns the note on a globally routed IP address, or `None` if the IP address is bogus or
+/// nothing is noted about it.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{annotate, classify, is_bogon, RoutableNote};
+///
+/// let ip = "192.175.48.1".parse().unwrap();
+/// assert_eq!(annotate(ip), Some(RoutableNote::As112));
+/// assert_eq!(classify(ip), None);
+/// assert!(!is_bogon(ip));
+///
+/// assert_eq!(annotate("8.8.8.8".parse().unwrap()), None);
+/// ```
+pub fn annotate(ip_address: IpAddr) -> Option<RoutableNote> {
+    if crate::is_bogon(ip_address) {
+        return None;
+    }
+
+    crate::matched_routable(ip_address).map(|(_, note)| note)
+}
@@ -0,0 +1,72 @@
+use core::net::IpAddr;
+
+use crate::{annotate, classify, describe, explain, is_bogon, matched_bogon_network, RoutableNote};
+
+#[test]
+fn as112_is_noted_but_routable() {
+    let ranges = [
+        (
+            "192.175.47.255",
+            "192.175.48.0",
+            "192.175.48.255",
+            "192.175.49.0",
+        ),
+        (
+            "2620:4f:7fff:ffff:ffff:ffff:ffff:ffff",
+            "2620:4f:8000::",
+            "2620:4f:8000:ffff:ffff:ffff:ffff:ffff",
+            "2620:4f:8001::",
+        ),
+    ];
+
+    for (before, first, last, after) in ranges {
+        let [before, first, last, after] =
+            [before, first, last, after].map(|ip| ip.parse::<IpAddr>().unwrap());
+
+        for ip in [first, last] {
+            assert_eq!(annotate(ip), Some(RoutableNote::As112), "{ip}");
+            assert_eq!(classify(ip), None, "{ip}");
+            assert!(!is_bogon(ip), "{ip}");
+            assert_eq!(matched_bogon_network(ip), None, "{ip}");
+        }
+        for ip in [before, after] {
+            assert_eq!(annotate(ip), None, "{ip}");
+            assert_eq!(classify(ip), None, "{ip}");
+            assert!(!is_bogon(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn bogons_are_never_noted() {
+    for ip in ["10.0.0.1", "127.0.0.1", "::1", "fd00::1", "3000::1"] {
+        let ip = ip.parse().unwrap();
+        assert!(is_bogon(ip), "{ip}");
+        assert_eq!(annotate(ip), None, "{ip}");
+    }
+}
+
+#[test]
+fn explain_notes() {
+    let explanation = explain("2620:4f:8000::1".parse().unwrap());
+    assert!(!explanation.is_bogon);
+    assert_eq!(explanation.kind, None);
+    assert_eq!(explanation.note, Some(RoutableNote::As112));
+    assert_eq!(
+        explanation.network,
+        Some(("2620:4f:8000::".parse().unwrap(), 48))
+    );
+    assert_eq!(
+        describe("192.175.48.1".parse().unwrap()),
+        "AS112 anycast (RFC 7534)"
+    );
+}
+
+#[test]
+fn note_metadata() {
+    for note in RoutableNote::ALL {
+        assert!(note.rfc().starts_with("RFC "), "{note:?}");
+        assert!(!note.description().is_empty(), "{note:?}");
+        assert!(!note.label().is_empty(), "{note:?}");
+    }
+}
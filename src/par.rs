@@ -0,0 +1,113 @@
+//! Checking large slices of IP addresses on every core with `rayon`, for the `rayon` feature.
+//!
+//! The slices are split into contiguous chunks of at least [`CHUNK`] addresses, so each worker
+//! streams through its own part of the input while the tables stay hot in its core's cache.
+
+use alloc::vec::Vec;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use rayon::prelude::*;
+
+use crate::{classify, is_bogon_v4_batch, is_bogon_v6_batch, BogonKind};
+
+/// The fewest addresses a worker checks at once. A multiple of 64, so the chunks of the batch checks
+/// fill whole words of the bitmap.
+pub(crate) const CHUNK: usize = 1 << 14;
+
+/// Returns the classification of each of a slice of IP addresses, see [`classify`], checking
+/// contiguous chunks of the slice in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use bogon::{par_classify, BogonKind};
+///
+/// let ips = [
+///     IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+///     IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)),
+/// ];
+/// assert_eq!(par_classify(&ips), [Some(BogonKind::Loopback), None]);
+/// ```
+pub fn par_classify(ips: &[IpAddr]) -> Vec<Option<BogonKind>> {
+    par_classify_iter(ips).collect()
+}
+
+/// Returns a parallel iterator over the classification of each of a slice of IP addresses, in the
+/// same order, see [`classify`].
+///
+/// Like [`par_classify`], for composing with other parallel iterators instead of collecting the
+/// classifications.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use bogon::par_classify_iter;
+/// use rayon::prelude::*;
+///
+/// let ips = [
+///     IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+///     IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)),
+///     IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+/// ];
+/// let bogus = par_classify_iter(&ips)
+///     .filter(Option::is_some)
+///     .count();
+/// assert_eq!(bogus, 2);
+/// ```
+pub fn par_classify_iter(
+    ips: &[IpAddr],
+) -> impl IndexedParallelIterator<Item = Option<BogonKind>> + '_ {
+    ips.par_iter().with_min_len(CHUNK).map(|&ip| classify(ip))
+}
+
+/// Checks a slice of IPv4 addresses like [`is_bogon_v4_batch`], filling disjoint parts of `out`
+/// in parallel.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than `ips.len().div_ceil(64)` words.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::par_is_bogon_v4_batch;
+///
+/// let ips = [Ipv4Addr::new(8, 8, 8, 8), Ipv4Addr::new(127, 0, 0, 1)];
+/// let mut out = [0; 1];
+/// par_is_bogon_v4_batch(&ips, &mut out);
+/// assert_eq!(out, [0b10]);
+/// ```
+pub fn par_is_bogon_v4_batch(ips: &[Ipv4Addr], out: &mut [u64]) {
+    crate::batch::check_len(ips.len(), out);
+    ips.par_chunks(CHUNK)
+        .zip(out.par_chunks_mut(CHUNK / 64))
+        .for_each(|(ips, out)| is_bogon_v4_batch(ips, out));
+}
+
+/// Checks a slice of IPv6 addresses like [`is_bogon_v6_batch`], filling disjoint parts of `out`
+/// in parallel.
+///
+/// # Panics
+///
+/// Panics if `out` is shorter than `ips.len().div_ceil(64)` words.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::par_is_bogon_v6_batch;
+///
+/// let ips = [Ipv6Addr::LOCALHOST, Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 1)];
+/// let mut out = [0; 1];
+/// par_is_bogon_v6_batch(&ips, &mut out);
+/// assert_eq!(out, [0b01]);
+/// ```
+pub fn par_is_bogon_v6_batch(ips: &[Ipv6Addr], out: &mut [u64]) {
+    crate::batch::check_len(ips.len(), out);
+    ips.par_chunks(CHUNK)
+        .zip(out.par_chunks_mut(CHUNK / 64))
+        .for_each(|(ips, out)| is_bogon_v6_batch(ips, out));
+}
@@ -0,0 +1,85 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::vec::Vec;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+
+use crate::{
+    classify, is_bogon_v4_batch, is_bogon_v6_batch, par::CHUNK, par_classify, par_classify_iter,
+    par_is_bogon_v4_batch, par_is_bogon_v6_batch,
+};
+
+/// Enough addresses for several chunks, ending in a partial chunk and a partial word.
+const LEN: usize = CHUNK * 3 + 100;
+
+fn random_ips(rng: &mut StdRng) -> Vec<IpAddr> {
+    (0..LEN)
+        .map(|_| {
+            if rng.gen() {
+                IpAddr::V4(Ipv4Addr::from_bits(rng.gen()))
+            } else {
+                // Inside of 2000::/3, where most of the tables are.
+                let bits: u128 = rng.gen();
+                IpAddr::V6(Ipv6Addr::from_bits(bits >> 3 | 1 << 125))
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn par_classify_matches_classify() {
+    let ips = random_ips(&mut StdRng::seed_from_u64(78));
+    let sequential = ips.iter().map(|&ip| classify(ip)).collect::<Vec<_>>();
+    assert_eq!(par_classify(&ips), sequential);
+    assert_eq!(par_classify_iter(&ips).collect::<Vec<_>>(), sequential);
+    assert!(par_classify(&[]).is_empty());
+}
+
+#[test]
+fn par_classify_iter_is_indexed() {
+    let ips = random_ips(&mut StdRng::seed_from_u64(8));
+    assert_eq!(par_classify_iter(&ips).len(), LEN);
+    let last = par_classify_iter(&ips).skip(LEN - 1).collect::<Vec<_>>();
+    assert_eq!(last, [classify(ips[LEN - 1])]);
+}
+
+#[test]
+fn par_batches_match_the_batches() {
+    let mut rng = StdRng::seed_from_u64(64);
+    let v4 = (0..LEN)
+        .map(|_| Ipv4Addr::from_bits(rng.gen()))
+        .collect::<Vec<_>>();
+    let v6 = (0..LEN)
+        .map(|_| Ipv6Addr::from_bits(rng.gen::<u128>() >> 3 | 1 << 125))
+        .collect::<Vec<_>>();
+
+    // Like the sequential batches, the words past the end of the input are left alone.
+    let words = LEN.div_ceil(64);
+    let mut sequential = vec![u64::MAX; words + 1];
+    let mut parallel = vec![u64::MAX; words + 1];
+    is_bogon_v4_batch(&v4, &mut sequential);
+    par_is_bogon_v4_batch(&v4, &mut parallel);
+    assert_eq!(parallel, sequential);
+
+    let mut sequential = vec![u64::MAX; words + 1];
+    let mut parallel = vec![u64::MAX; words + 1];
+    is_bogon_v6_batch(&v6, &mut sequential);
+    par_is_bogon_v6_batch(&v6, &mut parallel);
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+#[should_panic(expected = "1 words is too short for 65 addresses")]
+fn par_short_bitmap() {
+    par_is_bogon_v4_batch(&[Ipv4Addr::LOCALHOST; 65], &mut [0; 1]);
+}
+
+#[test]
+fn par_classify_composes() {
+    let ips = random_ips(&mut StdRng::seed_from_u64(1));
+    let bogus = par_classify_iter(&ips)
+        .zip(ips.par_iter())
+        .filter(|(kind, _)| kind.is_some())
+        .count();
+    assert_eq!(bogus, ips.iter().filter(|&&ip| crate::is_bogon(ip)).count());
+}
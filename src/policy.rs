@@ -0,0 +1,210 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{transition, BogonKind};
+
+/// Options for judging IPv6 transition addresses by the IPv4 address they embed.
+///
+/// Every option is off by default, so `is_bogon_with(ip, Policy::DEFAULT)` is always equal to
+/// `is_bogon(ip)`. [`Policy::LENIENT`] turns on every option.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon_with, Policy};
+///
+/// const POLICY: Policy = Policy::DEFAULT.with_6to4(true);
+///
+/// assert!(!is_bogon_with("2002:808:808::1".parse().unwrap(), POLICY));
+/// assert!(is_bogon_with("2002:a00:1::1".parse().unwrap(), POLICY));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct Policy {
+    /// Judge IPv4-mapped addresses (`::ffff:0:0/96`) by the IPv4 address in the low 32 bits.
+    pub canonicalize_mapped: bool,
+    /// Judge 6to4 addresses (`2002::/16`) by the IPv4 address in bits 16 through 47.
+    pub evaluate_6to4: bool,
+    /// Judge Teredo addresses (`2001::/32`) by the client's external IPv4 address.
+    pub evaluate_teredo: bool,
+    /// Judge NAT64 well-known prefix addresses (`64:ff9b::/96`) by the IPv4 address in the low 32
+    /// bits.
+    pub evaluate_nat64_wkp: bool,
+    nat64_prefixes: [Option<(Ipv6Addr, u8)>; Policy::MAX_NAT64_PREFIXES],
+}
+
+impl Policy {
+    /// The default policy, which doesn't look inside of transition addresses.
+    pub const DEFAULT: Policy = Policy {
+        canonicalize_mapped: false,
+        evaluate_6to4: false,
+        evaluate_teredo: false,
+        evaluate_nat64_wkp: false,
+        nat64_prefixes: [None; Policy::MAX_NAT64_PREFIXES],
+    };
+
+    /// A policy that judges every transition address by the IPv4 address it embeds.
+    pub const LENIENT: Policy = Policy {
+        canonicalize_mapped: true,
+        evaluate_6to4: true,
+        evaluate_teredo: true,
+        evaluate_nat64_wkp: true,
+        nat64_prefixes: [None; Policy::MAX_NAT64_PREFIXES],
+    };
+
+    /// The number of network-specific NAT64 prefixes a policy can hold.
+    pub const MAX_NAT64_PREFIXES: usize = 4;
+
+    /// Returns the policy with [`Policy::canonicalize_mapped`] set.
+    #[must_use]
+    pub const fn with_canonicalize_mapped(mut self, enabled: bool) -> Self {
+        self.canonicalize_mapped = enabled;
+        self
+    }
+
+    /// Returns the policy with [`Policy::evaluate_6to4`] set.
+    #[must_use]
+    pub const fn with_6to4(mut self, enabled: bool) -> Self {
+        self.evaluate_6to4 = enabled;
+        self
+    }
+
+    /// Returns the policy with [`Policy::evaluate_teredo`] set.
+    #[must_use]
+    pub const fn with_teredo(mut self, enabled: bool) -> Self {
+        self.evaluate_teredo = enabled;
+        self
+    }
+
+    /// Returns the policy with [`Policy::evaluate_nat64_wkp`] set.
+    #[must_use]
+    pub const fn with_nat64_wkp(mut self, enabled: bool) -> Self {
+        self.evaluate_nat64_wkp = enabled;
+        self
+    }
+
+    /// Returns the policy with a network-specific NAT64 prefix added, addresses under the prefix
+    /// are judged by the IPv4 address they embed (see [`nat64_ipv4`](crate::nat64_ipv4)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix length isn't 32, 40, 48, 56, 64, or 96, or if the policy already holds
+    /// [`Policy::MAX_NAT64_PREFIXES`] prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::net::Ipv6Addr;
+    /// use bogon::{is_bogon_with, Policy};
+    ///
+    /// const POLICY: Policy =
+    ///     Policy::DEFAULT.with_nat64_prefix(Ipv6Addr::new(0x2001, 0xdb8, 0x64, 0, 0, 0, 0, 0), 96);
+    ///
+    /// assert!(!is_bogon_with("2001:db8:64::8.8.8.8".parse().unwrap(), POLICY));
+    /// assert!(is_bogon_with("2001:db8:64::10.0.0.1".parse().unwrap(), POLICY));
+    /// ```
+    #[must_use]
+    pub const fn with_nat64_prefix(mut self, prefix: Ipv6Addr, prefix_len: u8) -> Self {
+        assert!(
+            matches!(prefix_len, 32 | 40 | 48 | 56 | 64 | 96),
+            "NAT64 prefix length must be 32, 40, 48, 56, 64, or 96"
+        );
+
+        let mask = u128::MAX << (128 - prefix_len as u32);
+        let prefix = Ipv6Addr::from_bits(prefix.to_bits() & mask);
+
+        let mut i = 0;
+        while i < self.nat64_prefixes.len() {
+            if self.nat64_prefixes[i].is_none() {
+                self.nat64_prefixes[i] = Some((prefix, prefix_len));
+                return self;
+            }
+            i += 1;
+        }
+        panic!("too many NAT64 prefixes");
+    }
+
+    /// Returns the network-specific NAT64 prefixes of the policy, in the order they were added.
+    pub fn nat64_prefixes(&self) -> impl Iterator<Item = (Ipv6Addr, u8)> + '_ {
+        self.nat64_prefixes.iter().flatten().copied()
+    }
+}
+
+/// Returns the IPv4 address an IPv6 address should be judged by under a policy, if any.
+fn evaluated_v4(ip_address: Ipv6Addr, policy: &Policy) -> Option<Ipv4Addr> {
+    if policy.canonicalize_mapped {
+        if let Some(embedded) = ip_address.to_ipv4_mapped() {
+            return Some(embedded);
+        }
+    }
+
+    if policy.evaluate_6to4 {
+        if let Some(embedded) = transition::six_to_four(ip_address) {
+            return Some(embedded);
+        }
+    }
+
+    if policy.evaluate_teredo {
+        if let Some(parts) = transition::teredo_parts(ip_address) {
+            return Some(parts.client);
+        }
+    }
+
+    if policy.evaluate_nat64_wkp {
+        if let Some(embedded) = transition::nat64_well_known(ip_address) {
+            return Some(embedded);
+        }
+    }
+
+    policy
+        .nat64_prefixes()
+        .find_map(|(prefix, prefix_len)| transition::nat64_ipv4(ip_address, prefix, prefix_len))
+}
+
+/// Returns a boolean indicating whether an IP address is bogus, following a [`Policy`].
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon, is_bogon_with, Policy};
+///
+/// let ip = "2002:808:808::1".parse().unwrap();
+/// assert_eq!(is_bogon_with(ip, Policy::DEFAULT), is_bogon(ip));
+/// assert!(!is_bogon_with(ip, Policy::DEFAULT.with_6to4(true)));
+/// ```
+#[inline]
+pub fn is_bogon_with(ip_address: IpAddr, policy: Policy) -> bool {
+    if let IpAddr::V6(ip) = ip_address {
+        if let Some(embedded) = evaluated_v4(ip, &policy) {
+            return crate::is_bogon_v4(embedded);
+        }
+    }
+
+    crate::is_bogon(ip_address)
+}
+
+/// Returns the classification of an IP address, following a [`Policy`].
+///
+/// Transition addresses judged by their embedded IPv4 address get the classification of that IPv4
+/// address. `is_bogon_with(ip, policy)` is equal to `classify_with(ip, policy).is_some()`, with the
+/// same exception for runtime tables as [`classify`](crate::classify).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{classify, classify_with, BogonKind, Policy};
+///
+/// let ip = "64:ff9b::10.0.0.1".parse().unwrap();
+/// assert_eq!(classify(ip), Some(BogonKind::Nat64WellKnown));
+/// assert_eq!(classify_with(ip, Policy::LENIENT), Some(BogonKind::PrivateUse));
+/// assert_eq!(classify_with("64:ff9b::8.8.8.8".parse().unwrap(), Policy::LENIENT), None);
+/// ```
+#[inline]
+pub fn classify_with(ip_address: IpAddr, policy: Policy) -> Option<BogonKind> {
+    if let IpAddr::V6(ip) = ip_address {
+        if let Some(embedded) = evaluated_v4(ip, &policy) {
+            return crate::classify_v4(embedded);
+        }
+    }
+
+    crate::classify(ip_address)
+}
@@ -0,0 +1,242 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    classify, classify_with, is_bogon, is_bogon_canonical, is_bogon_with, BogonKind, Policy,
+};
+
+#[test]
+fn default_policy_matches_is_bogon() {
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        let global = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+
+        for ip in [v4, v6, global] {
+            assert_eq!(is_bogon_with(ip, Policy::DEFAULT), is_bogon(ip), "{ip}");
+            assert_eq!(is_bogon_with(ip, Policy::default()), is_bogon(ip), "{ip}");
+            assert_eq!(classify_with(ip, Policy::DEFAULT), classify(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn evaluate_6to4() {
+    let policy = Policy::DEFAULT.with_6to4(true);
+    let expected = [
+        // 8.8.8.8
+        ("2002:808:808::", false),
+        ("2002:808:808:ffff:ffff:ffff:ffff:ffff", false),
+        // 10.0.0.1
+        ("2002:a00:1::", true),
+        // 0.0.0.0, a prefix too short to carry an IPv4 address
+        ("2002::", true),
+        ("2002::808:808", true),
+    ];
+
+    for (ip, bogus) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), bogus, "{ip}");
+    }
+
+    // Addresses outside of 2002::/16 are judged as usual.
+    for ip in [
+        "2003:808:808::",
+        "2001:808:808::",
+        "::ffff:808:808",
+        "2001:4860:4860::8888",
+    ] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), is_bogon(ip), "{ip}");
+    }
+
+    // The default behavior doesn't change.
+    assert!(is_bogon("2002:808:808::".parse().unwrap()));
+    assert!(is_bogon_with(
+        "2002:808:808::".parse().unwrap(),
+        Policy::DEFAULT
+    ));
+}
+
+#[test]
+fn evaluate_teredo() {
+    let policy = Policy::DEFAULT.with_teredo(true);
+    let expected = [
+        // Client 192.0.2.45 (documentation)
+        ("2001:0:4136:e378:8000:63bf:3fff:fdd2", true),
+        // Client 8.8.8.8
+        ("2001:0:4136:e378:8000:63bf:f7f7:f7f7", false),
+        // Client 10.0.0.1
+        ("2001:0:4136:e378:8000:63bf:f5ff:fffe", true),
+    ];
+
+    for (ip, bogus) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), bogus, "{ip}");
+        // Teredo addresses are always bogus by default.
+        assert!(is_bogon_with(ip, Policy::DEFAULT), "{ip}");
+    }
+
+    // The rest of 2001::/23 is judged as usual.
+    for ip in ["2001:1::1", "2001:3::1", "2001:2::1"] {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), is_bogon(ip), "{ip}");
+    }
+}
+
+#[test]
+fn evaluate_nat64_wkp() {
+    let policy = Policy::DEFAULT.with_nat64_wkp(true);
+    let expected = [
+        ("64:ff9b::8.8.8.8", false),
+        ("64:ff9b::10.0.0.1", true),
+        ("64:ff9b::", true),
+        ("64:ff9b::1.1.1.1", false),
+        // Just outside of 64:ff9b::/96.
+        ("64:ff9a:ffff:ffff:ffff:ffff:808:808", true),
+        ("64:ff9b:0:0:0:1:808:808", true),
+        ("64:ff9b:1::808:808", true),
+    ];
+
+    for (ip, bogus) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), bogus, "{ip}");
+        // The whole NAT64 well-known prefix is bogus by default.
+        assert!(is_bogon_with(ip, Policy::DEFAULT), "{ip}");
+    }
+}
+
+#[test]
+fn nat64_prefixes() {
+    let policy = Policy::DEFAULT
+        .with_nat64_prefix("2001:db8:64::".parse().unwrap(), 96)
+        .with_nat64_prefix("2001:db8:122::ffff".parse().unwrap(), 48);
+
+    // The host bits of a prefix are ignored.
+    assert_eq!(
+        policy.nat64_prefixes().collect::<Vec<_>>(),
+        [
+            ("2001:db8:64::".parse().unwrap(), 96),
+            ("2001:db8:122::".parse().unwrap(), 48),
+        ]
+    );
+
+    let expected = [
+        ("2001:db8:64::8.8.8.8", false),
+        ("2001:db8:64::10.0.0.1", true),
+        // 8.8.8.8 under 2001:db8:122::/48.
+        ("2001:db8:122:808:8:800::", false),
+        // 10.0.0.1 under 2001:db8:122::/48.
+        ("2001:db8:122:a00:0:100::", true),
+        // The u octet must be zero.
+        ("2001:db8:122:808:ff08:800::", true),
+    ];
+
+    for (ip, bogus) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(is_bogon_with(ip, policy), bogus, "{ip}");
+        assert!(is_bogon_with(ip, Policy::DEFAULT), "{ip}");
+    }
+
+    // The well-known prefix needs its own option.
+    assert!(is_bogon_with("64:ff9b::8.8.8.8".parse().unwrap(), policy));
+}
+
+#[test]
+#[should_panic(expected = "NAT64 prefix length")]
+fn nat64_prefix_invalid_length() {
+    let _ = Policy::DEFAULT.with_nat64_prefix("2001:db8::".parse().unwrap(), 33);
+}
+
+#[test]
+#[should_panic(expected = "too many NAT64 prefixes")]
+fn nat64_prefix_too_many() {
+    let mut policy = Policy::DEFAULT;
+    for i in 0..=Policy::MAX_NAT64_PREFIXES as u16 {
+        policy = policy.with_nat64_prefix(Ipv6Addr::new(0x2001, 0xdb8, i, 0, 0, 0, 0, 0), 96);
+    }
+}
+
+#[test]
+fn policy_matrix() {
+    let mapped = Policy::DEFAULT.with_canonicalize_mapped(true);
+    let six_to_four = Policy::DEFAULT.with_6to4(true);
+    let teredo = Policy::DEFAULT.with_teredo(true);
+    let nat64 = Policy::DEFAULT.with_nat64_wkp(true);
+    let policies = [
+        Policy::DEFAULT,
+        mapped,
+        six_to_four,
+        teredo,
+        nat64,
+        Policy::LENIENT,
+    ];
+
+    // Whether each address is bogus under each of the policies above.
+    let expected = [
+        ("::ffff:8.8.8.8", [true, false, true, true, true, false]),
+        ("::ffff:10.0.0.1", [true, true, true, true, true, true]),
+        ("2002:808:808::1", [true, true, false, true, true, false]),
+        ("2002:a00:1::1", [true, true, true, true, true, true]),
+        (
+            "2001:0:4136:e378:8000:63bf:f7f7:f7f7",
+            [true, true, true, false, true, false],
+        ),
+        (
+            "2001:0:4136:e378:8000:63bf:f5ff:fffe",
+            [true, true, true, true, true, true],
+        ),
+        ("64:ff9b::8.8.8.8", [true, true, true, true, false, false]),
+        ("64:ff9b::10.0.0.1", [true, true, true, true, true, true]),
+        ("::8.8.8.8", [true, true, true, true, true, true]),
+        ("8.8.8.8", [false, false, false, false, false, false]),
+        ("10.0.0.1", [true, true, true, true, true, true]),
+        (
+            "2606:4700::1111",
+            [false, false, false, false, false, false],
+        ),
+        ("fe80::1", [true, true, true, true, true, true]),
+    ];
+
+    for (ip, bogus) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        for (policy, bogus) in policies.iter().zip(bogus) {
+            assert_eq!(is_bogon_with(ip, *policy), bogus, "{ip} {policy:?}");
+            assert_eq!(
+                classify_with(ip, *policy).is_some(),
+                bogus,
+                "{ip} {policy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn classify_with_embedded() {
+    let expected = [
+        ("::ffff:10.0.0.1", Some(BogonKind::PrivateUse)),
+        ("2002:7f00:1::", Some(BogonKind::Loopback)),
+        ("64:ff9b::192.0.2.1", Some(BogonKind::Documentation)),
+        ("64:ff9b::192.175.48.1", None),
+        ("64:ff9b::8.8.8.8", None),
+    ];
+
+    for (ip, kind) in expected {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(classify_with(ip, Policy::LENIENT), kind, "{ip}");
+    }
+}
+
+#[test]
+fn canonicalize_mapped_matches_is_bogon_canonical() {
+    let policy = Policy::DEFAULT.with_canonicalize_mapped(true);
+    for _ in 0..100_000 {
+        let mapped = IpAddr::V6(Ipv4Addr::from_bits(rand::random()).to_ipv6_mapped());
+        assert_eq!(
+            is_bogon_with(mapped, policy),
+            is_bogon_canonical(mapped),
+            "{mapped}"
+        );
+    }
+}
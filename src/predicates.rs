@@ -0,0 +1,105 @@
+use core::net::IpAddr;
+
+use crate::BogonExt;
+
+/// Returns `true` if the IP address is in a Private-Use network (`10.0.0.0/8`, `172.16.0.0/12`,
+/// `192.168.0.0/16`) or is an IPv6 Unique-Local address (`fc00::/7`).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_private;
+///
+/// assert!(is_private("192.168.1.1".parse().unwrap()));
+/// assert!(is_private("fd12:3456::1".parse().unwrap()));
+/// assert!(!is_private("8.8.8.8".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_private(ip_address: IpAddr) -> bool {
+    BogonExt::is_private(&ip_address)
+}
+
+/// Returns `true` if the IP address is in the Shared Address Space (`100.64.0.0/10`).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_shared;
+///
+/// # #[cfg(not(feature = "allow-cgnat"))]
+/// assert!(is_shared("100.127.255.255".parse().unwrap()));
+/// assert!(!is_shared("100.63.255.255".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_shared(ip_address: IpAddr) -> bool {
+    BogonExt::is_shared(&ip_address)
+}
+
+/// Returns `true` if the IP address is reserved for documentation (`192.0.2.0/24`,
+/// `198.51.100.0/24`, `203.0.113.0/24`, `2001:db8::/32`, `3fff::/20`).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_documentation;
+///
+/// assert!(is_documentation("198.51.100.1".parse().unwrap()));
+/// assert!(is_documentation("2001:db8::1".parse().unwrap()));
+/// assert!(!is_documentation("2001:db9::1".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_documentation(ip_address: IpAddr) -> bool {
+    BogonExt::is_documentation(&ip_address)
+}
+
+/// Returns `true` if the IP address is reserved for benchmarking (`198.18.0.0/15`,
+/// `2001:2::/48`).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_benchmarking;
+///
+/// # #[cfg(not(feature = "allow-benchmarking"))]
+/// assert!(is_benchmarking("198.18.0.1".parse().unwrap()));
+/// assert!(is_benchmarking("2001:2::1".parse().unwrap()));
+/// assert!(!is_benchmarking("198.20.0.1".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_benchmarking(ip_address: IpAddr) -> bool {
+    BogonExt::is_benchmarking(&ip_address)
+}
+
+/// Returns `true` if the IP address is reserved for future use (`240.0.0.0/4`, except for the
+/// limited broadcast address) or is IPv6 space reserved by the IETF.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_reserved;
+///
+/// # #[cfg(not(feature = "allow-reserved-240-4"))]
+/// assert!(is_reserved("250.1.2.3".parse().unwrap()));
+/// assert!(!is_reserved("255.255.255.255".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_reserved(ip_address: IpAddr) -> bool {
+    BogonExt::is_reserved(&ip_address)
+}
+
+/// Returns `true` if the IP address is a cloud instance metadata endpoint (`169.254.169.254`,
+/// `169.254.170.2`, `fd00:ec2::254`).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_cloud_metadata;
+///
+/// assert!(is_cloud_metadata("169.254.169.254".parse().unwrap()));
+/// assert!(is_cloud_metadata("fd00:ec2::254".parse().unwrap()));
+/// assert!(!is_cloud_metadata("169.254.0.1".parse().unwrap()));
+/// ```
+#[inline]
+pub fn is_cloud_metadata(ip_address: IpAddr) -> bool {
+    BogonExt::is_cloud_metadata(&ip_address)
+}
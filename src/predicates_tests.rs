@@ -0,0 +1,179 @@
+use core::net::IpAddr;
+
+use crate::{
+    is_benchmarking, is_bogon, is_cloud_metadata, is_documentation, is_private, is_reserved,
+    is_shared,
+};
+
+/// Asserts that `predicate` holds for the first and last address of every range and not for the
+/// addresses just outside of them.
+fn check_boundaries(predicate: fn(IpAddr) -> bool, ranges: &[(&str, &str, &str, &str)]) {
+    for &(before, first, last, after) in ranges {
+        let [before, first, last, after] =
+            [before, first, last, after].map(|ip| ip.parse::<IpAddr>().unwrap());
+
+        assert!(!predicate(before), "{before}");
+        assert!(predicate(first), "{first}");
+        assert!(predicate(last), "{last}");
+        assert!(!predicate(after), "{after}");
+
+        // Every predicate implies that the address is bogus.
+        assert!(is_bogon(first) && is_bogon(last));
+    }
+}
+
+#[test]
+fn private() {
+    check_boundaries(
+        is_private,
+        &[
+            ("9.255.255.255", "10.0.0.0", "10.255.255.255", "11.0.0.0"),
+            (
+                "172.15.255.255",
+                "172.16.0.0",
+                "172.31.255.255",
+                "172.32.0.0",
+            ),
+            (
+                "192.167.255.255",
+                "192.168.0.0",
+                "192.168.255.255",
+                "192.169.0.0",
+            ),
+            (
+                "fbff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+                "fc00::",
+                "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+                "fe00::",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn shared() {
+    check_boundaries(
+        is_shared,
+        &[
+            #[cfg(not(feature = "allow-cgnat"))]
+            (
+                "100.63.255.255",
+                "100.64.0.0",
+                "100.127.255.255",
+                "100.128.0.0",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn documentation() {
+    check_boundaries(
+        is_documentation,
+        &[
+            ("192.0.1.255", "192.0.2.0", "192.0.2.255", "192.0.3.0"),
+            (
+                "198.51.99.255",
+                "198.51.100.0",
+                "198.51.100.255",
+                "198.51.101.0",
+            ),
+            (
+                "203.0.112.255",
+                "203.0.113.0",
+                "203.0.113.255",
+                "203.0.114.0",
+            ),
+            (
+                "2001:db7:ffff:ffff:ffff:ffff:ffff:ffff",
+                "2001:db8::",
+                "2001:db8:ffff:ffff:ffff:ffff:ffff:ffff",
+                "2001:db9::",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn benchmarking() {
+    check_boundaries(
+        is_benchmarking,
+        &[
+            #[cfg(not(feature = "allow-benchmarking"))]
+            (
+                "198.17.255.255",
+                "198.18.0.0",
+                "198.19.255.255",
+                "198.20.0.0",
+            ),
+            (
+                "2001:1:ffff:ffff:ffff:ffff:ffff:ffff",
+                "2001:2::",
+                "2001:2:0:ffff:ffff:ffff:ffff:ffff",
+                "2001:2:1::",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn reserved() {
+    check_boundaries(
+        is_reserved,
+        &[
+            #[cfg(not(feature = "allow-reserved-240-4"))]
+            (
+                "239.255.255.255",
+                "240.0.0.0",
+                "255.255.255.254",
+                "255.255.255.255",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn cloud_metadata() {
+    check_boundaries(
+        is_cloud_metadata,
+        &[
+            (
+                "169.254.169.253",
+                "169.254.169.254",
+                "169.254.169.254",
+                "169.254.169.255",
+            ),
+            (
+                "169.254.170.1",
+                "169.254.170.2",
+                "169.254.170.2",
+                "169.254.170.3",
+            ),
+            (
+                "fd00:ec2::253",
+                "fd00:ec2::254",
+                "fd00:ec2::254",
+                "fd00:ec2::255",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn cloud_metadata_keeps_its_network() {
+    use crate::{classify, BogonExt, BogonKind};
+
+    // The more specific classification wins, but the endpoint is still inside of Unique-Local
+    // space.
+    let ip: IpAddr = "fd00:ec2::254".parse().unwrap();
+    assert_eq!(classify(ip), Some(BogonKind::CloudMetadata));
+    assert!(is_cloud_metadata(ip));
+    assert!(is_private(ip));
+    assert!(BogonExt::is_private(&ip));
+
+    // Link Local has no predicate, but isn't mistaken for another category.
+    let ip: IpAddr = "169.254.169.254".parse().unwrap();
+    assert!(is_cloud_metadata(ip));
+    assert!(!is_private(ip));
+    assert!(!is_documentation(ip));
+}
@@ -0,0 +1,63 @@
+#[test]
+#[cfg(any(feature = "pregenerated", not(feature = "download")))]
+fn sample_verdicts() {
+    use crate::{classify, BogonKind};
+
+    // The verdicts for a sample of addresses, which must be the same whether the tables are
+    // generated by the build script or compiled from the checked-in copies.
+    let sample = [
+        ("0.1.2.3", Some(BogonKind::ThisNetwork)),
+        ("8.8.8.8", None),
+        ("10.1.2.3", Some(BogonKind::PrivateUse)),
+        ("127.0.0.1", Some(BogonKind::Loopback)),
+        ("169.254.169.254", Some(BogonKind::CloudMetadata)),
+        ("192.0.0.9", None),
+        ("192.0.2.1", Some(BogonKind::Documentation)),
+        ("192.88.99.1", Some(BogonKind::SixToFourRelayAnycast)),
+        ("224.0.0.1", Some(BogonKind::Multicast)),
+        ("255.255.255.255", Some(BogonKind::Broadcast)),
+        ("1.1.1.1", None),
+        ("::", Some(BogonKind::Unspecified)),
+        ("::1", Some(BogonKind::Loopback)),
+        ("64:ff9b::1", Some(BogonKind::Nat64WellKnown)),
+        ("2001::1", Some(BogonKind::Teredo)),
+        ("2001:1ff::1", Some(BogonKind::ProtocolAssignments)),
+        ("2001:4860:4860::8888", None),
+        ("2001:db8::1", Some(BogonKind::Documentation)),
+        ("2002::1", Some(BogonKind::Unallocated)),
+        ("2610:200::1", Some(BogonKind::Unallocated)),
+        ("2620:4f:8000::1", None),
+        ("2a00:1450:4001::1", None),
+        ("2c0f:ffff::1", None),
+        ("3000::1", Some(BogonKind::Unallocated)),
+        ("3fff::1", Some(BogonKind::Documentation)),
+        ("4000::1", Some(BogonKind::Reserved)),
+        ("fc00::1", Some(BogonKind::UniqueLocal)),
+        ("fe80::1", Some(BogonKind::LinkLocal)),
+        ("ff02::1", Some(BogonKind::Multicast)),
+    ];
+
+    for (ip, kind) in sample {
+        assert_eq!(classify(ip.parse().unwrap()), kind, "{ip}");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "pregenerated", feature = "std"))]
+fn pregenerated_tables_match_the_bundled_registry() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::{build_v6_table, is_bogon_v6, parse_iana_v6_csv};
+
+    // The same code the build script uses, applied to the bundled copy of the registry.
+    let csv = include_str!("../bogon-data/ipv6-unicast-address-assignments.csv");
+    let table = build_v6_table(&parse_iana_v6_csv(csv).unwrap());
+
+    let mut rng = StdRng::seed_from_u64(0x6267_6e70);
+    for _ in 0..100_000 {
+        // Mostly global unicast space, where the allocations are.
+        let bits = rng.gen::<u128>() >> 3 | 0x2000 << 112;
+        let ip = core::net::Ipv6Addr::from_bits(bits);
+        assert_eq!(is_bogon_v6(ip), table.contains(ip.into()), "{ip}");
+    }
+}
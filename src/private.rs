@@ -0,0 +1,151 @@
+use core::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use bogon_data::cidr::first_network;
+
+// The private address space, as sorted inclusive ranges: Private-Use (`10.0.0.0/8`,
+// `172.16.0.0/12`, `192.168.0.0/16`), Shared Address Space (`100.64.0.0/10`), and Unique-Local
+// (`fc00::/7`).
+const PRIVATE_V4: [(u128, u128); 4] = [
+    (0x0a00_0000, 0x0aff_ffff),
+    (0x6440_0000, 0x647f_ffff),
+    (0xac10_0000, 0xac1f_ffff),
+    (0xc0a8_0000, 0xc0a8_ffff),
+];
+const PRIVATE_V6: [(u128, u128); 1] = [(0xfc00 << 112, (0xfe00 << 112) - 1)];
+
+/// The error returned when a network is not entirely inside private address space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotPrivateError {
+    network: (IpAddr, u8),
+    public: (IpAddr, u8),
+}
+
+impl NotPrivateError {
+    /// Returns the network that was checked, with host bits cleared.
+    pub const fn network(&self) -> (IpAddr, u8) {
+        self.network
+    }
+
+    /// Returns the largest sub-network at the first address outside private address space.
+    pub const fn public(&self) -> (IpAddr, u8) {
+        self.public
+    }
+}
+
+impl fmt::Display for NotPrivateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((network, network_len), (public, public_len)) = (self.network, self.public);
+        write!(
+            f,
+            "{network}/{network_len} is not private, {public}/{public_len} is outside private address space"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotPrivateError {}
+
+/// Returns the first range of `start..=end` outside of sorted, disjoint `private` ranges.
+fn first_public(start: u128, end: u128, private: &[(u128, u128)]) -> Option<(u128, u128)> {
+    let mut cursor = start;
+    for &(private_start, private_end) in private {
+        if private_end < cursor {
+            continue;
+        }
+        if private_start > cursor {
+            return Some((cursor, end.min(private_start - 1)));
+        }
+        match private_end.checked_add(1) {
+            Some(next) if next <= end => cursor = next,
+            _ => return None,
+        }
+    }
+    Some((cursor, end))
+}
+
+/// Checks that an IPv4 network is entirely inside private address space.
+///
+/// Private address space is Private-Use (`10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`) and
+/// Shared Address Space (`100.64.0.0/10`). Host bits of the network address are ignored. This is
+/// meant for sanity checks of configured pools, like DHCP ranges or pod networks, that must never
+/// be routable on the public internet.
+///
+/// # Errors
+///
+/// Returns an error naming the first sub-network outside private address space.
+///
+/// # Panics
+///
+/// Panics if the prefix length is longer than 32.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use bogon::ensure_private_v4;
+///
+/// assert!(ensure_private_v4((Ipv4Addr::new(10, 244, 0, 0), 16)).is_ok());
+///
+/// // The network straddles the end of 172.16.0.0/12.
+/// let error = ensure_private_v4((Ipv4Addr::new(172, 16, 0, 0), 11)).unwrap_err();
+/// assert_eq!(error.public(), (IpAddr::V4(Ipv4Addr::new(172, 0, 0, 0)), 12));
+/// ```
+pub fn ensure_private_v4(network: (Ipv4Addr, u8)) -> Result<(), NotPrivateError> {
+    let (ip, prefix_len) = network;
+    assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+
+    let host = u32::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+    let start = ip.to_bits() & !host;
+    let Some((public, public_end)) =
+        first_public(u128::from(start), u128::from(start | host), &PRIVATE_V4)
+    else {
+        return Ok(());
+    };
+
+    let (_, public_len) = first_network(public, public_end, 32);
+    Err(NotPrivateError {
+        network: (IpAddr::V4(Ipv4Addr::from_bits(start)), prefix_len),
+        public: (IpAddr::V4(Ipv4Addr::from_bits(public as u32)), public_len),
+    })
+}
+
+/// Checks that an IPv6 network is entirely inside Unique-Local address space (`fc00::/7`).
+///
+/// Host bits of the network address are ignored.
+///
+/// # Errors
+///
+/// Returns an error naming the first sub-network outside Unique-Local address space.
+///
+/// # Panics
+///
+/// Panics if the prefix length is longer than 128.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::ensure_private_v6;
+///
+/// assert!(ensure_private_v6(("fd12:3456:789a::".parse().unwrap(), 48)).is_ok());
+/// assert!(ensure_private_v6(("2001:db8::".parse().unwrap(), 32)).is_err());
+/// ```
+pub fn ensure_private_v6(network: (Ipv6Addr, u8)) -> Result<(), NotPrivateError> {
+    let (ip, prefix_len) = network;
+    assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+
+    let host = u128::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+    let start = ip.to_bits() & !host;
+    let Some((public, public_end)) = first_public(start, start | host, &PRIVATE_V6) else {
+        return Ok(());
+    };
+
+    let (_, public_len) = first_network(public, public_end, 128);
+    Err(NotPrivateError {
+        network: (IpAddr::V6(Ipv6Addr::from_bits(start)), prefix_len),
+        public: (IpAddr::V6(Ipv6Addr::from_bits(public)), public_len),
+    })
+}
@@ -0,0 +1,148 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{ensure_private_v4, ensure_private_v6, is_private};
+
+fn v4(network: &str, prefix_len: u8) -> (Ipv4Addr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+fn v6(network: &str, prefix_len: u8) -> (Ipv6Addr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+fn public(network: &str, prefix_len: u8) -> (IpAddr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+#[test]
+fn private_v4() {
+    for (network, prefix_len) in [
+        ("10.0.0.0", 8),
+        ("10.244.0.0", 16),
+        ("100.64.0.0", 10),
+        ("100.127.255.255", 32),
+        ("172.16.0.0", 12),
+        ("172.31.0.0", 16),
+        ("192.168.0.0", 16),
+        ("192.168.1.77", 24),
+    ] {
+        assert_eq!(
+            ensure_private_v4(v4(network, prefix_len)),
+            Ok(()),
+            "{network}/{prefix_len}"
+        );
+    }
+}
+
+#[test]
+fn not_private_v4() {
+    let cases = [
+        // Entirely public, host bits are ignored.
+        (v4("9.255.255.0", 23), public("9.255.254.0", 23)),
+        (v4("8.8.8.8", 32), public("8.8.8.8", 32)),
+        // Straddling the boundary of a private network.
+        (v4("10.0.0.0", 7), public("11.0.0.0", 8)),
+        (v4("172.16.0.0", 11), public("172.0.0.0", 12)),
+        (v4("100.64.0.0", 9), public("100.0.0.0", 10)),
+        (v4("192.168.0.0", 15), public("192.169.0.0", 16)),
+        // The first public sub-network is named, not the largest one.
+        (v4("0.0.0.0", 0), public("0.0.0.0", 5)),
+        (v4("8.0.0.0", 6), public("8.0.0.0", 7)),
+        (v4("100.0.0.0", 8), public("100.0.0.0", 10)),
+    ];
+
+    for (network, expected) in cases {
+        let error = ensure_private_v4(network).unwrap_err();
+        assert_eq!(error.public(), expected, "{network:?}");
+    }
+
+    let error = ensure_private_v4(v4("9.255.255.0", 23)).unwrap_err();
+    assert_eq!(error.network(), public("9.255.254.0", 23));
+    assert_eq!(
+        error.to_string(),
+        "9.255.254.0/23 is not private, 9.255.254.0/23 is outside private address space"
+    );
+}
+
+#[test]
+fn private_v6() {
+    assert_eq!(ensure_private_v6(v6("fc00::", 7)), Ok(()));
+    assert_eq!(ensure_private_v6(v6("fd12:3456:789a::", 48)), Ok(()));
+    assert_eq!(ensure_private_v6(v6("fdff:ffff::1", 128)), Ok(()));
+
+    let cases = [
+        (v6("2001:db8::", 32), public("2001:db8::", 32)),
+        (v6("fc00::", 6), public("fe00::", 7)),
+        (v6("f800::", 5), public("f800::", 6)),
+        (v6("::", 0), public("::", 1)),
+        (v6("fd00::", 0), public("::", 1)),
+    ];
+    for (network, expected) in cases {
+        let error = ensure_private_v6(network).unwrap_err();
+        assert_eq!(error.public(), expected, "{network:?}");
+    }
+}
+
+#[test]
+fn matches_predicates() {
+    // A single address is private exactly when the predicates say so.
+    for _ in 0..100_000 {
+        let ip = Ipv4Addr::from_bits(rand::random());
+        // `is_shared` follows the `allow-cgnat` feature, the private address space doesn't.
+        let [a, b, ..] = ip.octets();
+        let expected = is_private(IpAddr::V4(ip)) || (a == 100 && b & 0xc0 == 64);
+        assert_eq!(ensure_private_v4((ip, 32)).is_ok(), expected, "{ip}");
+    }
+
+    for _ in 0..100_000 {
+        let ip = Ipv6Addr::from_bits(rand::random());
+        assert_eq!(
+            ensure_private_v6((ip, 128)).is_ok(),
+            is_private(IpAddr::V6(ip)),
+            "{ip}"
+        );
+    }
+}
+
+#[test]
+fn public_is_inside_network() {
+    for _ in 0..10_000 {
+        let prefix_len = rand::random::<u8>() % 33;
+        let network = (Ipv4Addr::from_bits(rand::random()), prefix_len);
+        let Err(error) = ensure_private_v4(network) else {
+            continue;
+        };
+
+        let (IpAddr::V4(public), public_len) = error.public() else {
+            unreachable!()
+        };
+        let (IpAddr::V4(start), _) = error.network() else {
+            unreachable!()
+        };
+        let host = u32::MAX.checked_shr(u32::from(public_len)).unwrap_or(0);
+        let end = public.to_bits() | host;
+        assert!(public_len >= prefix_len, "{network:?}");
+        assert_eq!(public.to_bits() & host, 0, "{network:?}");
+
+        let network_host = u32::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+        assert_eq!(
+            public.to_bits() & !network_host,
+            start.to_bits(),
+            "{network:?}"
+        );
+
+        for ip in [
+            public.to_bits(),
+            end,
+            public.to_bits() + (end - public.to_bits()) / 2,
+        ] {
+            assert!(ensure_private_v4((Ipv4Addr::from_bits(ip), 32)).is_err());
+        }
+    }
+}
+
+#[test]
+#[should_panic = "IPv4 prefix length must be at most 32"]
+fn prefix_too_long() {
+    let _ = ensure_private_v4(v4("10.0.0.0", 33));
+}
@@ -0,0 +1,193 @@
+//! Aggregating lists of networks into the fewest networks covering the same addresses.
+//!
+//! The routable address space is the complement of the bogus intervals, aggregated the same way.
+
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use bogon_data::ranges::{aggregate, range, range_to_cidrs, subtract};
+
+use crate::{v4_bogon_intervals, v6_bogon_intervals};
+
+/// Returns the fewest IPv4 networks covering exactly the same addresses as a list of networks.
+///
+/// Duplicate, nested, overlapping, and adjacent networks are merged. The networks are returned in
+/// order, without host bits.
+///
+/// # Panics
+///
+/// Panics if a prefix length is longer than 32.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::aggregate_v4;
+///
+/// let networks = [
+///     (Ipv4Addr::new(192, 0, 2, 0), 25),
+///     (Ipv4Addr::new(192, 0, 2, 128), 25),
+///     (Ipv4Addr::new(192, 0, 2, 64), 26),
+///     (Ipv4Addr::new(198, 51, 100, 7), 24),
+/// ];
+/// assert_eq!(
+///     aggregate_v4(networks),
+///     [(Ipv4Addr::new(192, 0, 2, 0), 24), (Ipv4Addr::new(198, 51, 100, 0), 24)]
+/// );
+/// ```
+pub fn aggregate_v4(networks: impl IntoIterator<Item = (Ipv4Addr, u8)>) -> Vec<(Ipv4Addr, u8)> {
+    let ranges = networks.into_iter().map(|(network, prefix_len)| {
+        assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+        range(u128::from(network.to_bits()), prefix_len, 32)
+    });
+    aggregate(ranges, 32)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv4Addr::from_bits(network as u32), prefix_len))
+        .collect()
+}
+
+/// Returns the fewest IPv6 networks covering exactly the same addresses as a list of networks.
+///
+/// Duplicate, nested, overlapping, and adjacent networks are merged. The networks are returned in
+/// order, without host bits.
+///
+/// # Panics
+///
+/// Panics if a prefix length is longer than 128.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::aggregate_v6;
+///
+/// let networks = [
+///     ("2001:db8::".parse().unwrap(), 33),
+///     ("2001:db8:8000::".parse().unwrap(), 33),
+///     ("2001:db8:1234::".parse().unwrap(), 48),
+/// ];
+/// assert_eq!(aggregate_v6(networks), [("2001:db8::".parse::<Ipv6Addr>().unwrap(), 32)]);
+/// ```
+pub fn aggregate_v6(networks: impl IntoIterator<Item = (Ipv6Addr, u8)>) -> Vec<(Ipv6Addr, u8)> {
+    let ranges = networks.into_iter().map(|(network, prefix_len)| {
+        assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+        range(network.to_bits(), prefix_len, 128)
+    });
+    aggregate(ranges, 128)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv6Addr::from_bits(network), prefix_len))
+        .collect()
+}
+
+/// Returns the fewest IPv4 networks exactly covering the addresses from `start` to `end`,
+/// inclusive, in order.
+///
+/// # Panics
+///
+/// Panics if `start` is after `end`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::range_to_cidrs_v4;
+///
+/// assert_eq!(
+///     range_to_cidrs_v4(Ipv4Addr::new(192, 0, 2, 64), Ipv4Addr::new(192, 0, 3, 255)),
+///     [
+///         (Ipv4Addr::new(192, 0, 2, 64), 26),
+///         (Ipv4Addr::new(192, 0, 2, 128), 25),
+///         (Ipv4Addr::new(192, 0, 3, 0), 24),
+///     ]
+/// );
+/// ```
+pub fn range_to_cidrs_v4(start: Ipv4Addr, end: Ipv4Addr) -> Vec<(Ipv4Addr, u8)> {
+    let (start, end) = (u128::from(start.to_bits()), u128::from(end.to_bits()));
+    range_to_cidrs(start, end, 32)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv4Addr::from_bits(network as u32), prefix_len))
+        .collect()
+}
+
+/// Returns the fewest IPv6 networks exactly covering the addresses from `start` to `end`,
+/// inclusive, in order.
+///
+/// # Panics
+///
+/// Panics if `start` is after `end`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::range_to_cidrs_v6;
+///
+/// let start: Ipv6Addr = "2001:db8::".parse().unwrap();
+/// let end: Ipv6Addr = "2001:db8:2:ffff:ffff:ffff:ffff:ffff".parse().unwrap();
+/// assert_eq!(
+///     range_to_cidrs_v6(start, end),
+///     [(start, 47), ("2001:db8:2::".parse().unwrap(), 48)]
+/// );
+/// ```
+pub fn range_to_cidrs_v6(start: Ipv6Addr, end: Ipv6Addr) -> Vec<(Ipv6Addr, u8)> {
+    range_to_cidrs(start.to_bits(), end.to_bits(), 128)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv6Addr::from_bits(network), prefix_len))
+        .collect()
+}
+
+/// Returns the fewest IPv4 networks covering exactly the addresses that aren't bogus, in order.
+///
+/// This is the complement of [`v4_bogon_intervals`], a permit list for default-deny firewalls
+/// rather than a deny list. Like [`is_bogon_v4_bits`](crate::is_bogon_v4_bits), only the
+/// compiled-in tables are consulted: the overrides of the `test-util` and `runtime` features don't
+/// apply.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::routable_networks_v4;
+///
+/// let networks = routable_networks_v4();
+/// assert_eq!(networks[0], (Ipv4Addr::new(1, 0, 0, 0), 8));
+/// assert!(!networks.contains(&(Ipv4Addr::new(10, 0, 0, 0), 8)));
+/// ```
+pub fn routable_networks_v4() -> Vec<(Ipv4Addr, u8)> {
+    let bogons = v4_bogon_intervals()
+        .map(|range| (range.start().to_bits().into(), range.end().to_bits().into()))
+        .collect::<Vec<_>>();
+    aggregate(subtract(&[(0, u32::MAX.into())], &bogons).into_iter(), 32)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv4Addr::from_bits(network as u32), prefix_len))
+        .collect()
+}
+
+/// Returns the fewest IPv6 networks covering exactly the addresses that aren't bogus, in order.
+///
+/// This is the complement of [`v6_bogon_intervals`], a permit list for default-deny firewalls
+/// rather than a deny list: the space allocated to the regional internet registries, without the
+/// bogus networks inside of it. Like [`is_bogon_v6_bits`](crate::is_bogon_v6_bits), only the
+/// compiled-in tables are consulted: the overrides of the `test-util` and `runtime` features don't
+/// apply.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::routable_networks_v6;
+///
+/// // Every routable network is inside of 2000::/3.
+/// let networks = routable_networks_v6();
+/// assert!(networks.iter().all(|(network, _)| network.segments()[0] & 0xe000 == 0x2000));
+/// assert!(networks.contains(&("2600::".parse::<Ipv6Addr>().unwrap(), 12)));
+/// ```
+pub fn routable_networks_v6() -> Vec<(Ipv6Addr, u8)> {
+    let bogons = v6_bogon_intervals()
+        .map(|range| (range.start().to_bits(), range.end().to_bits()))
+        .collect::<Vec<_>>();
+    aggregate(subtract(&[(0, u128::MAX)], &bogons).into_iter(), 128)
+        .into_iter()
+        .map(|(network, prefix_len)| (Ipv6Addr::from_bits(network), prefix_len))
+        .collect()
+}
@@ -0,0 +1,482 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use bogon_data::ranges::{coverage, range_to_cidrs, subtract};
+
+use crate::{
+    aggregate_v4, aggregate_v6, is_bogon_v4, is_bogon_v6, range_to_cidrs_v4, range_to_cidrs_v6,
+    routable_networks_v4, routable_networks_v6, v4_bogon_intervals, v6_bogon_intervals,
+};
+
+fn v4(network: &str, prefix_len: u8) -> (Ipv4Addr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+fn v6(network: &str, prefix_len: u8) -> (Ipv6Addr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+fn contains_v4(networks: &[(Ipv4Addr, u8)], ip: u32) -> bool {
+    networks.iter().any(|&(network, prefix_len)| {
+        let mask = u32::MAX
+            .checked_shl(32 - u32::from(prefix_len))
+            .unwrap_or(0);
+        ip & mask == network.to_bits() & mask
+    })
+}
+
+/// Asserts that networks are sorted, disjoint, without host bits, and that no two of them are the
+/// halves of a larger network, which makes them the fewest networks covering their addresses.
+fn assert_minimal_v4(networks: &[(Ipv4Addr, u8)]) {
+    let ranges = networks
+        .iter()
+        .map(|&(network, prefix_len)| {
+            let size = 1u64 << (32 - prefix_len);
+            let start = u64::from(network.to_bits());
+            assert_eq!(start % size, 0, "{network}/{prefix_len} has host bits");
+            (start, start + size - 1, prefix_len)
+        })
+        .collect::<Vec<_>>();
+
+    for pair in ranges.windows(2) {
+        let ((_, end, prefix_len), (start, _, next_len)) = (pair[0], pair[1]);
+        assert!(end < start, "{networks:?} isn't sorted and disjoint");
+        let siblings =
+            prefix_len == next_len && end + 1 == start && (start >> (32 - prefix_len)) % 2 == 1;
+        assert!(!siblings, "{networks:?} has two halves of a network");
+    }
+}
+
+#[test]
+fn aggregate_duplicates_and_nested() {
+    assert_eq!(
+        aggregate_v4([v4("10.0.0.0", 8), v4("10.0.0.0", 8), v4("10.20.0.0", 16)]),
+        [v4("10.0.0.0", 8)]
+    );
+    assert_eq!(
+        aggregate_v4([v4("10.20.0.0", 16), v4("10.0.0.0", 8)]),
+        [v4("10.0.0.0", 8)]
+    );
+    assert_eq!(aggregate_v4([]), []);
+}
+
+#[test]
+fn aggregate_adjacent() {
+    assert_eq!(
+        aggregate_v4([v4("10.0.0.0", 9), v4("10.128.0.0", 9)]),
+        [v4("10.0.0.0", 8)]
+    );
+
+    // Adjacent networks that aren't halves of one network stay apart.
+    assert_eq!(
+        aggregate_v4([v4("10.128.0.0", 9), v4("11.0.0.0", 9)]),
+        [v4("10.128.0.0", 9), v4("11.0.0.0", 9)]
+    );
+
+    // Overlapping and adjacent networks merged into an unaligned range.
+    assert_eq!(
+        aggregate_v4([
+            v4("192.0.2.0", 25),
+            v4("192.0.2.64", 26),
+            v4("192.0.2.128", 26)
+        ]),
+        [v4("192.0.2.0", 25), v4("192.0.2.128", 26)]
+    );
+    assert_eq!(
+        aggregate_v4([
+            v4("192.0.2.64", 26),
+            v4("192.0.2.128", 25),
+            v4("192.0.3.0", 24)
+        ]),
+        [
+            v4("192.0.2.64", 26),
+            v4("192.0.2.128", 25),
+            v4("192.0.3.0", 24)
+        ]
+    );
+}
+
+#[test]
+fn aggregate_host_bits() {
+    assert_eq!(aggregate_v4([v4("10.1.2.3", 8)]), [v4("10.0.0.0", 8)]);
+    assert_eq!(
+        aggregate_v6([v6("2001:db8::1", 32)]),
+        [v6("2001:db8::", 32)]
+    );
+}
+
+#[test]
+fn aggregate_full_space() {
+    assert_eq!(
+        aggregate_v4([v4("0.0.0.0", 0), v4("10.0.0.0", 8)]),
+        [v4("0.0.0.0", 0)]
+    );
+    assert_eq!(
+        aggregate_v4([v4("0.0.0.0", 1), v4("128.0.0.0", 1)]),
+        [v4("0.0.0.0", 0)]
+    );
+    assert_eq!(
+        aggregate_v4([v4("255.255.255.255", 32)]),
+        [v4("255.255.255.255", 32)]
+    );
+    assert_eq!(
+        aggregate_v6([v6("::", 1), v6("8000::", 1), v6("::1", 128)]),
+        [v6("::", 0)]
+    );
+    assert_eq!(
+        aggregate_v6([
+            v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", 128),
+            v6("::", 0)
+        ]),
+        [v6("::", 0)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "IPv4 prefix length must be at most 32")]
+fn aggregate_v4_long_prefix() {
+    aggregate_v4([v4("10.0.0.0", 33)]);
+}
+
+#[test]
+#[should_panic(expected = "IPv6 prefix length must be at most 128")]
+fn aggregate_v6_long_prefix() {
+    aggregate_v6([v6("::", 129)]);
+}
+
+#[test]
+fn aggregate_v4_covers_the_same_addresses() {
+    for _ in 0..1_000 {
+        // Short prefixes within a small part of the address space overlap often.
+        let len = rand::random::<usize>() % 10;
+        let networks = (0..len)
+            .map(|_| {
+                let prefix_len = 20 + rand::random::<u8>() % 13;
+                let ip = 0x0a00_0000 | rand::random::<u32>() & 0xfff;
+                (Ipv4Addr::from_bits(ip), prefix_len)
+            })
+            .collect::<Vec<_>>();
+        let aggregated = aggregate_v4(networks.iter().copied());
+        assert_minimal_v4(&aggregated);
+
+        for ip in 0x0a00_0000..0x0a00_2000 {
+            assert_eq!(
+                contains_v4(&aggregated, ip),
+                contains_v4(&networks, ip),
+                "{} in {networks:?}",
+                Ipv4Addr::from_bits(ip)
+            );
+        }
+    }
+}
+
+#[test]
+fn aggregate_v6_matches_v4() {
+    // IPv6 networks in the top 32 bits aggregate like the IPv4 networks with the same bits.
+    for _ in 0..1_000 {
+        let len = rand::random::<usize>() % 10;
+        let networks = (0..len)
+            .map(|_| {
+                let prefix_len = rand::random::<u8>() % 33;
+                (
+                    Ipv4Addr::from_bits(rand::random::<u32>() & 0xff00_00ff),
+                    prefix_len,
+                )
+            })
+            .collect::<Vec<_>>();
+        let to_v6 = |(network, prefix_len): (Ipv4Addr, u8)| {
+            (
+                Ipv6Addr::from_bits(u128::from(network.to_bits()) << 96),
+                prefix_len,
+            )
+        };
+
+        let expected = aggregate_v4(networks.iter().copied())
+            .into_iter()
+            .map(to_v6)
+            .collect::<Vec<_>>();
+        assert_eq!(aggregate_v6(networks.into_iter().map(to_v6)), expected);
+    }
+}
+
+/// Asserts that networks exactly tile the inclusive range from `start` to `end`, in order.
+fn assert_tiles(networks: &[(u128, u8)], start: u128, end: u128, bits: u32) {
+    let mut next = Some(start);
+    for &(network, prefix_len) in networks {
+        assert_eq!(Some(network), next, "{networks:?} has a gap or overlap");
+        let host = (u128::MAX >> (128 - bits))
+            .checked_shr(u32::from(prefix_len))
+            .unwrap_or(0);
+        assert_eq!(network & host, 0, "{network:#x}/{prefix_len} has host bits");
+        next = (network | host).checked_add(1);
+        assert!(
+            (network | host) <= end,
+            "{networks:?} extends past {end:#x}"
+        );
+    }
+    assert_eq!(
+        next,
+        end.checked_add(1),
+        "{networks:?} stops before {end:#x}"
+    );
+}
+
+#[test]
+fn range_to_cidrs_tiles_random_ranges() {
+    for _ in 0..10_000 {
+        let (a, b) = (rand::random::<u32>(), rand::random::<u32>());
+        // Narrow ranges too, where alignment matters most.
+        let (a, b) = if rand::random() {
+            (a, a.saturating_add(b % 1000))
+        } else {
+            (a, b)
+        };
+        let (start, end) = (Ipv4Addr::from_bits(a.min(b)), Ipv4Addr::from_bits(a.max(b)));
+        let networks = range_to_cidrs_v4(start, end)
+            .into_iter()
+            .map(|(network, prefix_len)| (u128::from(network.to_bits()), prefix_len))
+            .collect::<Vec<_>>();
+        assert_tiles(&networks, a.min(b).into(), a.max(b).into(), 32);
+        assert!(networks.len() <= 62, "{start} - {end}: {networks:?}");
+
+        let (a, b) = (rand::random::<u128>(), rand::random::<u128>());
+        let (start, end) = (Ipv6Addr::from_bits(a.min(b)), Ipv6Addr::from_bits(a.max(b)));
+        let networks = range_to_cidrs_v6(start, end)
+            .into_iter()
+            .map(|(network, prefix_len)| (network.to_bits(), prefix_len))
+            .collect::<Vec<_>>();
+        assert_tiles(&networks, a.min(b), a.max(b), 128);
+        assert!(networks.len() <= 254, "{start} - {end}: {networks:?}");
+    }
+}
+
+#[test]
+fn range_to_cidrs_covers_every_small_range() {
+    // Every range of an 8-bit address family, so that every alignment of both ends is covered.
+    for start in 0..=255u128 {
+        for end in start..=255 {
+            let networks = range_to_cidrs(start, end, 8);
+
+            let mut covered = [false; 256];
+            for &(network, prefix_len) in &networks {
+                let size = 1 << (8 - prefix_len);
+                assert_eq!(network % size, 0, "{network}/{prefix_len} has host bits");
+                for address in network..network + size {
+                    assert!(!covered[address as usize], "{networks:?} overlap");
+                    covered[address as usize] = true;
+                }
+            }
+            for (address, covered) in covered.into_iter().enumerate() {
+                let expected = (start..=end).contains(&(address as u128));
+                assert_eq!(
+                    covered, expected,
+                    "{start}-{end}: {networks:?} at {address}"
+                );
+            }
+            assert_eq!(coverage(networks.iter().copied(), 8), [(start, end)]);
+
+            // No two networks are the halves of a larger one.
+            let v4 = networks
+                .iter()
+                .map(|&(network, prefix_len)| {
+                    (Ipv4Addr::from_bits(network as u32), prefix_len + 24)
+                })
+                .collect::<Vec<_>>();
+            assert_minimal_v4(&v4);
+        }
+    }
+}
+
+#[test]
+fn coverage_merges_networks() {
+    assert_eq!(coverage([].into_iter(), 32), []);
+    assert_eq!(
+        coverage(
+            [(0x0a00_0000, 9), (0x0a80_0000, 9), (0x0a14_0000, 16)].into_iter(),
+            32
+        ),
+        [(0x0a00_0000, 0x0aff_ffff)]
+    );
+    assert_eq!(
+        coverage([(0x0a00_0001, 32), (0x0a00_0004, 30)].into_iter(), 32),
+        [(0x0a00_0001, 0x0a00_0001), (0x0a00_0004, 0x0a00_0007)]
+    );
+    assert_eq!(coverage([(0, 0)].into_iter(), 128), [(0, u128::MAX)]);
+}
+
+#[test]
+fn range_to_cidrs_edges() {
+    let ip = |ip: &str| ip.parse::<Ipv4Addr>().unwrap();
+
+    // Single addresses.
+    assert_eq!(
+        range_to_cidrs_v4(ip("192.0.2.7"), ip("192.0.2.7")),
+        [v4("192.0.2.7", 32)]
+    );
+    let top = Ipv6Addr::from_bits(u128::MAX);
+    assert_eq!(range_to_cidrs_v6(top, top), [(top, 128)]);
+
+    // Ranges ending at the top of the address space.
+    assert_eq!(
+        range_to_cidrs_v4(ip("0.0.0.0"), ip("255.255.255.255")),
+        [v4("0.0.0.0", 0)]
+    );
+    assert_eq!(
+        range_to_cidrs_v4(ip("255.255.255.254"), ip("255.255.255.255")),
+        [v4("255.255.255.254", 31)]
+    );
+    assert_eq!(range_to_cidrs_v6(Ipv6Addr::UNSPECIFIED, top), [v6("::", 0)]);
+    assert_eq!(range_to_cidrs_v6("::1".parse().unwrap(), top).len(), 128);
+
+    // An unaligned start takes small networks until it is aligned.
+    assert_eq!(
+        range_to_cidrs_v4(ip("10.0.0.1"), ip("10.0.0.8")),
+        [
+            v4("10.0.0.1", 32),
+            v4("10.0.0.2", 31),
+            v4("10.0.0.4", 30),
+            v4("10.0.0.8", 32)
+        ]
+    );
+    assert_eq!(
+        range_to_cidrs_v6(
+            "2001:200::".parse().unwrap(),
+            "2001:5ff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()
+        ),
+        [v6("2001:200::", 23), v6("2001:400::", 23)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "the range must not end before it starts")]
+fn range_to_cidrs_reversed() {
+    range_to_cidrs_v4(Ipv4Addr::new(10, 0, 0, 2), Ipv4Addr::new(10, 0, 0, 1));
+}
+
+#[test]
+fn subtract_ranges() {
+    assert_eq!(subtract(&[(0, 9)], &[]), [(0, 9)]);
+    assert_eq!(subtract(&[(0, 9)], &[(0, 9)]), []);
+    assert_eq!(subtract(&[(0, 9)], &[(3, 5)]), [(0, 2), (6, 9)]);
+    assert_eq!(subtract(&[(0, 9)], &[(0, 2), (8, 20)]), [(3, 7)]);
+    assert_eq!(
+        subtract(&[(0, 9), (20, 29)], &[(5, 24)]),
+        [(0, 4), (25, 29)]
+    );
+    assert_eq!(subtract(&[(10, 19)], &[(0, 5), (25, 30)]), [(10, 19)]);
+    assert_eq!(
+        subtract(&[(0, u128::MAX)], &[(1, u128::MAX - 1)]),
+        [(0, 0), (u128::MAX, u128::MAX)]
+    );
+    assert_eq!(subtract(&[(5, u128::MAX)], &[(0, u128::MAX)]), []);
+}
+
+#[test]
+fn subtract_matches_membership() {
+    // Every pair of small range lists, checked address by address.
+    let lists = [
+        vec![],
+        vec![(0, 15)],
+        vec![(2, 3), (6, 9)],
+        vec![(0, 0), (5, 5), (15, 15)],
+        vec![(1, 4), (7, 7), (10, 13)],
+        vec![(4, 11)],
+    ];
+    let contains = |ranges: &[(u128, u128)], ip: u128| {
+        ranges.iter().any(|&(start, end)| start <= ip && ip <= end)
+    };
+    for ranges in &lists {
+        for removed in &lists {
+            let remaining = subtract(ranges, removed);
+            assert_eq!(
+                coverage(
+                    remaining
+                        .iter()
+                        .flat_map(|&(start, end)| range_to_cidrs(start, end, 8)),
+                    8
+                ),
+                remaining
+            );
+            for ip in 0..16 {
+                assert_eq!(
+                    contains(&remaining, ip),
+                    contains(ranges, ip) && !contains(removed, ip),
+                    "{ranges:?} - {removed:?} at {ip}"
+                );
+            }
+        }
+    }
+}
+
+/// Asserts that the routable and the bogus ranges together tile the address family with `bits`
+/// bits, alternating without overlap.
+fn assert_partition(routable: Vec<(u128, u128)>, bogons: Vec<(u128, u128)>, bits: u32) {
+    let mut ranges = routable
+        .iter()
+        .map(|&range| (range, false))
+        .chain(bogons.iter().map(|&range| (range, true)))
+        .collect::<Vec<_>>();
+    ranges.sort_unstable();
+
+    let mut next = Some(0);
+    for pair in ranges.windows(2) {
+        let ((_, bogus), (_, next_bogus)) = (pair[0], pair[1]);
+        assert_ne!(bogus, next_bogus, "{pair:?} aren't merged");
+    }
+    for ((start, end), _) in ranges {
+        assert_eq!(Some(start), next, "gap or overlap at {start:#x}");
+        next = end.checked_add(1);
+    }
+    assert_eq!(next, (u128::MAX >> (128 - bits)).checked_add(1));
+}
+
+#[test]
+fn routable_networks_v4_complement_bogons() {
+    let routable = routable_networks_v4();
+    assert_minimal_v4(&routable);
+    let coverage = coverage(
+        routable
+            .iter()
+            .map(|&(network, prefix_len)| (network.to_bits().into(), prefix_len)),
+        32,
+    );
+    let bogons = v4_bogon_intervals()
+        .map(|range| (range.start().to_bits().into(), range.end().to_bits().into()))
+        .collect();
+    assert_partition(coverage, bogons, 32);
+
+    for _ in 0..100_000 {
+        let ip = Ipv4Addr::from_bits(rand::random());
+        assert_eq!(
+            contains_v4(&routable, ip.to_bits()),
+            !is_bogon_v4(ip),
+            "{ip}"
+        );
+    }
+}
+
+#[test]
+fn routable_networks_v6_complement_bogons() {
+    let routable = routable_networks_v6();
+    let networks = routable
+        .iter()
+        .map(|&(network, prefix_len)| (network.to_bits(), prefix_len))
+        .collect::<Vec<_>>();
+    let coverage = coverage(networks.iter().copied(), 128);
+    // The fewest networks: no more than each range takes on its own.
+    let fewest: usize = coverage
+        .iter()
+        .map(|&(start, end)| range_to_cidrs(start, end, 128).len())
+        .sum();
+    assert_eq!(networks.len(), fewest);
+
+    let bogons = v6_bogon_intervals()
+        .map(|range| (range.start().to_bits(), range.end().to_bits()))
+        .collect();
+    assert_partition(coverage, bogons, 128);
+
+    for &(network, prefix_len) in &routable {
+        assert!(prefix_len >= 3, "{network}/{prefix_len}");
+        assert_eq!(network.segments()[0] & 0xe000, 0x2000, "{network}");
+        assert!(!is_bogon_v6(network), "{network}");
+    }
+}
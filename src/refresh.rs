@@ -0,0 +1,207 @@
+//! Refreshing runtime bogon data in the background.
+
+use core::{future::Future, time::Duration};
+use std::{
+    boxed::Box,
+    error::Error,
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+    time::SystemTime,
+};
+
+use tokio::task::JoinHandle;
+
+use crate::{BogonList, BogonSet, Verdict};
+
+/// The error a [`DataSource`] fails with.
+pub type SourceError = Box<dyn Error + Send + Sync>;
+
+/// Somewhere to fetch a [`BogonSet`] from, for [`spawn_refresher`].
+pub trait DataSource: Send + 'static {
+    /// Fetches the current data.
+    fn fetch(&mut self) -> impl Future<Output = Result<BogonSet, SourceError>> + Send;
+}
+
+/// A text file with one network per line, as read by [`BogonList`]. Every network is denied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileSource {
+    path: PathBuf,
+}
+
+impl FileSource {
+    /// Returns a source reading the file at a path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileSource { path: path.into() }
+    }
+}
+
+impl DataSource for FileSource {
+    async fn fetch(&mut self) -> Result<BogonSet, SourceError> {
+        let path = self.path.clone();
+        let read = move || -> Result<BogonSet, SourceError> {
+            let file = std::io::BufReader::new(std::fs::File::open(path)?);
+            let list = BogonList::from_reader(file)?;
+            Ok(list
+                .networks()
+                .map(|network| (network, Verdict::Deny))
+                .collect())
+        };
+        tokio::task::spawn_blocking(read).await?
+    }
+}
+
+/// Team Cymru's fullbogons feeds, see [`FullBogons`](crate::FullBogons).
+#[cfg(feature = "fullbogons")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FullBogonsSource;
+
+#[cfg(feature = "fullbogons")]
+impl DataSource for FullBogonsSource {
+    async fn fetch(&mut self) -> Result<BogonSet, SourceError> {
+        let fullbogons = tokio::task::spawn_blocking(crate::FullBogons::fetch).await??;
+        Ok(fullbogons.into_set())
+    }
+}
+
+/// The state shared between a [`RefreshHandle`] and its task.
+#[derive(Default)]
+struct Shared {
+    set: RwLock<Option<Arc<BogonSet>>>,
+    status: Mutex<Status>,
+}
+
+#[derive(Default)]
+struct Status {
+    last_updated: Option<SystemTime>,
+    last_error: Option<Arc<dyn Error + Send + Sync>>,
+    failures: u32,
+}
+
+impl core::fmt::Debug for Shared {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Shared").finish_non_exhaustive()
+    }
+}
+
+impl Shared {
+    fn status(&self) -> std::sync::MutexGuard<'_, Status> {
+        self.status.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The first delay before retrying a failed fetch, doubled after every consecutive failure.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Returns the delay before retrying after a number of consecutive failures, at most `interval`.
+fn retry_delay(failures: u32, interval: Duration) -> Duration {
+    let factor = 1u32
+        .checked_shl(failures.saturating_sub(1))
+        .unwrap_or(u32::MAX);
+    INITIAL_RETRY_DELAY
+        .checked_mul(factor)
+        .unwrap_or(Duration::MAX)
+        .min(interval)
+}
+
+/// A handle to a refresher started by [`spawn_refresher`].
+///
+/// Dropping the handle stops the refresher.
+#[derive(Debug)]
+pub struct RefreshHandle {
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+impl RefreshHandle {
+    /// Returns the data of the last successful fetch, or `None` if no fetch succeeded yet.
+    pub fn set(&self) -> Option<Arc<BogonSet>> {
+        self.shared
+            .set
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the time of the last successful fetch.
+    pub fn last_updated(&self) -> Option<SystemTime> {
+        self.shared.status().last_updated
+    }
+
+    /// Returns the error of the last fetch, or `None` if it succeeded.
+    pub fn last_error(&self) -> Option<Arc<dyn Error + Send + Sync>> {
+        self.shared.status().last_error.clone()
+    }
+
+    /// Returns the number of fetches that failed since the last successful one.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.shared.status().failures
+    }
+
+    /// Stops the refresher. The data of the last successful fetch stays available.
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for RefreshHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Fetches data from a source now and then every `interval`, on the current tokio runtime.
+///
+/// Every successful fetch atomically replaces the data behind [`RefreshHandle::set`]. When a fetch
+/// fails the previous data stays in place, and the fetch is retried after a delay that starts at
+/// one second and doubles with every consecutive failure, up to `interval`.
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() {
+/// use std::time::Duration;
+/// use bogon::{spawn_refresher, FileSource};
+///
+/// let refresher = spawn_refresher(FileSource::new("/etc/bogons.txt"), Duration::from_secs(3600));
+///
+/// // Later, on every request.
+/// let bogus = refresher
+///     .set()
+///     .is_some_and(|set| set.is_bogon("203.0.113.7".parse().unwrap()));
+/// # }
+/// ```
+pub fn spawn_refresher(mut source: impl DataSource, interval: Duration) -> RefreshHandle {
+    let shared = Arc::new(Shared::default());
+
+    let task = tokio::spawn({
+        let shared = Arc::clone(&shared);
+        async move {
+            loop {
+                let delay = match source.fetch().await {
+                    Ok(set) => {
+                        *shared.set.write().unwrap_or_else(PoisonError::into_inner) =
+                            Some(Arc::new(set));
+                        let mut status = shared.status();
+                        status.last_updated = Some(SystemTime::now());
+                        status.last_error = None;
+                        status.failures = 0;
+                        interval
+                    }
+                    Err(error) => {
+                        let mut status = shared.status();
+                        status.last_error = Some(Arc::from(error));
+                        status.failures = status.failures.saturating_add(1);
+                        retry_delay(status.failures, interval)
+                    }
+                };
+                tokio::time::sleep(delay).await;
+            }
+        }
+    });
+
+    RefreshHandle { shared, task }
+}
@@ -0,0 +1,156 @@
+use core::{net::IpAddr, time::Duration};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use tokio::time::Instant;
+
+use crate::{spawn_refresher, BogonSet, DataSource, FileSource, SourceError, Verdict};
+
+/// A source that returns scripted results and records when it was called.
+#[derive(Clone, Default)]
+struct MockSource {
+    results: Arc<Mutex<VecDeque<Result<BogonSet, &'static str>>>>,
+    calls: Arc<Mutex<Vec<Instant>>>,
+}
+
+impl DataSource for MockSource {
+    async fn fetch(&mut self) -> Result<BogonSet, SourceError> {
+        self.calls.lock().unwrap().push(Instant::now());
+        let result = self.results.lock().unwrap().pop_front();
+        result
+            .unwrap_or_else(|| Ok(BogonSet::new()))
+            .map_err(Into::into)
+    }
+}
+
+fn deny(network: &str, prefix_len: u8) -> BogonSet {
+    [((network.parse().unwrap(), prefix_len), Verdict::Deny)]
+        .into_iter()
+        .collect()
+}
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+/// Runs a future on a runtime with a paused clock, so sleeps finish instantly and in order.
+fn run(future: impl core::future::Future<Output = ()>) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .unwrap()
+        .block_on(future);
+}
+
+#[test]
+fn retries_with_backoff() {
+    run(async {
+        let source = MockSource::default();
+        source.results.lock().unwrap().extend([
+            Ok(deny("192.0.2.0", 24)),
+            Err("unreachable"),
+            Err("unreachable"),
+            Err("unreachable"),
+            Ok(deny("198.51.100.0", 24)),
+        ]);
+
+        let start = Instant::now();
+        let interval = Duration::from_secs(60);
+        let handle = spawn_refresher(source.clone(), interval);
+        assert!(handle.set().is_none());
+
+        // The first fetch happens right away.
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(handle.set().unwrap().is_bogon(ip("192.0.2.1")));
+        assert!(handle.last_updated().is_some());
+        assert!(handle.last_error().is_none());
+
+        // The failures keep the previous data.
+        tokio::time::sleep(Duration::from_secs(64)).await;
+        assert!(handle.set().unwrap().is_bogon(ip("192.0.2.1")));
+        assert_eq!(handle.last_error().unwrap().to_string(), "unreachable");
+        assert_eq!(handle.consecutive_failures(), 3);
+
+        tokio::time::sleep(Duration::from_secs(4)).await;
+        let set = handle.set().unwrap();
+        assert!(!set.is_bogon(ip("192.0.2.1")));
+        assert!(set.is_bogon(ip("198.51.100.1")));
+        assert!(handle.last_error().is_none());
+        assert_eq!(handle.consecutive_failures(), 0);
+
+        // Back to the regular interval.
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        let calls = source
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|call| call.duration_since(start).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(calls, [0, 60, 61, 63, 67, 127]);
+    });
+}
+
+#[test]
+fn backoff_is_capped() {
+    run(async {
+        let source = MockSource::default();
+        source
+            .results
+            .lock()
+            .unwrap()
+            .extend((0..10).map(|_| Err("unreachable")));
+
+        let start = Instant::now();
+        let handle = spawn_refresher(source.clone(), Duration::from_secs(10));
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert!(handle.set().is_none());
+        assert!(handle.last_updated().is_none());
+
+        let calls = source
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|call| call.duration_since(start).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(calls, [0, 1, 3, 7, 15, 25, 35, 45, 55]);
+    });
+}
+
+#[test]
+fn stops_when_dropped() {
+    run(async {
+        let source = MockSource::default();
+        let handle = spawn_refresher(source.clone(), Duration::from_secs(1));
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        drop(handle);
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        assert_eq!(source.calls.lock().unwrap().len(), 3);
+    });
+}
+
+#[test]
+fn file_source() {
+    let path = std::env::temp_dir().join(format!("bogon-refresh-{}.txt", std::process::id()));
+    std::fs::write(&path, "# test\n203.0.113.0/24\n").unwrap();
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let set = runtime.block_on(FileSource::new(&path).fetch()).unwrap();
+    assert!(set.is_bogon(ip("203.0.113.9")));
+
+    std::fs::write(&path, "203.0.113.0/33\n").unwrap();
+    let error = runtime
+        .block_on(FileSource::new(&path).fetch())
+        .unwrap_err();
+    assert!(error.to_string().contains("line 1"), "{error}");
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(runtime.block_on(FileSource::new(&path).fetch()).is_err());
+}
@@ -0,0 +1,175 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    ipv4_address_space::{V4_REGISTRY_RDAP, V4_REGISTRY_RIRS, V4_REGISTRY_WHOIS},
+    ipv6_unicast_address_allocations::{
+        V6_REGISTRY_DATES, V6_REGISTRY_PREFIXES, V6_REGISTRY_RDAP, V6_REGISTRY_RIRS,
+        V6_REGISTRY_WHOIS,
+    },
+    registry_strings::{RDAP_URLS, WHOIS_SERVERS},
+    Rir,
+};
+
+/// Returns the index of the IANA registry row covering a routable IPv6 address.
+fn registry_index_v6(ip_address: Ipv6Addr) -> Option<usize> {
+    if crate::is_bogon_v6(ip_address) {
+        return None;
+    }
+
+    V6_REGISTRY_PREFIXES
+        .iter()
+        .position(|network| network.contains_v6(ip_address))
+}
+
+/// Returns the IANA allocation block covering a routable IPv6 address, or `None` if the IP address
+/// is bogus.
+///
+/// The block is the prefix exactly as it appears in the
+/// [IANA IPv6 unicast address assignments](https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.xhtml)
+/// registry, before neighboring allocations are merged.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::covering_allocation_v6;
+///
+/// assert_eq!(
+///     covering_allocation_v6(Ipv6Addr::new(0x2a00, 0x1450, 0, 0, 0, 0, 0, 1)),
+///     Some((Ipv6Addr::new(0x2a00, 0, 0, 0, 0, 0, 0, 0), 12))
+/// );
+/// assert_eq!(covering_allocation_v6(Ipv6Addr::LOCALHOST), None);
+/// ```
+pub fn covering_allocation_v6(ip_address: Ipv6Addr) -> Option<(Ipv6Addr, u8)> {
+    registry_index_v6(ip_address).map(|index| {
+        let network = V6_REGISTRY_PREFIXES[index];
+        let ip = Ipv6Addr::from_bits(network.network());
+        (ip, network.prefix())
+    })
+}
+
+/// Returns the regional internet registry responsible for a routable IPv6 address, or `None` if
+/// the IP address is bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::{rir_v6, Rir};
+///
+/// assert_eq!(rir_v6(Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111)), Some(Rir::Arin));
+/// assert_eq!(rir_v6(Ipv6Addr::new(0x2a00, 0x1450, 0, 0, 0, 0, 0, 1)), Some(Rir::RipeNcc));
+/// assert_eq!(rir_v6(Ipv6Addr::LOCALHOST), None);
+/// ```
+pub fn rir_v6(ip_address: Ipv6Addr) -> Option<Rir> {
+    registry_index_v6(ip_address).map(|index| V6_REGISTRY_RIRS[index])
+}
+
+/// Returns the regional internet registry that administers a routable IPv4 address, or `None` if
+/// the IP address is bogus.
+///
+/// Legacy blocks administered by a registry belong to that registry, blocks assigned directly to
+/// an organization before the registries existed return [`Rir::Legacy`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{rir_v4, Rir};
+///
+/// assert_eq!(rir_v4(Ipv4Addr::new(1, 1, 1, 1)), Some(Rir::Apnic));
+/// assert_eq!(rir_v4(Ipv4Addr::new(53, 0, 0, 1)), Some(Rir::Legacy));
+/// assert_eq!(rir_v4(Ipv4Addr::new(224, 0, 0, 1)), None);
+/// ```
+pub fn rir_v4(ip_address: Ipv4Addr) -> Option<Rir> {
+    if crate::is_bogon_v4(ip_address) {
+        return None;
+    }
+
+    V4_REGISTRY_RIRS[usize::from(ip_address.octets()[0])]
+}
+
+/// Returns the date the IANA allocation block covering a routable IPv6 address was allocated to
+/// its regional internet registry, or `None` if the IP address is bogus.
+///
+/// The date is formatted exactly as in the registry, usually `YYYY-MM-DD`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::allocation_date_v6;
+///
+/// assert_eq!(allocation_date_v6(Ipv6Addr::new(0x2001, 0x4860, 0x4860, 0, 0, 0, 0, 0x8888)), Some("2004-08-24"));
+/// assert_eq!(allocation_date_v6(Ipv6Addr::LOCALHOST), None);
+/// ```
+pub fn allocation_date_v6(ip_address: Ipv6Addr) -> Option<&'static str> {
+    registry_index_v6(ip_address).map(|index| V6_REGISTRY_DATES[index])
+}
+
+/// Returns the RDAP base URL of the registry responsible for a routable IPv6 address, or `None` if
+/// the IP address is bogus.
+///
+/// The URL is taken from the IANA registry as is, so it may or may not end with a `/`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::rdap_url_v6;
+///
+/// assert_eq!(rdap_url_v6(Ipv6Addr::new(0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111)), Some("https://rdap.arin.net/registry"));
+/// assert_eq!(rdap_url_v6(Ipv6Addr::LOCALHOST), None);
+/// ```
+pub fn rdap_url_v6(ip_address: Ipv6Addr) -> Option<&'static str> {
+    registry_index_v6(ip_address).map(|index| RDAP_URLS[usize::from(V6_REGISTRY_RDAP[index])])
+}
+
+/// Returns the RDAP base URL of the registry responsible for a routable IPv4 address, or `None` if
+/// the IP address is bogus.
+///
+/// The URL is taken from the IANA registry as is, so it may or may not end with a `/`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::rdap_url_v4;
+///
+/// assert_eq!(rdap_url_v4(Ipv4Addr::new(1, 1, 1, 1)), Some("https://rdap.apnic.net/"));
+/// assert_eq!(rdap_url_v4(Ipv4Addr::new(10, 0, 0, 1)), None);
+/// ```
+pub fn rdap_url_v4(ip_address: Ipv4Addr) -> Option<&'static str> {
+    if crate::is_bogon_v4(ip_address) {
+        return None;
+    }
+
+    V4_REGISTRY_RDAP[usize::from(ip_address.octets()[0])].map(|index| RDAP_URLS[usize::from(index)])
+}
+
+/// Returns the authoritative WHOIS server for a routable IP address, or `None` if the IP address is
+/// bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use bogon::whois_server;
+///
+/// assert_eq!(whois_server(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8))), Some("whois.arin.net"));
+/// assert_eq!(whois_server(IpAddr::V6(Ipv6Addr::new(0x2a00, 0x1450, 0, 0, 0, 0, 0, 1))), Some("whois.ripe.net"));
+/// assert_eq!(whois_server(IpAddr::V4(Ipv4Addr::LOCALHOST)), None);
+/// ```
+pub fn whois_server(ip_address: IpAddr) -> Option<&'static str> {
+    let index = match ip_address {
+        IpAddr::V4(ip) => {
+            if crate::is_bogon_v4(ip) {
+                return None;
+            }
+            V4_REGISTRY_WHOIS[usize::from(ip.octets()[0])]?
+        }
+        IpAddr::V6(ip) => V6_REGISTRY_WHOIS[registry_index_v6(ip)?],
+    };
+
+    Some(WHOIS_SERVERS[usize::from(index)])
+}
@@ -0,0 +1,173 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnetwork::Ipv6Network;
+
+use crate::{
+    allocation_date_v6, covering_allocation_v6, explain, is_bogon_v6, rdap_url_v4, rdap_url_v6,
+    registry_strings::RDAP_URLS, rir_v4, rir_v6, whois_server, Rir,
+};
+
+/// The RIR allocations listed in the bundled IANA registry.
+fn registry_rows() -> Vec<(Ipv6Network, String)> {
+    let csv = include_str!("../bogon-data/ipv6-unicast-address-assignments.csv");
+    let rirs = ["AFRINIC", "APNIC", "ARIN", "LACNIC", "RIPE NCC"];
+
+    csv::Reader::from_reader(csv.as_bytes())
+        .records()
+        .map(|record| record.unwrap())
+        .filter(|record| &record[5] == "ALLOCATED" && rirs.contains(&&record[1]))
+        .map(|record| (record[0].parse().unwrap(), record[1].to_string()))
+        .collect()
+}
+
+#[test]
+fn covering_allocation_matches_registry() {
+    for (prefix, _) in registry_rows() {
+        let expected = Some((prefix.network(), prefix.prefix()));
+        for ip in [prefix.network(), prefix.broadcast()] {
+            assert_eq!(covering_allocation_v6(ip), expected, "{ip}");
+        }
+    }
+}
+
+#[test]
+fn covering_allocation_bogons() {
+    for ip in ["::1", "fe80::1", "fc00::1", "3000::1", "2d00::1"] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert!(is_bogon_v6(ip));
+        assert_eq!(covering_allocation_v6(ip), None, "{ip}");
+    }
+}
+
+#[test]
+fn explain_designation_matches_registry() {
+    for (prefix, designation) in registry_rows() {
+        let explanation = explain(prefix.network().into());
+        assert!(!explanation.is_bogon);
+        assert_eq!(explanation.designation, Some(&*designation), "{prefix}");
+        assert_eq!(
+            explanation.network,
+            Some((prefix.network().into(), prefix.prefix()))
+        );
+    }
+}
+
+#[test]
+fn rir_matches_registry() {
+    for (prefix, designation) in registry_rows() {
+        for ip in [prefix.network(), prefix.broadcast()] {
+            assert_eq!(
+                rir_v6(ip).map(|rir| rir.name()),
+                Some(&*designation),
+                "{ip}"
+            );
+        }
+    }
+
+    assert_eq!(rir_v6("fe80::1".parse().unwrap()), None);
+}
+
+#[test]
+fn rir_v4_blocks() {
+    let rir = |a, b, c, d| rir_v4(Ipv4Addr::new(a, b, c, d));
+
+    assert_eq!(rir(1, 0, 0, 0), Some(Rir::Apnic));
+    assert_eq!(rir(1, 255, 255, 255), Some(Rir::Apnic));
+    assert_eq!(rir(41, 0, 0, 1), Some(Rir::Afrinic));
+    assert_eq!(rir(8, 8, 8, 8), Some(Rir::Arin));
+    assert_eq!(rir(177, 0, 0, 1), Some(Rir::Lacnic));
+    assert_eq!(rir(185, 0, 0, 1), Some(Rir::RipeNcc));
+
+    // Legacy space is attributed to its administrator when there is one.
+    assert_eq!(rir(53, 0, 0, 1), Some(Rir::Legacy));
+    assert_eq!(rir(17, 0, 0, 1), Some(Rir::Legacy));
+    assert_eq!(rir(25, 0, 0, 1), Some(Rir::RipeNcc));
+    assert_eq!(rir(133, 0, 0, 1), Some(Rir::Apnic));
+
+    // Bogus space has no registry, even inside of an allocated /8.
+    assert_eq!(rir(0, 0, 0, 1), None);
+    assert_eq!(rir(10, 0, 0, 1), None);
+    #[cfg(not(feature = "allow-cgnat"))]
+    assert_eq!(rir(100, 64, 0, 1), None);
+    assert_eq!(rir(100, 63, 255, 255), Some(Rir::Arin));
+    assert_eq!(rir(127, 0, 0, 1), None);
+    assert_eq!(rir(224, 0, 0, 1), None);
+    assert_eq!(rir(239, 255, 255, 255), None);
+    assert_eq!(rir(240, 0, 0, 1), None);
+    assert_eq!(rir(255, 255, 255, 255), None);
+}
+
+#[test]
+fn rir_v4_covers_routable_space() {
+    // Every routable address must belong to some registry.
+    for octet in 0..=255 {
+        // The `allow-reserved-240-4` feature makes reserved space routable, but it has no registry.
+        if cfg!(feature = "allow-reserved-240-4") && octet >= 240 {
+            continue;
+        }
+        for ip in [
+            Ipv4Addr::new(octet, 0, 0, 1),
+            Ipv4Addr::new(octet, 200, 0, 1),
+        ] {
+            assert_eq!(rir_v4(ip).is_some(), !crate::is_bogon_v4(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn allocation_dates() {
+    let date = |ip: &str| allocation_date_v6(ip.parse().unwrap());
+
+    // 2001:4860::/32 is inside of ARIN's 2001:4800::/23.
+    assert_eq!(date("2001:4860:4860::8888"), Some("2004-08-24"));
+    // 2a00::/12 was allocated to the RIPE NCC alongside the other /12s.
+    assert_eq!(date("2a00:1450::1"), Some("2006-10-03"));
+    assert_eq!(date("2a10::1"), Some("2019-06-05"));
+    assert_eq!(date("2001:200::1"), Some("1999-07-01"));
+
+    assert_eq!(date("::1"), None);
+    assert_eq!(date("3000::1"), None);
+}
+
+#[test]
+fn rdap_urls() {
+    let v6 = |ip: &str| rdap_url_v6(ip.parse().unwrap());
+    let v4 = |ip: &str| rdap_url_v4(ip.parse().unwrap());
+
+    assert_eq!(v6("2c0f:f000::1"), Some("https://rdap.afrinic.net/rdap/"));
+    assert_eq!(v6("2400:cb00::1"), Some("https://rdap.apnic.net/"));
+    assert_eq!(
+        v6("2606:4700::1111"),
+        Some("https://rdap.arin.net/registry")
+    );
+    assert_eq!(v6("2800:3f0::1"), Some("https://rdap.lacnic.net/rdap/"));
+    assert_eq!(v6("2a00:1450::1"), Some("https://rdap.db.ripe.net/"));
+    assert_eq!(v6("fe80::1"), None);
+
+    assert_eq!(v4("8.8.8.8"), Some("https://rdap.arin.net/registry"));
+    assert_eq!(v4("53.0.0.1"), Some("https://rdap.db.ripe.net/"));
+    assert_eq!(v4("192.168.0.1"), None);
+
+    // Every RIR shares a single URL.
+    assert_eq!(RDAP_URLS.len(), 5);
+}
+
+#[test]
+fn whois_servers() {
+    let whois = |ip: &str| whois_server(ip.parse().unwrap());
+
+    assert_eq!(whois("41.0.0.1"), Some("whois.afrinic.net"));
+    assert_eq!(whois("1.1.1.1"), Some("whois.apnic.net"));
+    assert_eq!(whois("8.8.8.8"), Some("whois.arin.net"));
+    assert_eq!(whois("200.0.0.1"), Some("whois.lacnic.net"));
+    assert_eq!(whois("193.0.0.1"), Some("whois.ripe.net"));
+
+    assert_eq!(whois("2c0f:f000::1"), Some("whois.afrinic.net"));
+    assert_eq!(whois("2400:cb00::1"), Some("whois.apnic.net"));
+    assert_eq!(whois("2606:4700::1111"), Some("whois.arin.net"));
+    assert_eq!(whois("2800:3f0::1"), Some("whois.lacnic.net"));
+    assert_eq!(whois("2a00:1450::1"), Some("whois.ripe.net"));
+
+    assert_eq!(whois("10.0.0.1"), None);
+    assert_eq!(whois("fe80::1"), None);
+}
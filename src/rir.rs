@@ -0,0 +1,40 @@
+use core::fmt;
+
+/// A regional internet registry (RIR).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Rir {
+    /// African Network Information Centre.
+    Afrinic,
+    /// Asia-Pacific Network Information Centre.
+    Apnic,
+    /// American Registry for Internet Numbers.
+    Arin,
+    /// Latin America and Caribbean Network Information Centre.
+    Lacnic,
+    /// Réseaux IP Européens Network Coordination Centre.
+    RipeNcc,
+    /// Legacy IPv4 space assigned directly to an organization before the regional internet
+    /// registries existed.
+    Legacy,
+}
+
+impl Rir {
+    /// Returns the name IANA uses for the registry (for example `"RIPE NCC"`).
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rir::Afrinic => "AFRINIC",
+            Rir::Apnic => "APNIC",
+            Rir::Arin => "ARIN",
+            Rir::Lacnic => "LACNIC",
+            Rir::RipeNcc => "RIPE NCC",
+            Rir::Legacy => "LEGACY",
+        }
+    }
+}
+
+impl fmt::Display for Rir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,542 @@
+//! Checking IP addresses with `?`, returning them when they are good, and types that prove an
+//! address was checked.
+
+use core::{
+    fmt,
+    net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::{classify, is_bogon, BogonKind};
+
+/// The error returned when an IP address is bogus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BogonError {
+    ip: IpAddr,
+    kind: Option<BogonKind>,
+}
+
+impl BogonError {
+    fn new(ip: IpAddr) -> Self {
+        BogonError {
+            ip,
+            kind: classify(ip),
+        }
+    }
+
+    /// Returns the bogus IP address.
+    pub const fn ip(&self) -> IpAddr {
+        self.ip
+    }
+
+    /// Returns the classification of the IP address, the reason it is bogus.
+    ///
+    /// `None` when the address is only bogus because of the overrides of the `test-util` or
+    /// `runtime` features, which don't say why.
+    pub const fn kind(&self) -> Option<BogonKind> {
+        self.kind
+    }
+}
+
+impl fmt::Display for BogonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            Some(kind) => write!(
+                f,
+                "{} is {}, not globally routable",
+                self.ip,
+                kind.label_for(self.ip)
+            ),
+            None => write!(f, "{} is bogus, not globally routable", self.ip),
+        }
+    }
+}
+
+impl core::error::Error for BogonError {}
+
+/// The error returned by [`ensure_routable_str`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnsureRoutableError {
+    /// The string is not an IP address.
+    InvalidAddress(AddrParseError),
+    /// The IP address is bogus.
+    Bogon(BogonError),
+}
+
+impl fmt::Display for EnsureRoutableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnsureRoutableError::InvalidAddress(error) => error.fmt(f),
+            EnsureRoutableError::Bogon(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for EnsureRoutableError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            EnsureRoutableError::InvalidAddress(error) => Some(error),
+            EnsureRoutableError::Bogon(error) => Some(error),
+        }
+    }
+}
+
+impl From<AddrParseError> for EnsureRoutableError {
+    fn from(error: AddrParseError) -> Self {
+        EnsureRoutableError::InvalidAddress(error)
+    }
+}
+
+impl From<BogonError> for EnsureRoutableError {
+    fn from(error: BogonError) -> Self {
+        EnsureRoutableError::Bogon(error)
+    }
+}
+
+/// Returns an IP address if it is good, or an error naming the reason it is bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::{ensure_routable, BogonError};
+///
+/// fn connect(ip: IpAddr) -> Result<IpAddr, BogonError> {
+///     let ip = ensure_routable(ip)?;
+///     // ...
+///     Ok(ip)
+/// }
+///
+/// assert!(connect("8.8.8.8".parse().unwrap()).is_ok());
+///
+/// let error = connect("10.1.2.3".parse().unwrap()).unwrap_err();
+/// assert_eq!(error.to_string(), "10.1.2.3 is private-use (RFC 1918), not globally routable");
+/// ```
+#[inline]
+pub fn ensure_routable(ip: IpAddr) -> Result<IpAddr, BogonError> {
+    if is_bogon(ip) {
+        Err(BogonError::new(ip))
+    } else {
+        Ok(ip)
+    }
+}
+
+/// Returns an IPv4 address if it is good, or an error naming the reason it is bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{ensure_routable_v4, BogonKind};
+///
+/// assert_eq!(ensure_routable_v4(Ipv4Addr::new(8, 8, 8, 8)), Ok(Ipv4Addr::new(8, 8, 8, 8)));
+///
+/// let error = ensure_routable_v4(Ipv4Addr::LOCALHOST).unwrap_err();
+/// assert_eq!(error.kind(), Some(BogonKind::Loopback));
+/// ```
+#[inline]
+pub fn ensure_routable_v4(ip: Ipv4Addr) -> Result<Ipv4Addr, BogonError> {
+    ensure_routable(IpAddr::V4(ip)).map(|_| ip)
+}
+
+/// Returns an IPv6 address if it is good, or an error naming the reason it is bogus.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::{ensure_routable_v6, BogonKind};
+///
+/// let cloudflare = Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111);
+/// assert_eq!(ensure_routable_v6(cloudflare), Ok(cloudflare));
+///
+/// let error = ensure_routable_v6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1)).unwrap_err();
+/// assert_eq!(error.kind(), Some(BogonKind::UniqueLocal));
+/// ```
+#[inline]
+pub fn ensure_routable_v6(ip: Ipv6Addr) -> Result<Ipv6Addr, BogonError> {
+    ensure_routable(IpAddr::V6(ip)).map(|_| ip)
+}
+
+/// Parses an IP address and returns it if it is good, for configuration loaders.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{ensure_routable_str, EnsureRoutableError};
+///
+/// assert_eq!(ensure_routable_str("8.8.8.8"), Ok("8.8.8.8".parse().unwrap()));
+/// assert!(matches!(ensure_routable_str("127.0.0.1"), Err(EnsureRoutableError::Bogon(_))));
+/// assert!(matches!(ensure_routable_str("foo"), Err(EnsureRoutableError::InvalidAddress(_))));
+/// ```
+#[inline]
+pub fn ensure_routable_str(s: &str) -> Result<IpAddr, EnsureRoutableError> {
+    Ok(ensure_routable(s.parse()?)?)
+}
+
+/// Implements the traits shared by the routable address types, which wrap `$ip` parsed and checked
+/// by `$ensure`.
+macro_rules! routable_address {
+    ($routable:ident, $ip:ty, $ensure:path, $expecting:literal) => {
+        impl TryFrom<$ip> for $routable {
+            type Error = BogonError;
+
+            #[inline]
+            fn try_from(ip: $ip) -> Result<Self, BogonError> {
+                $ensure(ip).map($routable)
+            }
+        }
+
+        impl FromStr for $routable {
+            type Err = EnsureRoutableError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, EnsureRoutableError> {
+                Ok($routable::try_from(s.parse::<$ip>()?)?)
+            }
+        }
+
+        impl Deref for $routable {
+            type Target = $ip;
+
+            #[inline]
+            fn deref(&self) -> &$ip {
+                &self.0
+            }
+        }
+
+        impl From<$routable> for $ip {
+            #[inline]
+            fn from(ip: $routable) -> $ip {
+                ip.0
+            }
+        }
+
+        impl fmt::Display for $routable {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $routable {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $routable {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::{self, Unexpected, Visitor};
+
+                struct RoutableVisitor;
+
+                impl Visitor<'_> for RoutableVisitor {
+                    type Value = $routable;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$routable, E> {
+                        v.parse().map_err(|error| match error {
+                            EnsureRoutableError::InvalidAddress(_) => {
+                                E::invalid_value(Unexpected::Str(v), &self)
+                            }
+                            error => E::custom(error),
+                        })
+                    }
+                }
+
+                deserializer.deserialize_str(RoutableVisitor)
+            }
+        }
+    };
+}
+
+/// An IP address that is known to be good, so that functions taking it can't be handed a bogus
+/// one by accident.
+///
+/// The only ways to make one are [`TryFrom<IpAddr>`](TryFrom) and [`FromStr`], which reject bogus
+/// addresses like [`ensure_routable`], and deserializing one with the `serde` feature, which
+/// checks the address again. It dereferences to the [`IpAddr`] it wraps.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::RoutableIp;
+///
+/// fn connect(ip: RoutableIp) -> IpAddr {
+///     // `ip` can't be a loopback address.
+///     *ip
+/// }
+///
+/// let ip: RoutableIp = "8.8.8.8".parse().unwrap();
+/// assert_eq!(connect(ip), "8.8.8.8".parse::<IpAddr>().unwrap());
+/// assert!(ip.is_ipv4());
+///
+/// assert!("127.0.0.1".parse::<RoutableIp>().is_err());
+/// assert!(RoutableIp::try_from("10.0.0.1".parse::<IpAddr>().unwrap()).is_err());
+/// ```
+///
+/// There is no other way to make one from a bogus address:
+///
+/// ```compile_fail
+/// use core::net::{IpAddr, Ipv4Addr};
+/// use bogon::RoutableIp;
+///
+/// let loopback = RoutableIp(IpAddr::V4(Ipv4Addr::LOCALHOST));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoutableIp(IpAddr);
+
+routable_address!(
+    RoutableIp,
+    IpAddr,
+    ensure_routable,
+    "a globally routable IP address"
+);
+
+impl From<RoutableIpv4> for RoutableIp {
+    #[inline]
+    fn from(ip: RoutableIpv4) -> Self {
+        RoutableIp(IpAddr::V4(ip.0))
+    }
+}
+
+impl From<RoutableIpv6> for RoutableIp {
+    #[inline]
+    fn from(ip: RoutableIpv6) -> Self {
+        RoutableIp(IpAddr::V6(ip.0))
+    }
+}
+
+/// An IPv4 address that is known to be good, see [`RoutableIp`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::RoutableIpv4;
+///
+/// let ip = RoutableIpv4::try_from(Ipv4Addr::new(8, 8, 8, 8)).unwrap();
+/// assert_eq!(ip.octets(), [8, 8, 8, 8]);
+///
+/// assert!(RoutableIpv4::try_from(Ipv4Addr::LOCALHOST).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use core::net::Ipv4Addr;
+/// use bogon::RoutableIpv4;
+///
+/// let loopback = RoutableIpv4(Ipv4Addr::LOCALHOST);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoutableIpv4(Ipv4Addr);
+
+routable_address!(
+    RoutableIpv4,
+    Ipv4Addr,
+    ensure_routable_v4,
+    "a globally routable IPv4 address"
+);
+
+/// An IPv6 address that is known to be good, see [`RoutableIp`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv6Addr;
+/// use bogon::RoutableIpv6;
+///
+/// let ip: RoutableIpv6 = "2606:4700:4700::1111".parse().unwrap();
+/// assert_eq!(ip.segments()[0], 0x2606);
+///
+/// assert!("::1".parse::<RoutableIpv6>().is_err());
+/// ```
+///
+/// ```compile_fail
+/// use core::net::Ipv6Addr;
+/// use bogon::RoutableIpv6;
+///
+/// let loopback = RoutableIpv6(Ipv6Addr::LOCALHOST);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoutableIpv6(Ipv6Addr);
+
+routable_address!(
+    RoutableIpv6,
+    Ipv6Addr,
+    ensure_routable_v6,
+    "a globally routable IPv6 address"
+);
+
+/// The error returned when a socket address is not a [`RoutableSocketAddr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RoutableSocketAddrError {
+    /// The string is not a socket address such as `8.8.8.8:53` or `[2606:4700:4700::1111]:443`.
+    InvalidAddress(AddrParseError),
+    /// The IP address is bogus.
+    Bogon(BogonError),
+    /// The port is 0.
+    ZeroPort,
+}
+
+impl fmt::Display for RoutableSocketAddrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoutableSocketAddrError::InvalidAddress(error) => error.fmt(f),
+            RoutableSocketAddrError::Bogon(error) => write!(f, "the host {error}"),
+            RoutableSocketAddrError::ZeroPort => f.write_str("port 0 is not allowed"),
+        }
+    }
+}
+
+impl core::error::Error for RoutableSocketAddrError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            RoutableSocketAddrError::InvalidAddress(error) => Some(error),
+            RoutableSocketAddrError::Bogon(error) => Some(error),
+            RoutableSocketAddrError::ZeroPort => None,
+        }
+    }
+}
+
+impl From<AddrParseError> for RoutableSocketAddrError {
+    fn from(error: AddrParseError) -> Self {
+        RoutableSocketAddrError::InvalidAddress(error)
+    }
+}
+
+impl From<BogonError> for RoutableSocketAddrError {
+    fn from(error: BogonError) -> Self {
+        RoutableSocketAddrError::Bogon(error)
+    }
+}
+
+/// A socket address with a good IP address and a port other than 0, such as an upstream read from
+/// a configuration file.
+///
+/// Like [`RoutableIp`], the only ways to make one are [`TryFrom<SocketAddr>`](TryFrom),
+/// [`FromStr`], and deserializing one with the `serde` feature, which checks it again. It
+/// dereferences to the [`SocketAddr`] it wraps, except that [`RoutableSocketAddr::ip`] returns a
+/// [`RoutableIp`].
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{RoutableSocketAddr, RoutableSocketAddrError};
+///
+/// let upstream: RoutableSocketAddr = "8.8.8.8:53".parse().unwrap();
+/// assert_eq!(upstream.ip().to_string(), "8.8.8.8");
+/// assert_eq!(upstream.port(), 53);
+///
+/// let error = "127.0.0.1:8080".parse::<RoutableSocketAddr>().unwrap_err();
+/// assert_eq!(error.to_string(), "the host 127.0.0.1 is loopback (RFC 1122), not globally routable");
+/// let error = "[2606:4700:4700::1111]:0".parse::<RoutableSocketAddr>().unwrap_err();
+/// assert_eq!(error, RoutableSocketAddrError::ZeroPort);
+/// assert!("8.8.8.8".parse::<RoutableSocketAddr>().is_err());
+/// ```
+///
+/// ```compile_fail
+/// use core::net::SocketAddr;
+/// use bogon::RoutableSocketAddr;
+///
+/// let loopback = RoutableSocketAddr("127.0.0.1:8080".parse::<SocketAddr>().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoutableSocketAddr(SocketAddr);
+
+impl RoutableSocketAddr {
+    /// Returns the IP address, known to be good.
+    #[inline]
+    pub const fn ip(&self) -> RoutableIp {
+        RoutableIp(self.0.ip())
+    }
+}
+
+impl TryFrom<SocketAddr> for RoutableSocketAddr {
+    type Error = RoutableSocketAddrError;
+
+    #[inline]
+    fn try_from(socket: SocketAddr) -> Result<Self, RoutableSocketAddrError> {
+        ensure_routable(socket.ip())?;
+        if socket.port() == 0 {
+            return Err(RoutableSocketAddrError::ZeroPort);
+        }
+        Ok(RoutableSocketAddr(socket))
+    }
+}
+
+impl FromStr for RoutableSocketAddr {
+    type Err = RoutableSocketAddrError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, RoutableSocketAddrError> {
+        RoutableSocketAddr::try_from(s.parse::<SocketAddr>()?)
+    }
+}
+
+impl Deref for RoutableSocketAddr {
+    type Target = SocketAddr;
+
+    #[inline]
+    fn deref(&self) -> &SocketAddr {
+        &self.0
+    }
+}
+
+impl From<RoutableSocketAddr> for SocketAddr {
+    #[inline]
+    fn from(socket: RoutableSocketAddr) -> SocketAddr {
+        socket.0
+    }
+}
+
+impl fmt::Display for RoutableSocketAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RoutableSocketAddr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RoutableSocketAddr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{self, Unexpected, Visitor};
+
+        struct RoutableSocketAddrVisitor;
+
+        impl Visitor<'_> for RoutableSocketAddrVisitor {
+            type Value = RoutableSocketAddr;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(
+                    "a socket address with a globally routable IP address, like `8.8.8.8:53`",
+                )
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<RoutableSocketAddr, E> {
+                v.parse().map_err(|error| match error {
+                    RoutableSocketAddrError::InvalidAddress(_) => {
+                        E::invalid_value(Unexpected::Str(v), &self)
+                    }
+                    error => E::custom(error),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(RoutableSocketAddrVisitor)
+    }
+}
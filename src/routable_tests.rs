@@ -0,0 +1,312 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::string::ToString;
+
+use crate::{
+    classify, ensure_routable, ensure_routable_str, ensure_routable_v4, ensure_routable_v6,
+    is_bogon, BogonKind, EnsureRoutableError, RoutableIp, RoutableIpv4, RoutableIpv6,
+    RoutableSocketAddr, RoutableSocketAddrError,
+};
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+#[test]
+fn good_addresses_are_returned() {
+    for good in ["8.8.8.8", "1.1.1.1", "192.175.48.1", "2606:4700:4700::1111"] {
+        assert_eq!(ensure_routable(ip(good)), Ok(ip(good)), "{good}");
+        assert_eq!(ensure_routable_str(good), Ok(ip(good)), "{good}");
+    }
+    assert_eq!(
+        ensure_routable_v4(Ipv4Addr::new(8, 8, 8, 8)),
+        Ok(Ipv4Addr::new(8, 8, 8, 8))
+    );
+    let google = Ipv6Addr::new(0x2001, 0x4860, 0x4860, 0, 0, 0, 0, 0x8888);
+    assert_eq!(ensure_routable_v6(google), Ok(google));
+}
+
+#[test]
+fn bogus_addresses_carry_their_classification() {
+    for bogus in [
+        "10.1.2.3",
+        "127.0.0.1",
+        "169.254.169.254",
+        "::1",
+        "fd00::1",
+        "3000::1",
+    ] {
+        let error = ensure_routable(ip(bogus)).unwrap_err();
+        assert_eq!(error.ip(), ip(bogus));
+        assert_eq!(error.kind(), classify(ip(bogus)), "{bogus}");
+        assert_eq!(
+            ensure_routable_str(bogus),
+            Err(EnsureRoutableError::Bogon(error))
+        );
+    }
+    let error = ensure_routable_v4(Ipv4Addr::BROADCAST).unwrap_err();
+    assert_eq!(error.ip(), IpAddr::V4(Ipv4Addr::BROADCAST));
+    assert_eq!(error.kind(), Some(BogonKind::Broadcast));
+    let error = ensure_routable_v6(Ipv6Addr::UNSPECIFIED).unwrap_err();
+    assert_eq!(error.kind(), Some(BogonKind::Unspecified));
+}
+
+#[test]
+fn random_addresses_match_is_bogon() {
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+        for ip in [v4, v6] {
+            assert_eq!(ensure_routable(ip).is_err(), is_bogon(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn messages() {
+    assert_eq!(
+        ensure_routable(ip("10.1.2.3")).unwrap_err().to_string(),
+        "10.1.2.3 is private-use (RFC 1918), not globally routable"
+    );
+    assert_eq!(
+        ensure_routable(ip("3000::1")).unwrap_err().to_string(),
+        "3000::1 is unallocated IPv6 space, not globally routable"
+    );
+    assert_eq!(
+        ensure_routable_str("::1").unwrap_err().to_string(),
+        "::1 is loopback (RFC 4291), not globally routable"
+    );
+    assert_eq!(
+        ensure_routable(ip("fe80::1")).unwrap_err().to_string(),
+        "fe80::1 is link-local (RFC 4291), not globally routable"
+    );
+    assert_eq!(
+        ensure_routable_str("foo").unwrap_err().to_string(),
+        "invalid IP address syntax"
+    );
+}
+
+#[test]
+fn errors_have_sources() {
+    use core::error::Error;
+
+    let error = ensure_routable_str("::1").unwrap_err();
+    let source = error.source().unwrap();
+    assert_eq!(source.to_string(), error.to_string());
+    assert!(source.source().is_none());
+
+    let error = ensure_routable_str("foo").unwrap_err();
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn invalid_strings() {
+    for input in ["", "foo", "10.0.0.0/8", "127.0.0.1:80", " 8.8.8.8"] {
+        assert!(
+            matches!(
+                ensure_routable_str(input),
+                Err(EnsureRoutableError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "test-util")]
+fn overrides_without_a_classification() {
+    use crate::{testing::with_policy, DefaultBogonPolicy, IpPolicy};
+
+    let denied = |ip_address: IpAddr| {
+        ip_address != ip("8.8.8.8") && DefaultBogonPolicy.is_allowed(ip_address)
+    };
+    with_policy(denied, || {
+        // The policy denies an address the tables don't classify.
+        let error = ensure_routable(ip("8.8.8.8")).unwrap_err();
+        assert_eq!(error.kind(), None);
+        assert_eq!(error.to_string(), "8.8.8.8 is bogus, not globally routable");
+    });
+}
+
+#[test]
+fn routable_types_reject_bogus_addresses() {
+    for bogus in ["127.0.0.1", "10.0.0.1", "::1", "fd00::1"] {
+        let error = ensure_routable(ip(bogus)).unwrap_err();
+        assert_eq!(RoutableIp::try_from(ip(bogus)), Err(error), "{bogus}");
+        assert_eq!(
+            bogus.parse::<RoutableIp>(),
+            Err(EnsureRoutableError::Bogon(error))
+        );
+    }
+    assert!(RoutableIpv4::try_from(Ipv4Addr::LOCALHOST).is_err());
+    assert!(RoutableIpv6::try_from(Ipv6Addr::LOCALHOST).is_err());
+    assert!(matches!(
+        "::1".parse::<RoutableIpv4>(),
+        Err(EnsureRoutableError::InvalidAddress(_))
+    ));
+    assert!(matches!(
+        "8.8.8.8".parse::<RoutableIpv6>(),
+        Err(EnsureRoutableError::InvalidAddress(_))
+    ));
+}
+
+#[test]
+fn routable_types_wrap_good_addresses() {
+    let google: RoutableIp = "8.8.8.8".parse().unwrap();
+    assert_eq!(*google, ip("8.8.8.8"));
+    assert_eq!(IpAddr::from(google), ip("8.8.8.8"));
+    assert_eq!(google.to_string(), "8.8.8.8");
+    assert!(google.is_ipv4());
+
+    let v4 = RoutableIpv4::try_from(Ipv4Addr::new(1, 1, 1, 1)).unwrap();
+    assert_eq!(v4.octets(), [1, 1, 1, 1]);
+    assert_eq!(Ipv4Addr::from(v4), Ipv4Addr::new(1, 1, 1, 1));
+    assert_eq!(RoutableIp::from(v4), "1.1.1.1".parse().unwrap());
+
+    let v6: RoutableIpv6 = "2606:4700:4700::1111".parse().unwrap();
+    assert_eq!(v6.to_string(), "2606:4700:4700::1111");
+    assert_eq!(*RoutableIp::from(v6), IpAddr::V6(Ipv6Addr::from(v6)));
+}
+
+#[test]
+fn routable_types_order_like_their_addresses() {
+    let mut ips = ["9.9.9.9", "2606:4700:4700::1111", "1.1.1.1", "8.8.8.8"]
+        .map(|ip| ip.parse::<RoutableIp>().unwrap());
+    ips.sort();
+    assert_eq!(
+        ips.map(|ip| *ip),
+        ["1.1.1.1", "8.8.8.8", "9.9.9.9", "2606:4700:4700::1111"].map(ip)
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_round_trip() {
+    let google: RoutableIp = "8.8.8.8".parse().unwrap();
+    let json = serde_json::to_string(&google).unwrap();
+    assert_eq!(json, r#""8.8.8.8""#);
+    assert_eq!(serde_json::from_str::<RoutableIp>(&json).unwrap(), google);
+
+    let v4: RoutableIpv4 = "1.1.1.1".parse().unwrap();
+    let json = serde_json::to_string(&v4).unwrap();
+    assert_eq!(serde_json::from_str::<RoutableIpv4>(&json).unwrap(), v4);
+
+    let v6: RoutableIpv6 = "2606:4700:4700::1111".parse().unwrap();
+    let json = serde_json::to_string(&v6).unwrap();
+    assert_eq!(json, r#""2606:4700:4700::1111""#);
+    assert_eq!(serde_json::from_str::<RoutableIpv6>(&json).unwrap(), v6);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn deserializing_checks_again() {
+    let error = serde_json::from_str::<RoutableIp>(r#""10.1.2.3""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "10.1.2.3 is private-use (RFC 1918), not globally routable at line 1 column 10"
+    );
+    let error = serde_json::from_str::<RoutableIpv4>(r#""localhost""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value: string \"localhost\", expected a globally routable IPv4 address at line 1 column 11"
+    );
+    assert!(serde_json::from_str::<RoutableIpv6>(r#""::1""#).is_err());
+    assert!(serde_json::from_str::<RoutableIpv6>(r#""8.8.8.8""#).is_err());
+    assert!(serde_json::from_str::<RoutableIp>("1").is_err());
+}
+
+#[test]
+fn socket_addresses() {
+    let v4: RoutableSocketAddr = "8.8.8.8:53".parse().unwrap();
+    assert_eq!(v4.ip(), "8.8.8.8".parse::<RoutableIp>().unwrap());
+    assert_eq!(v4.port(), 53);
+    assert!(v4.is_ipv4());
+    assert_eq!(v4.to_string(), "8.8.8.8:53");
+    assert_eq!(SocketAddr::from(v4), "8.8.8.8:53".parse().unwrap());
+
+    let v6: RoutableSocketAddr = "[2606:4700:4700::1111]:443".parse().unwrap();
+    assert_eq!(*v6.ip(), ip("2606:4700:4700::1111"));
+    assert_eq!(v6.port(), 443);
+    assert_eq!(v6.to_string(), "[2606:4700:4700::1111]:443");
+    assert_eq!(RoutableSocketAddr::try_from(SocketAddr::from(v6)), Ok(v6));
+}
+
+#[test]
+fn socket_address_errors() {
+    for (input, bogus) in [
+        ("127.0.0.1:8080", "127.0.0.1"),
+        ("10.1.2.3:443", "10.1.2.3"),
+        ("[::1]:443", "::1"),
+        ("[fe80::1]:22", "fe80::1"),
+        ("203.0.113.10:443", "203.0.113.10"),
+        // The host is checked before the port.
+        ("127.0.0.1:0", "127.0.0.1"),
+    ] {
+        let error = ensure_routable(ip(bogus)).unwrap_err();
+        assert_eq!(
+            input.parse::<RoutableSocketAddr>(),
+            Err(RoutableSocketAddrError::Bogon(error)),
+            "{input}"
+        );
+    }
+    for input in ["8.8.8.8:0", "[2606:4700:4700::1111]:0"] {
+        assert_eq!(
+            input.parse::<RoutableSocketAddr>(),
+            Err(RoutableSocketAddrError::ZeroPort),
+            "{input}"
+        );
+    }
+    for input in [
+        "8.8.8.8",
+        "2606:4700:4700::1111:443",
+        "8.8.8.8:65536",
+        "example.com:443",
+        "",
+    ] {
+        assert!(
+            matches!(
+                input.parse::<RoutableSocketAddr>(),
+                Err(RoutableSocketAddrError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn socket_address_messages() {
+    let message = |input: &str| input.parse::<RoutableSocketAddr>().unwrap_err().to_string();
+    assert_eq!(
+        message("127.0.0.1:8080"),
+        "the host 127.0.0.1 is loopback (RFC 1122), not globally routable"
+    );
+    assert_eq!(message("8.8.8.8:0"), "port 0 is not allowed");
+    assert_eq!(message("8.8.8.8"), "invalid socket address syntax");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn socket_address_serde_round_trip() {
+    for socket in ["8.8.8.8:53", "[2606:4700:4700::1111]:443"] {
+        let socket: RoutableSocketAddr = socket.parse().unwrap();
+        let json = serde_json::to_string(&socket).unwrap();
+        assert_eq!(json, format!("\"{socket}\""));
+        assert_eq!(
+            serde_json::from_str::<RoutableSocketAddr>(&json).unwrap(),
+            socket
+        );
+    }
+
+    let error = serde_json::from_str::<RoutableSocketAddr>(r#""127.0.0.1:8080""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "the host 127.0.0.1 is loopback (RFC 1122), not globally routable at line 1 column 16"
+    );
+    let error = serde_json::from_str::<RoutableSocketAddr>(r#""8.8.8.8:0""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "port 0 is not allowed at line 1 column 11"
+    );
+    assert!(serde_json::from_str::<RoutableSocketAddr>(r#""8.8.8.8""#).is_err());
+}
@@ -0,0 +1,110 @@
+//! Replacing the compiled-in bogon data at runtime.
+//!
+//! Long-running programs can install a [`BogonTable`] with [`set_runtime_table`]. While a table is
+//! installed, [`is_bogon`](crate::is_bogon) and its IPv4 and IPv6 variants follow the table instead
+//! of the compiled-in data. [`clear_runtime_table`] goes back to the compiled-in data.
+//!
+//! [`classify`](crate::classify) keeps the compiled-in classifications, but only reports bogus
+//! classifications for addresses the table flags. Addresses the table flags that have no
+//! compiled-in classification are bogus without a classification, so for them `is_bogon(ip)` is
+//! not equal to `classify(ip).is_some()`.
+//!
+//! Readers cache the installed table per thread, so checking an address costs an atomic load while
+//! the table stays the same. The first check on each thread after the table was swapped takes a
+//! read lock on the installed table to refresh its cache, so it can wait for a concurrent
+//! [`set_runtime_table`] or [`clear_runtime_table`] to finish, which only hold the write lock to
+//! swap a pointer.
+
+use core::{
+    net::IpAddr,
+    sync::atomic::{self, AtomicU64},
+};
+use std::{
+    cell::RefCell,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::BogonTable;
+
+// The installed table. `GENERATION` is bumped after every change, zero means no table was ever
+// installed.
+static TABLE: RwLock<Option<Arc<BogonTable>>> = RwLock::new(None);
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+std::thread_local! {
+    // The table this thread saw last, and the generation it saw it at.
+    static CACHE: RefCell<(u64, Option<Arc<BogonTable>>)> = const { RefCell::new((0, None)) };
+}
+
+/// Replaces the installed table and bumps the generation.
+fn replace(table: Option<Arc<BogonTable>>) {
+    let mut installed = TABLE.write().unwrap_or_else(PoisonError::into_inner);
+    let previous = core::mem::replace(&mut *installed, table);
+    GENERATION.fetch_add(1, atomic::Ordering::Release);
+    drop(installed);
+    // Readers may be waiting for the lock, so the previous table is dropped after releasing it.
+    drop(previous);
+}
+
+/// Returns the installed table without consulting the cache.
+fn installed() -> Option<Arc<BogonTable>> {
+    TABLE.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Makes the free functions follow a table instead of the compiled-in data, on every thread.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{clear_runtime_table, is_bogon, set_runtime_table, BogonTable};
+///
+/// set_runtime_table([("192.0.2.0".parse().unwrap(), 24)].into_iter().collect());
+/// assert!(is_bogon("192.0.2.1".parse().unwrap()));
+/// assert!(!is_bogon("10.0.0.1".parse().unwrap()));
+///
+/// clear_runtime_table();
+/// assert!(is_bogon("10.0.0.1".parse().unwrap()));
+/// ```
+pub fn set_runtime_table(table: BogonTable) {
+    replace(Some(Arc::new(table)));
+}
+
+/// Makes the free functions follow the compiled-in data again.
+pub fn clear_runtime_table() {
+    replace(None);
+}
+
+/// Returns the installed table, or `None` if the free functions follow the compiled-in data.
+pub fn runtime_table() -> Option<Arc<BogonTable>> {
+    with_runtime_table(Arc::clone)
+}
+
+/// Calls a function with the installed table, or returns `None` if there is none.
+#[inline]
+fn with_runtime_table<R>(f: impl FnOnce(&Arc<BogonTable>) -> R) -> Option<R> {
+    let generation = GENERATION.load(atomic::Ordering::Acquire);
+    if generation == 0 {
+        return None;
+    }
+
+    let mut f = Some(f);
+    let cached = CACHE.try_with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.0 != generation {
+            *cache = (generation, installed());
+        }
+        cache.1.as_ref().map(|table| f.take().unwrap()(table))
+    });
+
+    match cached {
+        Ok(result) => result,
+        // The cache is gone while the thread is shutting down.
+        Err(_) => installed().as_ref().map(f.take().unwrap()),
+    }
+}
+
+/// Returns the verdict of the installed table, if any.
+#[inline]
+pub(crate) fn is_bogon_override(ip_address: IpAddr) -> Option<bool> {
+    with_runtime_table(|table| table.contains(ip_address))
+}
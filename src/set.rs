@@ -0,0 +1,265 @@
+//! A prefix set with longest-prefix match that can be changed at runtime.
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::boxed::Box;
+
+use crate::{
+    unallocated_v6_networks, Verdict, V4_BOGON_EXCEPTIONS, V4_BOGON_NETWORKS,
+    V4_CLOUD_METADATA_NETWORKS, V6_BOGON_EXCEPTIONS, V6_BOGON_NETWORKS, V6_CLOUD_METADATA_NETWORKS,
+    V6_GLOBAL_BOGON_NETWORKS, V6_SPECIAL_BOGON_NETWORKS,
+};
+
+/// A node of a binary trie, indexed by the bits of the network address from the top.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Node {
+    verdict: Option<Verdict>,
+    children: [Option<Box<Node>>; 2],
+}
+
+/// Returns bit `depth` of a left-aligned key, counting from the top.
+fn bit(key: u128, depth: u8) -> usize {
+    (key >> (127 - depth) & 1) as usize
+}
+
+impl Node {
+    fn is_empty(&self) -> bool {
+        self.verdict.is_none() && self.children.iter().all(Option::is_none)
+    }
+
+    fn insert(&mut self, key: u128, prefix_len: u8, verdict: Verdict) -> Option<Verdict> {
+        let mut node = self;
+        for depth in 0..prefix_len {
+            node = node.children[bit(key, depth)].get_or_insert_with(Box::default);
+        }
+        node.verdict.replace(verdict)
+    }
+
+    /// Removes the verdict at a prefix, pruning nodes that became empty.
+    fn remove(&mut self, key: u128, prefix_len: u8, depth: u8) -> Option<Verdict> {
+        if depth == prefix_len {
+            return self.verdict.take();
+        }
+
+        let slot = &mut self.children[bit(key, depth)];
+        let child = slot.as_mut()?;
+        let removed = child.remove(key, prefix_len, depth + 1);
+        if child.is_empty() {
+            *slot = None;
+        }
+        removed
+    }
+
+    fn lookup(&self, key: u128, width: u8) -> Option<(u8, Verdict)> {
+        let mut node = self;
+        let mut matched = node.verdict.map(|verdict| (0, verdict));
+        for depth in 0..width {
+            let Some(child) = &node.children[bit(key, depth)] else {
+                break;
+            };
+            node = child;
+            if let Some(verdict) = node.verdict {
+                matched = Some((depth + 1, verdict));
+            }
+        }
+        matched
+    }
+
+    fn len(&self) -> usize {
+        usize::from(self.verdict.is_some())
+            + self
+                .children
+                .iter()
+                .flatten()
+                .map(|child| child.len())
+                .sum::<usize>()
+    }
+}
+
+/// Returns the left-aligned key of a network, with host bits cleared.
+///
+/// # Panics
+///
+/// Panics if the prefix length is longer than the address.
+fn key(network: IpAddr, prefix_len: u8) -> u128 {
+    let key = match network {
+        IpAddr::V4(ip) => {
+            assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+            u128::from(ip.to_bits()) << 96
+        }
+        IpAddr::V6(ip) => {
+            assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+            ip.to_bits()
+        }
+    };
+    key & u128::MAX
+        .checked_shl(128 - u32::from(prefix_len))
+        .unwrap_or(0)
+}
+
+/// A set of IPv4 and IPv6 networks, each with a [`Verdict`], looked up by longest-prefix match.
+///
+/// The most specific network containing an address decides, so a `/32` allowed inside of a
+/// denied `/8` is allowed. Networks of different address families never match each other.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::{BogonSet, Verdict};
+///
+/// let mut set = BogonSet::with_defaults();
+/// set.insert("8.8.8.0".parse().unwrap(), 24, Verdict::Deny);
+/// set.insert("10.1.2.3".parse().unwrap(), 32, Verdict::Allow);
+///
+/// assert!(set.is_bogon("8.8.8.8".parse().unwrap()));
+/// assert!(set.is_bogon("10.1.2.4".parse().unwrap()));
+/// assert_eq!(set.lookup("10.1.2.3".parse().unwrap()), Some(Verdict::Allow));
+/// assert_eq!(set.lookup("1.1.1.1".parse().unwrap()), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BogonSet {
+    v4: Node,
+    v6: Node,
+}
+
+impl BogonSet {
+    /// Returns an empty set.
+    pub fn new() -> Self {
+        BogonSet::default()
+    }
+
+    /// Returns a set that agrees with [`is_bogon`](crate::is_bogon) on the compiled-in data.
+    ///
+    /// Bogus networks are denied and the globally reachable exceptions inside of them are
+    /// allowed. Addresses that are fine aren't in the set at all.
+    pub fn with_defaults() -> Self {
+        let mut set = BogonSet::new();
+
+        let deny_v4 = V4_BOGON_NETWORKS.iter().chain(&V4_CLOUD_METADATA_NETWORKS);
+        for network in deny_v4 {
+            let (network, prefix_len) = network.to_v4();
+            set.insert(network, prefix_len, Verdict::Deny);
+        }
+        for network in &V4_BOGON_EXCEPTIONS {
+            let (network, prefix_len) = network.to_v4();
+            set.insert(network, prefix_len, Verdict::Allow);
+        }
+
+        let deny_v6 = V6_BOGON_NETWORKS
+            .iter()
+            .map(|network| network.to_v6())
+            .chain(unallocated_v6_networks().map(|network| network.to_v6()))
+            .chain(
+                V6_SPECIAL_BOGON_NETWORKS
+                    .iter()
+                    .chain(&V6_CLOUD_METADATA_NETWORKS)
+                    .map(|network| network.to_v6()),
+            )
+            .chain(
+                V6_GLOBAL_BOGON_NETWORKS
+                    .iter()
+                    .map(|network| network.to_v6()),
+            );
+        for (network, prefix_len) in deny_v6 {
+            set.insert(network, prefix_len, Verdict::Deny);
+        }
+        for network in &V6_BOGON_EXCEPTIONS {
+            let (network, prefix_len) = network.to_v6();
+            set.insert(network, prefix_len, Verdict::Allow);
+        }
+
+        set
+    }
+
+    /// Sets the verdict for a network, returning the previous verdict for exactly that network.
+    ///
+    /// Host bits of the network address are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix length is longer than the address.
+    pub fn insert(&mut self, network: IpAddr, prefix_len: u8, verdict: Verdict) -> Option<Verdict> {
+        let key = key(network, prefix_len);
+        match network {
+            IpAddr::V4(_) => self.v4.insert(key, prefix_len, verdict),
+            IpAddr::V6(_) => self.v6.insert(key, prefix_len, verdict),
+        }
+    }
+
+    /// Removes a network, returning its verdict. More and less specific networks are kept.
+    ///
+    /// Host bits of the network address are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix length is longer than the address.
+    pub fn remove(&mut self, network: IpAddr, prefix_len: u8) -> Option<Verdict> {
+        let key = key(network, prefix_len);
+        match network {
+            IpAddr::V4(_) => self.v4.remove(key, prefix_len, 0),
+            IpAddr::V6(_) => self.v6.remove(key, prefix_len, 0),
+        }
+    }
+
+    /// Returns the verdict of the most specific network containing an IP address, or `None` if no
+    /// network contains it.
+    #[inline]
+    pub fn lookup(&self, ip_address: IpAddr) -> Option<Verdict> {
+        self.longest_match(ip_address).map(|(_, verdict)| verdict)
+    }
+
+    /// Returns the most specific network containing an IP address and its verdict.
+    pub fn longest_match(&self, ip_address: IpAddr) -> Option<((IpAddr, u8), Verdict)> {
+        match ip_address {
+            IpAddr::V4(ip) => {
+                let (prefix_len, verdict) = self.v4.lookup(u128::from(ip.to_bits()) << 96, 32)?;
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(prefix_len))
+                    .unwrap_or(0);
+                let network = IpAddr::V4(Ipv4Addr::from_bits(ip.to_bits() & mask));
+                Some(((network, prefix_len), verdict))
+            }
+            IpAddr::V6(ip) => {
+                let (prefix_len, verdict) = self.v6.lookup(ip.to_bits(), 128)?;
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(prefix_len))
+                    .unwrap_or(0);
+                let network = IpAddr::V6(Ipv6Addr::from_bits(ip.to_bits() & mask));
+                Some(((network, prefix_len), verdict))
+            }
+        }
+    }
+
+    /// Returns a boolean indicating whether the most specific network containing an IP address
+    /// is denied.
+    #[inline]
+    pub fn is_bogon(&self, ip_address: IpAddr) -> bool {
+        self.lookup(ip_address) == Some(Verdict::Deny)
+    }
+
+    /// Returns the number of networks in the set.
+    pub fn len(&self) -> usize {
+        self.v4.len() + self.v6.len()
+    }
+
+    /// Returns a boolean indicating whether the set contains no networks.
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+}
+
+impl Extend<((IpAddr, u8), Verdict)> for BogonSet {
+    fn extend<I: IntoIterator<Item = ((IpAddr, u8), Verdict)>>(&mut self, networks: I) {
+        for ((network, prefix_len), verdict) in networks {
+            self.insert(network, prefix_len, verdict);
+        }
+    }
+}
+
+impl FromIterator<((IpAddr, u8), Verdict)> for BogonSet {
+    fn from_iter<I: IntoIterator<Item = ((IpAddr, u8), Verdict)>>(networks: I) -> Self {
+        let mut set = BogonSet::new();
+        set.extend(networks);
+        set
+    }
+}
@@ -0,0 +1,213 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{is_bogon, BogonSet, IpPolicy, Verdict};
+
+fn net(network: &str, prefix_len: u8) -> (IpAddr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+/// A naive linear implementation of longest-prefix match.
+#[derive(Default)]
+struct Reference(Vec<((IpAddr, u8), Verdict)>);
+
+impl Reference {
+    fn contains(network: (IpAddr, u8), ip_address: IpAddr) -> bool {
+        match (network, ip_address) {
+            ((IpAddr::V4(network), prefix_len), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(prefix_len))
+                    .unwrap_or(0);
+                network.to_bits() & mask == ip.to_bits() & mask
+            }
+            ((IpAddr::V6(network), prefix_len), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(prefix_len))
+                    .unwrap_or(0);
+                network.to_bits() & mask == ip.to_bits() & mask
+            }
+            _ => false,
+        }
+    }
+
+    fn same(a: (IpAddr, u8), b: (IpAddr, u8)) -> bool {
+        a.1 == b.1 && Reference::contains(a, b.0)
+    }
+
+    fn insert(&mut self, network: (IpAddr, u8), verdict: Verdict) -> Option<Verdict> {
+        let previous = self.remove(network);
+        self.0.push((network, verdict));
+        previous
+    }
+
+    fn remove(&mut self, network: (IpAddr, u8)) -> Option<Verdict> {
+        let index = self
+            .0
+            .iter()
+            .position(|&(n, _)| Reference::same(n, network))?;
+        Some(self.0.swap_remove(index).1)
+    }
+
+    fn lookup(&self, ip_address: IpAddr) -> Option<Verdict> {
+        self.0
+            .iter()
+            .filter(|&&(network, _)| Reference::contains(network, ip_address))
+            .max_by_key(|((_, prefix_len), _)| *prefix_len)
+            .map(|&(_, verdict)| verdict)
+    }
+}
+
+#[test]
+fn longest_prefix_wins() {
+    let mut set = BogonSet::new();
+    assert!(set.is_empty());
+    assert_eq!(set.insert(ip("10.0.0.0"), 8, Verdict::Deny), None);
+    assert_eq!(set.insert(ip("10.1.2.3"), 32, Verdict::Allow), None);
+    assert_eq!(set.insert(ip("10.1.0.0"), 16, Verdict::Deny), None);
+    assert_eq!(
+        set.insert(ip("10.1.255.255"), 16, Verdict::Allow),
+        Some(Verdict::Deny)
+    );
+    assert_eq!(set.len(), 3);
+
+    assert_eq!(set.lookup(ip("10.1.2.3")), Some(Verdict::Allow));
+    assert_eq!(set.lookup(ip("10.1.2.4")), Some(Verdict::Allow));
+    assert_eq!(set.lookup(ip("10.2.0.0")), Some(Verdict::Deny));
+    assert_eq!(set.lookup(ip("11.0.0.0")), None);
+    assert_eq!(
+        set.longest_match(ip("10.1.9.9")),
+        Some((net("10.1.0.0", 16), Verdict::Allow))
+    );
+
+    // Families don't mix, ::a00:0 shares the bits of 10.0.0.0.
+    assert_eq!(set.lookup(ip("::a00:0")), None);
+    assert_eq!(set.lookup(ip("a00::")), None);
+
+    assert_eq!(set.remove(ip("10.1.0.0"), 16), Some(Verdict::Allow));
+    assert_eq!(set.remove(ip("10.1.0.0"), 16), None);
+    assert_eq!(set.lookup(ip("10.1.2.4")), Some(Verdict::Deny));
+    assert_eq!(set.lookup(ip("10.1.2.3")), Some(Verdict::Allow));
+
+    assert_eq!(set.remove(ip("10.0.0.0"), 8), Some(Verdict::Deny));
+    assert_eq!(set.remove(ip("10.1.2.3"), 32), Some(Verdict::Allow));
+    assert!(set.is_empty());
+    assert_eq!(set, BogonSet::new());
+}
+
+#[test]
+fn default_routes() {
+    let set: BogonSet = [
+        (net("0.0.0.0", 0), Verdict::Deny),
+        (net("::", 0), Verdict::Allow),
+        (net("::1", 128), Verdict::Deny),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(set.lookup(ip("255.255.255.255")), Some(Verdict::Deny));
+    assert_eq!(set.lookup(ip("2606:4700::1111")), Some(Verdict::Allow));
+    assert_eq!(
+        set.longest_match(ip("::1")),
+        Some((net("::1", 128), Verdict::Deny))
+    );
+    assert_eq!(
+        set.longest_match(ip("::2")),
+        Some((net("::", 0), Verdict::Allow))
+    );
+}
+
+#[test]
+fn matches_reference() {
+    // Short prefixes around two hot spots, so networks overlap a lot.
+    let random_ip = || {
+        if rand::random() {
+            IpAddr::V4(Ipv4Addr::from_bits(
+                0x0a00_0000 | rand::random::<u32>() >> 8,
+            ))
+        } else {
+            IpAddr::V6(Ipv6Addr::from_bits(
+                0xfd00 << 112 | rand::random::<u128>() >> 104 << 96,
+            ))
+        }
+    };
+    let random_network = || (random_ip(), rand::random::<u8>() % 25 + 8);
+
+    for _ in 0..20 {
+        let mut set = BogonSet::new();
+        let mut reference = Reference::default();
+
+        for _ in 0..500 {
+            let network = random_network();
+            if rand::random::<u8>() < 64 {
+                let (ip, prefix_len) = network;
+                assert_eq!(set.remove(ip, prefix_len), reference.remove(network));
+            } else {
+                let verdict = if rand::random() {
+                    Verdict::Allow
+                } else {
+                    Verdict::Deny
+                };
+                let (ip, prefix_len) = network;
+                assert_eq!(
+                    set.insert(ip, prefix_len, verdict),
+                    reference.insert(network, verdict)
+                );
+            }
+        }
+        assert_eq!(set.len(), reference.0.len());
+
+        for _ in 0..2_000 {
+            let ip = random_ip();
+            assert_eq!(set.lookup(ip), reference.lookup(ip), "{ip}");
+        }
+    }
+}
+
+#[test]
+fn with_defaults_matches_is_bogon() {
+    let set = BogonSet::with_defaults();
+
+    for _ in 0..100_000 {
+        let v4 = IpAddr::V4(Ipv4Addr::from_bits(rand::random()));
+        let v6 = IpAddr::V6(Ipv6Addr::from_bits(rand::random()));
+        let global = IpAddr::V6(Ipv6Addr::from_bits(
+            (rand::random::<u128>() >> 3) | (1 << 125),
+        ));
+
+        for ip in [v4, v6, global] {
+            assert_eq!(set.is_bogon(ip), is_bogon(ip), "{ip}");
+        }
+    }
+
+    let edges = [
+        "192.0.0.8",
+        "192.0.0.9",
+        "192.0.0.10",
+        "169.254.169.254",
+        "255.255.255.255",
+        "192.175.48.1",
+        "2001:1::1",
+        "2001:1::2",
+        "2001:3::1",
+        "2001:4:112::1",
+        "2001:4:113::1",
+        "2001:db8::1",
+        "3fff::1",
+        "fd00:ec2::254",
+        "64:ff9b::1",
+    ];
+    for ip in edges {
+        let ip: IpAddr = ip.parse().unwrap();
+        assert_eq!(set.is_bogon(ip), is_bogon(ip), "{ip}");
+        assert_eq!(set.is_allowed(ip), !is_bogon(ip), "{ip}");
+    }
+}
+
+#[test]
+#[should_panic = "IPv6 prefix length must be at most 128"]
+fn prefix_too_long() {
+    BogonSet::new().insert(ip("::"), 129, Verdict::Deny);
+}
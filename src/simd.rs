@@ -0,0 +1,152 @@
+//! Checking an IPv6 address against eight networks at once with the portable vectors of `wide`, for
+//! the `simd` feature.
+//!
+//! The networks are laid out in lanes of `u32x8`, one per network, and an IP address is broadcast
+//! to every lane, so a single comparison checks eight networks with AVX2, or four with SSE2 or
+//! NEON. Lanes past the end of a table hold networks that never match. IPv4 addresses don't need
+//! the vectors: the few networks of the /8s their first octet doesn't settle are checked at once
+//! without them.
+
+use core::net::Ipv6Addr;
+
+use wide::u32x8;
+
+use crate::network::SixteenByteNetwork;
+
+/// The number of networks, or ranges, each vector holds.
+const LANES: usize = 8;
+
+/// IPv6 networks, eight to a group of four vectors, one per 32-bit word of the address starting
+/// with the most significant.
+pub(crate) struct WideLanes<const K: usize> {
+    networks: [[[u32; LANES]; 4]; K],
+    masks: [[[u32; LANES]; 4]; K],
+}
+
+/// Inclusive ranges of the top 32 bits of IPv6 addresses, eight to a vector.
+pub(crate) struct RangeLanes<const K: usize> {
+    firsts: [[u32; LANES]; K],
+    lasts: [[u32; LANES]; K],
+}
+
+/// Lays out IPv6 networks in `K` groups of vectors.
+///
+/// # Panics
+///
+/// Panics if the networks don't fit.
+pub(crate) const fn wide_lanes<const N: usize, const K: usize>(
+    networks: [(u128, u8); N],
+) -> WideLanes<K> {
+    assert!(N <= K * LANES, "The networks don't fit in the vectors");
+    let mut lanes = WideLanes {
+        networks: [[[u32::MAX; LANES]; 4]; K],
+        masks: [[[0; LANES]; 4]; K],
+    };
+    let mut i = 0;
+    while i < N {
+        let (network, prefix) = networks[i];
+        let network = SixteenByteNetwork::new(network, prefix);
+        let mut word = 0;
+        while word < 4 {
+            let shift = 96 - 32 * word;
+            lanes.networks[i / LANES][word][i % LANES] = (network.network() >> shift) as u32;
+            lanes.masks[i / LANES][word][i % LANES] = (network.mask() >> shift) as u32;
+            word += 1;
+        }
+        i += 1;
+    }
+    lanes
+}
+
+/// Lays out inclusive ranges of the top 32 bits of IPv6 addresses in `K` vectors.
+///
+/// # Panics
+///
+/// Panics if the ranges don't fit.
+pub(crate) const fn range_lanes<const N: usize, const K: usize>(
+    ranges: [(u32, u32); N],
+) -> RangeLanes<K> {
+    assert!(N <= K * LANES, "The ranges don't fit in the vectors");
+    // A range from `u32::MAX` to 0 is empty.
+    let mut lanes = RangeLanes {
+        firsts: [[u32::MAX; LANES]; K],
+        lasts: [[0; LANES]; K],
+    };
+    let mut i = 0;
+    while i < N {
+        let (first, last) = ranges[i];
+        lanes.firsts[i / LANES][i % LANES] = first;
+        lanes.lasts[i / LANES][i % LANES] = last;
+        i += 1;
+    }
+    lanes
+}
+
+impl<const K: usize> WideLanes<K> {
+    /// Returns a boolean indicating whether any of the networks contains the IPv6 address.
+    #[inline]
+    pub(crate) fn contains(&self, ip_address: Ipv6Addr) -> bool {
+        let bits = ip_address.to_bits();
+        let words = [0, 1, 2, 3].map(|word| u32x8::splat((bits >> (96 - 32 * word)) as u32));
+        let mut hits = u32x8::ZERO;
+        for (networks, masks) in self.networks.iter().zip(&self.masks) {
+            hits |= words.iter().zip(networks).zip(masks).fold(
+                u32x8::MAX,
+                |hit, ((&word, &network), &mask)| {
+                    hit & (word & u32x8::new(mask)).cmp_eq(u32x8::new(network))
+                },
+            );
+        }
+        hits.any()
+    }
+}
+
+impl<const K: usize> RangeLanes<K> {
+    /// Returns a boolean indicating whether any of the ranges contains the top 32 bits.
+    #[inline]
+    pub(crate) fn contains(&self, top: u32) -> bool {
+        let top = u32x8::splat(top);
+        // The lanes of a range are cleared if it contains the address, so only a miss in every
+        // range keeps every lane set.
+        let mut misses = u32x8::MAX;
+        for (&firsts, &lasts) in self.firsts.iter().zip(&self.lasts) {
+            misses &= u32x8::new(firsts).cmp_gt(top) | top.cmp_gt(u32x8::new(lasts));
+        }
+        !misses.all()
+    }
+}
+
+/// The number of vectors a table of `n` networks or ranges takes.
+const fn vectors(n: usize) -> usize {
+    n.div_ceil(LANES)
+}
+
+// The same tables as the scalar scans in `lib.rs` read, laid out in vectors.
+static V6_GLOBAL_BOGON_NETWORKS: WideLanes<
+    { vectors(bogon_data::V6_GLOBAL_BOGON_NETWORKS.len()) },
+> = wide_lanes(bogon_data::V6_GLOBAL_BOGON_NETWORKS);
+static V6_BOGON_EXCEPTIONS: WideLanes<{ vectors(bogon_data::V6_BOGON_EXCEPTIONS.len()) }> =
+    wide_lanes(bogon_data::V6_BOGON_EXCEPTIONS);
+static V6_ALLOCATION_RANGES: RangeLanes<{ vectors(bogon_data::V6_ALLOCATION_RANGES.len()) }> =
+    range_lanes(bogon_data::V6_ALLOCATION_RANGES);
+
+/// Returns a boolean indicating whether an IPv6 address inside of 2000::/3 is bogus: inside of one
+/// of the special-purpose networks and none of their exceptions, or outside of the allocations.
+#[inline]
+pub(crate) fn is_mixed_bogon_v6(ip_address: Ipv6Addr) -> bool {
+    if V6_GLOBAL_BOGON_NETWORKS.contains(ip_address) {
+        return !V6_BOGON_EXCEPTIONS.contains(ip_address);
+    }
+
+    !is_allocated_v6(ip_address)
+}
+
+/// Returns a boolean indicating whether an IPv6 address inside of 2000::/3 is inside of one of the
+/// RIR allocations, comparing the top 32 bits against every range at once.
+#[inline]
+fn is_allocated_v6(ip_address: Ipv6Addr) -> bool {
+    V6_ALLOCATION_RANGES.contains((ip_address.to_bits() >> 96) as u32)
+        || crate::ipv6_unicast_address_allocations::V6_WIDE_ALLOCATIONS
+            .iter()
+            .any(|network| network.contains_v6(ip_address))
+}
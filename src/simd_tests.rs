@@ -0,0 +1,69 @@
+use core::net::Ipv6Addr;
+use std::vec::Vec;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    ipv6_unicast_address_allocations::V6_ALLOCATION_RANGES, is_mixed_bogon_v6, simd,
+    V6_BOGON_EXCEPTIONS, V6_GLOBAL_BOGON_NETWORKS,
+};
+
+fn assert_same_v6(ip: Ipv6Addr) {
+    assert_eq!(simd::is_mixed_bogon_v6(ip), is_mixed_bogon_v6(ip), "{ip}");
+}
+
+#[test]
+fn random_v6_matches_the_scan() {
+    let mut rng = StdRng::seed_from_u64(77);
+    for _ in 0..1 << 20 {
+        // Inside of 2000::/3, like the addresses the first hextet leaves to the scan.
+        let bits: u128 = rng.gen();
+        assert_same_v6(Ipv6Addr::from_bits(bits >> 3 | 1 << 125));
+    }
+
+    // Random addresses near the bogus networks and exceptions, which are too small to hit by
+    // chance.
+    for network in V6_GLOBAL_BOGON_NETWORKS.iter().chain(&V6_BOGON_EXCEPTIONS) {
+        let host = u128::MAX
+            .checked_shr(u32::from(network.prefix()))
+            .unwrap_or(0);
+        for _ in 0..1 << 12 {
+            let bits: u128 = rng.gen();
+            assert_same_v6(Ipv6Addr::from_bits(network.network() | bits & host));
+            assert_same_v6(Ipv6Addr::from_bits(
+                (network.network() | host).wrapping_add(1 + (bits & 0xff)),
+            ));
+            assert_same_v6(Ipv6Addr::from_bits(
+                network.network().wrapping_sub(1 + (bits & 0xff)),
+            ));
+        }
+    }
+}
+
+#[test]
+fn allocation_range_bounds_match_the_scan() {
+    let tops = V6_ALLOCATION_RANGES
+        .iter()
+        .flat_map(|&(first, last)| [first, last])
+        .flat_map(|edge| [edge.wrapping_sub(1), edge, edge.wrapping_add(1)])
+        .chain([0x2000_0000, 0x3fff_ffff])
+        .collect::<Vec<_>>();
+    for top in tops {
+        let ip = Ipv6Addr::from_bits(u128::from(top) << 96 | 1);
+        assert_same_v6(ip);
+    }
+}
+
+#[test]
+fn padding_never_matches() {
+    let lanes: simd::WideLanes<1> = simd::wide_lanes([(0x2001_0db8 << 96, 32)]);
+    assert!(lanes.contains(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    assert!(!lanes.contains(Ipv6Addr::from_bits(u128::MAX)));
+    assert!(!lanes.contains(Ipv6Addr::UNSPECIFIED));
+
+    let lanes: simd::RangeLanes<1> = simd::range_lanes([(5, 7)]);
+    assert!(lanes.contains(5) && lanes.contains(7));
+    for top in [0, 4, 8, u32::MAX] {
+        assert!(!lanes.contains(top), "{top}");
+    }
+}
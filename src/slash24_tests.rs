@@ -0,0 +1,52 @@
+use core::net::Ipv4Addr;
+
+use crate::{is_bogon_v4, v4_bogon_slash24_bitmap, BitmapChecker};
+
+#[test]
+fn every_slash24_matches_is_bogon_v4() {
+    let checker = BitmapChecker::new();
+    let bitmap = v4_bogon_slash24_bitmap();
+    for slash24 in 0..1 << 24 {
+        let bit = bitmap[slash24 as usize / 8] >> (slash24 % 8) & 1 == 1;
+        if checker.mixed().binary_search(&slash24).is_ok() {
+            // Some of the addresses are bogus.
+            assert!(bit, "{slash24:#x}");
+            for host in 0..=255 {
+                let ip = Ipv4Addr::from_bits(slash24 << 8 | host);
+                assert_eq!(checker.is_bogon(ip), is_bogon_v4(ip), "{ip}");
+            }
+        } else {
+            for host in [0, 1, 128, 255] {
+                let ip = Ipv4Addr::from_bits(slash24 << 8 | host);
+                assert_eq!(bit, is_bogon_v4(ip), "{ip}");
+                assert_eq!(checker.is_bogon(ip), bit, "{ip}");
+            }
+        }
+    }
+}
+
+#[test]
+fn broadcast_and_exceptions() {
+    let checker = BitmapChecker::default();
+    assert!(checker.is_bogon(Ipv4Addr::BROADCAST));
+    assert!(!checker.is_bogon(Ipv4Addr::new(192, 0, 0, 9)));
+    assert!(!checker.is_bogon(Ipv4Addr::new(192, 0, 0, 10)));
+    assert!(checker.is_bogon(Ipv4Addr::new(192, 0, 0, 8)));
+    assert!(checker.is_bogon_bits(0x7f00_0001));
+    assert!(checker.mixed().contains(&0xc0_0000));
+    assert!(checker.mixed().contains(&0xff_ffff));
+}
+
+#[test]
+fn bitmap_is_built_once() {
+    assert!(core::ptr::eq(
+        v4_bogon_slash24_bitmap(),
+        v4_bogon_slash24_bitmap()
+    ));
+    let bogus = v4_bogon_slash24_bitmap()
+        .iter()
+        .map(|byte| byte.count_ones())
+        .sum::<u32>();
+    // 0.0.0.0/8, 10.0.0.0/8, 127.0.0.0/8, and 224.0.0.0/4 at least.
+    assert!(bogus > 3 << 16 | 1 << 20, "{bogus}");
+}
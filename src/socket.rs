@@ -0,0 +1,159 @@
+//! Checking IP addresses in strings with a port or an IPv6 zone, as they appear in logs and
+//! configuration.
+
+use core::{
+    fmt,
+    net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{is_bogon, is_bogon_v6};
+
+/// The error returned when a socket address string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The host is not an IP address.
+    InvalidAddress(AddrParseError),
+    /// The port is not a number from 0 to 65535.
+    InvalidPort,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidAddress(error) => write!(f, "invalid host: {error}"),
+            ParseError::InvalidPort => f.write_str("invalid port, expected a number up to 65535"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidAddress(error) => Some(error),
+            ParseError::InvalidPort => None,
+        }
+    }
+}
+
+impl From<AddrParseError> for ParseError {
+    fn from(error: AddrParseError) -> Self {
+        ParseError::InvalidAddress(error)
+    }
+}
+
+/// Returns an IPv6 address with an optional `%zone` suffix, such as `fe80::1%eth0` or `fe80::1%25`,
+/// without the zone. The zone is a non-empty run of printable ASCII characters other than `%`.
+pub(crate) fn parse_zoned_v6(s: &str) -> Result<Ipv6Addr, AddrParseError> {
+    if let Some((ip, zone)) = s.split_once('%') {
+        let valid = !zone.is_empty()
+            && zone
+                .bytes()
+                .all(|byte| byte.is_ascii_graphic() && byte != b'%');
+        if let Some(ip) = ip.parse().ok().filter(|_| valid) {
+            return Ok(ip);
+        }
+    }
+    // A string with a `%` never parses, so a malformed zone reports the error of the whole string.
+    s.parse()
+}
+
+/// Returns the IP address of a socket address string: `a.b.c.d:port`, `[v6]:port`, or a bare IP
+/// address.
+///
+/// An IPv6 address and a port can only be told apart with brackets, so a string without them that
+/// parses as an IP address, with or without a zone, is a bare address: `::1:80` is the IPv6 address
+/// `::1:80`, not `::1` with the port 80, and the zone of `fe80::1%eth0:1` is `eth0:1`. Only IPv4
+/// hosts can go without brackets.
+pub(crate) fn parse_host(s: &str) -> Result<IpAddr, ParseError> {
+    let error = match s.parse::<IpAddr>() {
+        Ok(ip) => return Ok(ip),
+        Err(error) => error,
+    };
+    if let Ok(ip) = parse_zoned_v6(s) {
+        return Ok(IpAddr::V6(ip));
+    }
+    let (host, port) = if let Some(rest) = s.strip_prefix('[') {
+        let (host, rest) = rest.split_once(']').ok_or(error.clone())?;
+        let host = IpAddr::V6(parse_zoned_v6(host)?);
+        if rest.is_empty() {
+            return Ok(host);
+        }
+        (host, rest.strip_prefix(':').ok_or(error)?)
+    } else {
+        let (host, port) = s.split_once(':').ok_or(error)?;
+        (IpAddr::V4(host.parse::<Ipv4Addr>()?), port)
+    };
+    // `u16::from_str` takes a leading `+`, which no port has.
+    if port.is_empty() || !port.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseError::InvalidPort);
+    }
+    port.parse::<u16>().map_err(|_| ParseError::InvalidPort)?;
+    Ok(host)
+}
+
+/// Returns a boolean indicating whether the host of a socket address string is bogus.
+///
+/// Accepts `a.b.c.d:port`, `[v6]:port`, and bare IP addresses, checking only the IP address. The
+/// IPv6 address can have a zone, like with [`is_bogon_str_zoned`]. An
+/// IPv6 address followed by a port needs brackets: a string without them that parses as an IP
+/// address is a bare address, so `::1:80` is the IPv6 address `::1:80`. Unlike
+/// [`is_bogon_str`](crate::is_bogon_str), a port is allowed, and an invalid port is reported with
+/// [`ParseError::InvalidPort`].
+///
+/// Returns `Ok(true)` if the IP address is bogus.
+/// Returns `Ok(false)` if the IP address is good.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{is_bogon_socket_str, ParseError};
+///
+/// assert_eq!(is_bogon_socket_str("127.0.0.1:8080"), Ok(true));
+/// assert_eq!(is_bogon_socket_str("8.8.8.8:53"), Ok(false));
+/// assert_eq!(is_bogon_socket_str("[::1]:443"), Ok(true));
+/// assert_eq!(is_bogon_socket_str("[fe80::1%eth0]:22"), Ok(true));
+/// assert_eq!(is_bogon_socket_str("[2606:4700:4700::1111]:443"), Ok(false));
+/// assert_eq!(is_bogon_socket_str("::1"), Ok(true));
+///
+/// assert_eq!(is_bogon_socket_str("8.8.8.8:65536"), Err(ParseError::InvalidPort));
+/// assert!(matches!(is_bogon_socket_str("foo:80"), Err(ParseError::InvalidAddress(_))));
+/// ```
+#[inline]
+pub fn is_bogon_socket_str(s: &str) -> Result<bool, ParseError> {
+    parse_host(s).map(is_bogon)
+}
+
+/// Returns a boolean indicating whether an IP address is bogus, like
+/// [`is_bogon_str`](crate::is_bogon_str), but also accepting an IPv6 address with a zone such as
+/// `fe80::1%eth0` or `fe80::1%25`. The zone is ignored: the verdict is for the address itself.
+///
+/// Returns an error if the IP address is invalid, if the zone is empty, or if the zone follows an
+/// IPv4 address, which has none.
+/// Returns `Ok(true)` if the IP address is bogus.
+/// Returns `Ok(false)` if the IP address is good.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_str_zoned;
+///
+/// assert_eq!(is_bogon_str_zoned("fe80::1%eth0"), Ok(true));
+/// assert_eq!(is_bogon_str_zoned("fe80::1%25"), Ok(true));
+/// assert_eq!(is_bogon_str_zoned("2606:4700:4700::1111%eth0"), Ok(false));
+/// assert_eq!(is_bogon_str_zoned("8.8.8.8"), Ok(false));
+///
+/// assert!(is_bogon_str_zoned("8.8.8.8%eth0").is_err());
+/// assert!(is_bogon_str_zoned("fe80::1%").is_err());
+/// ```
+#[inline]
+pub fn is_bogon_str_zoned(ip_address: impl AsRef<str>) -> Result<bool, AddrParseError> {
+    let ip_address = ip_address.as_ref();
+    match ip_address.parse() {
+        Ok(ip) => Ok(is_bogon(ip)),
+        Err(error) => parse_zoned_v6(ip_address)
+            .map(is_bogon_v6)
+            .map_err(|_| error),
+    }
+}
@@ -0,0 +1,188 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    is_bogon, is_bogon_socket_str, is_bogon_str, is_bogon_str_zoned,
+    socket::{parse_host, parse_zoned_v6},
+    ParseError,
+};
+
+#[test]
+fn host_and_port() {
+    assert_eq!(is_bogon_socket_str("127.0.0.1:8080"), Ok(true));
+    assert_eq!(is_bogon_socket_str("10.1.2.3:0"), Ok(true));
+    assert_eq!(is_bogon_socket_str("8.8.8.8:65535"), Ok(false));
+    assert_eq!(is_bogon_socket_str("[::1]:443"), Ok(true));
+    assert_eq!(is_bogon_socket_str("[fe80::1]:22"), Ok(true));
+    assert_eq!(is_bogon_socket_str("[2606:4700:4700::1111]:53"), Ok(false));
+}
+
+#[test]
+fn bare_addresses() {
+    for bare in ["127.0.0.1", "8.8.8.8", "::1", "::", "2606:4700:4700::1111"] {
+        assert_eq!(parse_host(bare), Ok(bare.parse().unwrap()), "{bare}");
+        assert_eq!(
+            is_bogon_socket_str(bare).ok(),
+            is_bogon_str(bare).ok(),
+            "{bare}"
+        );
+    }
+    assert_eq!(is_bogon_socket_str("[::1]"), Ok(true));
+    assert_eq!(is_bogon_socket_str("[2606:4700:4700::1111]"), Ok(false));
+}
+
+#[test]
+fn unbracketed_ipv6_is_never_host_and_port() {
+    // `::1:80` is an IPv6 address of its own, not `::1` with the port 80.
+    assert_eq!(
+        parse_host("::1:80"),
+        Ok(IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 1, 0x80)))
+    );
+    assert_eq!(
+        parse_host("2606:4700:4700::1111:53"),
+        Ok(IpAddr::V6(Ipv6Addr::new(
+            0x2606, 0x4700, 0x4700, 0, 0, 0, 0x1111, 0x53
+        )))
+    );
+    assert_eq!(parse_host("[::1]:80"), Ok(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    // The zone of a bare address runs to the end, as interface names can hold colons.
+    assert_eq!(
+        parse_host("fe80::1%eth0:22"),
+        Ok(IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)))
+    );
+    assert!(matches!(
+        is_bogon_socket_str("::1::80"),
+        Err(ParseError::InvalidAddress(_))
+    ));
+}
+
+#[test]
+fn invalid_ports() {
+    for input in [
+        "8.8.8.8:",
+        "8.8.8.8:65536",
+        "8.8.8.8:+80",
+        "8.8.8.8:-1",
+        "8.8.8.8:80:80",
+        "8.8.8.8: 80",
+        "[::1]:",
+        "[::1]:http",
+        "[::1]:99999",
+    ] {
+        assert_eq!(
+            is_bogon_socket_str(input),
+            Err(ParseError::InvalidPort),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn invalid_addresses() {
+    for input in [
+        "",
+        "foo",
+        "foo:80",
+        ":80",
+        "256.0.0.1:80",
+        "[::1",
+        "[::1]80",
+        "[127.0.0.1]:80",
+        "[]:80",
+        "[::1]]:80",
+    ] {
+        assert!(
+            matches!(
+                is_bogon_socket_str(input),
+                Err(ParseError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn is_bogon_str_still_rejects_ports() {
+    assert!(is_bogon_str("127.0.0.1:8080").is_err());
+    assert!(is_bogon_str("[::1]:443").is_err());
+}
+
+#[test]
+fn random_socket_addresses() {
+    let mut rng = StdRng::seed_from_u64(87);
+    for _ in 0..100_000 {
+        let ip = if rng.gen() {
+            IpAddr::V4(Ipv4Addr::from_bits(rng.gen()))
+        } else {
+            IpAddr::V6(Ipv6Addr::from_bits(rng.gen()))
+        };
+        let socket = SocketAddr::new(ip, rng.gen());
+        assert_eq!(
+            is_bogon_socket_str(&socket.to_string()),
+            Ok(is_bogon(ip)),
+            "{socket}"
+        );
+    }
+}
+
+#[test]
+fn zones() {
+    let link_local = Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1);
+    for zoned in ["fe80::1%eth0", "fe80::1%25", "fe80::1%1", "fe80::1%en0.100"] {
+        assert_eq!(parse_zoned_v6(zoned), Ok(link_local), "{zoned}");
+        assert_eq!(is_bogon_str_zoned(zoned), Ok(true), "{zoned}");
+        // `is_bogon_str` stays strict.
+        assert!(is_bogon_str(zoned).is_err(), "{zoned}");
+    }
+    assert_eq!(is_bogon_str_zoned("2606:4700:4700::1111%eth0"), Ok(false));
+    assert_eq!(is_bogon_str_zoned("fe80::1"), Ok(true));
+    assert_eq!(is_bogon_str_zoned("8.8.8.8"), Ok(false));
+}
+
+#[test]
+fn malformed_zones() {
+    for input in [
+        "fe80::1%",
+        "%eth0",
+        "fe80::1%%",
+        "fe80::1%eth0%1",
+        "fe80::1%eth 0",
+        "8.8.8.8%eth0",
+        "8.8.8.8%",
+        "fe80::g%eth0",
+    ] {
+        assert!(is_bogon_str_zoned(input).is_err(), "{input}");
+        assert!(parse_zoned_v6(input).is_err(), "{input}");
+        assert!(
+            matches!(
+                is_bogon_socket_str(input),
+                Err(ParseError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn zones_in_brackets() {
+    assert_eq!(is_bogon_socket_str("[fe80::1%eth0]:22"), Ok(true));
+    assert_eq!(is_bogon_socket_str("[fe80::1%25]"), Ok(true));
+    assert_eq!(
+        is_bogon_socket_str("[2606:4700:4700::1111%1]:53"),
+        Ok(false)
+    );
+    assert_eq!(
+        is_bogon_socket_str("[fe80::1%eth0]:http"),
+        Err(ParseError::InvalidPort)
+    );
+    for input in ["[fe80::1%]:22", "[8.8.8.8%eth0]:53"] {
+        assert!(
+            matches!(
+                is_bogon_socket_str(input),
+                Err(ParseError::InvalidAddress(_))
+            ),
+            "{input}"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use core::net::{IpAddr, Ipv6Addr};
+
+use crate::ipv6_special_registry::{V6_SPECIAL_REGISTRY, V6_SPECIAL_REGISTRY_NETWORKS};
+
+/// A block of the IANA IPv6 special-purpose address registry.
+///
+/// Returned by [`special_purpose_v6`]. The flags are `None` where the registry doesn't give a
+/// value, like the global reachability of Teredo, or any flag of a deprecated block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SpecialPurposeBlock {
+    /// The network of the block.
+    pub network: (IpAddr, u8),
+    /// The name of the block in the registry, for example `"Discard-Only Address Block"`.
+    pub name: &'static str,
+    /// Whether an address from the block is valid as a source address.
+    pub source: Option<bool>,
+    /// Whether an address from the block is valid as a destination address.
+    pub destination: Option<bool>,
+    /// Whether routers may forward packets with an address from the block.
+    pub forwardable: Option<bool>,
+    /// Whether an address from the block is reachable from the public internet.
+    pub globally_reachable: Option<bool>,
+    /// Whether the block has been deprecated.
+    pub deprecated: bool,
+}
+
+impl SpecialPurposeBlock {
+    pub(crate) const fn new(
+        network: u128,
+        prefix_len: u8,
+        name: &'static str,
+        [source, destination, forwardable, globally_reachable]: [Option<bool>; 4],
+        deprecated: bool,
+    ) -> Self {
+        SpecialPurposeBlock {
+            network: (IpAddr::V6(Ipv6Addr::from_bits(network)), prefix_len),
+            name,
+            source,
+            destination,
+            forwardable,
+            globally_reachable,
+            deprecated,
+        }
+    }
+}
+
+/// Returns the most specific block of the IANA IPv6 special-purpose address registry containing an
+/// IP address, or `None` if the IP address isn't special-purpose.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::special_purpose_v6;
+///
+/// let discard = special_purpose_v6("100::1".parse().unwrap()).unwrap();
+/// assert_eq!(discard.name, "Discard-Only Address Block");
+/// assert_eq!(discard.forwardable, Some(true));
+/// assert_eq!(discard.globally_reachable, Some(false));
+///
+/// let amt = special_purpose_v6("2001:3::1".parse().unwrap()).unwrap();
+/// assert_eq!(amt.globally_reachable, Some(true));
+///
+/// assert_eq!(special_purpose_v6("2606:4700::1111".parse().unwrap()), None);
+/// ```
+pub fn special_purpose_v6(ip_address: Ipv6Addr) -> Option<SpecialPurposeBlock> {
+    // The blocks are sorted from the most to the least specific.
+    V6_SPECIAL_REGISTRY_NETWORKS
+        .iter()
+        .zip(V6_SPECIAL_REGISTRY)
+        .find(|(network, _)| network.contains_v6(ip_address))
+        .map(|(_, block)| block)
+}
@@ -0,0 +1,124 @@
+use core::net::{IpAddr, Ipv6Addr};
+
+use crate::{classify_v6, is_bogon_v6, special_purpose_v6, BogonKind};
+
+fn block(ip: &str) -> crate::SpecialPurposeBlock {
+    special_purpose_v6(ip.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn amt_is_reachable() {
+    for ip in [
+        "2001:3::",
+        "2001:3::1",
+        "2001:3:ffff:ffff:ffff:ffff:ffff:ffff",
+    ] {
+        let amt = block(ip);
+        assert_eq!(amt.name, "AMT");
+        assert_eq!(amt.network, ("2001:3::".parse().unwrap(), 32));
+        assert_eq!(amt.globally_reachable, Some(true));
+        assert!(!is_bogon_v6(ip.parse().unwrap()), "{ip}");
+        assert_eq!(classify_v6(ip.parse().unwrap()), None, "{ip}");
+    }
+
+    // Just outside of 2001:3::/32 is the rest of the IETF Protocol Assignments.
+    let outside = block("2001:4::1");
+    assert_eq!(outside.name, "IETF Protocol Assignments");
+    assert_eq!(outside.globally_reachable, Some(false));
+    assert!(is_bogon_v6("2001:4::1".parse().unwrap()));
+}
+
+#[test]
+fn discard_only() {
+    for ip in ["100::", "100::1", "100::ffff:ffff:ffff:ffff"] {
+        let discard = block(ip);
+        assert_eq!(discard.name, "Discard-Only Address Block");
+        assert_eq!(discard.network, ("100::".parse().unwrap(), 64));
+        assert_eq!(
+            (
+                discard.source,
+                discard.destination,
+                discard.forwardable,
+                discard.globally_reachable
+            ),
+            (Some(true), Some(true), Some(true), Some(false))
+        );
+        assert!(!discard.deprecated);
+        assert!(is_bogon_v6(ip.parse().unwrap()), "{ip}");
+        assert_eq!(
+            classify_v6(ip.parse().unwrap()),
+            Some(BogonKind::DiscardOnly),
+            "{ip}"
+        );
+    }
+    assert_eq!(special_purpose_v6("100:0:0:1::".parse().unwrap()), None);
+}
+
+#[test]
+fn most_specific_block() {
+    assert_eq!(block("2001::1").name, "TEREDO");
+    assert_eq!(block("2001::1").globally_reachable, None);
+    assert_eq!(block("2001:1::1").name, "Port Control Protocol Anycast");
+    assert_eq!(block("2001:1::3").name, "IETF Protocol Assignments");
+    assert_eq!(block("::").name, "Unspecified Address");
+    assert_eq!(block("::1").name, "Loopback Address");
+}
+
+#[test]
+fn deprecated_blocks() {
+    let orchid = block("2001:10::1");
+    assert_eq!(orchid.name, "Deprecated (previously ORCHID)");
+    assert!(orchid.deprecated);
+    assert_eq!(orchid.globally_reachable, None);
+    assert_eq!(
+        classify_v6("2001:10::1".parse().unwrap()),
+        Some(BogonKind::ProtocolAssignments)
+    );
+}
+
+#[test]
+fn globally_reachable_blocks_are_not_bogus() {
+    // Except for ORCHIDv2, which is never routed, and everything outside of 2000::/3.
+    let global_unicast = |ip: Ipv6Addr| ip.segments()[0] & 0xe000 == 0x2000;
+    for (network, block) in crate::ipv6_special_registry::V6_SPECIAL_REGISTRY_NETWORKS
+        .iter()
+        .zip(crate::ipv6_special_registry::V6_SPECIAL_REGISTRY)
+    {
+        let IpAddr::V6(ip) = network.to_v6().0 else {
+            unreachable!()
+        };
+        if block.globally_reachable == Some(true) && global_unicast(ip) && block.name != "ORCHIDv2"
+        {
+            assert!(!is_bogon_v6(ip), "{}", block.name);
+        }
+    }
+    assert!(is_bogon_v6("2001:20::1".parse().unwrap()));
+    assert!(is_bogon_v6("64:ff9b::808:808".parse().unwrap()));
+
+    // Drone Remote ID Protocol Entity Tags are globally reachable.
+    assert!(!is_bogon_v6("2001:30::1".parse().unwrap()));
+}
+
+#[test]
+fn blocks_outside_global_unicast_are_bogus_networks() {
+    // Every block is classified by its name, none is left to the reserved fallback.
+    let global_unicast = |ip: Ipv6Addr| ip.segments()[0] & 0xe000 == 0x2000;
+    for (network, block) in crate::ipv6_special_registry::V6_SPECIAL_REGISTRY_NETWORKS
+        .iter()
+        .zip(crate::ipv6_special_registry::V6_SPECIAL_REGISTRY)
+    {
+        let (IpAddr::V6(ip), prefix_len) = network.to_v6() else {
+            unreachable!()
+        };
+        if global_unicast(ip) {
+            continue;
+        }
+        let kind = classify_v6(ip).unwrap();
+        assert_ne!(kind, BogonKind::Reserved, "{}", block.name);
+        assert!(
+            crate::networks_for(kind).any(|network| network == (IpAddr::V6(ip), prefix_len)),
+            "{}",
+            block.name
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use core::{fmt, net::IpAddr};
+
+use crate::{BogonKind, DefaultBogonPolicy, IpPolicy};
+
+/// A breakdown of a batch of IP addresses by classification.
+///
+/// Returned by [`summarize`] and [`summarize_with`]. Summaries of separate shards can be combined with
+/// [`Summary::merge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct Summary {
+    /// The number of IP addresses summarized.
+    pub total: usize,
+    /// The number of bogus IP addresses summarized, or the number rejected by the policy for
+    /// [`summarize_with`].
+    pub bogons: usize,
+    counts: [usize; BogonKind::ALL.len()],
+}
+
+impl Summary {
+    /// Returns an empty summary.
+    pub const fn new() -> Self {
+        Summary {
+            total: 0,
+            bogons: 0,
+            counts: [0; BogonKind::ALL.len()],
+        }
+    }
+
+    /// Adds a single IP address to the summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::{BogonKind, Summary};
+    ///
+    /// let mut summary = Summary::new();
+    /// summary.add("10.0.0.1".parse().unwrap());
+    /// assert_eq!(summary.count(BogonKind::PrivateUse), 1);
+    /// ```
+    #[inline]
+    pub fn add(&mut self, ip_address: IpAddr) {
+        self.add_with(ip_address, &DefaultBogonPolicy);
+    }
+
+    /// Adds a single IP address to the summary, judged by an [`IpPolicy`].
+    #[inline]
+    pub fn add_with<P: IpPolicy + ?Sized>(&mut self, ip_address: IpAddr, policy: &P) {
+        self.total += 1;
+        if let Some(kind) = policy.classify(ip_address) {
+            self.counts[kind as usize] += 1;
+        }
+        if !policy.is_allowed(ip_address) {
+            self.bogons += 1;
+        }
+    }
+
+    /// Returns the number of IP addresses with a classification.
+    #[inline]
+    pub const fn count(&self, kind: BogonKind) -> usize {
+        self.counts[kind as usize]
+    }
+
+    /// Returns the number of IP addresses without a classification.
+    #[inline]
+    pub fn unclassified(&self) -> usize {
+        self.total - self.counts.iter().sum::<usize>()
+    }
+
+    /// Adds the counts of another summary to this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::summarize;
+    ///
+    /// let mut summary = summarize(["10.0.0.1".parse().unwrap()]);
+    /// summary.merge(summarize(["8.8.8.8".parse().unwrap()]));
+    /// assert_eq!(summary.total, 2);
+    /// assert_eq!(summary.bogons, 1);
+    /// ```
+    pub fn merge(&mut self, other: Summary) {
+        self.total += other.total;
+        self.bogons += other.bogons;
+        for (count, other) in self.counts.iter_mut().zip(other.counts) {
+            *count += other;
+        }
+    }
+}
+
+/// Prints one row per classification that was seen, followed by the unclassified addresses.
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const WIDTH: usize = 28;
+
+        writeln!(f, "{:<WIDTH$}{:>10}", "total", self.total)?;
+        writeln!(f, "{:<WIDTH$}{:>10}", "bogons", self.bogons)?;
+        for kind in BogonKind::ALL {
+            let count = self.count(kind);
+            if count > 0 {
+                writeln!(
+                    f,
+                    "  {:<w$}{:>10}",
+                    kind.description(),
+                    count,
+                    w = WIDTH - 2
+                )?;
+            }
+        }
+        write!(
+            f,
+            "  {:<w$}{:>10}",
+            "unclassified",
+            self.unclassified(),
+            w = WIDTH - 2
+        )
+    }
+}
+
+/// Counts a batch of IP addresses by classification.
+///
+/// This does not allocate, so it can be used on streams of any size.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{summarize, BogonKind};
+///
+/// let summary = summarize(["10.0.0.1", "127.0.0.1", "8.8.8.8"].map(|ip| ip.parse().unwrap()));
+/// assert_eq!(summary.total, 3);
+/// assert_eq!(summary.bogons, 2);
+/// assert_eq!(summary.count(BogonKind::Loopback), 1);
+/// ```
+pub fn summarize<I: IntoIterator<Item = IpAddr>>(ips: I) -> Summary {
+    summarize_with(ips, &DefaultBogonPolicy)
+}
+
+/// Counts a batch of IP addresses by classification, judged by an [`IpPolicy`].
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{summarize_with, BogonKind, BogonProfile};
+///
+/// let ips = ["10.0.0.1", "192.0.2.1", "8.8.8.8"].map(|ip| ip.parse().unwrap());
+/// let summary = summarize_with(ips, &BogonProfile::Loose);
+/// assert_eq!(summary.bogons, 1);
+/// assert_eq!(summary.count(BogonKind::Documentation), 0);
+/// ```
+pub fn summarize_with<I, P>(ips: I, policy: &P) -> Summary
+where
+    I: IntoIterator<Item = IpAddr>,
+    P: IpPolicy + ?Sized,
+{
+    let mut summary = Summary::new();
+    for ip in ips {
+        summary.add_with(ip, policy);
+    }
+    summary
+}
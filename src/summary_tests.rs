@@ -0,0 +1,79 @@
+use core::net::IpAddr;
+
+use crate::{summarize, BogonKind, Summary};
+
+fn parse(ips: &[&str]) -> Vec<IpAddr> {
+    ips.iter().map(|ip| ip.parse().unwrap()).collect()
+}
+
+#[test]
+fn summarize_empty() {
+    let summary = summarize(core::iter::empty());
+    assert_eq!(summary, Summary::new());
+    assert_eq!(summary.total, 0);
+    assert_eq!(summary.bogons, 0);
+    assert_eq!(summary.unclassified(), 0);
+    for kind in BogonKind::ALL {
+        assert_eq!(summary.count(kind), 0, "{kind:?}");
+    }
+}
+
+#[test]
+fn summarize_mixed() {
+    let summary = summarize(parse(&[
+        "10.0.0.1",
+        "192.168.1.1",
+        "127.0.0.1",
+        "::1",
+        "fd00::1",
+        "3000::1",
+        "192.175.48.1",
+        "8.8.8.8",
+        "2606:4700::1111",
+    ]));
+
+    assert_eq!(summary.total, 9);
+    assert_eq!(summary.bogons, 6);
+    assert_eq!(summary.count(BogonKind::PrivateUse), 2);
+    assert_eq!(summary.count(BogonKind::Loopback), 2);
+    assert_eq!(summary.count(BogonKind::UniqueLocal), 1);
+    assert_eq!(summary.count(BogonKind::Unallocated), 1);
+    assert_eq!(summary.count(BogonKind::Multicast), 0);
+    assert_eq!(summary.unclassified(), 3);
+
+    assert_eq!(
+        summary.to_string(),
+        [
+            "total                                9",
+            "bogons                               6",
+            "  Private-Use                        2",
+            "  Loopback                           2",
+            "  Unique-Local                       1",
+            "  Unallocated Global Unicast         1",
+            "  unclassified                       3",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn summarize_merge() {
+    let ips = (0..1_000)
+        .map(|_| {
+            if rand::random() {
+                IpAddr::from(rand::random::<[u8; 4]>())
+            } else {
+                IpAddr::from(rand::random::<[u8; 16]>())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let (left, right) = ips.split_at(ips.len() / 3);
+    let mut merged = summarize(left.iter().copied());
+    merged.merge(summarize(right.iter().copied()));
+    assert_eq!(merged, summarize(ips.iter().copied()));
+
+    let mut empty = Summary::new();
+    empty.merge(merged);
+    assert_eq!(empty, merged);
+}
@@ -0,0 +1,579 @@
+//! A set of bogus networks, kept as sorted and merged ranges.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+use std::vec::Vec;
+
+use crate::{
+    network::{FourByteNetwork, SixteenByteNetwork},
+    unallocated_v6_networks, V4_BOGON_EXCEPTIONS, V4_BOGON_NETWORKS, V6_BOGON_EXCEPTIONS,
+    V6_GLOBAL_BOGON_NETWORKS,
+};
+
+/// The unsigned integer behind an address family.
+trait Bits: Copy + Ord {
+    const MAX: Self;
+
+    /// The size of the integer in bytes.
+    const BYTES: usize;
+
+    fn checked_succ(self) -> Option<Self>;
+
+    fn checked_pred(self) -> Option<Self>;
+
+    /// Reads the integer from exactly [`Bits::BYTES`] little-endian bytes.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    fn write_le(self, bytes: &mut Vec<u8>);
+}
+
+impl Bits for u32 {
+    const MAX: Self = u32::MAX;
+
+    const BYTES: usize = 4;
+
+    fn checked_succ(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn checked_pred(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        Self::from_le_bytes(bytes.try_into().expect("wrong number of bytes"))
+    }
+
+    fn write_le(self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl Bits for u128 {
+    const MAX: Self = u128::MAX;
+
+    const BYTES: usize = 16;
+
+    fn checked_succ(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn checked_pred(self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        Self::from_le_bytes(bytes.try_into().expect("wrong number of bytes"))
+    }
+
+    fn write_le(self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+/// Inserts an inclusive range into sorted, disjoint, and non-adjacent ranges.
+fn insert_range<T: Bits>(ranges: &mut Vec<(T, T)>, start: T, end: T) {
+    // Ranges that end before the new range starts, without touching it.
+    let first = ranges.partition_point(|&(_, e)| e.checked_succ().is_some_and(|e| e < start));
+
+    let (mut start, mut end) = (start, end);
+    let after_end = end.checked_succ().unwrap_or(T::MAX);
+    let mut last = first;
+    while last < ranges.len() && ranges[last].0 <= after_end {
+        start = start.min(ranges[last].0);
+        end = end.max(ranges[last].1);
+        last += 1;
+    }
+
+    ranges.splice(first..last, [(start, end)]);
+}
+
+/// Removes an inclusive range from sorted, disjoint, and non-adjacent ranges.
+fn remove_range<T: Bits>(ranges: &mut Vec<(T, T)>, start: T, end: T) {
+    let first = ranges.partition_point(|&(_, e)| e < start);
+    let mut last = first;
+    while last < ranges.len() && ranges[last].0 <= end {
+        last += 1;
+    }
+
+    // The ranges overlapping the removed range keep their parts before and after it.
+    let mut kept = Vec::new();
+    if let Some(&(s, _)) = ranges[first..last].first() {
+        if s < start {
+            kept.push((s, start.checked_pred().unwrap_or(s)));
+        }
+    }
+    if let Some(&(_, e)) = ranges[first..last].last() {
+        if e > end {
+            kept.push((end.checked_succ().unwrap_or(e), e));
+        }
+    }
+
+    ranges.splice(first..last, kept);
+}
+
+/// Combines sorted, disjoint, and non-adjacent ranges with a set operation, given as the
+/// membership of a value in the result from its membership in `a` and `b`.
+///
+/// Both inputs are turned into the sorted points where membership flips, and the points are swept
+/// in order. `op(false, false)` must be `false`.
+fn combine<T: Bits>(a: &[(T, T)], b: &[(T, T)], op: impl Fn(bool, bool) -> bool) -> Vec<(T, T)> {
+    // A range flips membership on at its start and off after its end, unless it ends at the top.
+    let flips = |ranges: &[(T, T)]| -> Vec<T> {
+        ranges
+            .iter()
+            .flat_map(|&(start, end)| [Some(start), end.checked_succ()])
+            .flatten()
+            .collect()
+    };
+    let (a, b) = (flips(a), flips(b));
+
+    let (mut i, mut j) = (0, 0);
+    let (mut in_a, mut in_b, mut inside) = (false, false, false);
+    let mut result = Vec::new();
+    let mut start = None;
+    while i < a.len() || j < b.len() {
+        let point = match (a.get(i), b.get(j)) {
+            (Some(&x), Some(&y)) => x.min(y),
+            (Some(&x), None) => x,
+            (None, Some(&y)) => y,
+            (None, None) => unreachable!(),
+        };
+        if a.get(i) == Some(&point) {
+            in_a = !in_a;
+            i += 1;
+        }
+        if b.get(j) == Some(&point) {
+            in_b = !in_b;
+            j += 1;
+        }
+
+        if op(in_a, in_b) != inside {
+            inside = !inside;
+            match start.take() {
+                None => start = Some(point),
+                Some(start) => {
+                    result.push((start, point.checked_pred().expect("flips are sorted")))
+                }
+            }
+        }
+    }
+    if let Some(start) = start {
+        result.push((start, T::MAX));
+    }
+
+    result
+}
+
+/// Decodes ranges, checking that they are sorted, disjoint, and non-adjacent.
+fn decode_ranges<T: Bits>(
+    bytes: &[u8],
+    family: &'static str,
+) -> Result<Vec<(T, T)>, DecodeTableError> {
+    let mut ranges: Vec<(T, T)> = Vec::with_capacity(bytes.len() / (2 * T::BYTES));
+    for (index, range) in bytes.chunks_exact(2 * T::BYTES).enumerate() {
+        let (start, end) = range.split_at(T::BYTES);
+        let (start, end) = (T::from_le_slice(start), T::from_le_slice(end));
+
+        let after_previous = match ranges.last() {
+            Some(&(_, e)) => e.checked_succ().is_some_and(|e| e < start),
+            None => true,
+        };
+        if start > end || !after_previous {
+            return Err(DecodeTableError::Unsorted { family, index });
+        }
+        ranges.push((start, end));
+    }
+    Ok(ranges)
+}
+
+/// Returns a boolean indicating whether sorted, disjoint ranges contain a value.
+fn ranges_contain<T: Bits>(ranges: &[(T, T)], value: T) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if end < value {
+                Ordering::Less
+            } else if start > value {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// The first bytes of an encoded [`BogonTable`].
+const MAGIC: [u8; 4] = *b"BGNT";
+
+/// The version of the [`BogonTable`] encoding, bumped on every incompatible change.
+const FORMAT_VERSION: u8 = 1;
+
+/// The size of the header of an encoded [`BogonTable`]: the magic, the version, and the number of
+/// IPv4 and IPv6 ranges.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + 4;
+
+/// The error returned when decoding a [`BogonTable`] fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeTableError {
+    /// The bytes don't start with the magic of an encoded table.
+    BadMagic,
+    /// The table was encoded with a version of the encoding this version of the crate can't read.
+    UnsupportedVersion {
+        /// The version of the encoding.
+        version: u8,
+    },
+    /// The bytes are truncated, or their number doesn't match the number of ranges in the header.
+    WrongLength {
+        /// The number of bytes the header calls for, `None` if it overflows.
+        expected: Option<usize>,
+        /// The number of bytes.
+        actual: usize,
+    },
+    /// A range is empty, or doesn't come after the previous range without touching it.
+    Unsorted {
+        /// The address family of the range, `"IPv4"` or `"IPv6"`.
+        family: &'static str,
+        /// The index of the range within its address family.
+        index: usize,
+    },
+}
+
+impl fmt::Display for DecodeTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeTableError::BadMagic => write!(f, "not an encoded bogon table"),
+            DecodeTableError::UnsupportedVersion { version } => write!(
+                f,
+                "unsupported bogon table format version {version}, expected version {FORMAT_VERSION}"
+            ),
+            DecodeTableError::WrongLength {
+                expected: Some(expected),
+                actual,
+            } => write!(f, "bogon table has {actual} bytes, expected {expected}"),
+            DecodeTableError::WrongLength {
+                expected: None,
+                actual,
+            } => write!(
+                f,
+                "bogon table has {actual} bytes, its header calls for more than fit in memory"
+            ),
+            DecodeTableError::Unsorted { family, index } => write!(
+                f,
+                "{family} range {index} of the bogon table is out of order"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeTableError {}
+
+/// A set of bogus IPv4 and IPv6 networks.
+///
+/// The networks are kept as sorted and merged ranges, so overlapping and adjacent networks can be
+/// inserted in any order.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::BogonTable;
+///
+/// let table: BogonTable = [
+///     ("10.0.0.0".parse().unwrap(), 8),
+///     ("fc00::".parse().unwrap(), 7),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert!(table.contains("10.1.2.3".parse().unwrap()));
+/// assert!(!table.contains("192.168.1.1".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BogonTable {
+    v4: Vec<(u32, u32)>,
+    v6: Vec<(u128, u128)>,
+}
+
+impl BogonTable {
+    /// Returns an empty table.
+    pub const fn new() -> Self {
+        BogonTable {
+            v4: Vec::new(),
+            v6: Vec::new(),
+        }
+    }
+
+    /// Returns a table that agrees with [`is_bogon`](crate::is_bogon) on the compiled-in data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonTable;
+    ///
+    /// let table = BogonTable::with_defaults();
+    /// assert!(table.contains("10.0.0.1".parse().unwrap()));
+    /// assert!(table.contains("fe80::1".parse().unwrap()));
+    /// assert!(!table.contains("2606:4700:4700::1111".parse().unwrap()));
+    /// ```
+    pub fn with_defaults() -> Self {
+        let v4 = |networks: &[FourByteNetwork]| -> BogonTable {
+            networks.iter().map(|network| network.to_v4()).collect()
+        };
+        let v6 = |networks: &[SixteenByteNetwork]| -> BogonTable {
+            networks.iter().map(|network| network.to_v6()).collect()
+        };
+
+        // Everything outside of 2000::/3 is bogus. Inside of it, special-purpose networks are
+        // bogus except for their globally reachable exceptions, and so is unallocated space.
+        let outside_global_unicast = v6(&[
+            SixteenByteNetwork::new(0, 3),
+            SixteenByteNetwork::new(0x4000 << 112, 2),
+            SixteenByteNetwork::new(0x8000 << 112, 1),
+        ]);
+        let special = v6(&V6_GLOBAL_BOGON_NETWORKS);
+        let unallocated: BogonTable = unallocated_v6_networks()
+            .map(|network| network.to_v6())
+            .collect();
+
+        v4(&V4_BOGON_NETWORKS)
+            .difference(&v4(&V4_BOGON_EXCEPTIONS))
+            .union(&outside_global_unicast)
+            .union(&special.difference(&v6(&V6_BOGON_EXCEPTIONS)))
+            .union(&unallocated.difference(&special))
+    }
+
+    /// Adds a network to the table. Host bits of the network address are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix length is longer than the address.
+    pub fn insert(&mut self, network: IpAddr, prefix_len: u8) {
+        match network {
+            IpAddr::V4(ip) => {
+                assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+                let host = u32::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+                let start = ip.to_bits() & !host;
+                insert_range(&mut self.v4, start, start | host);
+            }
+            IpAddr::V6(ip) => {
+                assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+                let host = u128::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+                let start = ip.to_bits() & !host;
+                insert_range(&mut self.v6, start, start | host);
+            }
+        }
+    }
+
+    /// Returns a table of the addresses in either table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonTable;
+    ///
+    /// let a: BogonTable = [("10.0.0.0".parse().unwrap(), 9)].into_iter().collect();
+    /// let b: BogonTable = [("10.128.0.0".parse().unwrap(), 9)].into_iter().collect();
+    /// let expected: BogonTable = [("10.0.0.0".parse().unwrap(), 8)].into_iter().collect();
+    /// assert_eq!(a.union(&b), expected);
+    /// ```
+    pub fn union(&self, other: &BogonTable) -> BogonTable {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns a table of the addresses in both tables.
+    pub fn intersection(&self, other: &BogonTable) -> BogonTable {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns a table of the addresses in this table but not the other one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonTable;
+    ///
+    /// // Our own space inside of 10.0.0.0/8 isn't bogus to us.
+    /// let bogons: BogonTable = [("10.0.0.0".parse().unwrap(), 8)].into_iter().collect();
+    /// let ours: BogonTable = [("10.1.0.0".parse().unwrap(), 16)].into_iter().collect();
+    ///
+    /// let table = bogons.difference(&ours);
+    /// assert!(table.contains("10.2.0.1".parse().unwrap()));
+    /// assert!(!table.contains("10.1.0.1".parse().unwrap()));
+    /// ```
+    pub fn difference(&self, other: &BogonTable) -> BogonTable {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Returns a table of the addresses not in this table.
+    ///
+    /// IPv4 addresses are complemented within 0.0.0.0/0 and IPv6 addresses within ::/0, so the
+    /// complement of an empty table contains every address of both families.
+    pub fn complement(&self) -> BogonTable {
+        BogonTable {
+            v4: combine(&[(0, u32::MAX)], &self.v4, |a, b| a && !b),
+            v6: combine(&[(0, u128::MAX)], &self.v6, |a, b| a && !b),
+        }
+    }
+
+    fn combine(&self, other: &BogonTable, op: impl Fn(bool, bool) -> bool + Copy) -> BogonTable {
+        BogonTable {
+            v4: combine(&self.v4, &other.v4, op),
+            v6: combine(&self.v6, &other.v6, op),
+        }
+    }
+
+    /// Encodes the table into a compact binary form, read back by [`BogonTable::from_bytes`].
+    ///
+    /// The encoding is the magic `BGNT`, a version byte, the number of IPv4 and IPv6 ranges as
+    /// little-endian `u32`s, and then the first and last address of every range as little-endian
+    /// integers, IPv4 ranges first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has more than `u32::MAX` ranges of an address family.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.v4.len() * 8 + self.v6.len() * 32);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(FORMAT_VERSION);
+        for len in [self.v4.len(), self.v6.len()] {
+            let len = u32::try_from(len).expect("too many ranges to encode");
+            len.write_le(&mut bytes);
+        }
+        for &(start, end) in &self.v4 {
+            start.write_le(&mut bytes);
+            end.write_le(&mut bytes);
+        }
+        for &(start, end) in &self.v6 {
+            start.write_le(&mut bytes);
+            end.write_le(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Decodes a table encoded by [`BogonTable::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes aren't an encoded table, were encoded by an incompatible
+    /// version of the encoding, are truncated, or have ranges out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bogon::BogonTable;
+    ///
+    /// let table: BogonTable = [("192.0.2.0".parse().unwrap(), 24)].into_iter().collect();
+    /// let bytes = table.to_bytes();
+    /// assert_eq!(BogonTable::from_bytes(&bytes), Ok(table));
+    ///
+    /// assert!(BogonTable::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeTableError> {
+        if bytes.get(..MAGIC.len()) != Some(&MAGIC[..]) {
+            return Err(DecodeTableError::BadMagic);
+        }
+        match bytes.get(MAGIC.len()) {
+            Some(&FORMAT_VERSION) | None => {}
+            Some(&version) => return Err(DecodeTableError::UnsupportedVersion { version }),
+        }
+        let (header, body) =
+            bytes
+                .split_at_checked(HEADER_LEN)
+                .ok_or(DecodeTableError::WrongLength {
+                    expected: Some(HEADER_LEN),
+                    actual: bytes.len(),
+                })?;
+
+        let v4_len = u32::from_le_slice(&header[MAGIC.len() + 1..MAGIC.len() + 5]) as usize;
+        let v6_len = u32::from_le_slice(&header[MAGIC.len() + 5..]) as usize;
+        let v4_bytes = v4_len.checked_mul(8);
+        let expected = v6_len
+            .checked_mul(32)
+            .zip(v4_bytes)
+            .and_then(|(v6_bytes, v4_bytes)| v6_bytes.checked_add(v4_bytes));
+        let v4_bytes = match (v4_bytes, expected) {
+            (Some(v4_bytes), Some(expected)) if expected == body.len() => v4_bytes,
+            _ => {
+                return Err(DecodeTableError::WrongLength {
+                    expected: expected.and_then(|expected| expected.checked_add(HEADER_LEN)),
+                    actual: bytes.len(),
+                })
+            }
+        };
+
+        let (v4, v6) = body.split_at(v4_bytes);
+        Ok(BogonTable {
+            v4: decode_ranges(v4, "IPv4")?,
+            v6: decode_ranges(v6, "IPv6")?,
+        })
+    }
+
+    /// Adds an inclusive range of IPv6 addresses, given as integers, to the table.
+    pub(crate) fn insert_v6_range(&mut self, start: u128, end: u128) {
+        insert_range(&mut self.v6, start, end);
+    }
+
+    /// Removes an inclusive range of IPv6 addresses, given as integers, from the table.
+    pub(crate) fn remove_v6_range(&mut self, start: u128, end: u128) {
+        remove_range(&mut self.v6, start, end);
+    }
+
+    /// Returns a boolean indicating whether the table contains an IP address.
+    #[inline]
+    pub fn contains(&self, ip_address: IpAddr) -> bool {
+        match ip_address {
+            IpAddr::V4(ip) => self.contains_v4(ip),
+            IpAddr::V6(ip) => self.contains_v6(ip),
+        }
+    }
+
+    /// Returns a boolean indicating whether the table contains an IPv4 address.
+    #[inline]
+    pub fn contains_v4(&self, ip_address: Ipv4Addr) -> bool {
+        ranges_contain(&self.v4, ip_address.to_bits())
+    }
+
+    /// Returns a boolean indicating whether the table contains an IPv6 address.
+    #[inline]
+    pub fn contains_v6(&self, ip_address: Ipv6Addr) -> bool {
+        ranges_contain(&self.v6, ip_address.to_bits())
+    }
+
+    /// Returns a boolean indicating whether the table contains no addresses.
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+
+    /// Returns the first and last address of every IPv4 range in the table, in order.
+    pub fn v4_ranges(&self) -> impl Iterator<Item = (Ipv4Addr, Ipv4Addr)> + '_ {
+        self.v4
+            .iter()
+            .map(|&(start, end)| (Ipv4Addr::from_bits(start), Ipv4Addr::from_bits(end)))
+    }
+
+    /// Returns the first and last address of every IPv6 range in the table, in order.
+    pub fn v6_ranges(&self) -> impl Iterator<Item = (Ipv6Addr, Ipv6Addr)> + '_ {
+        self.v6
+            .iter()
+            .map(|&(start, end)| (Ipv6Addr::from_bits(start), Ipv6Addr::from_bits(end)))
+    }
+}
+
+impl Extend<(IpAddr, u8)> for BogonTable {
+    fn extend<I: IntoIterator<Item = (IpAddr, u8)>>(&mut self, networks: I) {
+        for (network, prefix_len) in networks {
+            self.insert(network, prefix_len);
+        }
+    }
+}
+
+impl FromIterator<(IpAddr, u8)> for BogonTable {
+    fn from_iter<I: IntoIterator<Item = (IpAddr, u8)>>(networks: I) -> Self {
+        let mut table = BogonTable::new();
+        table.extend(networks);
+        table
+    }
+}
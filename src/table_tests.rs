@@ -0,0 +1,353 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{BogonTable, DecodeTableError};
+
+fn net(network: &str, prefix_len: u8) -> (IpAddr, u8) {
+    (network.parse().unwrap(), prefix_len)
+}
+
+#[test]
+fn table_merges_ranges() {
+    let table: BogonTable = [
+        net("10.0.0.0", 9),
+        net("10.128.0.0", 9),
+        net("192.168.1.0", 24),
+        net("192.168.0.0", 16),
+        net("0.0.0.0", 8),
+        net("fc00::", 8),
+        net("fd00::", 8),
+    ]
+    .into_iter()
+    .collect();
+
+    let v4 = |a: &str, b: &str| (a.parse::<Ipv4Addr>().unwrap(), b.parse().unwrap());
+    assert_eq!(
+        table.v4_ranges().collect::<Vec<_>>(),
+        [
+            v4("0.0.0.0", "0.255.255.255"),
+            v4("10.0.0.0", "10.255.255.255"),
+            v4("192.168.0.0", "192.168.255.255"),
+        ]
+    );
+
+    let v6 = |a: &str, b: &str| (a.parse::<Ipv6Addr>().unwrap(), b.parse().unwrap());
+    assert_eq!(
+        table.v6_ranges().collect::<Vec<_>>(),
+        [v6("fc00::", "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff")]
+    );
+
+    // Host bits are ignored, and the order of insertion doesn't matter.
+    let mut other = BogonTable::new();
+    other.extend([
+        net("fd00::1", 8),
+        net("192.168.255.255", 16),
+        net("fc00::", 8),
+        net("10.200.0.0", 8),
+        net("0.1.2.3", 8),
+    ]);
+    assert_eq!(other, table);
+}
+
+#[test]
+fn table_extremes() {
+    let table: BogonTable = [net("0.0.0.0", 0), net("ffff::", 16)].into_iter().collect();
+    assert!(table.contains("0.0.0.0".parse().unwrap()));
+    assert!(table.contains("255.255.255.255".parse().unwrap()));
+    assert!(table.contains("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()));
+    assert!(!table.contains("fffe:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()));
+
+    assert!(BogonTable::new().is_empty());
+    assert!(!BogonTable::new().contains("10.0.0.1".parse().unwrap()));
+}
+
+#[test]
+fn table_matches_networks() {
+    for _ in 0..100 {
+        let networks = (0..20)
+            .map(|_| {
+                let prefix_len = rand::random::<u8>() % 33;
+                (IpAddr::V4(Ipv4Addr::from_bits(rand::random())), prefix_len)
+            })
+            .collect::<Vec<_>>();
+        let table = networks.iter().copied().collect::<BogonTable>();
+
+        // The ranges are sorted, disjoint, and not adjacent.
+        let ranges = table.v4_ranges().collect::<Vec<_>>();
+        for pair in ranges.windows(2) {
+            assert!(u64::from(pair[0].1.to_bits()) + 1 < u64::from(pair[1].0.to_bits()));
+        }
+
+        for _ in 0..1_000 {
+            let ip = Ipv4Addr::from_bits(rand::random());
+            let expected = networks.iter().any(|&(network, prefix_len)| {
+                let IpAddr::V4(network) = network else {
+                    unreachable!()
+                };
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(prefix_len))
+                    .unwrap_or(0);
+                ip.to_bits() & mask == network.to_bits() & mask
+            });
+            assert_eq!(table.contains_v4(ip), expected, "{ip} in {networks:?}");
+        }
+    }
+}
+
+fn random_table() -> BogonTable {
+    (0..50)
+        .map(|_| {
+            if rand::random() {
+                let ip = Ipv4Addr::from_bits(rand::random());
+                (IpAddr::V4(ip), rand::random::<u8>() % 33)
+            } else {
+                let ip = Ipv6Addr::from_bits(rand::random());
+                (IpAddr::V6(ip), rand::random::<u8>() % 129)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn bytes_round_trip() {
+    for table in [BogonTable::new(), random_table(), random_table()] {
+        let bytes = table.to_bytes();
+        assert_eq!(
+            bytes.len(),
+            13 + table.v4_ranges().count() * 8 + table.v6_ranges().count() * 32
+        );
+        assert_eq!(BogonTable::from_bytes(&bytes), Ok(table));
+    }
+
+    let everything: BogonTable = [net("0.0.0.0", 0), net("::", 0)].into_iter().collect();
+    assert_eq!(
+        BogonTable::from_bytes(&everything.to_bytes()),
+        Ok(everything)
+    );
+}
+
+#[test]
+fn bytes_layout() {
+    let table: BogonTable = [net("10.0.0.0", 8), net("fc00::", 7)].into_iter().collect();
+    let mut expected = b"BGNT\x01".to_vec();
+    expected.extend_from_slice(&1u32.to_le_bytes());
+    expected.extend_from_slice(&1u32.to_le_bytes());
+    expected.extend_from_slice(&0x0a00_0000u32.to_le_bytes());
+    expected.extend_from_slice(&0x0aff_ffffu32.to_le_bytes());
+    expected.extend_from_slice(&(0xfc00u128 << 112).to_le_bytes());
+    expected.extend_from_slice(&(0xfdff_u128 << 112 | u128::MAX >> 16).to_le_bytes());
+    assert_eq!(table.to_bytes(), expected);
+}
+
+#[test]
+fn bytes_older_version_is_rejected() {
+    let mut bytes = random_table().to_bytes();
+    bytes[4] = 0;
+    let error = BogonTable::from_bytes(&bytes).unwrap_err();
+    assert_eq!(error, DecodeTableError::UnsupportedVersion { version: 0 });
+    assert_eq!(
+        error.to_string(),
+        "unsupported bogon table format version 0, expected version 1"
+    );
+}
+
+#[test]
+fn bytes_corruption_is_rejected() {
+    let table: BogonTable = [net("10.0.0.0", 8), net("192.168.0.0", 16), net("fc00::", 7)]
+        .into_iter()
+        .collect();
+    let bytes = table.to_bytes();
+
+    assert_eq!(
+        BogonTable::from_bytes(b"not a table at all"),
+        Err(DecodeTableError::BadMagic)
+    );
+    assert_eq!(BogonTable::from_bytes(b""), Err(DecodeTableError::BadMagic));
+    assert_eq!(
+        BogonTable::from_bytes(&bytes[..7]),
+        Err(DecodeTableError::WrongLength {
+            expected: Some(13),
+            actual: 7
+        })
+    );
+    assert_eq!(
+        BogonTable::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(DecodeTableError::WrongLength {
+            expected: Some(bytes.len()),
+            actual: bytes.len() - 1
+        })
+    );
+
+    let mut huge = bytes.clone();
+    huge[5..13].fill(0xff);
+    assert!(matches!(
+        BogonTable::from_bytes(&huge),
+        Err(DecodeTableError::WrongLength { .. })
+    ));
+
+    // Swap the two IPv4 ranges.
+    let mut swapped = bytes.clone();
+    let (first, second) = swapped[13..29].split_at_mut(8);
+    first.swap_with_slice(second);
+    assert_eq!(
+        BogonTable::from_bytes(&swapped),
+        Err(DecodeTableError::Unsorted {
+            family: "IPv4",
+            index: 1
+        })
+    );
+
+    // Make the IPv6 range end before it starts.
+    let mut reversed = bytes.clone();
+    reversed[29..].reverse();
+    assert_eq!(
+        BogonTable::from_bytes(&reversed),
+        Err(DecodeTableError::Unsorted {
+            family: "IPv6",
+            index: 0
+        })
+    );
+
+    // Adjacent ranges would have been merged.
+    let mut adjacent = bytes;
+    adjacent[21..25].copy_from_slice(&0x0b00_0000u32.to_le_bytes());
+    assert_eq!(
+        BogonTable::from_bytes(&adjacent),
+        Err(DecodeTableError::Unsorted {
+            family: "IPv4",
+            index: 1
+        })
+    );
+}
+
+/// A random table of a few networks, short enough to overlap often.
+fn overlapping_table() -> BogonTable {
+    let len = rand::random::<usize>() % 6;
+    (0..len)
+        .map(|_| {
+            if rand::random() {
+                let ip = Ipv4Addr::from_bits(rand::random::<u32>() & 0xf000_0000);
+                (IpAddr::V4(ip), 1 + rand::random::<u8>() % 8)
+            } else {
+                let ip = Ipv6Addr::from_bits(rand::random::<u128>() & (0xf << 124));
+                (IpAddr::V6(ip), 1 + rand::random::<u8>() % 8)
+            }
+        })
+        .collect()
+}
+
+/// Addresses where the membership of the tables may change, plus some random ones.
+fn probes(tables: &[&BogonTable]) -> Vec<IpAddr> {
+    let mut probes = Vec::new();
+    for table in tables {
+        for (start, end) in table.v4_ranges() {
+            for bits in [start.to_bits(), end.to_bits()] {
+                for bits in [bits.wrapping_sub(1), bits, bits.wrapping_add(1)] {
+                    probes.push(IpAddr::V4(Ipv4Addr::from_bits(bits)));
+                }
+            }
+        }
+        for (start, end) in table.v6_ranges() {
+            for bits in [start.to_bits(), end.to_bits()] {
+                for bits in [bits.wrapping_sub(1), bits, bits.wrapping_add(1)] {
+                    probes.push(IpAddr::V6(Ipv6Addr::from_bits(bits)));
+                }
+            }
+        }
+    }
+    for _ in 0..100 {
+        probes.push(IpAddr::V4(Ipv4Addr::from_bits(rand::random())));
+        probes.push(IpAddr::V6(Ipv6Addr::from_bits(rand::random())));
+    }
+    probes.extend([
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V4(Ipv4Addr::BROADCAST),
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        IpAddr::V6(Ipv6Addr::from_bits(u128::MAX)),
+    ]);
+    probes
+}
+
+/// Asserts that the ranges are sorted, disjoint, and not adjacent.
+fn assert_normalized(table: &BogonTable) {
+    let v4 = table.v4_ranges().collect::<Vec<_>>();
+    assert!(v4.iter().all(|(start, end)| start <= end));
+    for pair in v4.windows(2) {
+        assert!(u64::from(pair[0].1.to_bits()) + 1 < u64::from(pair[1].0.to_bits()));
+    }
+    let v6 = table.v6_ranges().collect::<Vec<_>>();
+    assert!(v6.iter().all(|(start, end)| start <= end));
+    for pair in v6.windows(2) {
+        assert!(pair[0].1.to_bits() < pair[1].0.to_bits() - 1);
+    }
+}
+
+#[test]
+fn set_operations_match_membership() {
+    for _ in 0..500 {
+        let (a, b) = (overlapping_table(), overlapping_table());
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        let difference = a.difference(&b);
+        let complement = a.complement();
+
+        for table in [&union, &intersection, &difference, &complement] {
+            assert_normalized(table);
+        }
+        for ip in probes(&[&a, &b]) {
+            let (in_a, in_b) = (a.contains(ip), b.contains(ip));
+            assert_eq!(union.contains(ip), in_a || in_b, "{ip} in {a:?} | {b:?}");
+            assert_eq!(
+                intersection.contains(ip),
+                in_a && in_b,
+                "{ip} in {a:?} & {b:?}"
+            );
+            assert_eq!(
+                difference.contains(ip),
+                in_a && !in_b,
+                "{ip} in {a:?} - {b:?}"
+            );
+            assert_eq!(complement.contains(ip), !in_a, "{ip} in !{a:?}");
+        }
+
+        assert_eq!(union.complement().complement(), union);
+    }
+}
+
+#[test]
+fn complement_universe() {
+    let everything = BogonTable::new().complement();
+    assert_eq!(
+        everything,
+        [net("0.0.0.0", 0), net("::", 0)].into_iter().collect()
+    );
+    assert_eq!(everything.complement(), BogonTable::new());
+
+    let table: BogonTable = [net("0.0.0.0", 8), net("255.0.0.0", 8), net("::", 1)]
+        .into_iter()
+        .collect();
+    let v4 = |a: &str, b: &str| (a.parse::<Ipv4Addr>().unwrap(), b.parse().unwrap());
+    assert_eq!(
+        table.complement().v4_ranges().collect::<Vec<_>>(),
+        [v4("1.0.0.0", "254.255.255.255")]
+    );
+    assert_eq!(
+        table.complement().v6_ranges().collect::<Vec<_>>(),
+        [("8000::".parse().unwrap(), Ipv6Addr::from_bits(u128::MAX))]
+    );
+}
+
+#[test]
+fn with_defaults_matches_is_bogon() {
+    let table = BogonTable::with_defaults();
+
+    // The edges of the ranges, and a random sample.
+    let mut ips = probes(&[&table]);
+    for _ in 0..100_000 {
+        ips.push(IpAddr::V4(Ipv4Addr::from_bits(rand::random())));
+        let v6 = rand::random::<u128>();
+        ips.push(IpAddr::V6(Ipv6Addr::from_bits(v6 >> 3 | 1 << 125)));
+    }
+    for ip in ips {
+        assert_eq!(table.contains(ip), crate::is_bogon(ip), "{ip}");
+    }
+}
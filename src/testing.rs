@@ -0,0 +1,90 @@
+//! Overriding the verdicts of the free functions in tests.
+//!
+//! **This module is only for tests.** It is behind the `test-util` feature, which must only ever
+//! be enabled from `[dev-dependencies]`, so that it is never compiled into the binaries you ship.
+//! Cargo builds dev-dependencies for `cargo test --release` and `cargo bench` too, so the feature
+//! works in any profile. Enabling it from `[dependencies]` would ship the override, and its cost
+//! on every check, in your program.
+//!
+//! While [`with_policy`] runs its closure, [`is_bogon`](crate::is_bogon),
+//! [`classify`](crate::classify), and their IPv4 and IPv6 variants follow the given
+//! [`IpPolicy`] instead of the built-in tables. The override is thread-local, so tests running in
+//! parallel don't see each other's policies. Everything built on top of the free functions, like
+//! [`BogonExt`](crate::BogonExt) and [`explain`](crate::explain), follows the override too.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! bogon = { version = "*", features = ["test-util"] }
+//! ```
+
+use core::net::IpAddr;
+use std::{cell::RefCell, rc::Rc, vec::Vec};
+
+use crate::{BogonKind, IpPolicy};
+
+std::thread_local! {
+    // The active overrides, innermost last. `None` marks that an override is being evaluated, so
+    // a policy built on the free functions sees the built-in tables.
+    static OVERRIDES: RefCell<Vec<Option<Rc<dyn IpPolicy>>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the innermost entry of the override stack when dropped, even if the closure panics.
+struct Pop;
+
+impl Drop for Pop {
+    fn drop(&mut self) {
+        OVERRIDES.with_borrow_mut(|overrides| overrides.pop());
+    }
+}
+
+/// Runs a closure with an entry pushed onto the override stack.
+fn with_entry<R>(entry: Option<Rc<dyn IpPolicy>>, f: impl FnOnce() -> R) -> R {
+    OVERRIDES.with_borrow_mut(|overrides| overrides.push(entry));
+    let _pop = Pop;
+    f()
+}
+
+/// Evaluates the innermost override, or returns `None` if there is none.
+fn overridden<T>(evaluate: impl FnOnce(&dyn IpPolicy) -> T) -> Option<T> {
+    let policy = OVERRIDES.with_borrow(|overrides| overrides.last().cloned().flatten())?;
+    Some(with_entry(None, || evaluate(&*policy)))
+}
+
+/// Returns the overridden verdict of [`is_bogon`](crate::is_bogon), if any.
+#[inline]
+pub(crate) fn is_bogon_override(ip_address: IpAddr) -> Option<bool> {
+    overridden(|policy| !policy.is_allowed(ip_address))
+}
+
+/// Returns the overridden verdict of [`classify`](crate::classify), if any.
+#[inline]
+pub(crate) fn classify_override(ip_address: IpAddr) -> Option<Option<BogonKind>> {
+    overridden(|policy| policy.classify(ip_address))
+}
+
+/// Runs a closure with the free functions following a policy on the current thread.
+///
+/// Overrides can be nested, the innermost one wins. The policy itself can call the free functions,
+/// which see the built-in tables while the policy is evaluated.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::IpAddr;
+/// use bogon::{is_bogon, testing::with_policy};
+///
+/// let documentation: IpAddr = "192.0.2.55".parse().unwrap();
+///
+/// with_policy(
+///     move |ip: IpAddr| ip == documentation || !is_bogon(ip),
+///     || {
+///         assert!(!is_bogon(documentation));
+///         assert!(is_bogon("10.0.0.1".parse().unwrap()));
+///     },
+/// );
+///
+/// assert!(is_bogon(documentation));
+/// ```
+pub fn with_policy<P: IpPolicy + 'static, R>(policy: P, f: impl FnOnce() -> R) -> R {
+    with_entry(Some(Rc::new(policy)), f)
+}
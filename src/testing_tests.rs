@@ -0,0 +1,92 @@
+use core::net::{IpAddr, Ipv4Addr};
+
+use crate::{
+    classify, classify_v4, is_bogon, is_bogon_v4, testing::with_policy, BogonExt, BogonFilter,
+    BogonKind, DefaultBogonPolicy, IpPolicy,
+};
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+/// Allows one documentation address on top of the default policy.
+fn allow_documentation(ip_address: IpAddr) -> bool {
+    ip_address == ip("192.0.2.55") || DefaultBogonPolicy.is_allowed(ip_address)
+}
+
+#[test]
+fn override_applies_inside_closure() {
+    assert!(is_bogon(ip("192.0.2.55")));
+
+    let classified = with_policy(allow_documentation, || {
+        assert!(!is_bogon(ip("192.0.2.55")));
+        assert!(!is_bogon_v4(Ipv4Addr::new(192, 0, 2, 55)));
+        assert!(!ip("192.0.2.55").is_bogon());
+        assert_eq!(classify_v4(Ipv4Addr::new(192, 0, 2, 55)), None);
+
+        // Everything else still follows the default policy.
+        assert!(is_bogon(ip("192.0.2.56")));
+        assert!(is_bogon(ip("::1")));
+        assert!(!is_bogon(ip("8.8.8.8")));
+        classify(ip("10.0.0.1"))
+    });
+    assert_eq!(classified, Some(BogonKind::PrivateUse));
+
+    // The override is gone afterwards.
+    assert!(is_bogon(ip("192.0.2.55")));
+    assert_eq!(classify(ip("192.0.2.55")), Some(BogonKind::Documentation));
+}
+
+#[test]
+fn override_with_filter() {
+    let filter = BogonFilter::default_rules()
+        .allow(BogonKind::UniqueLocal)
+        .deny_network(ip("8.8.8.0"), 24)
+        .build();
+
+    with_policy(filter, || {
+        assert!(!is_bogon(ip("fd00::1")));
+        assert!(is_bogon(ip("8.8.8.8")));
+        assert_eq!(classify(ip("fd00::1")), None);
+        assert_eq!(classify(ip("10.0.0.1")), Some(BogonKind::PrivateUse));
+    });
+}
+
+#[test]
+fn override_nested() {
+    with_policy(allow_documentation, || {
+        with_policy(
+            |_: IpAddr| false,
+            || {
+                assert!(is_bogon(ip("8.8.8.8")));
+                assert!(is_bogon(ip("192.0.2.55")));
+            },
+        );
+
+        assert!(!is_bogon(ip("8.8.8.8")));
+        assert!(!is_bogon(ip("192.0.2.55")));
+    });
+}
+
+#[test]
+fn override_is_thread_local() {
+    with_policy(
+        |_: IpAddr| true,
+        || {
+            assert!(!is_bogon(ip("10.0.0.1")));
+
+            let other = std::thread::spawn(|| is_bogon(ip("10.0.0.1")));
+            assert!(other.join().unwrap());
+        },
+    );
+}
+
+#[test]
+fn override_removed_after_panic() {
+    let result = std::panic::catch_unwind(|| {
+        with_policy(|_: IpAddr| true, || panic!("test failed"));
+    });
+    assert!(result.is_err());
+
+    assert!(is_bogon(ip("10.0.0.1")));
+}
@@ -0,0 +1,243 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Returns a boolean indicating whether an IP address is bogus, judging IPv4-mapped IPv6 addresses
+/// (`::ffff:0:0/96`) by the IPv4 address they embed.
+///
+/// Dual-stack sockets report IPv4 peers as IPv4-mapped IPv6 addresses, which [`is_bogon`] always
+/// considers bogus because they are outside of `2000::/3`. This is the same as
+/// `is_bogon_with(ip, Policy::DEFAULT.with_canonicalize_mapped(true))`, see
+/// [`is_bogon_with`](crate::is_bogon_with).
+///
+/// [`is_bogon`]: crate::is_bogon
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{is_bogon, is_bogon_canonical};
+///
+/// let mapped = Ipv4Addr::new(8, 8, 8, 8).to_ipv6_mapped().into();
+/// assert!(is_bogon(mapped));
+/// assert!(!is_bogon_canonical(mapped));
+///
+/// let mapped = Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped().into();
+/// assert!(is_bogon_canonical(mapped));
+/// ```
+#[inline]
+pub fn is_bogon_canonical(ip_address: IpAddr) -> bool {
+    crate::is_bogon(ip_address.to_canonical())
+}
+
+/// Returns the IPv4 address embedded in a deprecated IPv4-compatible IPv6 address (`::/96`).
+///
+/// The unspecified (`::`) and loopback (`::1`) addresses are inside of `::/96` but are not
+/// IPv4-compatible addresses, so they return `None`. This agrees with
+/// [`BogonKind::Ipv4Compatible`](crate::BogonKind::Ipv4Compatible).
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{Ipv4Addr, Ipv6Addr};
+/// use bogon::ipv4_compatible;
+///
+/// let compatible: Ipv6Addr = "::192.0.2.33".parse().unwrap();
+/// assert_eq!(ipv4_compatible(compatible), Some(Ipv4Addr::new(192, 0, 2, 33)));
+///
+/// assert_eq!(ipv4_compatible(Ipv6Addr::LOCALHOST), None);
+/// assert_eq!(ipv4_compatible(Ipv4Addr::new(192, 0, 2, 33).to_ipv6_mapped()), None);
+/// ```
+#[inline]
+pub const fn ipv4_compatible(ip_address: Ipv6Addr) -> Option<Ipv4Addr> {
+    let bits = ip_address.to_bits();
+    if bits >> 32 == 0 && bits > 1 {
+        Some(Ipv4Addr::from_bits(bits as u32))
+    } else {
+        None
+    }
+}
+
+/// Returns the IPv4 address embedded in a 6to4 address (`2002::/16`), in bits 16 through 47.
+#[inline]
+pub(crate) const fn six_to_four(ip_address: Ipv6Addr) -> Option<Ipv4Addr> {
+    let bits = ip_address.to_bits();
+    if bits >> 112 == 0x2002 {
+        Some(Ipv4Addr::from_bits((bits >> 80) as u32))
+    } else {
+        None
+    }
+}
+
+/// Returns the IPv4 address embedded in the low 32 bits of a NAT64 well-known prefix address
+/// (`64:ff9b::/96`).
+#[inline]
+pub(crate) const fn nat64_well_known(ip_address: Ipv6Addr) -> Option<Ipv4Addr> {
+    let bits = ip_address.to_bits();
+    if bits >> 32 == 0x0064_ff9b << 64 {
+        Some(Ipv4Addr::from_bits(bits as u32))
+    } else {
+        None
+    }
+}
+
+/// Returns the IPv4 address embedded in an IPv4-embedded IPv6 address under a NAT64 prefix, or
+/// `None` if the IP address is not under the prefix.
+///
+/// The IPv4 address is extracted following RFC 6052, which places it differently depending on the
+/// prefix length (32, 40, 48, 56, 64, or 96). Bits 64 through 71 (the "u" octet) are skipped, and
+/// must be zero for prefixes shorter than 96. Any other prefix length returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{Ipv4Addr, Ipv6Addr};
+/// use bogon::nat64_ipv4;
+///
+/// let prefix = "2001:db8:122::".parse().unwrap();
+/// let ip = "2001:db8:122:c000:2:2100::".parse().unwrap();
+/// assert_eq!(nat64_ipv4(ip, prefix, 48), Some(Ipv4Addr::new(192, 0, 2, 33)));
+///
+/// let well_known = "64:ff9b::".parse().unwrap();
+/// assert_eq!(nat64_ipv4("64:ff9b::8.8.8.8".parse().unwrap(), well_known, 96), Some(Ipv4Addr::new(8, 8, 8, 8)));
+/// assert_eq!(nat64_ipv4(Ipv6Addr::LOCALHOST, well_known, 96), None);
+/// ```
+pub const fn nat64_ipv4(
+    ip_address: Ipv6Addr,
+    prefix: Ipv6Addr,
+    prefix_len: u8,
+) -> Option<Ipv4Addr> {
+    let mask = match prefix_len {
+        32 | 40 | 48 | 56 | 64 | 96 => u128::MAX << (128 - prefix_len as u32),
+        _ => return None,
+    };
+    if ip_address.to_bits() & mask != prefix.to_bits() & mask {
+        return None;
+    }
+
+    let o = ip_address.octets();
+    if prefix_len < 96 && o[8] != 0 {
+        return None;
+    }
+
+    let [a, b, c, d] = match prefix_len {
+        32 => [o[4], o[5], o[6], o[7]],
+        40 => [o[5], o[6], o[7], o[9]],
+        48 => [o[6], o[7], o[9], o[10]],
+        56 => [o[7], o[9], o[10], o[11]],
+        64 => [o[9], o[10], o[11], o[12]],
+        _ => [o[12], o[13], o[14], o[15]],
+    };
+    Some(Ipv4Addr::new(a, b, c, d))
+}
+
+/// The parts of a Teredo address (`2001::/32`).
+///
+/// Returned by [`teredo_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct TeredoParts {
+    /// The IPv4 address of the Teredo server.
+    pub server: Ipv4Addr,
+    /// The external IPv4 address of the Teredo client, with the obfuscation removed.
+    pub client: Ipv4Addr,
+    /// The external UDP port of the Teredo client, with the obfuscation removed.
+    pub port: u16,
+    /// The flags, the high bit is set if the client is behind a cone NAT.
+    pub flags: u16,
+}
+
+/// Returns the parts of a Teredo address (`2001::/32`), or `None` if the IP address is not a
+/// Teredo address.
+///
+/// # Examples
+///
+/// ```
+/// use core::net::{Ipv4Addr, Ipv6Addr};
+/// use bogon::teredo_parts;
+///
+/// let parts = teredo_parts("2001:0:4136:e378:8000:63bf:3fff:fdd2".parse().unwrap()).unwrap();
+/// assert_eq!(parts.server, Ipv4Addr::new(65, 54, 227, 120));
+/// assert_eq!(parts.client, Ipv4Addr::new(192, 0, 2, 45));
+/// assert_eq!(parts.port, 40000);
+/// assert_eq!(parts.flags, 0x8000);
+///
+/// assert_eq!(teredo_parts(Ipv6Addr::LOCALHOST), None);
+/// ```
+#[inline]
+pub const fn teredo_parts(ip_address: Ipv6Addr) -> Option<TeredoParts> {
+    let bits = ip_address.to_bits();
+    if bits >> 96 != 0x2001_0000 {
+        return None;
+    }
+
+    Some(TeredoParts {
+        server: Ipv4Addr::from_bits((bits >> 64) as u32),
+        client: Ipv4Addr::from_bits(!(bits as u32)),
+        port: !((bits >> 32) as u16),
+        flags: (bits >> 48) as u16,
+    })
+}
+
+/// A transition mechanism that embeds an IPv4 address in an IPv6 address.
+///
+/// Returned by [`embedded_ipv4`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum TransitionKind {
+    /// IPv4-mapped addresses (`::ffff:0:0/96`).
+    Ipv4Mapped,
+    /// Deprecated IPv4-compatible addresses (`::/96`).
+    Ipv4Compatible,
+    /// 6to4 addresses (`2002::/16`).
+    SixToFour,
+    /// Teredo addresses (`2001::/32`), embedding the client's external IPv4 address.
+    Teredo,
+    /// NAT64 well-known prefix addresses (`64:ff9b::/96`).
+    Nat64WellKnown,
+}
+
+/// Returns the IPv4 address embedded in an IPv6 transition address, along with the mechanism it
+/// was embedded by.
+///
+/// The mechanisms are recognized by prefix alone. Any address in `2001::/32` is decoded as a
+/// Teredo address, even if it wasn't made by a Teredo client, while `2001:1::1` is outside of
+/// `2001::/32` and returns `None`. The unspecified and loopback addresses are not IPv4-compatible,
+/// see [`ipv4_compatible`].
+///
+/// # Examples
+///
+/// ```
+/// use core::net::Ipv4Addr;
+/// use bogon::{embedded_ipv4, TransitionKind};
+///
+/// assert_eq!(
+///     embedded_ipv4("::ffff:192.0.2.1".parse().unwrap()),
+///     Some((Ipv4Addr::new(192, 0, 2, 1), TransitionKind::Ipv4Mapped))
+/// );
+/// assert_eq!(
+///     embedded_ipv4("2002:c000:201::1".parse().unwrap()),
+///     Some((Ipv4Addr::new(192, 0, 2, 1), TransitionKind::SixToFour))
+/// );
+/// assert_eq!(
+///     embedded_ipv4("2001:0:4136:e378:8000:63bf:3fff:fdd2".parse().unwrap()),
+///     Some((Ipv4Addr::new(192, 0, 2, 45), TransitionKind::Teredo))
+/// );
+/// assert_eq!(embedded_ipv4("2606:4700::1111".parse().unwrap()), None);
+/// ```
+pub const fn embedded_ipv4(ip_address: Ipv6Addr) -> Option<(Ipv4Addr, TransitionKind)> {
+    if let Some(ip) = ip_address.to_ipv4_mapped() {
+        return Some((ip, TransitionKind::Ipv4Mapped));
+    }
+    if let Some(ip) = ipv4_compatible(ip_address) {
+        return Some((ip, TransitionKind::Ipv4Compatible));
+    }
+    if let Some(ip) = six_to_four(ip_address) {
+        return Some((ip, TransitionKind::SixToFour));
+    }
+    if let Some(parts) = teredo_parts(ip_address) {
+        return Some((parts.client, TransitionKind::Teredo));
+    }
+    if let Some(ip) = nat64_well_known(ip_address) {
+        return Some((ip, TransitionKind::Nat64WellKnown));
+    }
+    None
+}
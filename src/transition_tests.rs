@@ -0,0 +1,276 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    classify_v6, embedded_ipv4, ipv4_compatible, is_bogon, is_bogon_canonical, is_bogon_v4,
+    nat64_ipv4, teredo_parts, BogonKind, TransitionKind,
+};
+
+#[test]
+fn canonical_mapped() {
+    let mapped = |ip: Ipv4Addr| IpAddr::V6(ip.to_ipv6_mapped());
+
+    assert!(!is_bogon_canonical(mapped(Ipv4Addr::new(8, 8, 8, 8))));
+    assert!(is_bogon_canonical(mapped(Ipv4Addr::new(10, 0, 0, 1))));
+
+    // The default behavior doesn't change.
+    assert!(is_bogon(mapped(Ipv4Addr::new(8, 8, 8, 8))));
+
+    for _ in 0..100_000 {
+        let v4 = Ipv4Addr::from_bits(rand::random());
+        assert_eq!(is_bogon_canonical(mapped(v4)), is_bogon_v4(v4), "{v4}");
+        assert_eq!(is_bogon_canonical(v4.into()), is_bogon_v4(v4), "{v4}");
+    }
+}
+
+#[test]
+fn canonical_not_mapped() {
+    // Only ::ffff:0:0/96 is unmapped, everything else is judged as is.
+    for ip in [
+        "::8.8.8.8",
+        "::fffe:808:808",
+        "::1:ffff:808:808",
+        "64:ff9b::808:808",
+        "2606:4700::1111",
+    ] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(is_bogon_canonical(ip.into()), is_bogon(ip.into()), "{ip}");
+    }
+}
+
+#[test]
+fn ipv4_compatible_boundaries() {
+    let expected = [
+        ("::", None, BogonKind::Unspecified),
+        ("::0.0.0.1", None, BogonKind::Loopback),
+        (
+            "::0.0.0.2",
+            Some(Ipv4Addr::new(0, 0, 0, 2)),
+            BogonKind::Ipv4Compatible,
+        ),
+        (
+            "::192.0.2.33",
+            Some(Ipv4Addr::new(192, 0, 2, 33)),
+            BogonKind::Ipv4Compatible,
+        ),
+        (
+            "::8.8.8.8",
+            Some(Ipv4Addr::new(8, 8, 8, 8)),
+            BogonKind::Ipv4Compatible,
+        ),
+        (
+            "::255.255.255.255",
+            Some(Ipv4Addr::BROADCAST),
+            BogonKind::Ipv4Compatible,
+        ),
+        ("::1:0:0", None, BogonKind::Reserved),
+        ("::ffff:192.0.2.33", None, BogonKind::Ipv4Mapped),
+    ];
+
+    for (ip, embedded, kind) in expected {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(ipv4_compatible(ip), embedded, "{ip}");
+        assert_eq!(classify_v6(ip), Some(kind), "{ip}");
+    }
+}
+
+#[test]
+fn teredo_rfc_4380_example() {
+    // Server 65.54.227.120, client 192.0.2.45:40000 behind a cone NAT.
+    let parts = teredo_parts("2001:0:4136:e378:8000:63bf:3fff:fdd2".parse().unwrap()).unwrap();
+    assert_eq!(parts.server, Ipv4Addr::new(65, 54, 227, 120));
+    assert_eq!(parts.client, Ipv4Addr::new(192, 0, 2, 45));
+    assert_eq!(parts.port, 40000);
+    assert_eq!(parts.flags, 0x8000);
+
+    // Without the cone flag, behind a restricted NAT.
+    let parts = teredo_parts("2001:0:4136:e378::63bf:3fff:fdd2".parse().unwrap()).unwrap();
+    assert_eq!(parts.flags, 0);
+    assert_eq!(parts.client, Ipv4Addr::new(192, 0, 2, 45));
+}
+
+#[test]
+fn teredo_parts_round_trip() {
+    for _ in 0..10_000 {
+        let server = Ipv4Addr::from_bits(rand::random());
+        let client = Ipv4Addr::from_bits(rand::random());
+        let port = rand::random::<u16>();
+        let flags = rand::random::<u16>();
+
+        let [s0, s1] = [(server.to_bits() >> 16) as u16, server.to_bits() as u16];
+        let [c0, c1] = [!(client.to_bits() >> 16) as u16, !client.to_bits() as u16];
+        let ip = Ipv6Addr::new(0x2001, 0, s0, s1, flags, !port, c0, c1);
+
+        let parts = teredo_parts(ip).unwrap();
+        assert_eq!(
+            (parts.server, parts.client, parts.port, parts.flags),
+            (server, client, port, flags),
+            "{ip}"
+        );
+    }
+}
+
+#[test]
+fn teredo_parts_not_teredo() {
+    for ip in [
+        "2001:1::1",
+        "2001:2:4136:e378:8000:63bf:3fff:fdd2",
+        "2002:4136:e378::",
+        "3001:0:4136:e378:8000:63bf:3fff:fdd2",
+        "::",
+    ] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(teredo_parts(ip), None, "{ip}");
+    }
+}
+
+#[test]
+fn nat64_ipv4_rfc_6052_examples() {
+    // RFC 6052, section 2.4: 192.0.2.33 under every prefix length.
+    let expected = [
+        ("2001:db8::", 32, "2001:db8:c000:221::"),
+        ("2001:db8:100::", 40, "2001:db8:1c0:2:21::"),
+        ("2001:db8:122::", 48, "2001:db8:122:c000:2:2100::"),
+        ("2001:db8:122:300::", 56, "2001:db8:122:3c0:0:221::"),
+        ("2001:db8:122:344::", 64, "2001:db8:122:344:c0:2:2100:0"),
+        ("2001:db8:122:344::", 96, "2001:db8:122:344::192.0.2.33"),
+        ("64:ff9b::", 96, "64:ff9b::192.0.2.33"),
+    ];
+
+    for (prefix, prefix_len, ip) in expected {
+        let prefix: Ipv6Addr = prefix.parse().unwrap();
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(
+            nat64_ipv4(ip, prefix, prefix_len),
+            Some(Ipv4Addr::new(192, 0, 2, 33)),
+            "{ip} under {prefix}/{prefix_len}"
+        );
+
+        // Setting the u octet makes the address invalid, or moves it out of a /96 prefix.
+        let u = Ipv6Addr::from_bits(ip.to_bits() | (0xff << 56));
+        assert_eq!(nat64_ipv4(u, prefix, prefix_len), None, "{u}");
+
+        // Addresses outside of the prefix don't embed anything.
+        let outside = Ipv6Addr::from_bits(ip.to_bits() ^ (1 << (128 - u32::from(prefix_len))));
+        assert_eq!(nat64_ipv4(outside, prefix, prefix_len), None, "{outside}");
+    }
+}
+
+#[test]
+fn nat64_ipv4_round_trip() {
+    for prefix_len in [32u8, 40, 48, 56, 64, 96] {
+        for _ in 0..10_000 {
+            let prefix = rand::random::<u128>() & (u128::MAX << (128 - u32::from(prefix_len)));
+            let v4 = rand::random::<u32>();
+
+            // Place the IPv4 address around the u octet (bits 64 through 71).
+            let bits = if prefix_len == 96 {
+                prefix | u128::from(v4)
+            } else {
+                let split = 64 - u32::from(prefix_len);
+                let high = u128::from(v4) >> (32 - split);
+                let low = u128::from(v4) & ((1u128 << (32 - split)) - 1);
+                prefix | (high << 64) | (low << (56 - (32 - split)))
+            };
+
+            let ip = Ipv6Addr::from_bits(bits);
+            let prefix = Ipv6Addr::from_bits(prefix);
+            assert_eq!(
+                nat64_ipv4(ip, prefix, prefix_len),
+                Some(Ipv4Addr::from_bits(v4)),
+                "{ip} under {prefix}/{prefix_len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn nat64_ipv4_invalid_prefix_length() {
+    let ip: Ipv6Addr = "64:ff9b::192.0.2.33".parse().unwrap();
+    for prefix_len in [0, 16, 33, 95, 128] {
+        assert_eq!(
+            nat64_ipv4(ip, "64:ff9b::".parse().unwrap(), prefix_len),
+            None
+        );
+    }
+}
+
+/// Asserts `embedded_ipv4` for every `(address, expected)` pair.
+fn check_embedded(expected: &[(&str, Option<(Ipv4Addr, TransitionKind)>)]) {
+    for &(ip, embedded) in expected {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert_eq!(embedded_ipv4(ip), embedded, "{ip}");
+    }
+}
+
+#[test]
+fn embedded_ipv4_mapped() {
+    let kind = TransitionKind::Ipv4Mapped;
+    check_embedded(&[
+        ("::ffff:0.0.0.0", Some((Ipv4Addr::UNSPECIFIED, kind))),
+        ("::ffff:8.8.8.8", Some((Ipv4Addr::new(8, 8, 8, 8), kind))),
+        ("::ffff:255.255.255.255", Some((Ipv4Addr::BROADCAST, kind))),
+        ("::fffe:808:808", None),
+        ("::1:ffff:808:808", None),
+    ]);
+}
+
+#[test]
+fn embedded_ipv4_compatible() {
+    let kind = TransitionKind::Ipv4Compatible;
+    check_embedded(&[
+        ("::", None),
+        ("::1", None),
+        ("::2", Some((Ipv4Addr::new(0, 0, 0, 2), kind))),
+        ("::192.0.2.33", Some((Ipv4Addr::new(192, 0, 2, 33), kind))),
+        ("::1:c000:221", None),
+    ]);
+}
+
+#[test]
+fn embedded_ipv4_6to4() {
+    let kind = TransitionKind::SixToFour;
+    check_embedded(&[
+        ("2002::", Some((Ipv4Addr::UNSPECIFIED, kind))),
+        (
+            "2002:c000:221::",
+            Some((Ipv4Addr::new(192, 0, 2, 33), kind)),
+        ),
+        (
+            "2002:c000:221:ffff:ffff:ffff:ffff:ffff",
+            Some((Ipv4Addr::new(192, 0, 2, 33), kind)),
+        ),
+        ("2003:c000:221::", None),
+        ("2001:c000:221::", None),
+    ]);
+}
+
+#[test]
+fn embedded_ipv4_teredo() {
+    let kind = TransitionKind::Teredo;
+    check_embedded(&[
+        (
+            "2001:0:4136:e378:8000:63bf:3fff:fdd2",
+            Some((Ipv4Addr::new(192, 0, 2, 45), kind)),
+        ),
+        // Inside of 2001::/32, so decoded even though no Teredo client made it.
+        ("2001::1", Some((Ipv4Addr::new(255, 255, 255, 254), kind))),
+        // Port Control Protocol anycast is outside of 2001::/32.
+        ("2001:1::1", None),
+        ("2001:2::1", None),
+    ]);
+}
+
+#[test]
+fn embedded_ipv4_nat64() {
+    let kind = TransitionKind::Nat64WellKnown;
+    check_embedded(&[
+        ("64:ff9b::", Some((Ipv4Addr::UNSPECIFIED, kind))),
+        (
+            "64:ff9b::192.0.2.33",
+            Some((Ipv4Addr::new(192, 0, 2, 33), kind)),
+        ),
+        ("64:ff9b::1:c000:221", None),
+        ("64:ff9b:1::192.0.2.33", None),
+        ("64:ff9a::192.0.2.33", None),
+    ]);
+}
@@ -0,0 +1,221 @@
+//! The `is_bogon` and `bogon_kind` SQL functions for DataFusion, for the `datafusion` feature.
+
+use std::{
+    any::Any,
+    net::{IpAddr, Ipv4Addr},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+use arrow_array::{cast::AsArray, Array, ArrayRef, BooleanArray, StringArray};
+use datafusion::{
+    arrow::datatypes::{DataType, UInt32Type},
+    common::{exec_err, Result},
+    logical_expr::{ColumnarValue, ScalarUDF, ScalarUDFImpl, Signature, TypeSignature, Volatility},
+    prelude::SessionContext,
+};
+
+use crate::{classify, is_bogon, is_bogon_v4_array, is_bogon_v6_array};
+
+/// Options for the SQL functions [`register_bogon_udfs`] registers.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::UdfOptions;
+/// use datafusion::prelude::SessionContext;
+///
+/// let ctx = SessionContext::new();
+/// UdfOptions::default().strict().register(&ctx);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[must_use]
+pub struct UdfOptions {
+    strict: bool,
+}
+
+impl UdfOptions {
+    /// Fails the query on a row that isn't an IP address, instead of returning NULL for it.
+    pub const fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Registers the `is_bogon` and `bogon_kind` functions with these options, replacing any
+    /// functions with the same names.
+    pub fn register(self, ctx: &SessionContext) {
+        for function in [Function::IsBogon, Function::BogonKind] {
+            ctx.register_udf(ScalarUDF::new_from_impl(BogonUdf::new(function, self)));
+        }
+    }
+}
+
+/// Registers the `is_bogon` and `bogon_kind` SQL functions in a DataFusion session.
+///
+/// Both take an IP address as a string, parsed like [`is_bogon_str`](crate::is_bogon_str), an
+/// IPv4 address as a `UInt32` in host order, like
+/// [`Ipv4Addr::to_bits`](core::net::Ipv4Addr::to_bits) returns it, or the 4 or 16 bytes of an
+/// address in network byte order as `Binary` or `FixedSizeBinary(16)`. `is_bogon` returns a
+/// boolean, see [`is_bogon`], and `bogon_kind` returns the [`label`](crate::BogonKind::label_for) of
+/// the classification, see [`classify`], or NULL if there is none.
+///
+/// NULL rows, and rows that aren't IP addresses, return NULL. Use [`UdfOptions::strict`] to fail
+/// the query on rows that aren't IP addresses instead.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::register_bogon_udfs;
+/// use datafusion::prelude::SessionContext;
+///
+/// let ctx = SessionContext::new();
+/// register_bogon_udfs(&ctx);
+/// // SELECT src_ip FROM flows WHERE NOT is_bogon(src_ip)
+/// ```
+pub fn register_bogon_udfs(ctx: &SessionContext) {
+    UdfOptions::default().register(ctx);
+}
+
+/// The SQL functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    IsBogon,
+    BogonKind,
+}
+
+#[derive(Debug)]
+struct BogonUdf {
+    function: Function,
+    options: UdfOptions,
+    signature: Signature,
+}
+
+impl BogonUdf {
+    fn new(function: Function, options: UdfOptions) -> Self {
+        let types = [
+            DataType::Utf8,
+            DataType::LargeUtf8,
+            DataType::Utf8View,
+            DataType::UInt32,
+            DataType::Binary,
+            DataType::FixedSizeBinary(16),
+        ];
+        let signature = Signature::one_of(
+            types
+                .into_iter()
+                .map(|data_type| TypeSignature::Exact(vec![data_type]))
+                .collect(),
+            Volatility::Immutable,
+        );
+        BogonUdf {
+            function,
+            options,
+            signature,
+        }
+    }
+
+    /// Returns the IP address of each row, or `None` for NULL rows and, unless the options are
+    /// strict, rows that aren't IP addresses.
+    fn addresses(&self, array: &ArrayRef) -> Result<Vec<Option<IpAddr>>> {
+        let parse = |text: Option<&str>| self.or_null(text, |text| text.parse().ok());
+        let decode = |bytes: Option<&[u8]>| self.or_null(bytes, from_octets);
+        match array.data_type() {
+            DataType::Utf8 => array.as_string::<i32>().iter().map(parse).collect(),
+            DataType::LargeUtf8 => array.as_string::<i64>().iter().map(parse).collect(),
+            DataType::Utf8View => array.as_string_view().iter().map(parse).collect(),
+            DataType::UInt32 => Ok(array
+                .as_primitive::<UInt32Type>()
+                .iter()
+                .map(|bits| bits.map(|bits| IpAddr::V4(Ipv4Addr::from_bits(bits))))
+                .collect()),
+            DataType::Binary => array.as_binary::<i32>().iter().map(decode).collect(),
+            DataType::FixedSizeBinary(_) => {
+                array.as_fixed_size_binary().iter().map(decode).collect()
+            }
+            data_type => exec_err!("{} doesn't take {data_type}", self.name()),
+        }
+    }
+
+    /// Returns the IP address of a row, `None` for a NULL row, and `None` or an error for a row
+    /// that isn't an IP address.
+    fn or_null<T: core::fmt::Debug + Copy>(
+        &self,
+        row: Option<T>,
+        address: impl Fn(T) -> Option<IpAddr>,
+    ) -> Result<Option<IpAddr>> {
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        match address(row) {
+            Some(ip) => Ok(Some(ip)),
+            None if self.options.strict => {
+                exec_err!("{}: {row:?} is not an IP address", self.name())
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Returns the IP address of 4 or 16 bytes in network byte order.
+fn from_octets(bytes: &[u8]) -> Option<IpAddr> {
+    if let Ok(octets) = <[u8; 4]>::try_from(bytes) {
+        Some(IpAddr::from(octets))
+    } else {
+        <[u8; 16]>::try_from(bytes).ok().map(IpAddr::from)
+    }
+}
+
+impl ScalarUDFImpl for BogonUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        match self.function {
+            Function::IsBogon => "is_bogon",
+            Function::BogonKind => "bogon_kind",
+        }
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(match self.function {
+            Function::IsBogon => DataType::Boolean,
+            Function::BogonKind => DataType::Utf8,
+        })
+    }
+
+    fn invoke_batch(&self, args: &[ColumnarValue], number_rows: usize) -> Result<ColumnarValue> {
+        let [arg] = args else {
+            return exec_err!("{} takes one argument, not {}", self.name(), args.len());
+        };
+        let array = arg.clone().into_array(number_rows)?;
+
+        let result: ArrayRef = match (self.function, array.data_type()) {
+            // The integers and 16-byte addresses are always valid, so the kernels check them.
+            (Function::IsBogon, DataType::UInt32) => {
+                Arc::new(is_bogon_v4_array(array.as_primitive()))
+            }
+            (Function::IsBogon, DataType::FixedSizeBinary(16)) => {
+                Arc::new(is_bogon_v6_array(array.as_fixed_size_binary()))
+            }
+            (Function::IsBogon, _) => Arc::new(
+                self.addresses(&array)?
+                    .into_iter()
+                    .map(|ip| ip.map(is_bogon))
+                    .collect::<BooleanArray>(),
+            ),
+            (Function::BogonKind, _) => Arc::new(
+                self.addresses(&array)?
+                    .into_iter()
+                    .map(|ip| ip.and_then(|ip| Some(classify(ip)?.label_for(ip))))
+                    .collect::<StringArray>(),
+            ),
+        };
+        Ok(ColumnarValue::Array(result))
+    }
+}
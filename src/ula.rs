@@ -0,0 +1,42 @@
+use core::net::Ipv6Addr;
+
+/// Returns `true` if the IPv6 address is a locally assigned Unique-Local address (`fd00::/8`).
+///
+/// The other half of the Unique-Local space, `fc00::/8`, has the L bit cleared and is reserved
+/// for a future, centrally assigned definition, so it isn't a usable ULA. Both halves are
+/// [`is_private`](crate::is_private).
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_ula;
+///
+/// assert!(is_ula("fd12:3456:789a::1".parse().unwrap()));
+/// assert!(!is_ula("fc00::1".parse().unwrap()));
+/// assert!(!is_ula("2001:db8::1".parse().unwrap()));
+/// ```
+#[inline]
+pub const fn is_ula(ip_address: Ipv6Addr) -> bool {
+    ip_address.octets()[0] == 0xfd
+}
+
+/// Generates a locally assigned Unique-Local `/48` prefix as described in RFC 4193.
+///
+/// The prefix is `fd00::/8` followed by a 40-bit random Global ID. Use a cryptographically secure
+/// random number generator, the Global ID is what keeps prefixes of different sites from colliding.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{generate_ula_prefix, is_bogon_v6, is_ula};
+///
+/// let (prefix, prefix_len) = generate_ula_prefix(&mut rand::thread_rng());
+/// assert_eq!(prefix_len, 48);
+/// assert!(is_ula(prefix));
+/// assert!(is_bogon_v6(prefix));
+/// ```
+#[cfg(feature = "rand")]
+pub fn generate_ula_prefix<R: rand::Rng + ?Sized>(rng: &mut R) -> (Ipv6Addr, u8) {
+    let global_id = u128::from(rng.gen::<u64>() & 0xff_ffff_ffff);
+    (Ipv6Addr::from_bits((0xfd << 120) | (global_id << 80)), 48)
+}
@@ -0,0 +1,55 @@
+use core::net::Ipv6Addr;
+
+use crate::is_ula;
+
+#[test]
+fn ula() {
+    for ip in [
+        "fd00::",
+        "fd12:3456:789a::1",
+        "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+    ] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert!(is_ula(ip), "{ip}");
+    }
+
+    for ip in [
+        "fc00::",
+        "fcff::1",
+        "fe00::",
+        "fe80::1",
+        "::fd00:0:0",
+        "2001:db8::1",
+    ] {
+        let ip: Ipv6Addr = ip.parse().unwrap();
+        assert!(!is_ula(ip), "{ip}");
+    }
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn generate_ula_prefix() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{classify_v6, is_bogon_v6, BogonKind};
+
+    for seed in 0..10_000 {
+        let (prefix, prefix_len) = crate::generate_ula_prefix(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(prefix_len, 48);
+        assert!(is_ula(prefix), "{prefix}");
+        assert!(is_bogon_v6(prefix), "{prefix}");
+        assert_eq!(
+            classify_v6(prefix),
+            Some(BogonKind::UniqueLocal),
+            "{prefix}"
+        );
+
+        // Only the Global ID is set.
+        assert_eq!(prefix.to_bits() & ((1 << 80) - 1), 0, "{prefix}");
+    }
+
+    // The Global ID is random.
+    let mut rng = StdRng::seed_from_u64(0);
+    let first = crate::generate_ula_prefix(&mut rng);
+    assert!((0..100).any(|_| crate::generate_ula_prefix(&mut rng) != first));
+}
@@ -0,0 +1,214 @@
+//! Reloading a list of networks when its file changes.
+//!
+//! The file is polled instead of watched with operating system notifications, which works the same
+//! on every platform and file system, including network file systems and bind mounts, and needs no
+//! dependencies. Every poll reads the whole file, lists are small enough for that to be cheap.
+
+use core::{net::IpAddr, time::Duration};
+use std::{
+    boxed::Box,
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::Hasher,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+    thread::{self, JoinHandle},
+    time::{Instant, SystemTime},
+};
+
+use crate::{BogonList, BogonSet, ParseListError, Verdict};
+
+/// How often the file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long the file must stay unchanged before it is reloaded.
+///
+/// Editors and configuration management often write a file in several steps, or replace it with
+/// a temporary file, so reloading as soon as a change is seen could read a half-written file.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// What the watcher knows about a version of the file: its length, modification time, and a hash of
+/// its contents, `None` if the file couldn't be read.
+///
+/// The length and modification time alone miss rewrites of the same length within the resolution
+/// of the file system's timestamps, a second or two on some file systems, and copies that keep the
+/// modification time.
+pub(crate) type Fingerprint = Option<(u64, SystemTime, u64)>;
+
+fn fingerprint(path: &Path) -> Fingerprint {
+    let metadata = fs::metadata(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    hasher.write(&fs::read(path).ok()?);
+    Some((metadata.len(), metadata.modified().ok()?, hasher.finish()))
+}
+
+/// Decides when a changed file has settled and should be reloaded.
+#[derive(Debug)]
+pub(crate) struct Debouncer {
+    loaded: Fingerprint,
+    pending: Option<(Fingerprint, Instant)>,
+}
+
+impl Debouncer {
+    pub(crate) fn new(loaded: Fingerprint) -> Self {
+        Debouncer {
+            loaded,
+            pending: None,
+        }
+    }
+
+    /// Records the fingerprint seen at `now`, and returns a boolean indicating whether the file
+    /// should be reloaded.
+    pub(crate) fn poll(&mut self, seen: Fingerprint, now: Instant) -> bool {
+        if seen == self.loaded {
+            self.pending = None;
+            return false;
+        }
+
+        match self.pending {
+            Some((pending, since)) if pending == seen => {
+                if now.duration_since(since) < DEBOUNCE {
+                    return false;
+                }
+                // Reloading fails or succeeds, either way this version was dealt with.
+                self.loaded = seen;
+                self.pending = None;
+                true
+            }
+            _ => {
+                self.pending = Some((seen, now));
+                false
+            }
+        }
+    }
+}
+
+/// Reads a list and denies all of its networks.
+fn load(path: &Path) -> Result<BogonSet, ParseListError> {
+    let list = BogonList::from_reader(BufReader::new(fs::File::open(path)?))?;
+    Ok(list
+        .networks()
+        .map(|network| (network, Verdict::Deny))
+        .collect())
+}
+
+/// A [`BogonSet`] read from a list file, reloaded whenever the file changes.
+///
+/// Returned by [`watch_list`]. Dropping it stops watching the file.
+#[derive(Debug)]
+pub struct WatchedBogonSet {
+    set: Arc<RwLock<Arc<BogonSet>>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WatchedBogonSet {
+    /// Returns the networks of the last version of the file that was read successfully.
+    pub fn set(&self) -> Arc<BogonSet> {
+        Arc::clone(&self.set.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns a boolean indicating whether the last version of the file that was read
+    /// successfully denies an IP address.
+    pub fn is_bogon(&self, ip_address: IpAddr) -> bool {
+        self.set().is_bogon(ip_address)
+    }
+}
+
+impl Drop for WatchedBogonSet {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Reads a list file, in the format of [`BogonList`], and reloads it whenever it changes.
+///
+/// Every network of the file is denied. A background thread reads the file every 100 milliseconds,
+/// and reloads it once it stayed unchanged for 250 milliseconds. When the changed
+/// file can't be read, the previous networks stay in place until the file is fixed.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read at first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let bogons = bogon::watch_list(Path::new("/etc/firewall/bogons.txt"))?;
+///
+/// // Later, on every packet.
+/// let bogus = bogons.is_bogon("203.0.113.7".parse().unwrap());
+/// # Ok::<_, bogon::ParseListError>(())
+/// ```
+pub fn watch_list(path: &Path) -> Result<WatchedBogonSet, ParseListError> {
+    watch_list_with(path, |_| {})
+}
+
+/// Like [`watch_list`], but calls `on_error` with the error whenever a changed file can't be read.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read at first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// let bogons = bogon::watch_list_with(Path::new("/etc/firewall/bogons.txt"), |error| {
+///     eprintln!("keeping the previous bogons: {error}");
+/// })?;
+/// # Ok::<_, bogon::ParseListError>(())
+/// ```
+pub fn watch_list_with(
+    path: &Path,
+    on_error: impl FnMut(ParseListError) + Send + 'static,
+) -> Result<WatchedBogonSet, ParseListError> {
+    let path = path.to_path_buf();
+    let loaded = fingerprint(&path);
+    let set = Arc::new(RwLock::new(Arc::new(load(&path)?)));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let thread = thread::Builder::new().name("bogon-watch".into()).spawn({
+        let set = Arc::clone(&set);
+        let stop = Arc::clone(&stop);
+        let on_error: Box<dyn FnMut(ParseListError) + Send> = Box::new(on_error);
+        move || watch(path, loaded, set, stop, on_error)
+    })?;
+
+    Ok(WatchedBogonSet {
+        set,
+        stop,
+        thread: Some(thread),
+    })
+}
+
+fn watch(
+    path: PathBuf,
+    loaded: Fingerprint,
+    set: Arc<RwLock<Arc<BogonSet>>>,
+    stop: Arc<AtomicBool>,
+    mut on_error: Box<dyn FnMut(ParseListError) + Send>,
+) {
+    let mut debouncer = Debouncer::new(loaded);
+
+    while !stop.load(Ordering::Relaxed) {
+        if debouncer.poll(fingerprint(&path), Instant::now()) {
+            match load(&path) {
+                Ok(new) => *set.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(new),
+                Err(error) => on_error(error),
+            }
+        }
+        thread::park_timeout(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::watch::{Debouncer, Fingerprint};
+
+fn version(len: u64) -> Fingerprint {
+    Some((len, SystemTime::UNIX_EPOCH + Duration::from_secs(len), len))
+}
+
+#[test]
+fn unchanged_file_is_not_reloaded() {
+    let start = Instant::now();
+    let mut debouncer = Debouncer::new(version(1));
+    for ms in (0..2000).step_by(100) {
+        assert!(!debouncer.poll(version(1), start + Duration::from_millis(ms)));
+    }
+}
+
+#[test]
+fn changed_file_is_reloaded_once_settled() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut debouncer = Debouncer::new(version(1));
+
+    assert!(!debouncer.poll(version(2), at(0)));
+    assert!(!debouncer.poll(version(2), at(100)));
+    assert!(!debouncer.poll(version(2), at(200)));
+    assert!(debouncer.poll(version(2), at(300)));
+
+    // The reloaded version isn't reloaded again.
+    assert!(!debouncer.poll(version(2), at(400)));
+    assert!(!debouncer.poll(version(2), at(1000)));
+}
+
+#[test]
+fn flapping_file_waits_for_the_last_change() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut debouncer = Debouncer::new(version(1));
+
+    // An editor removes the file, writes a temporary file, and moves it in place.
+    assert!(!debouncer.poll(None, at(0)));
+    assert!(!debouncer.poll(version(2), at(100)));
+    assert!(!debouncer.poll(version(3), at(200)));
+    assert!(!debouncer.poll(version(3), at(400)));
+    assert!(debouncer.poll(version(3), at(500)));
+}
+
+#[test]
+fn change_reverted_before_settling_is_not_reloaded() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut debouncer = Debouncer::new(version(1));
+
+    assert!(!debouncer.poll(version(2), at(0)));
+    assert!(!debouncer.poll(version(1), at(100)));
+    assert!(!debouncer.poll(version(1), at(1000)));
+}
+
+#[test]
+fn missing_file_is_reported_once() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut debouncer = Debouncer::new(version(1));
+
+    assert!(!debouncer.poll(None, at(0)));
+    assert!(debouncer.poll(None, at(300)));
+    assert!(!debouncer.poll(None, at(600)));
+
+    // The file is back.
+    assert!(!debouncer.poll(version(1), at(700)));
+    assert!(debouncer.poll(version(1), at(1000)));
+}
+
+#[test]
+fn rewrite_of_the_same_length_and_time_is_reloaded() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+    let mut debouncer = Debouncer::new(Some((13, modified, 1)));
+
+    assert!(!debouncer.poll(Some((13, modified, 2)), at(0)));
+    assert!(debouncer.poll(Some((13, modified, 2)), at(300)));
+}
@@ -0,0 +1,115 @@
+//! Checking addresses straight out of packet headers, as bytes in network byte order.
+
+use core::fmt;
+
+use crate::{is_bogon_v4_bits, is_bogon_v6_bits};
+
+/// Whether addresses are fine or bogus, as a `BogonSet` or [`from_wire`] says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The addresses are fine.
+    Allow,
+    /// The addresses are bogus.
+    Deny,
+}
+
+/// The error returned when the bytes of an address are neither an IPv4 nor an IPv6 address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WireError {
+    /// The bytes are neither 4 nor 16 bytes long.
+    WrongLength {
+        /// The number of bytes.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::WrongLength { actual } => {
+                write!(f, "an address on the wire has 4 or 16 bytes, not {actual}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WireError {}
+
+/// Returns a boolean indicating whether an IPv4 address, as its octets in network byte order
+/// (big-endian, as on the wire), is bogus.
+///
+/// Like [`is_bogon_v4_bits`], only the compiled-in tables are consulted: the overrides of the
+/// `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_v4_octets;
+///
+/// assert_eq!(is_bogon_v4_octets([127, 0, 0, 1]), true);
+/// assert_eq!(is_bogon_v4_octets([8, 8, 8, 8]), false);
+/// ```
+#[inline]
+pub const fn is_bogon_v4_octets(octets: [u8; 4]) -> bool {
+    is_bogon_v4_bits(u32::from_be_bytes(octets))
+}
+
+/// Returns a boolean indicating whether an IPv6 address, as its octets in network byte order
+/// (big-endian, as on the wire), is bogus.
+///
+/// Like [`is_bogon_v6_bits`], only the compiled-in tables are consulted: the overrides of the
+/// `test-util` and `runtime` features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::is_bogon_v6_octets;
+///
+/// let mut loopback = [0; 16];
+/// loopback[15] = 1;
+/// assert_eq!(is_bogon_v6_octets(loopback), true);
+///
+/// let cloudflare = [0x26, 0x06, 0x47, 0, 0x47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x11, 0x11];
+/// assert_eq!(is_bogon_v6_octets(cloudflare), false);
+/// ```
+#[inline]
+pub fn is_bogon_v6_octets(octets: [u8; 16]) -> bool {
+    is_bogon_v6_bits(u128::from_be_bytes(octets))
+}
+
+/// Returns the verdict of an address field of a packet, 4 bytes for IPv4 or 16 bytes for IPv6, in
+/// network byte order (big-endian, as on the wire).
+///
+/// See [`is_bogon_v4_octets`] and [`is_bogon_v6_octets`].
+///
+/// # Errors
+///
+/// Returns [`WireError::WrongLength`] if the bytes are neither 4 nor 16 bytes long.
+///
+/// # Examples
+///
+/// ```
+/// use bogon::{from_wire, Verdict, WireError};
+///
+/// // The source address of an IPv4 header.
+/// let header = [
+///     0x45, 0, 0, 0x54, 0, 0, 0x40, 0, 0x40, 0x01, 0, 0, 192, 168, 1, 1, 8, 8, 8, 8,
+/// ];
+/// assert_eq!(from_wire(&header[12..16]), Ok(Verdict::Deny));
+/// assert_eq!(from_wire(&header[16..20]), Ok(Verdict::Allow));
+/// assert_eq!(from_wire(&header[12..20]), Err(WireError::WrongLength { actual: 8 }));
+/// ```
+pub fn from_wire(bytes: &[u8]) -> Result<Verdict, WireError> {
+    let bogus = if let Ok(&octets) = <&[u8; 4]>::try_from(bytes) {
+        is_bogon_v4_octets(octets)
+    } else if let Ok(&octets) = <&[u8; 16]>::try_from(bytes) {
+        is_bogon_v6_octets(octets)
+    } else {
+        return Err(WireError::WrongLength {
+            actual: bytes.len(),
+        });
+    };
+    Ok(if bogus { Verdict::Deny } else { Verdict::Allow })
+}
@@ -0,0 +1,107 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    from_wire, is_bogon_v4, is_bogon_v4_octets, is_bogon_v6, is_bogon_v6_octets, Verdict, WireError,
+};
+
+#[test]
+fn known_v4_octets() {
+    for bogon in [
+        [0, 0, 0, 0],
+        [10, 1, 2, 3],
+        [127, 0, 0, 1],
+        [169, 254, 169, 254],
+        [192, 0, 0, 8],
+        [192, 0, 2, 1],
+        [224, 0, 0, 1],
+        [255, 255, 255, 255],
+    ] {
+        assert!(is_bogon_v4_octets(bogon), "{bogon:?}");
+        assert_eq!(from_wire(&bogon), Ok(Verdict::Deny), "{bogon:?}");
+    }
+    for good in [[1, 1, 1, 1], [8, 8, 8, 8], [192, 0, 0, 9], [193, 0, 2, 1]] {
+        assert!(!is_bogon_v4_octets(good), "{good:?}");
+        assert_eq!(from_wire(&good), Ok(Verdict::Allow), "{good:?}");
+    }
+}
+
+#[test]
+fn known_v6_octets() {
+    for bogon in [
+        Ipv6Addr::UNSPECIFIED,
+        Ipv6Addr::LOCALHOST,
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1),
+        Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1),
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+        Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1),
+    ] {
+        assert!(is_bogon_v6_octets(bogon.octets()), "{bogon}");
+        assert_eq!(from_wire(&bogon.octets()), Ok(Verdict::Deny), "{bogon}");
+    }
+    for good in [
+        Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111),
+        Ipv6Addr::new(0x2001, 0x4860, 0x4860, 0, 0, 0, 0, 0x8888),
+        Ipv6Addr::new(0x2001, 3, 0, 0, 0, 0, 0, 1),
+    ] {
+        assert!(!is_bogon_v6_octets(good.octets()), "{good}");
+        assert_eq!(from_wire(&good.octets()), Ok(Verdict::Allow), "{good}");
+    }
+}
+
+#[test]
+fn octets_are_big_endian() {
+    // 10.0.0.1 is private, 1.0.0.10 isn't.
+    assert!(is_bogon_v4_octets([10, 0, 0, 1]));
+    assert!(!is_bogon_v4_octets([1, 0, 0, 10]));
+
+    // ::1 is loopback, and 2606:4700:4700::1111 with its bytes reversed is outside of 2000::/3.
+    let mut octets = [0; 16];
+    octets[15] = 1;
+    assert!(is_bogon_v6_octets(octets));
+    let cloudflare = Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111).octets();
+    let mut reversed = cloudflare;
+    reversed.reverse();
+    assert!(!is_bogon_v6_octets(cloudflare));
+    assert!(is_bogon_v6_octets(reversed));
+}
+
+#[test]
+fn wrong_lengths() {
+    for len in [0, 1, 3, 5, 8, 15, 17, 32] {
+        assert_eq!(
+            from_wire(&vec![0; len]),
+            Err(WireError::WrongLength { actual: len })
+        );
+    }
+    assert_eq!(
+        WireError::WrongLength { actual: 5 }.to_string(),
+        "an address on the wire has 4 or 16 bytes, not 5"
+    );
+}
+
+#[test]
+fn random_octets_match_the_addresses() {
+    let mut rng = StdRng::seed_from_u64(80);
+    for _ in 0..1 << 16 {
+        let octets: [u8; 4] = rng.gen();
+        let ip = Ipv4Addr::from(octets);
+        assert_eq!(is_bogon_v4_octets(octets), is_bogon_v4(ip), "{ip}");
+
+        let mut octets: [u8; 16] = rng.gen();
+        // Half of them inside of 2000::/3, where most of the tables are.
+        if rng.gen() {
+            octets[0] = 0x20 | octets[0] & 0x1f;
+        }
+        let ip = Ipv6Addr::from(octets);
+        assert_eq!(is_bogon_v6_octets(octets), is_bogon_v6(ip), "{ip}");
+
+        let expected = if is_bogon_v6(ip) {
+            Verdict::Deny
+        } else {
+            Verdict::Allow
+        };
+        assert_eq!(from_wire(&octets), Ok(expected), "{ip}");
+    }
+}
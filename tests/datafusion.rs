@@ -0,0 +1,223 @@
+//! SQL queries over a small in-memory table of flows.
+#![cfg(feature = "datafusion")]
+
+use std::{net::IpAddr, sync::Arc};
+
+use bogon::{classify, is_bogon, register_bogon_udfs, UdfOptions};
+use datafusion::{
+    arrow::{
+        array::{
+            Array, ArrayRef, BinaryArray, BooleanArray, FixedSizeBinaryArray, StringArray,
+            UInt32Array,
+        },
+        record_batch::RecordBatch,
+    },
+    error::Result,
+    prelude::SessionContext,
+};
+
+/// The source addresses of the flows, as text.
+const SOURCES: [Option<&str>; 7] = [
+    Some("10.0.0.1"),
+    Some("8.8.8.8"),
+    None,
+    Some("not an address"),
+    Some("::1"),
+    Some("2606:4700:4700::1111"),
+    Some("192.0.0.9"),
+];
+
+fn address(source: Option<&str>) -> Option<IpAddr> {
+    source.and_then(|source| source.parse().ok())
+}
+
+/// Returns a session with a `flows` table holding the sources as text, as `UInt32` and
+/// `FixedSizeBinary(16)` where they are IPv4 or IPv6 addresses, and as 4 or 16 bytes.
+fn session(ctx: SessionContext) -> SessionContext {
+    let ips = SOURCES.map(address);
+    let text: ArrayRef = Arc::new(StringArray::from(SOURCES.to_vec()));
+    let v4: ArrayRef = Arc::new(
+        ips.iter()
+            .map(|ip| match ip {
+                Some(IpAddr::V4(ip)) => Some(ip.to_bits()),
+                _ => None,
+            })
+            .collect::<UInt32Array>(),
+    );
+    let v6: ArrayRef = Arc::new(
+        FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            ips.iter().map(|ip| match ip {
+                Some(IpAddr::V6(ip)) => Some(ip.octets()),
+                _ => None,
+            }),
+            16,
+        )
+        .unwrap(),
+    );
+    let octets = ips.map(|ip| match ip {
+        Some(IpAddr::V4(ip)) => Some(ip.octets().to_vec()),
+        Some(IpAddr::V6(ip)) => Some(ip.octets().to_vec()),
+        // A truncated address.
+        None => Some(vec![10, 0]),
+    });
+    let bytes: ArrayRef = Arc::new(BinaryArray::from_iter(octets));
+    let batch = RecordBatch::try_from_iter([
+        ("src_text", text),
+        ("src_v4", v4),
+        ("src_v6", v6),
+        ("src_bytes", bytes),
+    ])
+    .unwrap();
+    ctx.register_batch("flows", batch).unwrap();
+    ctx
+}
+
+/// Runs a query on a fresh runtime, returning the first column of its results.
+fn query(ctx: &SessionContext, sql: &str) -> Result<ArrayRef> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let batches = runtime.block_on(async { ctx.sql(sql).await?.collect().await })?;
+    let columns = batches
+        .iter()
+        .map(|batch| batch.column(0).as_ref())
+        .collect::<Vec<_>>();
+    Ok(datafusion::arrow::compute::concat(&columns)?)
+}
+
+fn booleans(array: &ArrayRef) -> Vec<Option<bool>> {
+    let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+    array.iter().collect()
+}
+
+fn strings(array: &ArrayRef) -> Vec<Option<String>> {
+    let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+    array.iter().map(|row| row.map(String::from)).collect()
+}
+
+fn default_session() -> SessionContext {
+    let ctx = session(SessionContext::new());
+    register_bogon_udfs(&ctx);
+    ctx
+}
+
+#[test]
+fn is_bogon_of_text() {
+    let ctx = default_session();
+    let verdicts = query(&ctx, "SELECT is_bogon(src_text) FROM flows").unwrap();
+    let expected = SOURCES.map(|source| address(source).map(is_bogon));
+    assert_eq!(booleans(&verdicts), expected);
+    assert_eq!(
+        expected,
+        [
+            Some(true),
+            Some(false),
+            None,
+            None,
+            Some(true),
+            Some(false),
+            Some(false)
+        ]
+    );
+}
+
+#[test]
+fn is_bogon_of_integers_and_bytes() {
+    let ctx = default_session();
+    let ips = SOURCES.map(address);
+    let v4 = ips.map(|ip| match ip {
+        Some(IpAddr::V4(ip)) => Some(is_bogon(IpAddr::V4(ip))),
+        _ => None,
+    });
+    let v6 = ips.map(|ip| match ip {
+        Some(IpAddr::V6(ip)) => Some(is_bogon(IpAddr::V6(ip))),
+        _ => None,
+    });
+    let verdicts = query(&ctx, "SELECT is_bogon(src_v4) FROM flows").unwrap();
+    assert_eq!(booleans(&verdicts), v4);
+    let verdicts = query(&ctx, "SELECT is_bogon(src_v6) FROM flows").unwrap();
+    assert_eq!(booleans(&verdicts), v6);
+
+    // The truncated addresses are NULL.
+    let verdicts = query(&ctx, "SELECT is_bogon(src_bytes) FROM flows").unwrap();
+    assert_eq!(booleans(&verdicts), ips.map(|ip| ip.map(is_bogon)));
+}
+
+#[test]
+fn where_not_is_bogon() {
+    let ctx = default_session();
+    let sources = query(
+        &ctx,
+        "SELECT src_text FROM flows WHERE NOT is_bogon(src_text) ORDER BY src_text",
+    )
+    .unwrap();
+    assert_eq!(
+        strings(&sources),
+        [
+            Some("192.0.0.9".to_string()),
+            Some("2606:4700:4700::1111".to_string()),
+            Some("8.8.8.8".to_string())
+        ]
+    );
+}
+
+#[test]
+fn bogon_kind_labels() {
+    let ctx = default_session();
+    let kinds = query(&ctx, "SELECT bogon_kind(src_text) FROM flows").unwrap();
+    let expected = SOURCES
+        .map(|source| address(source).and_then(|ip| Some(classify(ip)?.label_for(ip).to_string())));
+    assert_eq!(strings(&kinds), expected);
+    assert_eq!(expected[0].as_deref(), Some("private-use (RFC 1918)"));
+    assert_eq!(expected[1], None);
+    assert_eq!(expected[4].as_deref(), Some("loopback (RFC 4291)"));
+
+    let kinds = query(&ctx, "SELECT bogon_kind(src_v4) FROM flows").unwrap();
+    assert_eq!(
+        strings(&kinds)[0].as_deref(),
+        Some("private-use (RFC 1918)")
+    );
+}
+
+#[test]
+fn literals() {
+    let ctx = default_session();
+    let verdicts = query(&ctx, "SELECT is_bogon('127.0.0.1'), is_bogon('1.1.1.1')").unwrap();
+    assert_eq!(booleans(&verdicts), [Some(true)]);
+    let verdicts = query(&ctx, "SELECT is_bogon(arrow_cast(167772161, 'UInt32'))").unwrap();
+    assert_eq!(booleans(&verdicts), [Some(true)]);
+}
+
+#[test]
+fn strict_fails_on_invalid_rows() {
+    let ctx = session(SessionContext::new());
+    UdfOptions::default().strict().register(&ctx);
+
+    let error = query(&ctx, "SELECT is_bogon(src_text) FROM flows").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("is_bogon: \"not an address\" is not an IP address"),
+        "{error}"
+    );
+    let error = query(&ctx, "SELECT bogon_kind(src_bytes) FROM flows").unwrap_err();
+    assert!(
+        error.to_string().contains("is not an IP address"),
+        "{error}"
+    );
+
+    // NULL rows are still NULL.
+    let verdicts = query(
+        &ctx,
+        "SELECT is_bogon(src_text) FROM flows WHERE src_text IS NULL",
+    )
+    .unwrap();
+    assert_eq!(booleans(&verdicts), [None]);
+}
+
+#[test]
+fn one_argument() {
+    let ctx = default_session();
+    assert!(query(&ctx, "SELECT is_bogon('10.0.0.1', '10.0.0.2')").is_err());
+    assert!(query(&ctx, "SELECT bogon_kind()").is_err());
+}
@@ -0,0 +1,110 @@
+//! The runtime table is global, so these tests run in their own process.
+#![cfg(feature = "runtime")]
+
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Barrier, Mutex,
+    },
+    thread,
+};
+
+use bogon::{
+    classify, clear_runtime_table, is_bogon, is_bogon_v4, runtime_table, set_runtime_table,
+    BogonKind, BogonTable,
+};
+
+// The tests in this file share the installed table.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn table(networks: &[(&str, u8)]) -> BogonTable {
+    networks
+        .iter()
+        .map(|&(network, prefix_len)| (network.parse().unwrap(), prefix_len))
+        .collect()
+}
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+#[test]
+fn set_and_clear() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    assert!(runtime_table().is_none());
+    assert!(is_bogon(ip("10.0.0.1")));
+    assert!(!is_bogon(ip("8.8.8.8")));
+
+    set_runtime_table(table(&[
+        ("8.8.8.0", 24),
+        ("192.0.2.0", 24),
+        ("2001:db8::", 32),
+    ]));
+    assert!(runtime_table().is_some());
+    assert!(is_bogon(ip("8.8.8.8")));
+    assert!(!is_bogon(ip("10.0.0.1")));
+    assert!(is_bogon(ip("2001:db8::1")));
+    assert!(!is_bogon(ip("::1")));
+
+    // Classifications are kept for addresses the table flags.
+    assert_eq!(classify(ip("192.0.2.1")), Some(BogonKind::Documentation));
+    assert_eq!(classify(ip("8.8.8.8")), None);
+    assert_eq!(classify(ip("10.0.0.1")), None);
+    assert_eq!(classify(ip("192.0.2.1")), Some(BogonKind::Documentation));
+
+    // The compiled-in data is the fallback.
+    clear_runtime_table();
+    assert!(runtime_table().is_none());
+    assert!(is_bogon(ip("10.0.0.1")));
+    assert!(!is_bogon(ip("8.8.8.8")));
+    assert_eq!(classify(ip("10.0.0.1")), Some(BogonKind::PrivateUse));
+}
+
+#[test]
+fn swap_while_reading() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    // Each table contains both or neither address of a pair, so a snapshot that disagrees on a
+    // pair is torn.
+    let a = table(&[("1.0.0.0", 8), ("9.0.0.0", 8)]);
+    let b = table(&[("2.0.0.0", 8), ("8.0.0.0", 8)]);
+    let done = Arc::new(AtomicBool::new(false));
+    let start = Arc::new(Barrier::new(9));
+
+    let readers = (0..8)
+        .map(|_| {
+            let (done, start) = (Arc::clone(&done), Arc::clone(&start));
+            thread::spawn(move || {
+                start.wait();
+                while !done.load(Ordering::Relaxed) {
+                    is_bogon_v4(Ipv4Addr::new(1, 2, 3, 4));
+                    is_bogon_v4(Ipv4Addr::new(8, 7, 6, 5));
+
+                    if let Some(table) = runtime_table() {
+                        assert_eq!(table.contains(ip("1.2.3.4")), table.contains(ip("9.8.7.6")));
+                        assert_eq!(table.contains(ip("2.3.4.5")), table.contains(ip("8.7.6.5")));
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    start.wait();
+    for i in 0..10_000 {
+        match i % 3 {
+            0 => set_runtime_table(a.clone()),
+            1 => set_runtime_table(b.clone()),
+            _ => clear_runtime_table(),
+        }
+    }
+    done.store(true, Ordering::Relaxed);
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    clear_runtime_table();
+    assert!(is_bogon(ip("10.0.0.1")));
+}
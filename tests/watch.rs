@@ -0,0 +1,111 @@
+//! These tests rewrite files and wait for the watcher thread to notice.
+#![cfg(feature = "watch")]
+
+use std::{
+    fs,
+    io::Write,
+    net::IpAddr,
+    path::PathBuf,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use bogon::{watch_list, watch_list_with, ParseListError};
+
+/// A file in a fresh temporary directory, removed with the directory when dropped.
+struct TempFile {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn new(name: &str, contents: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("bogon-watch-{}-{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bogons.txt");
+        fs::write(&path, contents).unwrap();
+        TempFile { dir, path }
+    }
+
+    /// Replaces the file the way editors do, by renaming a temporary file over it.
+    fn replace(&self, contents: &str) {
+        let temp = self.dir.join("bogons.txt.tmp");
+        fs::write(&temp, contents).unwrap();
+        fs::rename(&temp, &self.path).unwrap();
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn ip(ip: &str) -> IpAddr {
+    ip.parse().unwrap()
+}
+
+/// Waits until a condition holds, failing after a few seconds.
+fn wait_for(mut condition: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !condition() {
+        assert!(Instant::now() < deadline, "timed out");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn verdict_flips_after_rewrite() {
+    let file = TempFile::new("rewrite", "192.0.2.0/24\n");
+    let bogons = watch_list(&file.path).unwrap();
+    assert!(bogons.is_bogon(ip("192.0.2.1")));
+    assert!(!bogons.is_bogon(ip("198.51.100.1")));
+
+    file.replace("# Moved\n198.51.100.0/24\n");
+    wait_for(|| bogons.is_bogon(ip("198.51.100.1")));
+    assert!(!bogons.is_bogon(ip("192.0.2.1")));
+
+    fs::write(&file.path, "192.0.2.0/24\n2001:db8::/32\n").unwrap();
+    wait_for(|| bogons.is_bogon(ip("2001:db8::1")));
+    assert!(bogons.is_bogon(ip("192.0.2.1")));
+}
+
+#[test]
+fn rewrite_in_place_with_the_same_length_and_time_is_reloaded() {
+    let file = TempFile::new("same-length", "192.0.2.0/24\n");
+    let modified = fs::metadata(&file.path).unwrap().modified().unwrap();
+    let bogons = watch_list(&file.path).unwrap();
+
+    let rewritten = fs::File::options().write(true).open(&file.path).unwrap();
+    (&rewritten).write_all(b"192.0.3.0/24\n").unwrap();
+    rewritten.set_modified(modified).unwrap();
+    wait_for(|| bogons.is_bogon(ip("192.0.3.1")));
+    assert!(!bogons.is_bogon(ip("192.0.2.1")));
+}
+
+#[test]
+fn invalid_rewrite_keeps_previous_data() {
+    let file = TempFile::new("invalid", "192.0.2.0/24\n");
+    let (errors, received) = mpsc::channel();
+    let bogons = watch_list_with(&file.path, move |error| errors.send(error).unwrap()).unwrap();
+
+    file.replace("192.0.2.0/24\nnot a network\n");
+    let error = received.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(matches!(error, ParseListError::InvalidLine { line: 2, .. }));
+    assert!(bogons.is_bogon(ip("192.0.2.1")));
+
+    // The broken version is only reported once.
+    thread::sleep(Duration::from_millis(500));
+    assert!(received.try_recv().is_err());
+
+    file.replace("198.51.100.0/24\n");
+    wait_for(|| bogons.is_bogon(ip("198.51.100.1")));
+}
+
+#[test]
+fn missing_file_fails() {
+    let file = TempFile::new("missing", "");
+    let error = watch_list(&file.dir.join("missing.txt")).unwrap_err();
+    assert!(matches!(error, ParseListError::Io(_)));
+}
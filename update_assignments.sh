@@ -1 +0,0 @@
-wget -O ipv6-unicast-address-assignments.csv https://www.iana.org/assignments/ipv6-unicast-address-assignments/ipv6-unicast-address-assignments.csv
\ No newline at end of file
@@ -0,0 +1,241 @@
+//! Maintenance tasks for the workspace, run with `cargo xtask <task>`.
+
+// Shared with the `bogon-data` build script.
+#[path = "../../bogon-data/src/csv_cache.rs"]
+#[allow(dead_code)]
+mod csv_cache;
+#[path = "../../bogon-data/src/iana_client.rs"]
+mod iana_client;
+#[path = "../../bogon-data/src/registry_files.rs"]
+#[allow(dead_code)]
+mod registry_files;
+#[path = "../../bogon-data/src/sha256.rs"]
+#[allow(dead_code)]
+mod sha256;
+mod snapshot;
+#[cfg(test)]
+mod snapshot_tests;
+#[path = "../../bogon-data/src/staleness.rs"]
+#[allow(dead_code)]
+mod staleness;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+    time::SystemTime,
+};
+
+use csv_cache::{Fetcher, Response};
+use iana_client::Iana;
+use registry_files::{
+    RegistryFile, IPV4_ADDRESS_SPACE, IPV4_SPECIAL, IPV6_ASSIGNMENTS, IPV6_ASSIGNMENTS_SHA256,
+    IPV6_SPECIAL, SNAPSHOT_DATE,
+};
+use snapshot::{allocation_diff, RowChange, MAX_SHRINK_PERCENT};
+
+const USAGE: &str = "\
+Usage: cargo xtask update-data [--force] [--no-download]
+
+Downloads the IANA registries into bogon-data, prints what changed, and regenerates the checked-in
+tables the `pregenerated` feature compiles.
+
+Options:
+  --force        Replace registries that lost more than 20% of their rows
+  --no-download  Only regenerate the checked-in tables from the bundled registries";
+
+/// The registries bundled with `bogon-data`.
+const REGISTRIES: [RegistryFile; 4] = [
+    IPV6_ASSIGNMENTS,
+    IPV4_ADDRESS_SPACE,
+    IPV6_SPECIAL,
+    IPV4_SPECIAL,
+];
+
+/// The options of `update-data`.
+#[derive(Debug, Default)]
+struct Options {
+    force: bool,
+    no_download: bool,
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.split_first() {
+        Some((task, options)) if task == "update-data" => {
+            parse_options(options).and_then(update_data)
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    for arg in args {
+        match arg.as_str() {
+            "--force" => options.force = true,
+            "--no-download" => options.no_download = true,
+            _ => return Err(format!("Unknown option `{arg}`\n\n{USAGE}")),
+        }
+    }
+    Ok(options)
+}
+
+/// Returns the directory of the `bogon-data` crate.
+fn data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("bogon-data")
+}
+
+fn update_data(options: Options) -> Result<(), String> {
+    let dir = data_dir();
+    if !options.no_download {
+        download(&dir, options.force)?;
+    }
+    regenerate(&dir)
+}
+
+/// A downloaded registry, and the bundled copy it replaces.
+struct Snapshot {
+    registry: &'static RegistryFile,
+    path: PathBuf,
+    old: String,
+    new: String,
+    rows: RowChange,
+}
+
+/// Downloads the registries, and replaces the bundled copies unless one of them lost suspiciously
+/// many rows.
+fn download(dir: &Path, force: bool) -> Result<(), String> {
+    let mut iana = Iana::new(
+        &format!("bogon-xtask/{}", env!("CARGO_PKG_VERSION")),
+        env::consts::OS,
+        env::consts::ARCH,
+    );
+
+    // Every registry is downloaded and checked before any of them is replaced.
+    let mut snapshots = Vec::new();
+    for registry in &REGISTRIES {
+        let path = dir.join(registry.name);
+        let old = fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+        println!("Downloading {}", registry.url);
+        let new = match iana.fetch(registry.url, None) {
+            Ok(Response::Modified { body, .. }) => body,
+            Ok(Response::NotModified) => unreachable!("unconditional requests are never 304"),
+            Err(error) => return Err(format!("Failed to download {}: {error}", registry.name)),
+        };
+        let rows = RowChange::new(registry, &old, &new)?;
+        snapshots.push(Snapshot {
+            registry,
+            path,
+            old,
+            new,
+            rows,
+        });
+    }
+
+    println!();
+    for snapshot in &snapshots {
+        println!("{}", snapshot.rows);
+    }
+    let suspicious = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.rows.is_suspicious())
+        .map(|snapshot| snapshot.registry.name)
+        .collect::<Vec<_>>();
+    if !suspicious.is_empty() && !force {
+        return Err(format!(
+            "\nRefusing to replace {}, which lost more than {MAX_SHRINK_PERCENT}% of their rows. \
+             Check the downloads, and rerun with --force to replace them anyway.",
+            suspicious.join(", ")
+        ));
+    }
+
+    // The IPv6 unicast address assignments come first.
+    let assignments = &snapshots[0];
+    let diff = allocation_diff(&assignments.old, &assignments.new)
+        .map_err(|error| format!("{}: {error}", IPV6_ASSIGNMENTS.name))?;
+    println!();
+    if diff.is_empty() {
+        println!("The bogus IPv6 addresses didn't change");
+    } else {
+        print!("{diff}");
+    }
+
+    for snapshot in &snapshots {
+        if snapshot.old != snapshot.new {
+            let path = &snapshot.path;
+            fs::write(path, &snapshot.new)
+                .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+        }
+    }
+    let digest = sha256::sha256(assignments.new.as_bytes());
+    let path = dir.join(IPV6_ASSIGNMENTS_SHA256);
+    fs::write(&path, format!("{digest}  {}\n", IPV6_ASSIGNMENTS.name))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+
+    // The build script warns once the recorded date is too long ago.
+    let today = staleness::build_date(None, SystemTime::now());
+    let path = dir.join(SNAPSHOT_DATE);
+    fs::write(&path, format!("{today}\n"))
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+/// Builds `bogon-data` from the bundled registries, and copies the generated tables into
+/// `src/generated`.
+fn regenerate(dir: &Path) -> Result<(), String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .args(["build", "--package", "bogon-data", "--message-format=json"])
+        .env_remove("BOGON_CSV_SHA256");
+    for registry in &REGISTRIES {
+        command.env_remove(registry.env);
+    }
+    let output = command
+        .output()
+        .map_err(|error| format!("Failed to run cargo: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to build bogon-data:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // The build script writes the tables to its `OUT_DIR`.
+    let out_dir = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|message| {
+            message["reason"] == "build-script-executed"
+                && message["package_id"]
+                    .as_str()
+                    .is_some_and(|id| id.contains("bogon-data"))
+        })
+        .and_then(|message| Some(PathBuf::from(message["out_dir"].as_str()?)))
+        .ok_or("Cargo didn't report where the bogon-data build script wrote the tables")?;
+
+    let generated = dir.join("src/generated");
+    let entries = fs::read_dir(&out_dir)
+        .map_err(|error| format!("Failed to read {}: {error}", out_dir.display()))?;
+    for entry in entries {
+        let path = entry.map_err(|error| error.to_string())?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            let target = generated.join(path.file_name().unwrap());
+            fs::copy(&path, &target)
+                .map_err(|error| format!("Failed to write {}: {error}", target.display()))?;
+        }
+    }
+    println!("\nRegenerated {}", generated.display());
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//! Comparing a downloaded snapshot of a registry with the bundled copy it replaces.
+
+use std::fmt;
+
+use bogon::{build_v6_table, diff_tables, parse_iana_v6_csv, CsvError, TableDiff};
+use bogon_data::iana_csv::{parse_address_space, parse_assignments, parse_special_registry};
+
+use crate::registry_files::{RegistryFile, IPV4_ADDRESS_SPACE, IPV6_ASSIGNMENTS};
+
+/// The largest share of its rows, in percent, a registry may lose between two snapshots before the
+/// update is refused without `--force`.
+pub(crate) const MAX_SHRINK_PERCENT: usize = 20;
+
+/// The number of rows of a bundled registry and of its downloaded replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RowChange {
+    pub(crate) name: &'static str,
+    pub(crate) old: usize,
+    pub(crate) new: usize,
+}
+
+impl RowChange {
+    /// Returns the rows of both snapshots of a registry, parsed with the parsers the build script
+    /// uses, so that a malformed download is caught before it replaces the bundled copy.
+    pub(crate) fn new(registry: &RegistryFile, old: &str, new: &str) -> Result<Self, String> {
+        let rows =
+            |csv| count_rows(registry, csv).map_err(|error| format!("{}: {error}", registry.name));
+        Ok(RowChange {
+            name: registry.name,
+            old: rows(old)?,
+            new: rows(new)?,
+        })
+    }
+
+    /// Returns a boolean indicating whether the registry lost more than `MAX_SHRINK_PERCENT` of its
+    /// rows, which is more likely a truncated download or a changed format than IANA withdrawing
+    /// that much of a registry.
+    pub(crate) fn is_suspicious(&self) -> bool {
+        self.new * 100 < self.old * (100 - MAX_SHRINK_PERCENT)
+    }
+}
+
+impl fmt::Display for RowChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.old == self.new {
+            write!(f, "{}: {} rows, unchanged", self.name, self.old)
+        } else {
+            write!(f, "{}: {} -> {} rows", self.name, self.old, self.new)
+        }
+    }
+}
+
+/// Returns the number of rows of a registry CSV file.
+///
+/// Rows of the IPv4 address space with an unknown status don't count, the build script skips them.
+fn count_rows(registry: &RegistryFile, csv: &str) -> Result<usize, CsvError> {
+    let rows = if registry.name == IPV6_ASSIGNMENTS.name {
+        parse_assignments(csv, &mut Vec::new()).map(|rows| rows.len())
+    } else if registry.name == IPV4_ADDRESS_SPACE.name {
+        parse_address_space(csv, &mut Vec::new()).map(|blocks| blocks.iter().flatten().count())
+    } else {
+        parse_special_registry(csv).map(|rows| rows.len())
+    };
+    // The first malformed record is enough to refuse the download.
+    rows.map_err(|mut errors| errors.swap_remove(0))
+}
+
+/// Returns how the bogus IPv6 addresses change between two snapshots of the IPv6 unicast address
+/// assignments.
+pub(crate) fn allocation_diff(old: &str, new: &str) -> Result<TableDiff, CsvError> {
+    let table = |csv| parse_iana_v6_csv(csv).map(|allocations| build_v6_table(&allocations));
+    Ok(diff_tables(&table(old)?, &table(new)?))
+}
@@ -0,0 +1,112 @@
+use std::net::Ipv6Addr;
+
+use crate::{
+    registry_files::{IPV4_ADDRESS_SPACE, IPV4_SPECIAL, IPV6_ASSIGNMENTS, IPV6_SPECIAL},
+    snapshot::{allocation_diff, RowChange},
+};
+
+const OLD: &str = include_str!("../tests/fixtures/ipv6-old.csv");
+const NEW: &str = include_str!("../tests/fixtures/ipv6-new.csv");
+const TRUNCATED: &str = include_str!("../tests/fixtures/ipv6-truncated.csv");
+
+fn rows(old: usize, new: usize) -> RowChange {
+    RowChange {
+        name: IPV6_ASSIGNMENTS.name,
+        old,
+        new,
+    }
+}
+
+#[test]
+fn counts_the_rows_of_both_snapshots() {
+    let change = RowChange::new(&IPV6_ASSIGNMENTS, OLD, NEW).unwrap();
+    assert_eq!(change, rows(6, 7));
+    assert!(!change.is_suspicious());
+    assert_eq!(
+        change.to_string(),
+        "ipv6-unicast-address-assignments.csv: 6 -> 7 rows"
+    );
+}
+
+#[test]
+fn unchanged_registry() {
+    let change = RowChange::new(&IPV6_ASSIGNMENTS, OLD, OLD).unwrap();
+    assert_eq!(
+        change.to_string(),
+        "ipv6-unicast-address-assignments.csv: 6 rows, unchanged"
+    );
+}
+
+#[test]
+fn counts_the_rows_of_every_bundled_registry() {
+    for registry in [
+        IPV6_ASSIGNMENTS,
+        IPV4_ADDRESS_SPACE,
+        IPV6_SPECIAL,
+        IPV4_SPECIAL,
+    ] {
+        let change = RowChange::new(&registry, registry.bundled, registry.bundled).unwrap();
+        assert!(change.old > 0, "{}", registry.name);
+        assert_eq!(change.old, change.new, "{}", registry.name);
+    }
+    let bundled = IPV4_ADDRESS_SPACE.bundled;
+    let change = RowChange::new(&IPV4_ADDRESS_SPACE, bundled, bundled).unwrap();
+    assert_eq!(change.new, 256);
+}
+
+#[test]
+fn truncated_download_is_suspicious() {
+    let change = RowChange::new(&IPV6_ASSIGNMENTS, OLD, TRUNCATED).unwrap();
+    assert_eq!(change, rows(6, 2));
+    assert!(change.is_suspicious());
+}
+
+#[test]
+fn shrink_threshold() {
+    assert!(!rows(100, 80).is_suspicious());
+    assert!(rows(100, 79).is_suspicious());
+    assert!(!rows(5, 4).is_suspicious());
+    assert!(rows(5, 3).is_suspicious());
+    assert!(rows(1, 0).is_suspicious());
+    assert!(!rows(0, 0).is_suspicious());
+    assert!(!rows(100, 150).is_suspicious());
+}
+
+#[test]
+fn malformed_download_is_an_error() {
+    let error = RowChange::new(&IPV6_ASSIGNMENTS, OLD, "not,a,registry\n").unwrap_err();
+    assert!(
+        error.starts_with("ipv6-unicast-address-assignments.csv: "),
+        "{error}"
+    );
+}
+
+#[test]
+fn diff_of_the_allocations() {
+    let diff = allocation_diff(OLD, NEW).unwrap();
+    assert!(!diff.is_empty());
+
+    let returned = "2001:600::1".parse::<Ipv6Addr>().unwrap();
+    let allocated = "2a00::1".parse::<Ipv6Addr>().unwrap();
+    let still_allocated = "2001:200::1".parse::<Ipv6Addr>().unwrap();
+    assert!(diff.became_bogus().contains_v6(returned));
+    assert!(!diff.became_routable().contains_v6(returned));
+    assert!(diff.became_routable().contains_v6(allocated));
+    assert!(!diff.became_bogus().contains_v6(allocated));
+    assert!(!diff.became_bogus().contains_v6(still_allocated));
+    assert!(!diff.became_routable().contains_v6(still_allocated));
+
+    let printed = diff.to_string();
+    assert!(printed.contains("+ 2001:600::/23\n"), "{printed}");
+    assert!(printed.contains("- 2a00::/12\n"), "{printed}");
+}
+
+#[test]
+fn diff_of_unchanged_allocations_is_empty() {
+    assert!(allocation_diff(OLD, OLD).unwrap().is_empty());
+}
+
+#[test]
+fn diff_of_a_malformed_download_is_an_error() {
+    assert!(allocation_diff(OLD, "not,a,registry\n").is_err());
+}